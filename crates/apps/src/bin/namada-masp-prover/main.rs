@@ -0,0 +1,52 @@
+//! A service generating the zero-knowledge proofs of MASP transactions on
+//! behalf of clients that cannot afford to do it themselves. Clients delegate
+//! their proofs to it by setting `NAMADA_MASP_REMOTE_PROVER` to its address.
+//! The service and its clients must share the secret token set in
+//! `NAMADA_MASP_REMOTE_PROVER_TOKEN`, which encrypts and authenticates their
+//! messages.
+
+use std::net::TcpListener;
+
+use color_eyre::eyre::{eyre, Result, WrapErr};
+use namada_apps_lib::logging;
+use namada_apps_lib::masp::prover::{
+    load_local_tx_prover, masp_params_present, serve, ProverKey,
+    ProverServiceConfig, ENV_VAR_MASP_REMOTE_PROVER_TOKEN,
+};
+use namada_apps_lib::masp::ENV_VAR_MASP_PARAMS_DIR;
+use tracing_subscriber::filter::LevelFilter;
+
+/// The address to listen on, unless one is given as the first argument
+const DEFAULT_LISTEN_ADDRESS: &str = "127.0.0.1:26670";
+
+fn main() -> Result<()> {
+    // init error reporting
+    color_eyre::install()?;
+
+    // init logging
+    let _log_guard = logging::init_from_env_or(LevelFilter::INFO)?;
+
+    let listen_address = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| DEFAULT_LISTEN_ADDRESS.to_string());
+
+    if !masp_params_present() {
+        return Err(eyre!(
+            "MASP parameters not present, download them or point \
+             {ENV_VAR_MASP_PARAMS_DIR} to their location"
+        ));
+    }
+    let key = ProverKey::from_env().wrap_err_with(|| {
+        format!(
+            "{ENV_VAR_MASP_REMOTE_PROVER_TOKEN} must be set to a non-empty \
+             secret token shared with the clients"
+        )
+    })?;
+    let prover = load_local_tx_prover();
+
+    let listener = TcpListener::bind(&listen_address)
+        .wrap_err_with(|| format!("Failed to bind to {listen_address}"))?;
+    tracing::info!("MASP prover listening on {listen_address}");
+    serve(listener, &prover, &key, &ProverServiceConfig::default())
+        .wrap_err("MASP prover service failed")
+}
@@ -242,6 +242,7 @@ pub mod cmds {
                 .subcommand(TxCustom::def().display_order(1))
                 .subcommand(TxTransparentTransfer::def().display_order(1))
                 .subcommand(TxShieldedTransfer::def().display_order(1))
+                .subcommand(TxShieldedConsolidate::def().display_order(1))
                 .subcommand(TxShieldingTransfer::def().display_order(1))
                 .subcommand(TxUnshieldingTransfer::def().display_order(1))
                 .subcommand(TxIbcTransfer::def().display_order(1))
@@ -251,6 +252,9 @@ pub mod cmds {
                 // Governance transactions
                 .subcommand(TxInitProposal::def().display_order(1))
                 .subcommand(TxVoteProposal::def().display_order(1))
+                .subcommand(TxCancelProposal::def().display_order(1))
+                .subcommand(TxVetoProposal::def().display_order(1))
+                .subcommand(TxDelegateVote::def().display_order(1))
                 // PoS transactions
                 .subcommand(TxBecomeValidator::def().display_order(2))
                 .subcommand(TxInitValidator::def().display_order(2))
@@ -261,9 +265,13 @@ pub mod cmds {
                 .subcommand(Unbond::def().display_order(2))
                 .subcommand(Withdraw::def().display_order(2))
                 .subcommand(Redelegate::def().display_order(2))
+                .subcommand(RebalanceDelegations::def().display_order(2))
                 .subcommand(ClaimRewards::def().display_order(2))
+                .subcommand(AutoCompound::def().display_order(2))
                 .subcommand(TxCommissionRateChange::def().display_order(2))
+                .subcommand(TxCommissionScheduleChange::def().display_order(2))
                 .subcommand(TxChangeConsensusKey::def().display_order(2))
+                .subcommand(TxChangeValidatorKeys::def().display_order(2))
                 .subcommand(TxMetadataChange::def().display_order(2))
                 // Ethereum bridge transactions
                 .subcommand(AddToEthBridgePool::def().display_order(3))
@@ -289,16 +297,23 @@ pub mod cmds {
                 .subcommand(QueryProposal::def().display_order(5))
                 .subcommand(QueryProposalVotes::def().display_order(5))
                 .subcommand(QueryProposalResult::def().display_order(5))
+                .subcommand(QueryProposalDryRun::def().display_order(5))
                 .subcommand(QueryProtocolParameters::def().display_order(5))
                 .subcommand(QueryPgf::def().display_order(5))
                 .subcommand(QueryValidatorState::def().display_order(5))
                 .subcommand(QueryCommissionRate::def().display_order(5))
                 .subcommand(QueryRewards::def().display_order(5))
+                .subcommand(QueryRewardsHistory::def().display_order(5))
                 .subcommand(QueryMetaData::def().display_order(5))
+                .subcommand(QueryValidatorsByMetadata::def().display_order(5))
+                .subcommand(QueryValidatorSelfBond::def().display_order(5))
+                .subcommand(QueryValidatorLiveness::def().display_order(5))
+                .subcommand(QuerySlashSimulation::def().display_order(5))
                 // Actions
                 .subcommand(SignTx::def().display_order(6))
                 .subcommand(ShieldedSync::def().display_order(6))
                 .subcommand(GenIbcShieldingTransfer::def().display_order(6))
+                .subcommand(DisposableKeys::def().display_order(6))
                 // Utils
                 .subcommand(ClientUtils::def().display_order(7))
         }
@@ -310,6 +325,9 @@ pub mod cmds {
                 Self::parse_with_ctx(matches, TxTransparentTransfer);
             let tx_shielded_transfer =
                 Self::parse_with_ctx(matches, TxShieldedTransfer);
+            let tx_shielded_consolidate =
+                Self::parse_with_ctx(matches, TxShieldedConsolidate);
+            let disposable_keys = Self::parse_with_ctx(matches, DisposableKeys);
             let tx_shielding_transfer =
                 Self::parse_with_ctx(matches, TxShieldingTransfer);
             let tx_unshielding_transfer =
@@ -333,21 +351,34 @@ pub mod cmds {
                 Self::parse_with_ctx(matches, TxInitProposal);
             let tx_vote_proposal =
                 Self::parse_with_ctx(matches, TxVoteProposal);
+            let tx_cancel_proposal =
+                Self::parse_with_ctx(matches, TxCancelProposal);
+            let tx_veto_proposal =
+                Self::parse_with_ctx(matches, TxVetoProposal);
+            let tx_delegate_vote =
+                Self::parse_with_ctx(matches, TxDelegateVote);
             let tx_update_steward_commission =
                 Self::parse_with_ctx(matches, TxUpdateStewardCommission);
             let tx_resign_steward =
                 Self::parse_with_ctx(matches, TxResignSteward);
             let tx_commission_rate_change =
                 Self::parse_with_ctx(matches, TxCommissionRateChange);
+            let tx_commission_schedule_change =
+                Self::parse_with_ctx(matches, TxCommissionScheduleChange);
             let tx_change_consensus_key =
                 Self::parse_with_ctx(matches, TxChangeConsensusKey);
+            let tx_change_validator_keys =
+                Self::parse_with_ctx(matches, TxChangeValidatorKeys);
             let tx_change_metadata =
                 Self::parse_with_ctx(matches, TxMetadataChange);
             let bond = Self::parse_with_ctx(matches, Bond);
             let unbond = Self::parse_with_ctx(matches, Unbond);
             let withdraw = Self::parse_with_ctx(matches, Withdraw);
             let redelegate = Self::parse_with_ctx(matches, Redelegate);
+            let rebalance_delegations =
+                Self::parse_with_ctx(matches, RebalanceDelegations);
             let claim_rewards = Self::parse_with_ctx(matches, ClaimRewards);
+            let auto_compound = Self::parse_with_ctx(matches, AutoCompound);
             let query_epoch = Self::parse_with_ctx(matches, QueryEpoch);
             let query_next_epoch_info =
                 Self::parse_with_ctx(matches, QueryNextEpochInfo);
@@ -364,6 +395,8 @@ pub mod cmds {
                 Self::parse_with_ctx(matches, QueryBondedStake);
             let query_slashes = Self::parse_with_ctx(matches, QuerySlashes);
             let query_rewards = Self::parse_with_ctx(matches, QueryRewards);
+            let query_rewards_history =
+                Self::parse_with_ctx(matches, QueryRewardsHistory);
             let query_delegations =
                 Self::parse_with_ctx(matches, QueryDelegations);
             let query_find_validator =
@@ -375,6 +408,8 @@ pub mod cmds {
                 Self::parse_with_ctx(matches, QueryProposalVotes);
             let query_proposal_result =
                 Self::parse_with_ctx(matches, QueryProposalResult);
+            let query_proposal_dry_run =
+                Self::parse_with_ctx(matches, QueryProposalDryRun);
             let query_protocol_parameters =
                 Self::parse_with_ctx(matches, QueryProtocolParameters);
             let query_pgf = Self::parse_with_ctx(matches, QueryPgf);
@@ -383,6 +418,14 @@ pub mod cmds {
             let query_commission =
                 Self::parse_with_ctx(matches, QueryCommissionRate);
             let query_metadata = Self::parse_with_ctx(matches, QueryMetaData);
+            let query_validators_by_metadata =
+                Self::parse_with_ctx(matches, QueryValidatorsByMetadata);
+            let query_validator_self_bond =
+                Self::parse_with_ctx(matches, QueryValidatorSelfBond);
+            let query_validator_liveness =
+                Self::parse_with_ctx(matches, QueryValidatorLiveness);
+            let query_slash_simulation =
+                Self::parse_with_ctx(matches, QuerySlashSimulation);
             let add_to_eth_bridge_pool =
                 Self::parse_with_ctx(matches, AddToEthBridgePool);
             let sign_tx = Self::parse_with_ctx(matches, SignTx);
@@ -393,6 +436,8 @@ pub mod cmds {
             tx_custom
                 .or(tx_transparent_transfer)
                 .or(tx_shielded_transfer)
+                .or(tx_shielded_consolidate)
+                .or(disposable_keys)
                 .or(tx_shielding_transfer)
                 .or(tx_unshielding_transfer)
                 .or(tx_ibc_transfer)
@@ -401,10 +446,15 @@ pub mod cmds {
                 .or(tx_reveal_pk)
                 .or(tx_init_proposal)
                 .or(tx_vote_proposal)
+                .or(tx_cancel_proposal)
+                .or(tx_veto_proposal)
+                .or(tx_delegate_vote)
                 .or(tx_become_validator)
                 .or(tx_init_validator)
                 .or(tx_commission_rate_change)
+                .or(tx_commission_schedule_change)
                 .or(tx_change_consensus_key)
+                .or(tx_change_validator_keys)
                 .or(tx_change_metadata)
                 .or(tx_unjail_validator)
                 .or(tx_deactivate_validator)
@@ -413,7 +463,9 @@ pub mod cmds {
                 .or(unbond)
                 .or(withdraw)
                 .or(redelegate)
+                .or(rebalance_delegations)
                 .or(claim_rewards)
+                .or(auto_compound)
                 .or(add_to_eth_bridge_pool)
                 .or(tx_update_steward_commission)
                 .or(tx_resign_steward)
@@ -428,6 +480,7 @@ pub mod cmds {
                 .or(query_bonded_stake)
                 .or(query_slashes)
                 .or(query_rewards)
+                .or(query_rewards_history)
                 .or(query_delegations)
                 .or(query_find_validator)
                 .or(query_result)
@@ -435,11 +488,16 @@ pub mod cmds {
                 .or(query_proposal)
                 .or(query_proposal_votes)
                 .or(query_proposal_result)
+                .or(query_proposal_dry_run)
                 .or(query_protocol_parameters)
                 .or(query_pgf)
                 .or(query_validator_state)
                 .or(query_commission)
                 .or(query_metadata)
+                .or(query_validators_by_metadata)
+                .or(query_validator_self_bond)
+                .or(query_validator_liveness)
+                .or(query_slash_simulation)
                 .or(query_account)
                 .or(sign_tx)
                 .or(shielded_sync)
@@ -484,6 +542,8 @@ pub mod cmds {
         TxCustom(TxCustom),
         TxTransparentTransfer(TxTransparentTransfer),
         TxShieldedTransfer(TxShieldedTransfer),
+        TxShieldedConsolidate(TxShieldedConsolidate),
+        DisposableKeys(DisposableKeys),
         TxShieldingTransfer(TxShieldingTransfer),
         TxUnshieldingTransfer(TxUnshieldingTransfer),
         TxIbcTransfer(TxIbcTransfer),
@@ -493,19 +553,26 @@ pub mod cmds {
         TxBecomeValidator(TxBecomeValidator),
         TxInitValidator(TxInitValidator),
         TxCommissionRateChange(TxCommissionRateChange),
+        TxCommissionScheduleChange(TxCommissionScheduleChange),
         TxChangeConsensusKey(TxChangeConsensusKey),
+        TxChangeValidatorKeys(TxChangeValidatorKeys),
         TxMetadataChange(TxMetadataChange),
         TxUnjailValidator(TxUnjailValidator),
         TxDeactivateValidator(TxDeactivateValidator),
         TxReactivateValidator(TxReactivateValidator),
         TxInitProposal(TxInitProposal),
         TxVoteProposal(TxVoteProposal),
+        TxCancelProposal(TxCancelProposal),
+        TxVetoProposal(TxVetoProposal),
+        TxDelegateVote(TxDelegateVote),
         TxRevealPk(TxRevealPk),
         Bond(Bond),
         Unbond(Unbond),
         Withdraw(Withdraw),
         ClaimRewards(ClaimRewards),
+        AutoCompound(AutoCompound),
         Redelegate(Redelegate),
+        RebalanceDelegations(RebalanceDelegations),
         AddToEthBridgePool(AddToEthBridgePool),
         TxUpdateStewardCommission(TxUpdateStewardCommission),
         TxResignSteward(TxResignSteward),
@@ -521,6 +588,10 @@ pub mod cmds {
         QueryBondedStake(QueryBondedStake),
         QueryCommissionRate(QueryCommissionRate),
         QueryMetaData(QueryMetaData),
+        QueryValidatorsByMetadata(QueryValidatorsByMetadata),
+        QueryValidatorSelfBond(QueryValidatorSelfBond),
+        QueryValidatorLiveness(QueryValidatorLiveness),
+        QuerySlashSimulation(QuerySlashSimulation),
         QuerySlashes(QuerySlashes),
         QueryDelegations(QueryDelegations),
         QueryFindValidator(QueryFindValidator),
@@ -528,10 +599,12 @@ pub mod cmds {
         QueryProposal(QueryProposal),
         QueryProposalVotes(QueryProposalVotes),
         QueryProposalResult(QueryProposalResult),
+        QueryProposalDryRun(QueryProposalDryRun),
         QueryProtocolParameters(QueryProtocolParameters),
         QueryPgf(QueryPgf),
         QueryValidatorState(QueryValidatorState),
         QueryRewards(QueryRewards),
+        QueryRewardsHistory(QueryRewardsHistory),
         SignTx(SignTx),
         ShieldedSync(ShieldedSync),
         GenIbcShieldingTransfer(GenIbcShieldingTransfer),
@@ -1247,6 +1320,37 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct QueryProposalDryRun(
+        pub args::QueryProposalDryRun<args::CliTypes>,
+    );
+
+    impl SubCmd for QueryProposalDryRun {
+        const CMD: &'static str = "query-proposal-dry-run";
+
+        fn parse(matches: &ArgMatches) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                QueryProposalDryRun(args::QueryProposalDryRun::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Dry run the wasm code of a proposal against the state of \
+                     the last committed block, as if the proposal had passed. \
+                     Reports the result, the storage changes, the emitted \
+                     events and the gas used, without modifying the chain \
+                     state. Only the current state is supported, the proposal \
+                     cannot be dry run at an earlier block height."
+                ))
+                .add_args::<args::QueryProposalDryRun<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct QueryProtocolParameters(
         pub args::QueryProtocolParameters<args::CliTypes>,
@@ -1358,6 +1462,60 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct TxShieldedConsolidate(
+        pub args::TxShieldedConsolidate<crate::cli::args::CliTypes>,
+    );
+
+    impl SubCmd for TxShieldedConsolidate {
+        const CMD: &'static str = "shielded-consolidate";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                TxShieldedConsolidate(args::TxShieldedConsolidate::parse(
+                    matches,
+                ))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Merge the shielded notes of a token held by a spending \
+                     key into fewer notes by transferring them back to the \
+                     key. Several transactions are submitted if the notes do \
+                     not fit in the gas limit of a single one."
+                ))
+                .add_args::<args::TxShieldedConsolidate<crate::cli::args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct DisposableKeys(
+        pub args::DisposableKeys<crate::cli::args::CliTypes>,
+    );
+
+    impl SubCmd for DisposableKeys {
+        const CMD: &'static str = "disposable-keys";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                DisposableKeys(args::DisposableKeys::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "List the disposable keys generated to pay transaction \
+                     fees along with their remaining balances. Optionally \
+                     sweep the balances into the shielded pool and remove the \
+                     keys that hold no funds."
+                ))
+                .add_args::<args::DisposableKeys<crate::cli::args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct TxShieldingTransfer(
         pub args::TxShieldingTransfer<crate::cli::args::CliTypes>,
@@ -1684,6 +1842,28 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct AutoCompound(pub args::AutoCompound<args::CliTypes>);
+
+    impl SubCmd for AutoCompound {
+        const CMD: &'static str = "auto-compound";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| AutoCompound(args::AutoCompound::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Opt in or out of the automatic re-bonding of the rewards \
+                     of a bond at the start of every epoch."
+                ))
+                .add_args::<args::AutoCompound<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct Redelegate(pub args::Redelegate<args::CliTypes>);
 
@@ -1705,6 +1885,31 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct RebalanceDelegations(
+        pub args::RebalanceDelegations<args::CliTypes>,
+    );
+
+    impl SubCmd for RebalanceDelegations {
+        const CMD: &'static str = "rebalance-delegations";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                RebalanceDelegations(args::RebalanceDelegations::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Rebalance a delegator's bonds to a target distribution \
+                     across validators with redelegations, unbonds and bonds \
+                     in a single transaction."
+                ))
+                .add_args::<args::RebalanceDelegations<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct QueryEpoch(pub args::Query<args::CliTypes>);
 
@@ -1984,6 +2189,108 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct QueryValidatorsByMetadata(
+        pub args::QueryValidatorsByMetadata<args::CliTypes>,
+    );
+
+    impl SubCmd for QueryValidatorsByMetadata {
+        const CMD: &'static str = "validators-by-metadata";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                QueryValidatorsByMetadata(
+                    args::QueryValidatorsByMetadata::parse(matches),
+                )
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Find the validators that have the given metadata field \
+                     set."
+                ))
+                .add_args::<args::QueryValidatorsByMetadata<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct QueryValidatorSelfBond(
+        pub args::QueryValidatorSelfBond<args::CliTypes>,
+    );
+
+    impl SubCmd for QueryValidatorSelfBond {
+        const CMD: &'static str = "validator-self-bond";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                QueryValidatorSelfBond(args::QueryValidatorSelfBond::parse(
+                    matches,
+                ))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Query a validator's self-bond and the minimum self-bond \
+                     it requires."
+                ))
+                .add_args::<args::QueryValidatorSelfBond<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct QueryValidatorLiveness(
+        pub args::QueryValidatorLiveness<args::CliTypes>,
+    );
+
+    impl SubCmd for QueryValidatorLiveness {
+        const CMD: &'static str = "validator-liveness";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                QueryValidatorLiveness(args::QueryValidatorLiveness::parse(
+                    matches,
+                ))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Query a validator's missed votes in the liveness window \
+                     and per epoch."
+                ))
+                .add_args::<args::QueryValidatorLiveness<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct QuerySlashSimulation(
+        pub args::QuerySlashSimulation<args::CliTypes>,
+    );
+
+    impl SubCmd for QuerySlashSimulation {
+        const CMD: &'static str = "simulate-slash";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                QuerySlashSimulation(args::QuerySlashSimulation::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Simulate the slash of a validator for a hypothetical \
+                     infraction and show the loss of each affected bond."
+                ))
+                .add_args::<args::QuerySlashSimulation<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct QuerySlashes(pub args::QuerySlashes<args::CliTypes>);
 
@@ -2028,6 +2335,30 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct QueryRewardsHistory(
+        pub args::QueryRewardsHistory<args::CliTypes>,
+    );
+
+    impl SubCmd for QueryRewardsHistory {
+        const CMD: &'static str = "rewards-history";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                QueryRewardsHistory(args::QueryRewardsHistory::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Query the rewards earned in each epoch by a given \
+                     delegation (or self-bond), as CSV."
+                ))
+                .add_args::<args::QueryRewardsHistory<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct QueryDelegations(pub args::QueryDelegations<args::CliTypes>);
 
@@ -2192,6 +2523,34 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct TxCommissionScheduleChange(
+        pub args::CommissionScheduleChange<args::CliTypes>,
+    );
+
+    impl SubCmd for TxCommissionScheduleChange {
+        const CMD: &'static str = "change-commission-schedule";
+
+        fn parse(matches: &ArgMatches) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                TxCommissionScheduleChange(
+                    args::CommissionScheduleChange::parse(matches),
+                )
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Schedule commission rate changes at future epochs."
+                ))
+                .add_args::<args::CommissionScheduleChange<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct TxMetadataChange(pub args::MetaDataChange<args::CliTypes>);
 
@@ -2241,6 +2600,34 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct TxChangeValidatorKeys(
+        pub args::ValidatorKeysChange<args::CliTypes>,
+    );
+
+    impl SubCmd for TxChangeValidatorKeys {
+        const CMD: &'static str = "change-validator-keys";
+
+        fn parse(matches: &ArgMatches) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                TxChangeValidatorKeys(args::ValidatorKeysChange::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Rotate the validator's protocol key and/or Ethereum \
+                     bridge keys. The new keys become active at the pipeline \
+                     epoch."
+                ))
+                .add_args::<args::ValidatorKeysChange<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct TxVoteProposal(pub args::VoteProposal<args::CliTypes>);
 
@@ -2264,82 +2651,162 @@ pub mod cmds {
     }
 
     #[derive(Clone, Debug)]
-    pub struct TxRevealPk(pub args::RevealPk<args::CliTypes>);
+    pub struct TxCancelProposal(pub args::CancelProposal<args::CliTypes>);
 
-    impl SubCmd for TxRevealPk {
-        const CMD: &'static str = "reveal-pk";
+    impl SubCmd for TxCancelProposal {
+        const CMD: &'static str = "cancel-proposal";
 
         fn parse(matches: &ArgMatches) -> Option<Self>
         where
             Self: Sized,
         {
-            matches
-                .subcommand_matches(Self::CMD)
-                .map(|matches| TxRevealPk(args::RevealPk::parse(matches)))
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                TxCancelProposal(args::CancelProposal::parse(matches))
+            })
         }
 
         fn def() -> App {
             App::new(Self::CMD)
                 .about(wrap!(
-                    "Submit a tx to reveal the public key an implicit \
-                     account. Typically, you don't have to do this manually \
-                     and the client will detect when a tx to reveal PK is \
-                     needed and submit it automatically. This will write the \
-                     PK into the account's storage so that it can be used for \
-                     signature verification on transactions authorized by \
-                     this account."
+                    "Cancel a proposal before its voting period starts. Part \
+                     of the deposit is refunded to the author and the rest is \
+                     burned."
                 ))
-                .add_args::<args::RevealPk<args::CliTypes>>()
+                .add_args::<args::CancelProposal<args::CliTypes>>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct Complete(pub args::Complete);
+    pub struct TxVetoProposal(pub args::VetoProposal<args::CliTypes>);
 
-    impl SubCmd for Complete {
-        const CMD: &'static str = "complete";
+    impl SubCmd for TxVetoProposal {
+        const CMD: &'static str = "veto-proposal";
 
         fn parse(matches: &ArgMatches) -> Option<Self>
         where
             Self: Sized,
         {
-            matches
-                .subcommand_matches(Self::CMD)
-                .map(|matches| Complete(args::Complete::parse(matches)))
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                TxVetoProposal(args::VetoProposal::parse(matches))
+            })
         }
 
         fn def() -> App {
             App::new(Self::CMD)
-                .about(wrap!("Generate shell completions"))
-                .add_args::<args::Complete>()
+                .about(wrap!(
+                    "Veto a passed proposal waiting in the timelock queue. \
+                     The proposal is discarded and its deposit burned if \
+                     validators holding a supermajority of the voting power \
+                     veto it before its execution epoch."
+                ))
+                .add_args::<args::VetoProposal<args::CliTypes>>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct GenIbcShieldingTransfer(
-        pub args::GenIbcShieldingTransfer<args::CliTypes>,
-    );
+    pub struct TxDelegateVote(pub args::DelegateVote<args::CliTypes>);
 
-    impl SubCmd for GenIbcShieldingTransfer {
-        const CMD: &'static str = "ibc-gen-shielding";
+    impl SubCmd for TxDelegateVote {
+        const CMD: &'static str = "delegate-vote";
 
-        fn parse(matches: &ArgMatches) -> Option<Self> {
+        fn parse(matches: &ArgMatches) -> Option<Self>
+        where
+            Self: Sized,
+        {
             matches.subcommand_matches(Self::CMD).map(|matches| {
-                GenIbcShieldingTransfer(args::GenIbcShieldingTransfer::parse(
-                    matches,
-                ))
+                TxDelegateVote(args::DelegateVote::parse(matches))
             })
         }
 
         fn def() -> App {
             App::new(Self::CMD)
-                .about("Generate shielding transfer for IBC.")
-                .add_args::<args::GenIbcShieldingTransfer<args::CliTypes>>()
+                .about(wrap!(
+                    "Delegate the governance voting power of an account to a \
+                     representative, or revoke the delegation. The \
+                     representative votes on behalf of the account unless it \
+                     votes directly."
+                ))
+                .add_args::<args::DelegateVote<args::CliTypes>>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct EpochSleep(pub args::Query<args::CliTypes>);
+    pub struct TxRevealPk(pub args::RevealPk<args::CliTypes>);
+
+    impl SubCmd for TxRevealPk {
+        const CMD: &'static str = "reveal-pk";
+
+        fn parse(matches: &ArgMatches) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| TxRevealPk(args::RevealPk::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Submit a tx to reveal the public key an implicit \
+                     account. Typically, you don't have to do this manually \
+                     and the client will detect when a tx to reveal PK is \
+                     needed and submit it automatically. This will write the \
+                     PK into the account's storage so that it can be used for \
+                     signature verification on transactions authorized by \
+                     this account."
+                ))
+                .add_args::<args::RevealPk<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct Complete(pub args::Complete);
+
+    impl SubCmd for Complete {
+        const CMD: &'static str = "complete";
+
+        fn parse(matches: &ArgMatches) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Complete(args::Complete::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!("Generate shell completions"))
+                .add_args::<args::Complete>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct GenIbcShieldingTransfer(
+        pub args::GenIbcShieldingTransfer<args::CliTypes>,
+    );
+
+    impl SubCmd for GenIbcShieldingTransfer {
+        const CMD: &'static str = "ibc-gen-shielding";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                GenIbcShieldingTransfer(args::GenIbcShieldingTransfer::parse(
+                    matches,
+                ))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about("Generate shielding transfer for IBC.")
+                .add_args::<args::GenIbcShieldingTransfer<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct EpochSleep(pub args::Query<args::CliTypes>);
 
     impl SubCmd for EpochSleep {
         const CMD: &'static str = "epoch-sleep";
@@ -3149,6 +3616,7 @@ pub mod cmds {
 }
 
 pub mod args {
+    use std::collections::BTreeMap;
     use std::env;
     use std::net::SocketAddr;
     use std::path::PathBuf;
@@ -3161,25 +3629,30 @@ pub mod args {
     use namada_sdk::collections::HashMap;
     use namada_sdk::dec::Dec;
     use namada_sdk::ethereum_events::EthAddress;
+    use namada_sdk::governance::storage::proposal::RepresentationScope;
     use namada_sdk::hash::Hash;
     use namada_sdk::ibc::core::host::types::identifiers::{ChannelId, PortId};
     use namada_sdk::keccak::KeccakHash;
     use namada_sdk::key::*;
     use namada_sdk::masp::{MaspEpoch, PaymentAddress};
+    use namada_sdk::proof_of_stake::types::{MetadataField, SlashType};
     use namada_sdk::storage::{self, BlockHeight, Epoch};
     use namada_sdk::time::DateTimeUtc;
     use namada_sdk::token::NATIVE_MAX_DECIMAL_PLACES;
     use namada_sdk::tx::data::GasLimit;
     pub use namada_sdk::tx::{
-        TX_BECOME_VALIDATOR_WASM, TX_BOND_WASM, TX_BRIDGE_POOL_WASM,
-        TX_CHANGE_COMMISSION_WASM, TX_CHANGE_CONSENSUS_KEY_WASM,
-        TX_CHANGE_METADATA_WASM, TX_CLAIM_REWARDS_WASM,
-        TX_DEACTIVATE_VALIDATOR_WASM, TX_IBC_WASM, TX_INIT_ACCOUNT_WASM,
-        TX_INIT_PROPOSAL, TX_REACTIVATE_VALIDATOR_WASM, TX_REDELEGATE_WASM,
-        TX_RESIGN_STEWARD, TX_REVEAL_PK, TX_TRANSFER_WASM, TX_UNBOND_WASM,
+        TX_AUTO_COMPOUND_WASM, TX_BECOME_VALIDATOR_WASM, TX_BOND_WASM,
+        TX_BRIDGE_POOL_WASM, TX_CANCEL_PROPOSAL,
+        TX_CHANGE_COMMISSION_SCHEDULE_WASM, TX_CHANGE_COMMISSION_WASM,
+        TX_CHANGE_CONSENSUS_KEY_WASM, TX_CHANGE_METADATA_WASM,
+        TX_CHANGE_VALIDATOR_KEYS_WASM, TX_CLAIM_REWARDS_WASM,
+        TX_DEACTIVATE_VALIDATOR_WASM, TX_DELEGATE_VOTE, TX_IBC_WASM,
+        TX_INIT_ACCOUNT_WASM, TX_INIT_PROPOSAL, TX_REACTIVATE_VALIDATOR_WASM,
+        TX_REBALANCE_DELEGATIONS_WASM, TX_REDELEGATE_WASM, TX_RESIGN_STEWARD,
+        TX_REVEAL_PK, TX_TRANSFER_WASM, TX_UNBOND_WASM,
         TX_UNJAIL_VALIDATOR_WASM, TX_UPDATE_ACCOUNT_WASM,
-        TX_UPDATE_STEWARD_COMMISSION, TX_VOTE_PROPOSAL, TX_WITHDRAW_WASM,
-        VP_USER_WASM,
+        TX_UPDATE_STEWARD_COMMISSION, TX_VETO_PROPOSAL, TX_VOTE_PROPOSAL,
+        TX_WITHDRAW_WASM, VP_USER_WASM,
     };
     use namada_sdk::{token, DEFAULT_GAS_LIMIT};
 
@@ -3240,6 +3713,8 @@ pub mod args {
     pub const CHAIN_ID_OPT: ArgOpt<ChainId> = CHAIN_ID.opt();
     pub const CHAIN_ID_PREFIX: Arg<ChainIdPrefix> = arg("chain-prefix");
     pub const CHANNEL_ID: Arg<ChannelId> = arg("channel-id");
+    pub const CHECKPOINT_INTERVAL: ArgOpt<usize> =
+        arg_opt("checkpoint-interval");
     pub const CODE_PATH: Arg<PathBuf> = arg("code-path");
     pub const CODE_PATH_OPT: ArgOpt<PathBuf> = CODE_PATH.opt();
     pub const COMMISSION_RATE: Arg<Dec> = arg("commission-rate");
@@ -3261,10 +3736,12 @@ pub mod args {
         DefaultFn(|| storage::SUBSPACE_CF.to_string()),
     );
     pub const DECRYPT: ArgFlag = flag("decrypt");
+    pub const DELEGATOR: Arg<WalletAddress> = arg("delegator");
     pub const DESCRIPTION_OPT: ArgOpt<String> = arg_opt("description");
     pub const DISPOSABLE_SIGNING_KEY: ArgFlag = flag("disposable-gas-payer");
     pub const DESTINATION_VALIDATOR: Arg<WalletAddress> =
         arg("destination-validator");
+    pub const DISABLE: ArgFlag = flag("disable");
     pub const DISCORD_OPT: ArgOpt<String> = arg_opt("discord-handle");
     pub const DO_IT: ArgFlag = flag("do-it");
     pub const DONT_PREFETCH_WASM: ArgFlag = flag("dont-prefetch-wasm");
@@ -3291,6 +3768,7 @@ pub mod args {
     pub const FEE_PAYER_OPT: ArgOpt<WalletPublicKey> = arg_opt("gas-payer");
     pub const FILE_PATH: Arg<String> = arg("file");
     pub const FORCE: ArgFlag = flag("force");
+    pub const FROM_EPOCH: ArgOpt<Epoch> = arg_opt("from-epoch");
     pub const GAS_LIMIT: ArgDefault<GasLimit> = arg_default(
         "gas-limit",
         DefaultFn(|| GasLimit::from(DEFAULT_GAS_LIMIT)),
@@ -3329,6 +3807,8 @@ pub mod args {
     pub const IBC_SHIELDING_DATA_PATH: ArgOpt<PathBuf> =
         arg_opt("ibc-shielding-data");
     pub const IBC_MEMO: ArgOpt<String> = arg_opt("ibc-memo");
+    pub const IDENTITY_PROOF_OPT: ArgOpt<Hash> = arg_opt("identity-proof");
+    pub const INFRACTION_EPOCH: Arg<Epoch> = arg("infraction-epoch");
     pub const INPUT_OPT: ArgOpt<PathBuf> = arg_opt("input");
     pub const LEDGER_ADDRESS_ABOUT: &str = textwrap_macros::fill!(
         "Address of a ledger node as \"{scheme}://{host}:{port}\". If the \
@@ -3344,12 +3824,17 @@ pub mod args {
             Url::from_str(raw).unwrap()
         }));
     pub const LIST_FIND_ADDRESSES_ONLY: ArgFlag = flag("addr");
+    pub const METADATA_EXTENSIONS: ArgMulti<KeyValueEntry, GlobStar> =
+        arg_multi("metadata-extensions");
+    pub const METADATA_FIELD: Arg<MetadataField> = arg("field");
+    pub const METADATA_VALUE_OPT: ArgOpt<String> = arg_opt("value");
     pub const LIST_FIND_KEYS_ONLY: ArgFlag = flag("keys");
     pub const LOCALHOST: ArgFlag = flag("localhost");
     pub const MASP_EPOCH: ArgOpt<MaspEpoch> = arg_opt("masp-epoch");
     pub const MAX_COMMISSION_RATE_CHANGE: Arg<Dec> =
         arg("max-commission-rate-change");
     pub const MAX_ETH_GAS: ArgOpt<u64> = arg_opt("max_eth-gas");
+    pub const MAX_NOTES: ArgOpt<usize> = arg_opt("max-notes");
     pub const MEMO_OPT: ArgOpt<String> = arg_opt("memo");
     pub const MIGRATION_PATH: ArgOpt<PathBuf> = arg_opt("migration-path");
     pub const MODE: ArgOpt<String> = arg_opt("mode");
@@ -3357,6 +3842,7 @@ pub mod args {
     pub const NAMADA_START_TIME: ArgOpt<DateTimeUtc> = arg_opt("time");
     pub const NO_CONVERSIONS: ArgFlag = flag("no-conversions");
     pub const NO_EXPIRATION: ArgFlag = flag("no-expiration");
+    pub const NUM_EPOCHS: ArgOpt<u64> = arg_opt("num-epochs");
     pub const NUT: ArgFlag = flag("nut");
     pub const OUT_FILE_PATH_OPT: ArgOpt<PathBuf> = arg_opt("out-file-path");
     pub const OUTPUT: ArgOpt<PathBuf> = arg_opt("output");
@@ -3375,6 +3861,12 @@ pub mod args {
     pub const PROPOSAL_ETH: ArgFlag = flag("eth");
     pub const PROPOSAL_PGF_STEWARD: ArgFlag = flag("pgf-stewards");
     pub const PROPOSAL_PGF_FUNDING: ArgFlag = flag("pgf-funding");
+    pub const PROPOSAL_PARAMETER_CHANGE: ArgFlag = flag("parameter-change");
+    pub const PROPOSAL_WASM_ALLOWLIST: ArgFlag = flag("wasm-allowlist");
+    pub const PROJECT_REWARDS: ArgOpt<u64> = arg_opt("project-rewards");
+    pub const PRUNE: ArgFlag = flag("prune");
+    pub const PRUNE_AGE: ArgDefault<u64> =
+        arg_default("prune-age", DefaultFn(|| 1_000));
     pub const PROTOCOL_KEY: ArgOpt<WalletPublicKey> = arg_opt("protocol-key");
     pub const PRE_GENESIS_PATH: ArgOpt<PathBuf> = arg_opt("pre-genesis-path");
     pub const PUBLIC_KEY: Arg<WalletPublicKey> = arg("public-key");
@@ -3402,10 +3894,19 @@ pub mod args {
     pub const REFUND_TARGET: ArgOpt<WalletTransferTarget> =
         arg_opt("refund-target");
     pub const RELAYER: Arg<Address> = arg("relayer");
+    pub const REPRESENTATION_SCOPE: ArgDefault<RepresentationScope> =
+        arg_default("scope", DefaultFn(|| RepresentationScope::All));
+    pub const REPRESENTATIVE_OPT: ArgOpt<WalletAddress> =
+        arg_opt("representative");
     pub const SAFE_MODE: ArgFlag = flag("safe-mode");
+    pub const SCAN_WORKERS: ArgOpt<usize> = arg_opt("scan-workers");
+    pub const SCHEDULE: ArgMulti<CommissionScheduleEntry, GlobStar> =
+        arg_multi("schedule");
     pub const SCHEME: ArgDefault<SchemeType> =
         arg_default("scheme", DefaultFn(|| SchemeType::Ed25519));
     pub const SHELL: Arg<Shell> = arg("shell");
+    pub const SECURITY_CONTACT_OPT: ArgOpt<String> =
+        arg_opt("security-contact");
     pub const SELF_BOND_AMOUNT: Arg<token::DenominatedAmount> =
         arg("self-bond-amount");
     pub const SENDER: Arg<String> = arg("sender");
@@ -3415,6 +3916,9 @@ pub mod args {
     pub const SIGNING_KEYS: ArgMulti<WalletPublicKey, GlobStar> =
         arg_multi("signing-keys");
     pub const SIGNATURES: ArgMulti<PathBuf, GlobStar> = arg_multi("signatures");
+    pub const SLASH_TYPE: Arg<SlashType> = arg("slash-type");
+    pub const SOCIAL_HANDLES: ArgMulti<KeyValueEntry, GlobStar> =
+        arg_multi("social-handles");
     pub const SOURCE: Arg<WalletAddress> = arg("source");
     pub const SOURCE_OPT: ArgOpt<WalletAddress> = SOURCE.opt();
     pub const SOURCE_VALIDATOR: Arg<WalletAddress> = arg("source-validator");
@@ -3424,11 +3928,15 @@ pub mod args {
     pub const STEWARD: Arg<WalletAddress> = arg("steward");
     pub const STORAGE_KEY: Arg<storage::Key> = arg("storage-key");
     pub const SUSPEND_ACTION: ArgFlag = flag("suspend");
+    pub const SWEEP_TARGET: ArgOpt<WalletPaymentAddr> = arg_opt("sweep-target");
     pub const TARGET: Arg<WalletAddress> = arg("target");
+    pub const TARGETS: ArgMulti<RebalanceTarget, GlobStar> =
+        arg_multi("targets");
     pub const TEMPLATES_PATH: Arg<PathBuf> = arg("templates-path");
     pub const TIMEOUT_HEIGHT: ArgOpt<u64> = arg_opt("timeout-height");
     pub const TIMEOUT_SEC_OFFSET: ArgOpt<u64> = arg_opt("timeout-sec-offset");
     pub const TM_ADDRESS: ArgOpt<String> = arg_opt("tm-address");
+    pub const TO_EPOCH: ArgOpt<Epoch> = arg_opt("to-epoch");
     pub const TOKEN_OPT: ArgOpt<WalletAddress> = TOKEN.opt();
     pub const TOKEN_STR_OPT: ArgOpt<String> = TOKEN_STR.opt();
     pub const TOKEN: Arg<WalletAddress> = arg("token");
@@ -4579,6 +5087,121 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<TxShieldedConsolidate<SdkTypes>>
+        for TxShieldedConsolidate<CliTypes>
+    {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<TxShieldedConsolidate<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_mut_chain_or_exit();
+
+            Ok(TxShieldedConsolidate::<SdkTypes> {
+                tx,
+                source: chain_ctx.get_cached(&self.source),
+                token: chain_ctx.get(&self.token),
+                max_notes: self.max_notes,
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    impl Args for TxShieldedConsolidate<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let source = SPENDING_KEY_SOURCE.parse(matches);
+            let token = TOKEN.parse(matches);
+            let max_notes = MAX_NOTES.parse(matches);
+            let tx_code_path = PathBuf::from(TX_TRANSFER_WASM);
+
+            Self {
+                tx,
+                source,
+                token,
+                max_notes,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(SPENDING_KEY_SOURCE.def().help(wrap!(
+                    "The shielded spending key whose notes to consolidate."
+                )))
+                .arg(
+                    TOKEN
+                        .def()
+                        .help(wrap!("The token whose notes to consolidate.")),
+                )
+                .arg(MAX_NOTES.def().help(wrap!(
+                    "The maximum number of notes to spend in each \
+                     transaction. Defaults to the number of notes that fits \
+                     in the gas limit."
+                )))
+        }
+    }
+
+    impl CliToSdk<DisposableKeys<SdkTypes>> for DisposableKeys<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<DisposableKeys<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_mut_chain_or_exit();
+
+            Ok(DisposableKeys::<SdkTypes> {
+                tx,
+                sweep_target: self
+                    .sweep_target
+                    .map(|target| chain_ctx.get(&target)),
+                prune: self.prune,
+                prune_age: self.prune_age,
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    impl Args for DisposableKeys<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let sweep_target = SWEEP_TARGET.parse(matches);
+            let prune = PRUNE.parse(matches);
+            let prune_age = PRUNE_AGE.parse(matches);
+            let tx_code_path = PathBuf::from(TX_TRANSFER_WASM);
+
+            Self {
+                tx,
+                sweep_target,
+                prune,
+                prune_age,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(SWEEP_TARGET.def().help(wrap!(
+                    "Shield the fee token balances of the disposable keys to \
+                     this payment address. The fees of the sweeping \
+                     transactions are paid by the keys themselves."
+                )))
+                .arg(PRUNE.def().help(wrap!(
+                    "Remove the disposable keys that hold no funds from the \
+                     wallet."
+                )))
+                .arg(PRUNE_AGE.def().help(wrap!(
+                    "The minimum number of blocks elapsed since their \
+                     creation for disposable keys to be pruned. Defaults to \
+                     1000."
+                )))
+        }
+    }
+
     impl CliToSdk<TxShieldingTransfer<SdkTypes>> for TxShieldingTransfer<CliTypes> {
         type Error = std::io::Error;
 
@@ -5562,6 +6185,99 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<RebalanceDelegations<SdkTypes>>
+        for RebalanceDelegations<CliTypes>
+    {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<RebalanceDelegations<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
+
+            Ok(RebalanceDelegations::<SdkTypes> {
+                tx,
+                owner: chain_ctx.get(&self.owner),
+                targets: self
+                    .targets
+                    .iter()
+                    .map(|(validator, amount)| {
+                        (chain_ctx.get(validator), *amount)
+                    })
+                    .collect(),
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    /// A target of a delegation rebalancing, given as `VALIDATOR:AMOUNT`
+    #[derive(Clone, Debug)]
+    pub struct RebalanceTarget {
+        /// The validator's address or alias
+        pub validator: String,
+        /// The amount of native tokens to delegate to the validator
+        pub amount: token::Amount,
+    }
+
+    impl FromStr for RebalanceTarget {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let (validator, amount) = s
+                .rsplit_once(':')
+                .ok_or_else(|| "Expected VALIDATOR:AMOUNT".to_string())?;
+            let amount = token::DenominatedAmount::from_str(amount.trim())
+                .and_then(|amount| {
+                    amount
+                        .canonical()
+                        .increase_precision(NATIVE_MAX_DECIMAL_PLACES.into())
+                })
+                .map_err(|e| format!("Invalid amount {amount}: {e}"))?
+                .amount();
+            Ok(Self {
+                validator: validator.trim().to_owned(),
+                amount,
+            })
+        }
+    }
+
+    impl Args for RebalanceDelegations<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let owner = OWNER.parse(matches);
+            let targets = TARGETS
+                .parse(matches)
+                .into_iter()
+                .map(|RebalanceTarget { validator, amount }| {
+                    (WalletAddress::new(validator), amount)
+                })
+                .collect();
+            let tx_code_path = PathBuf::from(TX_REBALANCE_DELEGATIONS_WASM);
+            Self {
+                tx,
+                owner,
+                targets,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(OWNER.def().help(wrap!(
+                    "Delegator (owner) address of the bonds that are being \
+                     rebalanced."
+                )))
+                .arg(TARGETS.def().help(wrap!(
+                    "Comma-separated list of VALIDATOR:AMOUNT entries with \
+                     the target bond amount at each validator. The bonds at \
+                     validators that are not listed are fully redelegated or \
+                     unbonded. Omit to unbond all of the delegator's bonds."
+                )))
+        }
+    }
+
     impl CliToSdk<InitProposal<SdkTypes>> for InitProposal<CliTypes> {
         type Error = std::io::Error;
 
@@ -5577,6 +6293,8 @@ pub mod args {
                 proposal_data,
                 is_pgf_stewards: self.is_pgf_stewards,
                 is_pgf_funding: self.is_pgf_funding,
+                is_parameter_change: self.is_parameter_change,
+                is_wasm_allowlist: self.is_wasm_allowlist,
                 tx_code_path: self.tx_code_path,
             })
         }
@@ -5588,6 +6306,8 @@ pub mod args {
             let proposal_data = DATA_PATH.parse(matches);
             let is_pgf_stewards = PROPOSAL_PGF_STEWARD.parse(matches);
             let is_pgf_funding = PROPOSAL_PGF_FUNDING.parse(matches);
+            let is_parameter_change = PROPOSAL_PARAMETER_CHANGE.parse(matches);
+            let is_wasm_allowlist = PROPOSAL_WASM_ALLOWLIST.parse(matches);
             let tx_code_path = PathBuf::from(TX_INIT_PROPOSAL);
 
             Self {
@@ -5596,6 +6316,8 @@ pub mod args {
                 tx_code_path,
                 is_pgf_stewards,
                 is_pgf_funding,
+                is_parameter_change,
+                is_wasm_allowlist,
             }
         }
 
@@ -5611,6 +6333,8 @@ pub mod args {
                         .conflicts_with_all([
                             PROPOSAL_PGF_FUNDING.name,
                             PROPOSAL_PGF_STEWARD.name,
+                            PROPOSAL_PARAMETER_CHANGE.name,
+                            PROPOSAL_WASM_ALLOWLIST.name,
                         ]),
                 )
                 .arg(
@@ -5623,6 +6347,8 @@ pub mod args {
                         .conflicts_with_all([
                             PROPOSAL_ETH.name,
                             PROPOSAL_PGF_FUNDING.name,
+                            PROPOSAL_PARAMETER_CHANGE.name,
+                            PROPOSAL_WASM_ALLOWLIST.name,
                         ]),
                 )
                 .arg(
@@ -5635,6 +6361,39 @@ pub mod args {
                         .conflicts_with_all([
                             PROPOSAL_ETH.name,
                             PROPOSAL_PGF_STEWARD.name,
+                            PROPOSAL_PARAMETER_CHANGE.name,
+                            PROPOSAL_WASM_ALLOWLIST.name,
+                        ]),
+                )
+                .arg(
+                    PROPOSAL_PARAMETER_CHANGE
+                        .def()
+                        .help(wrap!(
+                            "Flag if the proposal is of type \
+                             parameter-change. Used to change protocol, PoS, \
+                             governance, PGF or IBC parameters without wasm \
+                             code."
+                        ))
+                        .conflicts_with_all([
+                            PROPOSAL_ETH.name,
+                            PROPOSAL_PGF_STEWARD.name,
+                            PROPOSAL_PGF_FUNDING.name,
+                            PROPOSAL_WASM_ALLOWLIST.name,
+                        ]),
+                )
+                .arg(
+                    PROPOSAL_WASM_ALLOWLIST
+                        .def()
+                        .help(wrap!(
+                            "Flag if the proposal is of type wasm-allowlist. \
+                             Used to upload tx and vp wasm codes and add them \
+                             to or remove them from the wasm allowlists."
+                        ))
+                        .conflicts_with_all([
+                            PROPOSAL_ETH.name,
+                            PROPOSAL_PGF_STEWARD.name,
+                            PROPOSAL_PGF_FUNDING.name,
+                            PROPOSAL_PARAMETER_CHANGE.name,
                         ]),
                 )
         }
@@ -5686,42 +6445,191 @@ pub mod args {
                         .help(wrap!("The proposal identifier.")),
                 )
                 .arg(PROPOSAL_VOTE.def().help(wrap!(
-                    "The vote for the proposal. Either yay, nay, or abstain."
+                    "The vote for the proposal. Either yay, nay, abstain, or \
+                     no_with_veto, or a weighted split of the voting power as \
+                     comma-separated OPTION:WEIGHT entries adding up to 1, \
+                     e.g. yay:0.7,nay:0.2,no_with_veto:0.1."
                 )))
                 .arg(ADDRESS.def().help(wrap!("The address of the voter.")))
         }
     }
 
-    impl CliToSdk<RevealPk<SdkTypes>> for RevealPk<CliTypes> {
+    impl CliToSdk<CancelProposal<SdkTypes>> for CancelProposal<CliTypes> {
         type Error = std::io::Error;
 
         fn to_sdk(
             self,
             ctx: &mut Context,
-        ) -> Result<RevealPk<SdkTypes>, Self::Error> {
+        ) -> Result<CancelProposal<SdkTypes>, Self::Error> {
             let tx = self.tx.to_sdk(ctx)?;
-            let chain_ctx = ctx.borrow_mut_chain_or_exit();
 
-            Ok(RevealPk::<SdkTypes> {
+            Ok(CancelProposal::<SdkTypes> {
                 tx,
-                public_key: chain_ctx.get(&self.public_key),
+                proposal_id: self.proposal_id,
+                author: ctx.borrow_chain_or_exit().get(&self.author),
+                tx_code_path: self.tx_code_path.to_path_buf(),
             })
         }
     }
 
-    impl Args for RevealPk<CliTypes> {
+    impl CliToSdk<VetoProposal<SdkTypes>> for VetoProposal<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<VetoProposal<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+
+            Ok(VetoProposal::<SdkTypes> {
+                tx,
+                proposal_id: self.proposal_id,
+                validator: ctx.borrow_chain_or_exit().get(&self.validator),
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    impl Args for VetoProposal<CliTypes> {
         fn parse(matches: &ArgMatches) -> Self {
             let tx = Tx::parse(matches);
-            let public_key = PUBLIC_KEY.parse(matches);
+            let proposal_id = PROPOSAL_ID.parse(matches);
+            let validator = VALIDATOR.parse(matches);
+            let tx_code_path = PathBuf::from(TX_VETO_PROPOSAL);
 
-            Self { tx, public_key }
+            Self {
+                tx,
+                proposal_id,
+                validator,
+                tx_code_path,
+            }
         }
 
         fn def(app: App) -> App {
             app.add_args::<Tx<CliTypes>>()
-                .arg(PUBLIC_KEY.def().help(wrap!("A public key to reveal.")))
-        }
-    }
+                .arg(PROPOSAL_ID.def().help(wrap!("The proposal identifier.")))
+                .arg(
+                    VALIDATOR
+                        .def()
+                        .help(wrap!("The address of the vetoing validator.")),
+                )
+        }
+    }
+
+    impl CliToSdk<DelegateVote<SdkTypes>> for DelegateVote<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<DelegateVote<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
+
+            Ok(DelegateVote::<SdkTypes> {
+                tx,
+                delegator: chain_ctx.get(&self.delegator),
+                representative: self
+                    .representative
+                    .map(|representative| chain_ctx.get(&representative)),
+                scope: self.scope,
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    impl Args for DelegateVote<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let delegator = DELEGATOR.parse(matches);
+            let representative = REPRESENTATIVE_OPT.parse(matches);
+            let scope = REPRESENTATION_SCOPE.parse(matches);
+            let tx_code_path = PathBuf::from(TX_DELEGATE_VOTE);
+
+            Self {
+                tx,
+                delegator,
+                representative,
+                scope,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(DELEGATOR.def().help(wrap!(
+                    "The address delegating its governance voting power."
+                )))
+                .arg(REPRESENTATIVE_OPT.def().help(wrap!(
+                    "The representative voting on behalf of the delegator. \
+                     Omit it to revoke the delegation."
+                )))
+                .arg(REPRESENTATION_SCOPE.def().help(wrap!(
+                    "The proposals the delegation applies to. One of all, \
+                     default, default_with_wasm, pgf_steward, pgf_payment, \
+                     parameter_change or wasm_allowlist. A delegation for a \
+                     proposal type takes precedence over a delegation for all \
+                     proposals. Defaults to all."
+                )))
+        }
+    }
+
+    impl Args for CancelProposal<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let proposal_id = PROPOSAL_ID.parse(matches);
+            let author = ADDRESS.parse(matches);
+            let tx_code_path = PathBuf::from(TX_CANCEL_PROPOSAL);
+
+            Self {
+                tx,
+                proposal_id,
+                author,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(PROPOSAL_ID.def().help(wrap!("The proposal identifier.")))
+                .arg(
+                    ADDRESS
+                        .def()
+                        .help(wrap!("The address of the proposal author.")),
+                )
+        }
+    }
+
+    impl CliToSdk<RevealPk<SdkTypes>> for RevealPk<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<RevealPk<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_mut_chain_or_exit();
+
+            Ok(RevealPk::<SdkTypes> {
+                tx,
+                public_key: chain_ctx.get(&self.public_key),
+            })
+        }
+    }
+
+    impl Args for RevealPk<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let public_key = PUBLIC_KEY.parse(matches);
+
+            Self { tx, public_key }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(PUBLIC_KEY.def().help(wrap!("A public key to reveal.")))
+        }
+    }
 
     impl CliToSdk<Complete> for Complete {
         type Error = std::io::Error;
@@ -5856,6 +6764,45 @@ pub mod args {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct QueryProposalDryRun<C: NamadaTypes = SdkTypes> {
+        /// Common query args
+        pub query: Query<C>,
+        /// Proposal id
+        pub proposal_id: u64,
+    }
+
+    impl CliToSdk<QueryProposalDryRun<SdkTypes>> for QueryProposalDryRun<CliTypes> {
+        type Error = std::convert::Infallible;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<QueryProposalDryRun<SdkTypes>, Self::Error> {
+            Ok(QueryProposalDryRun::<SdkTypes> {
+                query: self.query.to_sdk(ctx)?,
+                proposal_id: self.proposal_id,
+            })
+        }
+    }
+
+    impl Args for QueryProposalDryRun<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let proposal_id = PROPOSAL_ID.parse(matches);
+
+            Self { query, proposal_id }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>().arg(
+                PROPOSAL_ID
+                    .def()
+                    .help(wrap!("The identifier of the proposal to dry run.")),
+            )
+        }
+    }
+
     impl CliToSdk<QueryProtocolParameters<SdkTypes>>
         for QueryProtocolParameters<CliTypes>
     {
@@ -5995,6 +6942,57 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<AutoCompound<SdkTypes>> for AutoCompound<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<AutoCompound<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
+
+            Ok(AutoCompound::<SdkTypes> {
+                tx,
+                validator: chain_ctx.get(&self.validator),
+                source: self.source.map(|x| chain_ctx.get(&x)),
+                disable: self.disable,
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    impl Args for AutoCompound<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let validator = VALIDATOR.parse(matches);
+            let source = SOURCE_OPT.parse(matches);
+            let disable = DISABLE.parse(matches);
+            let tx_code_path = PathBuf::from(TX_AUTO_COMPOUND_WASM);
+            Self {
+                tx,
+                validator,
+                source,
+                disable,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(VALIDATOR.def().help(wrap!("Validator address.")))
+                .arg(SOURCE_OPT.def().help(wrap!(
+                    "Source address of the bond. For self-bonds, the \
+                     validator is also the source."
+                )))
+                .arg(DISABLE.def().help(wrap!(
+                    "Opt out of the auto-compounding of the rewards. The \
+                     rewards that have not been compounded yet remain \
+                     claimable."
+                )))
+        }
+    }
+
     impl CliToSdk<QueryConversions<SdkTypes>> for QueryConversions<CliTypes> {
         type Error = std::convert::Infallible;
 
@@ -6079,6 +7077,8 @@ pub mod args {
                 owner: chain_ctx.get_cached(&self.owner),
                 token: chain_ctx.get(&self.token),
                 no_conversions: self.no_conversions,
+                masp_epoch: self.masp_epoch,
+                project_rewards: self.project_rewards,
             })
         }
     }
@@ -6089,11 +7089,15 @@ pub mod args {
             let owner = BALANCE_OWNER.parse(matches);
             let token = TOKEN.parse(matches);
             let no_conversions = NO_CONVERSIONS.parse(matches);
+            let masp_epoch = MASP_EPOCH.parse(matches);
+            let project_rewards = PROJECT_REWARDS.parse(matches);
             Self {
                 query,
                 owner,
                 token,
                 no_conversions,
+                masp_epoch,
+                project_rewards,
             }
         }
 
@@ -6112,6 +7116,21 @@ pub mod args {
                 .arg(NO_CONVERSIONS.def().help(wrap!(
                     "Whether not to automatically perform conversions."
                 )))
+                .arg(
+                    MASP_EPOCH
+                        .def()
+                        .help(wrap!(
+                            "Evaluate the shielded balance at the given past \
+                             MASP epoch, from the notes that were unspent at \
+                             the end of that epoch."
+                        ))
+                        .conflicts_with(NO_CONVERSIONS.name),
+                )
+                .arg(PROJECT_REWARDS.def().help(wrap!(
+                    "Project the shielded rewards of the balance for the \
+                     given number of upcoming MASP epochs, assuming that the \
+                     amounts locked in the shielded pool remain constant."
+                )))
         }
     }
 
@@ -6283,6 +7302,85 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<CommissionScheduleChange<SdkTypes>>
+        for CommissionScheduleChange<CliTypes>
+    {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<CommissionScheduleChange<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+
+            Ok(CommissionScheduleChange::<SdkTypes> {
+                tx,
+                validator: ctx.borrow_chain_or_exit().get(&self.validator),
+                schedule: self.schedule,
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    /// An entry of a commission rate schedule, given as `EPOCH:RATE`
+    #[derive(Clone, Debug)]
+    pub struct CommissionScheduleEntry {
+        /// The epoch from which the rate applies
+        pub epoch: Epoch,
+        /// The commission rate
+        pub rate: Dec,
+    }
+
+    impl FromStr for CommissionScheduleEntry {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let (epoch, rate) = s
+                .split_once(':')
+                .ok_or_else(|| "Expected EPOCH:RATE".to_string())?;
+            Ok(Self {
+                epoch: Epoch::from_str(epoch.trim())
+                    .map_err(|e| format!("Invalid epoch {epoch}: {e}"))?,
+                rate: Dec::from_str(rate.trim())
+                    .map_err(|e| format!("Invalid rate {rate}: {e}"))?,
+            })
+        }
+    }
+
+    impl Args for CommissionScheduleChange<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let validator = VALIDATOR.parse(matches);
+            let schedule = SCHEDULE
+                .parse(matches)
+                .into_iter()
+                .map(|CommissionScheduleEntry { epoch, rate }| (epoch, rate))
+                .collect();
+            let tx_code_path =
+                PathBuf::from(TX_CHANGE_COMMISSION_SCHEDULE_WASM);
+            Self {
+                tx,
+                validator,
+                schedule,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(VALIDATOR.def().help(wrap!(
+                    "The validator's address whose commission schedule to \
+                     change."
+                )))
+                .arg(SCHEDULE.def().help(wrap!(
+                    "Comma-separated list of EPOCH:RATE entries, in \
+                     increasing epoch order, each after the pipeline epoch. \
+                     Replaces any pending schedule. Omit to cancel all \
+                     pending scheduled changes."
+                )))
+        }
+    }
+
     impl CliToSdk<ConsensusKeyChange<SdkTypes>> for ConsensusKeyChange<CliTypes> {
         type Error = std::io::Error;
 
@@ -6335,6 +7433,75 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<ValidatorKeysChange<SdkTypes>> for ValidatorKeysChange<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<ValidatorKeysChange<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_mut_chain_or_exit();
+
+            Ok(ValidatorKeysChange::<SdkTypes> {
+                tx,
+                validator: chain_ctx.get(&self.validator),
+                protocol_key: self.protocol_key.map(|x| chain_ctx.get(&x)),
+                eth_hot_key: self.eth_hot_key.map(|x| chain_ctx.get(&x)),
+                eth_cold_key: self.eth_cold_key.map(|x| chain_ctx.get(&x)),
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    impl Args for ValidatorKeysChange<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let validator = VALIDATOR.parse(matches);
+            let protocol_key = PROTOCOL_KEY.parse(matches);
+            let eth_hot_key = VALIDATOR_ETH_HOT_KEY.parse(matches);
+            let eth_cold_key = VALIDATOR_ETH_COLD_KEY.parse(matches);
+            let tx_code_path = PathBuf::from(TX_CHANGE_VALIDATOR_KEYS_WASM);
+            Self {
+                tx,
+                validator,
+                protocol_key,
+                eth_hot_key,
+                eth_cold_key,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(VALIDATOR.def().help(wrap!(
+                    "The validator's address whose keys to change."
+                )))
+                .arg(PROTOCOL_KEY.def().help(wrap!(
+                    "The new protocol key. Its secret key must be in the \
+                     wallet."
+                )))
+                .arg(VALIDATOR_ETH_HOT_KEY.def().help(wrap!(
+                    "The new Ethereum hot key. Its secret key must be in the \
+                     wallet. Note this key must be secp256k1."
+                )))
+                .arg(VALIDATOR_ETH_COLD_KEY.def().help(wrap!(
+                    "The new Ethereum cold key. Its secret key must be in the \
+                     wallet. Note this key must be secp256k1."
+                )))
+                .group(
+                    ArgGroup::new("validator_keys")
+                        .args([
+                            PROTOCOL_KEY.name,
+                            VALIDATOR_ETH_HOT_KEY.name,
+                            VALIDATOR_ETH_COLD_KEY.name,
+                        ])
+                        .required(true)
+                        .multiple(true),
+                )
+        }
+    }
+
     impl CliToSdk<MetaDataChange<SdkTypes>> for MetaDataChange<CliTypes> {
         type Error = std::io::Error;
 
@@ -6354,11 +7521,48 @@ pub mod args {
                 avatar: self.avatar,
                 name: self.name,
                 commission_rate: self.commission_rate,
+                security_contact: self.security_contact,
+                social_handles: self.social_handles,
+                identity_proof: self.identity_proof,
+                extensions: self.extensions,
                 tx_code_path: self.tx_code_path.to_path_buf(),
             })
         }
     }
 
+    /// An entry of a multi-valued argument given as `KEY:VALUE`
+    #[derive(Clone, Debug)]
+    pub struct KeyValueEntry {
+        /// The entry's key
+        pub key: String,
+        /// The entry's value
+        pub value: String,
+    }
+
+    impl FromStr for KeyValueEntry {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let (key, value) = s
+                .split_once(':')
+                .ok_or_else(|| "Expected KEY:VALUE".to_string())?;
+            Ok(Self {
+                key: key.trim().to_string(),
+                value: value.trim().to_string(),
+            })
+        }
+    }
+
+    /// Collect the entries of a multi-valued `KEY:VALUE` argument into a map
+    fn key_value_entries_map(
+        entries: Vec<KeyValueEntry>,
+    ) -> BTreeMap<String, String> {
+        entries
+            .into_iter()
+            .map(|KeyValueEntry { key, value }| (key, value))
+            .collect()
+    }
+
     impl Args for MetaDataChange<CliTypes> {
         fn parse(matches: &ArgMatches) -> Self {
             let tx = Tx::parse(matches);
@@ -6370,6 +7574,12 @@ pub mod args {
             let avatar = AVATAR_OPT.parse(matches);
             let name = VALIDATOR_NAME_OPT.parse(matches);
             let commission_rate = COMMISSION_RATE_OPT.parse(matches);
+            let security_contact = SECURITY_CONTACT_OPT.parse(matches);
+            let social_handles =
+                key_value_entries_map(SOCIAL_HANDLES.parse(matches));
+            let identity_proof = IDENTITY_PROOF_OPT.parse(matches);
+            let extensions =
+                key_value_entries_map(METADATA_EXTENSIONS.parse(matches));
             let tx_code_path = PathBuf::from(TX_CHANGE_METADATA_WASM);
             Self {
                 tx,
@@ -6381,6 +7591,10 @@ pub mod args {
                 avatar,
                 name,
                 commission_rate,
+                security_contact,
+                social_handles,
+                identity_proof,
+                extensions,
                 tx_code_path,
             }
         }
@@ -6422,6 +7636,25 @@ pub mod args {
                         .def()
                         .help(wrap!("The desired new commission rate.")),
                 )
+                .arg(SECURITY_CONTACT_OPT.def().help(wrap!(
+                    "The desired new validator security contact. To remove \
+                     the existing security contact, pass an empty string to \
+                     this argument."
+                )))
+                .arg(SOCIAL_HANDLES.def().help(wrap!(
+                    "Comma-separated list of PLATFORM:HANDLE entries to set \
+                     the validator's social handles. To remove a handle, \
+                     leave it empty, e.g. \"twitter:\"."
+                )))
+                .arg(IDENTITY_PROOF_OPT.def().help(wrap!(
+                    "The hash of a proof of the validator's identity. To \
+                     remove the existing proof, pass a zero hash."
+                )))
+                .arg(METADATA_EXTENSIONS.def().help(wrap!(
+                    "Comma-separated list of KEY:VALUE entries to set custom \
+                     metadata fields. To remove a field, leave its value \
+                     empty."
+                )))
         }
     }
 
@@ -6592,6 +7825,8 @@ pub mod args {
             let spending_keys = SPENDING_KEYS.parse(matches);
             let viewing_keys = VIEWING_KEYS.parse(matches);
             let with_indexer = WITH_INDEXER.parse(matches);
+            let scan_workers = SCAN_WORKERS.parse(matches);
+            let checkpoint_interval = CHECKPOINT_INTERVAL.parse(matches);
             Self {
                 ledger_address,
                 start_query_height,
@@ -6599,6 +7834,8 @@ pub mod args {
                 spending_keys,
                 viewing_keys,
                 with_indexer,
+                scan_workers,
+                checkpoint_interval,
             }
         }
 
@@ -6625,6 +7862,16 @@ pub mod args {
                      present, the shielded sync will be performed using data \
                      retrieved from the given indexer."
                 )))
+                .arg(SCAN_WORKERS.def().help(wrap!(
+                    "Number of threads among which the viewing keys are \
+                     partitioned for trial decryption. Defaults to the number \
+                     of available cores."
+                )))
+                .arg(CHECKPOINT_INTERVAL.def().help(wrap!(
+                    "Number of scanned transactions after which the shielded \
+                     context is saved, such that an interrupted sync can \
+                     resume from there. A value of 0 disables checkpoints."
+                )))
         }
     }
 
@@ -6652,6 +7899,8 @@ pub mod args {
                     .map(|vk| chain_ctx.get_cached(vk))
                     .collect(),
                 with_indexer: self.with_indexer.map(|_| ()),
+                scan_workers: self.scan_workers,
+                checkpoint_interval: self.checkpoint_interval,
             })
         }
     }
@@ -6789,6 +8038,186 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<QueryValidatorsByMetadata<SdkTypes>>
+        for QueryValidatorsByMetadata<CliTypes>
+    {
+        type Error = std::convert::Infallible;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<QueryValidatorsByMetadata<SdkTypes>, Self::Error> {
+            Ok(QueryValidatorsByMetadata::<SdkTypes> {
+                query: self.query.to_sdk(ctx)?,
+                field: self.field,
+                value: self.value,
+                epoch: self.epoch,
+            })
+        }
+    }
+
+    impl Args for QueryValidatorsByMetadata<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let field = METADATA_FIELD.parse(matches);
+            let value = METADATA_VALUE_OPT.parse(matches);
+            let epoch = EPOCH.parse(matches);
+            Self {
+                query,
+                field,
+                value,
+                epoch,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>()
+                .arg(METADATA_FIELD.def().help(wrap!(
+                    "The metadata field, one of \"email\", \"description\", \
+                     \"website\", \"avatar\", \"name\", \"security-contact\", \
+                     \"identity-proof\", \"social:PLATFORM\" or \"ext:KEY\"."
+                )))
+                .arg(METADATA_VALUE_OPT.def().help(wrap!(
+                    "Only show the validators whose field has this value, \
+                     ignoring case."
+                )))
+                .arg(EPOCH.def().help(wrap!(
+                    "The epoch in which to find the validators (corresponding \
+                     to the last committed block, if not specified)."
+                )))
+        }
+    }
+
+    impl CliToSdk<QueryValidatorSelfBond<SdkTypes>>
+        for QueryValidatorSelfBond<CliTypes>
+    {
+        type Error = std::convert::Infallible;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<QueryValidatorSelfBond<SdkTypes>, Self::Error> {
+            Ok(QueryValidatorSelfBond::<SdkTypes> {
+                query: self.query.to_sdk(ctx)?,
+                validator: ctx.borrow_chain_or_exit().get(&self.validator),
+                epoch: self.epoch,
+            })
+        }
+    }
+
+    impl Args for QueryValidatorSelfBond<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let validator = VALIDATOR.parse(matches);
+            let epoch = EPOCH.parse(matches);
+            Self {
+                query,
+                validator,
+                epoch,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>()
+                .arg(VALIDATOR.def().help(wrap!(
+                    "The validator's address whose self-bond is queried."
+                )))
+                .arg(EPOCH.def().help(wrap!(
+                    "The epoch at which to query (corresponding to the last \
+                     committed block, if not specified)."
+                )))
+        }
+    }
+
+    impl CliToSdk<QueryValidatorLiveness<SdkTypes>>
+        for QueryValidatorLiveness<CliTypes>
+    {
+        type Error = std::convert::Infallible;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<QueryValidatorLiveness<SdkTypes>, Self::Error> {
+            Ok(QueryValidatorLiveness::<SdkTypes> {
+                query: self.query.to_sdk(ctx)?,
+                validator: ctx.borrow_chain_or_exit().get(&self.validator),
+                num_epochs: self.num_epochs,
+            })
+        }
+    }
+
+    impl Args for QueryValidatorLiveness<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let validator = VALIDATOR.parse(matches);
+            let num_epochs = NUM_EPOCHS.parse(matches);
+            Self {
+                query,
+                validator,
+                num_epochs,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>()
+                .arg(VALIDATOR.def().help(wrap!(
+                    "The validator's address whose liveness to query."
+                )))
+                .arg(NUM_EPOCHS.def().help(wrap!(
+                    "The number of past epochs of history to show. Defaults \
+                     to all the history kept by the chain."
+                )))
+        }
+    }
+
+    impl CliToSdk<QuerySlashSimulation<SdkTypes>>
+        for QuerySlashSimulation<CliTypes>
+    {
+        type Error = std::convert::Infallible;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<QuerySlashSimulation<SdkTypes>, Self::Error> {
+            Ok(QuerySlashSimulation::<SdkTypes> {
+                query: self.query.to_sdk(ctx)?,
+                validator: ctx.borrow_chain_or_exit().get(&self.validator),
+                slash_type: self.slash_type,
+                infraction_epoch: self.infraction_epoch,
+            })
+        }
+    }
+
+    impl Args for QuerySlashSimulation<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let validator = VALIDATOR.parse(matches);
+            let slash_type = SLASH_TYPE.parse(matches);
+            let infraction_epoch = INFRACTION_EPOCH.parse(matches);
+            Self {
+                query,
+                validator,
+                slash_type,
+                infraction_epoch,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>()
+                .arg(VALIDATOR.def().help(wrap!(
+                    "The address of the validator to simulate a slash for."
+                )))
+                .arg(SLASH_TYPE.def().help(wrap!(
+                    "The type of the hypothetical infraction, either \
+                     \"duplicate-vote\" or \"light-client-attack\"."
+                )))
+                .arg(INFRACTION_EPOCH.def().help(wrap!(
+                    "The epoch of the hypothetical infraction. Its slash must \
+                     not have been processed yet."
+                )))
+        }
+    }
+
     impl CliToSdk<QuerySlashes<SdkTypes>> for QuerySlashes<CliTypes> {
         type Error = std::convert::Infallible;
 
@@ -6862,6 +8291,68 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<QueryRewardsHistory<SdkTypes>> for QueryRewardsHistory<CliTypes> {
+        type Error = std::convert::Infallible;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<QueryRewardsHistory<SdkTypes>, Self::Error> {
+            Ok(QueryRewardsHistory::<SdkTypes> {
+                query: self.query.to_sdk(ctx)?,
+                validator: ctx.borrow_chain_or_exit().get(&self.validator),
+                source: self.source.map(|x| ctx.borrow_chain_or_exit().get(&x)),
+                from_epoch: self.from_epoch,
+                to_epoch: self.to_epoch,
+                output: self.output,
+            })
+        }
+    }
+
+    impl Args for QueryRewardsHistory<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let source = SOURCE_OPT.parse(matches);
+            let validator = VALIDATOR.parse(matches);
+            let from_epoch = FROM_EPOCH.parse(matches);
+            let to_epoch = TO_EPOCH.parse(matches);
+            let output = OUTPUT.parse(matches);
+            Self {
+                query,
+                source,
+                validator,
+                from_epoch,
+                to_epoch,
+                output,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>()
+                .arg(SOURCE_OPT.def().help(wrap!(
+                    "Source address for the rewards query. For self-bonds, \
+                     this arg does not need to be supplied."
+                )))
+                .arg(
+                    VALIDATOR.def().help(wrap!(
+                        "Validator address for the rewards query."
+                    )),
+                )
+                .arg(FROM_EPOCH.def().help(wrap!(
+                    "The first epoch of the history. Defaults to the genesis \
+                     epoch."
+                )))
+                .arg(TO_EPOCH.def().help(wrap!(
+                    "The last epoch of the history. Defaults to the last \
+                     epoch for which rewards have been computed."
+                )))
+                .arg(OUTPUT.def().help(wrap!(
+                    "Save the CSV to a file. When not supplied, it will be \
+                     printed to stdout instead."
+                )))
+        }
+    }
+
     impl Args for QueryDelegations<CliTypes> {
         fn parse(matches: &ArgMatches) -> Self {
             let query = Query::parse(matches);
@@ -3,6 +3,7 @@ use std::io::Read;
 use color_eyre::eyre::Result;
 use masp_primitives::zip32::ExtendedFullViewingKey;
 use namada_sdk::io::Io;
+use namada_sdk::masp::utils::ScanConfig;
 use namada_sdk::{display_line, Namada, NamadaImpl};
 
 use crate::cli;
@@ -76,6 +77,31 @@ impl CliApi {
                         let namada = ctx.to_sdk(client, io);
                         tx::submit_shielded_transfer(&namada, args).await?;
                     }
+                    Sub::TxShieldedConsolidate(TxShieldedConsolidate(args)) => {
+                        let chain_ctx = ctx.borrow_mut_chain_or_exit();
+                        let ledger_address =
+                            chain_ctx.get(&args.tx.ledger_address);
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(&ledger_address)
+                        });
+                        client.wait_until_node_is_synced(&io).await?;
+                        let args = args.to_sdk(&mut ctx)?;
+                        let namada = ctx.to_sdk(client, io);
+                        tx::submit_shielded_consolidation(&namada, args)
+                            .await?;
+                    }
+                    Sub::DisposableKeys(DisposableKeys(args)) => {
+                        let chain_ctx = ctx.borrow_mut_chain_or_exit();
+                        let ledger_address =
+                            chain_ctx.get(&args.tx.ledger_address);
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(&ledger_address)
+                        });
+                        client.wait_until_node_is_synced(&io).await?;
+                        let args = args.to_sdk(&mut ctx)?;
+                        let namada = ctx.to_sdk(client, io);
+                        tx::submit_disposable_keys(&namada, args).await?;
+                    }
                     Sub::TxShieldingTransfer(TxShieldingTransfer(args)) => {
                         let chain_ctx = ctx.borrow_mut_chain_or_exit();
                         let ledger_address =
@@ -224,6 +250,42 @@ impl CliApi {
                         let namada = ctx.to_sdk(client, io);
                         tx::submit_vote_proposal(&namada, args).await?;
                     }
+                    Sub::TxCancelProposal(TxCancelProposal(args)) => {
+                        let chain_ctx = ctx.borrow_mut_chain_or_exit();
+                        let ledger_address =
+                            chain_ctx.get(&args.tx.ledger_address);
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(&ledger_address)
+                        });
+                        client.wait_until_node_is_synced(&io).await?;
+                        let args = args.to_sdk(&mut ctx)?;
+                        let namada = ctx.to_sdk(client, io);
+                        tx::submit_cancel_proposal(&namada, args).await?;
+                    }
+                    Sub::TxVetoProposal(TxVetoProposal(args)) => {
+                        let chain_ctx = ctx.borrow_mut_chain_or_exit();
+                        let ledger_address =
+                            chain_ctx.get(&args.tx.ledger_address);
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(&ledger_address)
+                        });
+                        client.wait_until_node_is_synced(&io).await?;
+                        let args = args.to_sdk(&mut ctx)?;
+                        let namada = ctx.to_sdk(client, io);
+                        tx::submit_veto_proposal(&namada, args).await?;
+                    }
+                    Sub::TxDelegateVote(TxDelegateVote(args)) => {
+                        let chain_ctx = ctx.borrow_mut_chain_or_exit();
+                        let ledger_address =
+                            chain_ctx.get(&args.tx.ledger_address);
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(&ledger_address)
+                        });
+                        client.wait_until_node_is_synced(&io).await?;
+                        let args = args.to_sdk(&mut ctx)?;
+                        let namada = ctx.to_sdk(client, io);
+                        tx::submit_delegate_vote(&namada, args).await?;
+                    }
                     Sub::TxRevealPk(TxRevealPk(args)) => {
                         let chain_ctx = ctx.borrow_mut_chain_or_exit();
                         let ledger_address =
@@ -284,6 +346,18 @@ impl CliApi {
                         let namada = ctx.to_sdk(client, io);
                         tx::submit_claim_rewards(&namada, args).await?;
                     }
+                    Sub::AutoCompound(AutoCompound(args)) => {
+                        let chain_ctx = ctx.borrow_mut_chain_or_exit();
+                        let ledger_address =
+                            chain_ctx.get(&args.tx.ledger_address);
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(&ledger_address)
+                        });
+                        client.wait_until_node_is_synced(&io).await?;
+                        let args = args.to_sdk(&mut ctx)?;
+                        let namada = ctx.to_sdk(client, io);
+                        tx::submit_auto_compound(&namada, args).await?;
+                    }
                     Sub::Redelegate(Redelegate(args)) => {
                         let chain_ctx = ctx.borrow_mut_chain_or_exit();
                         let ledger_address =
@@ -296,6 +370,18 @@ impl CliApi {
                         let namada = ctx.to_sdk(client, io);
                         tx::submit_redelegate(&namada, args).await?;
                     }
+                    Sub::RebalanceDelegations(RebalanceDelegations(args)) => {
+                        let chain_ctx = ctx.borrow_mut_chain_or_exit();
+                        let ledger_address =
+                            chain_ctx.get(&args.tx.ledger_address);
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(&ledger_address)
+                        });
+                        client.wait_until_node_is_synced(&io).await?;
+                        let args = args.to_sdk(&mut ctx)?;
+                        let namada = ctx.to_sdk(client, io);
+                        tx::submit_rebalance_delegations(&namada, args).await?;
+                    }
                     Sub::TxCommissionRateChange(TxCommissionRateChange(
                         args,
                     )) => {
@@ -311,6 +397,23 @@ impl CliApi {
                         tx::submit_validator_commission_change(&namada, args)
                             .await?;
                     }
+                    Sub::TxCommissionScheduleChange(
+                        TxCommissionScheduleChange(args),
+                    ) => {
+                        let chain_ctx = ctx.borrow_mut_chain_or_exit();
+                        let ledger_address =
+                            chain_ctx.get(&args.tx.ledger_address);
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(&ledger_address)
+                        });
+                        client.wait_until_node_is_synced(&io).await?;
+                        let args = args.to_sdk(&mut ctx)?;
+                        let namada = ctx.to_sdk(client, io);
+                        tx::submit_validator_commission_schedule_change(
+                            &namada, args,
+                        )
+                        .await?;
+                    }
                     Sub::TxChangeConsensusKey(TxChangeConsensusKey(args)) => {
                         let chain_ctx = ctx.borrow_mut_chain_or_exit();
                         let ledger_address =
@@ -323,6 +426,18 @@ impl CliApi {
                         let namada = ctx.to_sdk(client, io);
                         tx::submit_change_consensus_key(&namada, args).await?;
                     }
+                    Sub::TxChangeValidatorKeys(TxChangeValidatorKeys(args)) => {
+                        let chain_ctx = ctx.borrow_mut_chain_or_exit();
+                        let ledger_address =
+                            chain_ctx.get(&args.tx.ledger_address);
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(&ledger_address)
+                        });
+                        client.wait_until_node_is_synced(&io).await?;
+                        let args = args.to_sdk(&mut ctx)?;
+                        let namada = ctx.to_sdk(client, io);
+                        tx::submit_change_validator_keys(&namada, args).await?;
+                    }
                     Sub::TxMetadataChange(TxMetadataChange(args)) => {
                         let chain_ctx = ctx.borrow_mut_chain_or_exit();
                         let ledger_address =
@@ -361,6 +476,17 @@ impl CliApi {
                             .into_iter()
                             .map(|sk| sk.into())
                             .collect::<Vec<_>>();
+                        let default_scan_config = ScanConfig::default();
+                        let scan_config = ScanConfig {
+                            workers: args
+                                .scan_workers
+                                .unwrap_or(default_scan_config.workers),
+                            checkpoint_interval: args
+                                .checkpoint_interval
+                                .unwrap_or(
+                                    default_scan_config.checkpoint_interval,
+                                ),
+                        };
                         crate::client::masp::syncing(
                             chain_ctx.shielded,
                             &client,
@@ -368,6 +494,7 @@ impl CliApi {
                             &io,
                             args.start_query_height,
                             args.last_query_height,
+                            scan_config,
                             &sks,
                             &vks,
                         )
@@ -614,6 +741,66 @@ impl CliApi {
                         let namada = ctx.to_sdk(client, io);
                         rpc::query_and_print_metadata(&namada, args).await;
                     }
+                    Sub::QueryValidatorsByMetadata(
+                        QueryValidatorsByMetadata(args),
+                    ) => {
+                        let chain_ctx = ctx.borrow_mut_chain_or_exit();
+                        let ledger_address =
+                            chain_ctx.get(&args.query.ledger_address);
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(&ledger_address)
+                        });
+                        client.wait_until_node_is_synced(&io).await?;
+                        let args = args.to_sdk(&mut ctx)?;
+                        let namada = ctx.to_sdk(client, io);
+                        rpc::query_and_print_validators_by_metadata(
+                            &namada, args,
+                        )
+                        .await;
+                    }
+                    Sub::QueryValidatorSelfBond(QueryValidatorSelfBond(
+                        args,
+                    )) => {
+                        let chain_ctx = ctx.borrow_mut_chain_or_exit();
+                        let ledger_address =
+                            chain_ctx.get(&args.query.ledger_address);
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(&ledger_address)
+                        });
+                        client.wait_until_node_is_synced(&io).await?;
+                        let args = args.to_sdk(&mut ctx)?;
+                        let namada = ctx.to_sdk(client, io);
+                        rpc::query_and_print_validator_self_bond(&namada, args)
+                            .await;
+                    }
+                    Sub::QueryValidatorLiveness(QueryValidatorLiveness(
+                        args,
+                    )) => {
+                        let chain_ctx = ctx.borrow_mut_chain_or_exit();
+                        let ledger_address =
+                            chain_ctx.get(&args.query.ledger_address);
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(&ledger_address)
+                        });
+                        client.wait_until_node_is_synced(&io).await?;
+                        let args = args.to_sdk(&mut ctx)?;
+                        let namada = ctx.to_sdk(client, io);
+                        rpc::query_and_print_validator_liveness(&namada, args)
+                            .await;
+                    }
+                    Sub::QuerySlashSimulation(QuerySlashSimulation(args)) => {
+                        let chain_ctx = ctx.borrow_mut_chain_or_exit();
+                        let ledger_address =
+                            chain_ctx.get(&args.query.ledger_address);
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(&ledger_address)
+                        });
+                        client.wait_until_node_is_synced(&io).await?;
+                        let args = args.to_sdk(&mut ctx)?;
+                        let namada = ctx.to_sdk(client, io);
+                        rpc::query_and_print_slash_simulation(&namada, args)
+                            .await;
+                    }
                     Sub::QuerySlashes(QuerySlashes(args)) => {
                         let chain_ctx = ctx.borrow_mut_chain_or_exit();
                         let ledger_address =
@@ -638,6 +825,19 @@ impl CliApi {
                         let namada = ctx.to_sdk(client, io);
                         rpc::query_and_print_rewards(&namada, args).await;
                     }
+                    Sub::QueryRewardsHistory(QueryRewardsHistory(args)) => {
+                        let chain_ctx = ctx.borrow_mut_chain_or_exit();
+                        let ledger_address =
+                            chain_ctx.get(&args.query.ledger_address);
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(&ledger_address)
+                        });
+                        client.wait_until_node_is_synced(&io).await?;
+                        let args = args.to_sdk(&mut ctx)?;
+                        let namada = ctx.to_sdk(client, io);
+                        rpc::query_and_print_rewards_history(&namada, args)
+                            .await;
+                    }
                     Sub::QueryDelegations(QueryDelegations(args)) => {
                         let chain_ctx = ctx.borrow_mut_chain_or_exit();
                         let ledger_address =
@@ -710,6 +910,18 @@ impl CliApi {
                         let namada = ctx.to_sdk(client, io);
                         rpc::query_proposal_result(&namada, args).await;
                     }
+                    Sub::QueryProposalDryRun(QueryProposalDryRun(args)) => {
+                        let chain_ctx = ctx.borrow_mut_chain_or_exit();
+                        let ledger_address =
+                            chain_ctx.get(&args.query.ledger_address);
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(&ledger_address)
+                        });
+                        client.wait_until_node_is_synced(&io).await?;
+                        let args = args.to_sdk(&mut ctx)?;
+                        let namada = ctx.to_sdk(client, io);
+                        rpc::query_proposal_dry_run(&namada, args).await;
+                    }
                     Sub::QueryProposalVotes(QueryProposalVotes(args)) => {
                         let chain_ctx = ctx.borrow_mut_chain_or_exit();
                         let ledger_address =
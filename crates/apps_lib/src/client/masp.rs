@@ -9,7 +9,7 @@ use namada_sdk::error::Error;
 use namada_sdk::io::Io;
 use namada_sdk::masp::utils::{
     IndexerMaspClient, LedgerMaspClient, PeekableIter, ProgressTracker,
-    ProgressType, RetryStrategy,
+    ProgressType, RetryStrategy, ScanConfig,
 };
 use namada_sdk::masp::{IndexedNoteEntry, ShieldedContext, ShieldedUtils};
 use namada_sdk::queries::Client;
@@ -28,6 +28,7 @@ pub async fn syncing<
     io: &IO,
     start_query_height: Option<BlockHeight>,
     last_query_height: Option<BlockHeight>,
+    scan_config: ScanConfig,
     sks: &[ExtendedSpendingKey],
     fvks: &[ViewingKey],
 ) -> Result<ShieldedContext<U>, Error> {
@@ -56,6 +57,7 @@ pub async fn syncing<
                     start_query_height,
                     last_query_height,
                     RetryStrategy::Forever,
+                    scan_config,
                     sks,
                     fvks,
                 )
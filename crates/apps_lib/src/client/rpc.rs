@@ -29,8 +29,9 @@ use namada_sdk::key::*;
 use namada_sdk::masp::{BalanceOwner, MaspEpoch, MaspTokenRewardData};
 use namada_sdk::parameters::{storage as param_storage, EpochDuration};
 use namada_sdk::proof_of_stake::types::{
-    CommissionPair, Slash, ValidatorMetaData, ValidatorState,
-    ValidatorStateInfo, WeightedValidator,
+    CommissionPair, EpochLiveness, Slash, StructuredValidatorMetadata,
+    ValidatorMetaData, ValidatorState, ValidatorStateInfo, WeightedValidator,
+    DISCORD_PLATFORM,
 };
 use namada_sdk::proof_of_stake::PosParams;
 use namada_sdk::queries::{Client, RPC};
@@ -268,17 +269,31 @@ pub async fn query_proposal_votes(
     )
     .await
     .unwrap();
+    let represented_votes = namada_sdk::rpc::query_represented_votes(
+        context.client(),
+        args.proposal_id,
+    )
+    .await
+    .unwrap();
 
     match args.voter {
         Some(voter) => {
-            match result.into_iter().find(|vote| vote.delegator == voter) {
-                Some(vote) => display_line!(context.io(), "{}", vote,),
-                None => display_line!(
+            if let Some(vote) =
+                result.into_iter().find(|vote| vote.delegator == voter)
+            {
+                display_line!(context.io(), "{}", vote);
+            } else if let Some(vote) = represented_votes
+                .into_iter()
+                .find(|vote| vote.delegator == voter)
+            {
+                display_line!(context.io(), "{}", vote);
+            } else {
+                display_line!(
                     context.io(),
                     "The address {} has not voted on proposal {}",
                     voter,
                     args.proposal_id
-                ),
+                );
             }
         }
         None => {
@@ -290,6 +305,9 @@ pub async fn query_proposal_votes(
             for vote in result {
                 display_line!(context.io(), "{}\n", vote);
             }
+            for vote in represented_votes {
+                display_line!(context.io(), "{}\n", vote);
+            }
         }
     }
 }
@@ -355,6 +373,10 @@ async fn query_shielded_balance(
         token,
         // Used to control whether conversions are automatically performed
         no_conversions,
+        // Evaluate the balance at a past masp epoch
+        masp_epoch: target_epoch,
+        // The number of masp epochs for which to project rewards
+        project_rewards,
         ..
     } = args;
 
@@ -379,6 +401,17 @@ async fn query_shielded_balance(
 
     // The epoch is required to identify timestamped tokens
     let masp_epoch = query_and_print_masp_epoch(context).await;
+    let masp_epoch = match target_epoch {
+        Some(target_epoch) if target_epoch > masp_epoch => {
+            edisplay_line!(
+                context.io(),
+                "The requested MASP epoch {target_epoch} is in the future."
+            );
+            cli::safe_exit(1)
+        }
+        Some(target_epoch) => target_epoch,
+        None => masp_epoch,
+    };
 
     // Query the token alias in the wallet for pretty printing token balances
     let token_alias = lookup_token_alias(context, &token, &MASP).await;
@@ -400,6 +433,21 @@ async fn query_shielded_balance(
             return;
         };
         bal
+    } else if target_epoch.is_some() {
+        let Some(bal) = shielded
+            .compute_exchanged_balance_at_epoch(
+                context.client(),
+                context.io(),
+                &viewing_key,
+                masp_epoch,
+            )
+            .await
+            .unwrap()
+        else {
+            no_balance();
+            return;
+        };
+        bal
     } else {
         let Some(bal) = shielded
             .compute_exchanged_balance(
@@ -433,6 +481,44 @@ async fn query_shielded_balance(
             context.format_amount(&token, total_balance.into()).await
         );
     }
+
+    if let Some(epochs) = project_rewards {
+        let projection = shielded
+            .project_shielded_rewards(
+                context.client(),
+                context.io(),
+                &viewing_key,
+                epochs,
+            )
+            .await
+            .unwrap()
+            .unwrap_or_default();
+        let rewards = projection
+            .into_iter()
+            .find_map(|(addr, rewards)| (addr == token).then_some(rewards))
+            .unwrap_or_default();
+        let native_token = context.native_token();
+        let native_alias =
+            lookup_token_alias(context, &native_token, &MASP).await;
+        display_line!(
+            context.io(),
+            "Projected rewards of {token_alias} for the next {epochs} MASP \
+             epoch(s):"
+        );
+        if rewards.is_empty() {
+            display_line!(context.io(), "{:4}No rewards", "");
+        }
+        for (idx, reward) in (1u64..).zip(rewards) {
+            display_line!(
+                context.io(),
+                "{:4}+{} epoch(s): {} {}",
+                "",
+                idx,
+                context.format_amount(&native_token, reward).await,
+                native_alias
+            );
+        }
+    }
 }
 
 pub async fn query_proposal_result(
@@ -479,6 +565,77 @@ pub async fn query_proposal_result(
     }
 }
 
+/// Dry run the wasm code of a governance proposal and print the outcome
+pub async fn query_proposal_dry_run(
+    context: &impl Namada,
+    args: args::QueryProposalDryRun,
+) {
+    let proposal_id = args.proposal_id;
+
+    let dry_run =
+        match namada_sdk::rpc::dry_run_proposal(context.client(), proposal_id)
+            .await
+        {
+            Ok(dry_run) => dry_run,
+            Err(err) => {
+                edisplay_line!(context.io(), "{}", err);
+                cli::safe_exit(1)
+            }
+        };
+
+    match &dry_run.result {
+        Ok(result) if result.is_accepted() => display_line!(
+            context.io(),
+            "The code of proposal {proposal_id} would be successfully applied."
+        ),
+        Ok(result) => display_line!(
+            context.io(),
+            "The code of proposal {proposal_id} would be rejected by VPs: \
+             {}\nErrors: {}",
+            serde_json::to_string_pretty(&result.vps_result.rejected_vps)
+                .unwrap(),
+            serde_json::to_string_pretty(&result.vps_result.errors).unwrap(),
+        ),
+        Err(err) => display_line!(
+            context.io(),
+            "The code of proposal {proposal_id} would fail with error: {err}"
+        ),
+    }
+    display_line!(context.io(), "Gas used: {}", dry_run.gas_used);
+
+    if !dry_run.storage_diff.is_empty() {
+        let fmt_value = |value: &Option<Vec<u8>>| match value {
+            Some(bytes) => format!("0x{}", HEXLOWER.encode(bytes)),
+            None => "none".to_string(),
+        };
+        display_line!(context.io(), "Storage changes:");
+        for (key, diff) in &dry_run.storage_diff {
+            display_line!(
+                context.io(),
+                "{:4}{key}: {} -> {}",
+                "",
+                fmt_value(&diff.before),
+                fmt_value(&diff.after),
+            );
+        }
+    }
+
+    if let Ok(result) = &dry_run.result {
+        if !result.events.is_empty() {
+            display_line!(context.io(), "Events:");
+            for event in &result.events {
+                display_line!(
+                    context.io(),
+                    "{:4}{}: {}",
+                    "",
+                    event.kind(),
+                    serde_json::to_string(event.attributes()).unwrap(),
+                );
+            }
+        }
+    }
+}
+
 pub async fn query_account(context: &impl Namada, args: args::QueryAccount) {
     let account = rpc::get_account_info(context.client(), &args.owner)
         .await
@@ -589,6 +746,38 @@ pub async fn query_protocol_parameters(
         "",
         governance_parameters.min_proposal_grace_epochs
     );
+    display_line!(
+        context.io(),
+        "{:4}Min. expedited proposal funds: {}",
+        "",
+        governance_parameters
+            .min_expedited_proposal_fund
+            .to_string_native()
+    );
+    display_line!(
+        context.io(),
+        "{:4}Min. expedited proposal voting period: {}",
+        "",
+        governance_parameters.min_expedited_proposal_voting_period
+    );
+    display_line!(
+        context.io(),
+        "{:4}Proposal cancellation refund rate: {}",
+        "",
+        governance_parameters.proposal_cancellation_refund_rate
+    );
+    display_line!(
+        context.io(),
+        "{:4}Veto threshold: {}",
+        "",
+        governance_parameters.veto_threshold
+    );
+    display_line!(
+        context.io(),
+        "{:4}Proposal timelock epochs: {}",
+        "",
+        governance_parameters.proposal_timelock_epochs
+    );
 
     let pgf_parameters = query_pgf_parameters(context.client()).await;
     display_line!(context.io(), "\nPublic Goods Funding Parameters");
@@ -701,6 +890,18 @@ pub async fn query_protocol_parameters(
         "",
         pos_params.validator_stake_threshold
     );
+    display_line!(
+        context.io(),
+        "{:4}Validator minimum self-bond: {}",
+        "",
+        pos_params.min_self_bond
+    );
+    display_line!(
+        context.io(),
+        "{:4}Max validator stake share: {}",
+        "",
+        pos_params.max_validator_stake_share
+    );
     display_line!(
         context.io(),
         "{:4}Duplicate vote minimum slash rate: {}",
@@ -1250,7 +1451,19 @@ pub async fn query_and_print_commission_rate(
                 "Validator {validator} commission rate: {commission_rate}, \
                  max change per epoch: {max_commission_change_per_epoch} in \
                  epoch {query_epoch}"
+            );
+            let schedule = namada_sdk::rpc::query_commission_schedule(
+                context.client(),
+                &validator,
             )
+            .await
+            .unwrap_or_default();
+            if !schedule.is_empty() {
+                display_line!(context.io(), "Scheduled commission changes:");
+                for (epoch, rate) in schedule {
+                    display_line!(context.io(), "  Epoch {epoch}: {rate}");
+                }
+            }
         }
         (None, None) => display_line!(
             context.io(),
@@ -1269,6 +1482,176 @@ pub async fn query_and_print_commission_rate(
     }
 }
 
+/// Query PoS validator's self-bond
+pub async fn query_and_print_validator_self_bond(
+    context: &impl Namada,
+    args: args::QueryValidatorSelfBond,
+) {
+    let validator = args.validator;
+    let epoch = match args.epoch {
+        Some(epoch) => epoch,
+        None => query_and_print_epoch(context).await,
+    };
+
+    let self_bond = match namada_sdk::rpc::query_validator_self_bond(
+        context.client(),
+        &validator,
+        Some(epoch),
+    )
+    .await
+    {
+        Ok(self_bond) => self_bond,
+        Err(err) => {
+            edisplay_line!(context.io(), "{}", err);
+            cli::safe_exit(1)
+        }
+    };
+    let pos_params = query_pos_parameters(context.client()).await;
+
+    display_line!(
+        context.io(),
+        "Self-bond of validator {validator} in epoch {epoch}: {}",
+        self_bond.to_string_native()
+    );
+    display_line!(
+        context.io(),
+        "Minimum self-bond: {}",
+        pos_params.min_self_bond.to_string_native()
+    );
+    if self_bond < pos_params.min_self_bond {
+        display_line!(
+            context.io(),
+            "The self-bond is below the minimum, so the validator is kept in \
+             the below-threshold set."
+        );
+    }
+}
+
+/// Query PoS validator's liveness
+pub async fn query_and_print_validator_liveness(
+    context: &impl Namada,
+    args: args::QueryValidatorLiveness,
+) {
+    let validator = args.validator;
+
+    let liveness = match namada_sdk::rpc::query_validator_liveness(
+        context.client(),
+        &validator,
+        args.num_epochs,
+    )
+    .await
+    {
+        Ok(liveness) => liveness,
+        Err(err) => {
+            edisplay_line!(context.io(), "{}", err);
+            cli::safe_exit(1)
+        }
+    };
+
+    display_line!(
+        context.io(),
+        "Validator {validator} missed {} of the last {} blocks. It gets \
+         jailed at {} missed blocks.",
+        liveness.window_missed_votes,
+        liveness.window_len,
+        liveness.window_missed_votes_threshold,
+    );
+    if liveness.history.is_empty() {
+        display_line!(context.io(), "No liveness history found.");
+        return;
+    }
+    display_line!(context.io(), "Missed votes per epoch:");
+    for (
+        epoch,
+        EpochLiveness {
+            expected_votes,
+            missed_votes,
+        },
+    ) in liveness.history
+    {
+        display_line!(
+            context.io(),
+            "  Epoch {epoch}: {missed_votes} of {expected_votes}"
+        );
+    }
+}
+
+/// Simulate a slash of a PoS validator for a hypothetical infraction
+pub async fn query_and_print_slash_simulation(
+    context: &impl Namada,
+    args: args::QuerySlashSimulation,
+) {
+    let validator = args.validator;
+
+    let simulation = match namada_sdk::rpc::query_slash_simulation(
+        context.client(),
+        &validator,
+        args.slash_type,
+        args.infraction_epoch,
+    )
+    .await
+    {
+        Ok(simulation) => simulation,
+        Err(err) => {
+            edisplay_line!(context.io(), "{}", err);
+            cli::safe_exit(1)
+        }
+    };
+
+    display_line!(
+        context.io(),
+        "A {} infraction of validator {validator} in epoch {} would be \
+         processed in epoch {}.",
+        args.slash_type,
+        simulation.infraction_epoch,
+        simulation.processing_epoch,
+    );
+    display_line!(
+        context.io(),
+        "Slash rate: {} (cubic slash rate: {}, total validator slash rate in \
+         the processing epoch: {})",
+        simulation.slash_rate,
+        simulation.cubic_slash_rate,
+        simulation.validator_slash_rate,
+    );
+    if simulation.bond_losses.is_empty() {
+        display_line!(context.io(), "No bonds would be affected.");
+        return;
+    }
+    display_line!(context.io(), "Bond losses:");
+    let mut total_loss = token::Amount::zero();
+    for bond_loss in &simulation.bond_losses {
+        let loss = bond_loss.loss();
+        let Some(new_total_loss) = total_loss.checked_add(loss) else {
+            edisplay_line!(
+                context.io(),
+                "The total loss of the simulated slash overflows."
+            );
+            cli::safe_exit(1)
+        };
+        total_loss = new_total_loss;
+        let redelegated = if bond_loss.bond_id.validator == validator {
+            ""
+        } else {
+            " (redelegated)"
+        };
+        display_line!(
+            context.io(),
+            "  {} -> {}{redelegated}: {} -> {}, loss {}",
+            bond_loss.bond_id.source,
+            bond_loss.bond_id.validator,
+            bond_loss.bond_before.to_string_native(),
+            bond_loss.bond_after.to_string_native(),
+            loss.to_string_native(),
+        );
+    }
+    display_line!(
+        context.io(),
+        "Total loss: {}",
+        total_loss.to_string_native()
+    );
+}
+
 /// Query PoS validator's metadata
 pub async fn query_and_print_metadata(
     context: &impl Namada,
@@ -1323,6 +1706,18 @@ pub async fn query_and_print_metadata(
             } else {
                 display_line!(context.io(), "No avatar");
             }
+            let structured = namada_sdk::rpc::query_structured_metadata(
+                context.client(),
+                &validator,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                edisplay_line!(context.io(), "{err}");
+                cli::safe_exit(1)
+            });
+            if let Some(structured) = structured {
+                print_structured_metadata(context, structured);
+            }
         }
         None => display_line!(
             context.io(),
@@ -1363,6 +1758,64 @@ pub async fn query_and_print_metadata(
     }
 }
 
+fn print_structured_metadata(
+    context: &impl Namada,
+    metadata: StructuredValidatorMetadata,
+) {
+    if let Some(security_contact) = metadata.security_contact {
+        display_line!(context.io(), "Security contact: {}", security_contact);
+    }
+    for (platform, handle) in metadata
+        .social_handles
+        .into_iter()
+        .filter(|(platform, _)| platform != DISCORD_PLATFORM)
+    {
+        display_line!(context.io(), "Social handle ({platform}): {handle}");
+    }
+    if let Some(identity_proof) = metadata.identity_proof {
+        display_line!(context.io(), "Identity proof: {}", identity_proof);
+    }
+    for (key, value) in metadata.extensions {
+        display_line!(context.io(), "Extension {key}: {value}");
+    }
+}
+
+/// Query the validators with the given metadata field set
+pub async fn query_and_print_validators_by_metadata(
+    context: &impl Namada,
+    args: args::QueryValidatorsByMetadata,
+) {
+    let validators = namada_sdk::rpc::query_validators_by_metadata(
+        context.client(),
+        &args.field,
+        args.epoch,
+    )
+    .await
+    .unwrap_or_else(|err| {
+        edisplay_line!(context.io(), "{err}");
+        cli::safe_exit(1)
+    });
+    let mut found = false;
+    for (validator, value) in validators {
+        if args
+            .value
+            .as_ref()
+            .is_some_and(|expected| !value.eq_ignore_ascii_case(expected))
+        {
+            continue;
+        }
+        found = true;
+        display_line!(context.io(), "{}: {}", validator.encode(), value);
+    }
+    if !found {
+        display_line!(
+            context.io(),
+            "No validators found with the metadata field {}",
+            args.field
+        );
+    }
+}
+
 /// Query PoS slashes
 pub async fn query_slashes<N: Namada>(context: &N, args: args::QuerySlashes) {
     match args.validator {
@@ -1529,6 +1982,79 @@ pub async fn query_and_print_rewards<N: Namada>(
     );
 }
 
+/// Query the rewards earned in each epoch by a bond and print them as CSV
+pub async fn query_and_print_rewards_history<N: Namada>(
+    context: &N,
+    args: args::QueryRewardsHistory,
+) {
+    let (source, validator) = (args.source, args.validator);
+    let from_epoch = args.from_epoch.unwrap_or_default();
+    // Not printing the epoch to keep the output a valid CSV. The history is
+    // capped at the last epoch with rewards.
+    let to_epoch = match args.to_epoch {
+        Some(epoch) => epoch,
+        None => query_epoch(context.client()).await.unwrap(),
+    };
+
+    let history = match namada_sdk::rpc::query_rewards_history(
+        context.client(),
+        &source,
+        &validator,
+        from_epoch,
+        to_epoch,
+    )
+    .await
+    {
+        Ok(history) => history,
+        Err(err) => {
+            edisplay_line!(context.io(), "{}", err);
+            cli::safe_exit(1)
+        }
+    };
+
+    let mut csv = String::from(
+        "epoch,bond_amount,slashed_amount,rewards_product,rewards,\
+         commission_rate,commission\n",
+    );
+    for (epoch, rewards) in history {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{}\n",
+            epoch,
+            rewards.bond_amount.to_string_native(),
+            rewards.slashed_amount.to_string_native(),
+            rewards.rewards_product,
+            rewards.rewards.to_string_native(),
+            rewards
+                .commission_rate
+                .map(|rate| rate.to_string())
+                .unwrap_or_default(),
+            rewards
+                .commission
+                .map(|commission| commission.to_string_native())
+                .unwrap_or_default(),
+        ));
+    }
+
+    match args.output {
+        Some(output_path) => {
+            std::fs::write(&output_path, csv).unwrap_or_else(|err| {
+                edisplay_line!(
+                    context.io(),
+                    "Failed to write output to {} with {err}.",
+                    output_path.to_string_lossy()
+                );
+                cli::safe_exit(1)
+            });
+            display_line!(
+                context.io(),
+                "The rewards history has been written to {}",
+                output_path.to_string_lossy()
+            );
+        }
+        None => display!(context.io(), "{csv}"),
+    }
+}
+
 pub async fn query_delegations<N: Namada>(
     context: &N,
     args: args::QueryDelegations,
@@ -10,7 +10,8 @@ use namada_sdk::address::{Address, ImplicitAddress};
 use namada_sdk::args::TxBecomeValidator;
 use namada_sdk::collections::HashSet;
 use namada_sdk::governance::cli::onchain::{
-    DefaultProposal, PgfFundingProposal, PgfStewardProposal,
+    DefaultProposal, ParameterChangeProposal, PgfFundingProposal,
+    PgfStewardProposal, WasmAllowlistProposal,
 };
 use namada_sdk::ibc::convert_masp_tx_to_ibc_memo;
 use namada_sdk::io::Io;
@@ -416,6 +417,92 @@ pub async fn submit_change_consensus_key(
     Ok(())
 }
 
+pub async fn submit_change_validator_keys(
+    namada: &impl Namada,
+    args: args::ValidatorKeysChange,
+) -> Result<(), error::Error> {
+    // The node needs the secret keys of the new protocol and Eth hot keys to
+    // sign with them once they become active
+    let mut wallet = namada.wallet_mut().await;
+    let mut find_secret_key = |pk: &Option<common::PublicKey>, name: &str| {
+        pk.as_ref().map(|pk| {
+            wallet.find_key_by_pk(pk, None).unwrap_or_else(|err| {
+                edisplay_line!(
+                    namada.io(),
+                    "Unable to find the secret key of the new {name} in the \
+                     wallet: {err}"
+                );
+                safe_exit(1)
+            })
+        })
+    };
+    let next_protocol_keypair =
+        find_secret_key(&args.protocol_key, "protocol key");
+    let next_eth_bridge_keypair =
+        find_secret_key(&args.eth_hot_key, "Eth hot key");
+    // To avoid wallet deadlocks in following operations
+    drop(wallet);
+
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+        let cmt = tx.first_commitments().unwrap().to_owned();
+        let wrapper_hash = tx.wrapper_hash();
+        let resp = namada.submit(tx, &args.tx).await?;
+
+        if args.tx.dry_run {
+            display_line!(
+                namada.io(),
+                "Transaction dry run. No validator keys have been saved."
+            );
+        } else if resp
+            .is_applied_and_valid(wrapper_hash.as_ref(), &cmt)
+            .is_some()
+        {
+            let mut wallet = namada.wallet_mut().await;
+            let Some(data) = wallet
+                .get_validator_data_mut()
+                .filter(|data| data.address == args.validator)
+            else {
+                display_line!(
+                    namada.io(),
+                    "The new validator keys will become active at the \
+                     pipeline epoch relative to the current epoch (current \
+                     epoch + pipeline offset). The validator data of {} was \
+                     not found in this wallet, so the new protocol and Eth \
+                     hot secret keys must be added to the validator node's \
+                     wallet before then.",
+                    args.validator
+                );
+                return Ok(());
+            };
+            if next_protocol_keypair.is_some() {
+                data.keys.next_protocol_keypair = next_protocol_keypair;
+            }
+            if next_eth_bridge_keypair.is_some() {
+                data.keys.next_eth_bridge_keypair = next_eth_bridge_keypair;
+            }
+            wallet
+                .save()
+                .unwrap_or_else(|err| edisplay_line!(namada.io(), "{}", err));
+
+            display_line!(
+                namada.io(),
+                "The new validator keys have been stored in the validator \
+                 data of the wallet. They will become active at the pipeline \
+                 epoch relative to the current epoch (current epoch + \
+                 pipeline offset). The validator node has to be restarted \
+                 before then to pick up the new keys, after which it switches \
+                 to them at the start of that epoch."
+            );
+        }
+    }
+    Ok(())
+}
+
 pub async fn submit_become_validator(
     namada: &impl Namada,
     config: &mut crate::config::Config,
@@ -669,6 +756,14 @@ pub async fn submit_become_validator(
              your node for the changes to take effect!",
             pos_params.pipeline_len
         );
+        if !pos_params.min_self_bond.is_zero() {
+            display_line!(
+                namada.io(),
+                "To join the validator sets, your validator must self-bond at \
+                 least {} tokens.",
+                pos_params.min_self_bond.to_string_native()
+            );
+        }
     }
     Ok(())
 }
@@ -805,6 +900,162 @@ pub async fn submit_shielded_transfer(
     Ok(())
 }
 
+pub async fn submit_shielded_consolidation(
+    namada: &impl Namada,
+    args: args::TxShieldedConsolidate,
+) -> Result<(), error::Error> {
+    let txs = args.clone().build(namada).await?;
+    if txs.is_empty() {
+        display_line!(namada.io(), "No notes to consolidate.");
+        return Ok(());
+    }
+
+    let num_txs = txs.len();
+    for (idx, (mut tx, signing_data)) in (1usize..).zip(txs) {
+        display_line!(
+            namada.io(),
+            "Consolidation transaction {idx} of {num_txs}:"
+        );
+        if args.tx.dump_tx {
+            tx::dump_tx(namada.io(), &args.tx, tx);
+        } else {
+            sign(namada, &mut tx, &args.tx, signing_data).await?;
+            namada.submit(tx, &args.tx).await?;
+        }
+    }
+    Ok(())
+}
+
+pub async fn submit_disposable_keys(
+    namada: &impl Namada,
+    args: args::DisposableKeys,
+) -> Result<(), error::Error> {
+    let keys = namada.wallet().await.get_disposable_keys();
+    if keys.is_empty() {
+        display_line!(namada.io(), "No disposable keys found in the wallet.");
+        return Ok(());
+    }
+    let last_height = namada_sdk::rpc::query_block(namada.client())
+        .await?
+        .map(|block| block.height);
+
+    for key in &keys {
+        let balance = namada_sdk::rpc::get_token_balance(
+            namada.client(),
+            &args.tx.fee_token,
+            &key.address(),
+        )
+        .await?;
+        let (created_at, purpose) = match &key.info {
+            Some(info) => (
+                info.created_at
+                    .map(|height| height.to_string())
+                    .unwrap_or_else(|| "unknown".to_string()),
+                info.purpose.to_string(),
+            ),
+            None => ("unknown".to_string(), "unknown".to_string()),
+        };
+        display_line!(
+            namada.io(),
+            "{}:\n  Address: {}\n  Created at height: {}\n  Created at \
+             timestamp: {}\n  Purpose: {}\n  Balance: {} {}",
+            key.alias,
+            key.address(),
+            created_at,
+            key.created_at_timestamp,
+            purpose,
+            balance.to_string_native(),
+            args.tx.fee_token,
+        );
+    }
+
+    if let Some(target) = &args.sweep_target {
+        for key in &keys {
+            let Some((mut tx, signing_data, _)) =
+                tx::build_disposable_key_sweep(
+                    namada,
+                    &args.tx,
+                    &key.public_key,
+                    target.clone(),
+                    args.tx_code_path.clone(),
+                )
+                .await?
+            else {
+                display_line!(
+                    namada.io(),
+                    "Nothing to sweep from {}: its balance does not cover the \
+                     fees.",
+                    key.alias
+                );
+                continue;
+            };
+            display_line!(
+                namada.io(),
+                "Sweeping the balance of {}:",
+                key.alias
+            );
+            if args.tx.dump_tx {
+                tx::dump_tx(namada.io(), &args.tx, tx);
+            } else {
+                sign(namada, &mut tx, &args.tx, signing_data).await?;
+                namada.submit(tx, &args.tx).await?;
+            }
+        }
+    }
+
+    if args.prune {
+        let mut pruned = vec![];
+        for key in &keys {
+            // Keys created before their height was recorded can only be aged
+            // by their timestamp, which says nothing about blocks, so only
+            // their balance is checked
+            let old_enough = match (
+                key.info.as_ref().and_then(|info| info.created_at),
+                last_height,
+            ) {
+                (Some(created_at), Some(last_height)) => {
+                    last_height.0.saturating_sub(created_at.0) >= args.prune_age
+                }
+                (Some(_), None) => false,
+                (None, _) => true,
+            };
+            if !old_enough {
+                continue;
+            }
+            let balance = namada_sdk::rpc::get_token_balance(
+                namada.client(),
+                &args.tx.fee_token,
+                &key.address(),
+            )
+            .await?;
+            if balance.is_zero() {
+                pruned.push(key.alias.clone());
+            }
+        }
+
+        let mut wallet = namada.wallet_mut().await;
+        for alias in &pruned {
+            if wallet.remove_disposable_key(alias) {
+                display_line!(namada.io(), "Removed disposable key {alias}.");
+            }
+        }
+        if !pruned.is_empty() {
+            wallet.save().map_err(|_| {
+                error::Error::Other(
+                    "Failed to save the pruned wallet".to_string(),
+                )
+            })?;
+        }
+        display_line!(
+            namada.io(),
+            "Pruned {} of {} disposable keys.",
+            pruned.len(),
+            keys.len()
+        );
+    }
+    Ok(())
+}
+
 pub async fn submit_shielding_transfer(
     namada: &impl Namada,
     args: args::TxShieldingTransfer,
@@ -954,6 +1205,66 @@ where
             .await?;
 
         tx::build_pgf_stewards_proposal(namada, &args, proposal).await?
+    } else if args.is_parameter_change {
+        let proposal =
+            ParameterChangeProposal::try_from(args.proposal_data.as_ref())
+                .map_err(|e| {
+                    error::TxSubmitError::FailedGovernaneProposalDeserialize(
+                        e.to_string(),
+                    )
+                })?;
+        let author_balance = namada_sdk::rpc::get_token_balance(
+            namada.client(),
+            &namada.native_token(),
+            &proposal.proposal.author,
+        )
+        .await
+        .unwrap();
+        let proposal = proposal
+            .validate(
+                &governance_parameters,
+                current_epoch,
+                author_balance,
+                args.tx.force,
+            )
+            .map_err(|e| {
+                error::TxSubmitError::InvalidProposal(e.to_string())
+            })?;
+
+        submit_reveal_aux(namada, args.tx.clone(), &proposal.proposal.author)
+            .await?;
+
+        tx::build_parameter_change_proposal(namada, &args, proposal).await?
+    } else if args.is_wasm_allowlist {
+        let proposal =
+            WasmAllowlistProposal::try_from(args.proposal_data.as_ref())
+                .map_err(|e| {
+                    error::TxSubmitError::FailedGovernaneProposalDeserialize(
+                        e.to_string(),
+                    )
+                })?;
+        let author_balance = namada_sdk::rpc::get_token_balance(
+            namada.client(),
+            &namada.native_token(),
+            &proposal.proposal.author,
+        )
+        .await
+        .unwrap();
+        let proposal = proposal
+            .validate(
+                &governance_parameters,
+                current_epoch,
+                author_balance,
+                args.tx.force,
+            )
+            .map_err(|e| {
+                error::TxSubmitError::InvalidProposal(e.to_string())
+            })?;
+
+        submit_reveal_aux(namada, args.tx.clone(), &proposal.proposal.author)
+            .await?;
+
+        tx::build_wasm_allowlist_proposal(namada, &args, proposal).await?
     } else {
         let proposal = DefaultProposal::try_from(args.proposal_data.as_ref())
             .map_err(|e| {
@@ -1016,6 +1327,66 @@ where
     Ok(())
 }
 
+pub async fn submit_cancel_proposal<N: Namada>(
+    namada: &N,
+    args: args::CancelProposal,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx_builder, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx_builder);
+    } else {
+        sign(namada, &mut tx_builder, &args.tx, signing_data).await?;
+
+        namada.submit(tx_builder, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn submit_veto_proposal<N: Namada>(
+    namada: &N,
+    args: args::VetoProposal,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx_builder, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx_builder);
+    } else {
+        sign(namada, &mut tx_builder, &args.tx, signing_data).await?;
+
+        namada.submit(tx_builder, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn submit_delegate_vote<N: Namada>(
+    namada: &N,
+    args: args::DelegateVote,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx_builder, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx_builder);
+    } else {
+        sign(namada, &mut tx_builder, &args.tx, signing_data).await?;
+
+        namada.submit(tx_builder, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
 pub async fn sign_tx<N: Namada>(
     namada: &N,
     args::SignTx {
@@ -1203,6 +1574,26 @@ where
     Ok(())
 }
 
+pub async fn submit_auto_compound<N: Namada>(
+    namada: &N,
+    args: args::AutoCompound,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
 pub async fn submit_redelegate<N: Namada>(
     namada: &N,
     args: args::Redelegate,
@@ -1223,6 +1614,26 @@ where
     Ok(())
 }
 
+pub async fn submit_rebalance_delegations<N: Namada>(
+    namada: &N,
+    args: args::RebalanceDelegations,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
 pub async fn submit_validator_commission_change<N: Namada>(
     namada: &N,
     args: args::CommissionRateChange,
@@ -1243,6 +1654,26 @@ where
     Ok(())
 }
 
+pub async fn submit_validator_commission_schedule_change<N: Namada>(
+    namada: &N,
+    args: args::CommissionScheduleChange,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
 pub async fn submit_validator_metadata_change<N: Namada>(
     namada: &N,
     args: args::MetaDataChange,
@@ -378,6 +378,8 @@ impl Finalized {
             light_client_attack_min_slash_rate,
             cubic_slashing_window_length,
             validator_stake_threshold,
+            min_self_bond,
+            max_validator_stake_share,
             liveness_window_check,
             liveness_threshold,
             rewards_gain_p,
@@ -398,6 +400,8 @@ impl Finalized {
                 light_client_attack_min_slash_rate,
                 cubic_slashing_window_length,
                 validator_stake_threshold,
+                min_self_bond,
+                max_validator_stake_share,
                 liveness_window_check,
                 liveness_threshold,
                 rewards_gain_p,
@@ -418,6 +422,11 @@ impl Finalized {
             max_proposal_content_size,
             min_proposal_grace_epochs,
             max_proposal_latency,
+            min_expedited_proposal_fund,
+            min_expedited_proposal_voting_period,
+            proposal_cancellation_refund_rate,
+            veto_threshold,
+            proposal_timelock_epochs,
         } = self.parameters.gov_params.clone();
         namada_sdk::governance::parameters::GovernanceParameters {
             min_proposal_fund: Amount::native_whole(min_proposal_fund),
@@ -427,6 +436,13 @@ impl Finalized {
             min_proposal_grace_epochs,
             min_proposal_voting_period,
             max_proposal_latency,
+            min_expedited_proposal_fund: Amount::native_whole(
+                min_expedited_proposal_fund,
+            ),
+            min_expedited_proposal_voting_period,
+            proposal_cancellation_refund_rate,
+            veto_threshold,
+            proposal_timelock_epochs,
         }
     }
 
@@ -410,6 +410,12 @@ pub struct PosParams {
     /// The minimum amount of bonded tokens that a validator needs to be in
     /// either the `consensus` or `below_capacity` validator sets
     pub validator_stake_threshold: token::Amount,
+    /// The minimum amount of tokens that a validator needs to have self-bonded
+    /// to be in either the `consensus` or `below_capacity` validator sets
+    pub min_self_bond: token::Amount,
+    /// The maximum fraction of the total stake that a validator can have.
+    /// One disables the cap.
+    pub max_validator_stake_share: Dec,
     /// The length, in blocks, of the sliding window for consensus validators
     /// inactivity verification
     pub liveness_window_check: u64,
@@ -449,6 +455,20 @@ pub struct GovernanceParams {
     pub min_proposal_grace_epochs: u64,
     /// Maximum number of epochs between current epoch and start epochs
     pub max_proposal_latency: u64,
+    /// Min funds to stake to submit an expedited proposal
+    pub min_expedited_proposal_fund: u64,
+    /// Minimum number of epochs between the expedited proposal end epoch and
+    /// start epoch
+    pub min_expedited_proposal_voting_period: u64,
+    /// Share of the locked funds refunded to the author of a cancelled
+    /// proposal
+    pub proposal_cancellation_refund_rate: Dec,
+    /// Share of the voting power voting `NoWithVeto` above which a proposal
+    /// is vetoed
+    pub veto_threshold: Dec,
+    /// Number of epochs a passed proposal waits in the timelock queue before
+    /// its execution
+    pub proposal_timelock_epochs: u64,
 }
 
 #[derive(
@@ -1105,6 +1105,35 @@ pub fn validate(
         None
     };
 
+    // Every validator must have at least the minimum self-bond at genesis
+    if let Some(parameters) = parameters {
+        let min_self_bond = parameters.pos_params.min_self_bond;
+        if !min_self_bond.is_zero() {
+            for validator in &validator_accounts {
+                let self_bond = validated_bonds
+                    .iter()
+                    .flatten()
+                    .filter(|bond| {
+                        &bond.validator == validator
+                            && &bond.source.address() == validator
+                    })
+                    .try_fold(token::Amount::zero(), |acc, bond| {
+                        acc.checked_add(bond.amount.amount())
+                    });
+                if self_bond
+                    .map_or(false, |self_bond| self_bond < min_self_bond)
+                {
+                    eprintln!(
+                        "The self-bond of validator {validator} is below the \
+                         minimum self-bond of {}.",
+                        min_self_bond.to_string_native()
+                    );
+                    is_valid = false;
+                }
+            }
+        }
+    }
+
     is_valid.then_some(Transactions {
         established_account: transactions.established_account,
         validator_account: transactions.validator_account.map(
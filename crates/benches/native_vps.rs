@@ -4,6 +4,7 @@ use std::ops::Deref;
 use std::rc::Rc;
 use std::str::FromStr;
 
+use borsh_ext::BorshSerializeExt;
 use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
 use masp_primitives::sapling::redjubjub::PublicKey;
 use masp_primitives::sapling::Node;
@@ -108,8 +109,12 @@ fn governance(c: &mut Criterion) {
                 )
             }
             "minimal_proposal" => {
-                let content_section =
-                    Section::ExtraData(Code::new(vec![], None));
+                let content_section = Section::ExtraData(Code::new(
+                    governance::ProposalContent::new("Title", "Abstract")
+                        .to_map()
+                        .serialize_to_vec(),
+                    None,
+                ));
                 let params =
                     proof_of_stake::storage::read_pos_params(&shell.state)
                         .unwrap();
@@ -131,6 +136,7 @@ fn governance(c: &mut Criterion) {
                             .unchecked_add(3_u64),
                         activation_epoch: voting_start_epoch
                             .unchecked_add(9_u64),
+                        is_expedited: false,
                     },
                     None,
                     Some(vec![content_section]),
@@ -154,8 +160,17 @@ fn governance(c: &mut Criterion) {
                     .expect(
                         "Missing max_proposal_content parameter in storage",
                     );
+                // Pad the details to get content of the maximum size
+                let mut content =
+                    governance::ProposalContent::new("Title", "Abstract");
+                content.details = Some(String::new());
+                let content_overhead =
+                    content.to_map().serialize_to_vec().len();
+                content.details = Some("x".repeat(
+                    max_proposal_content_size as usize - content_overhead,
+                ));
                 let content_section = Section::ExtraData(Code::new(
-                    vec![0; max_proposal_content_size as _],
+                    content.to_map().serialize_to_vec(),
                     None,
                 ));
                 let wasm_code_section = Section::ExtraData(Code::new(
@@ -186,6 +201,7 @@ fn governance(c: &mut Criterion) {
                             .unchecked_add(3_u64),
                         activation_epoch: voting_start_epoch
                             .unchecked_add(9_u64),
+                        is_expedited: false,
                     },
                     None,
                     Some(vec![content_section, wasm_code_section]),
@@ -3,6 +3,7 @@ use std::fmt::Display;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use namada_core::address::Address;
+use namada_core::hash::{self, Hash};
 use namada_core::storage::Epoch;
 use namada_core::token;
 use namada_macros::BorshDeserializer;
@@ -12,12 +13,16 @@ use serde::{Deserialize, Serialize};
 
 use super::validation::{
     is_valid_activation_epoch, is_valid_author_balance, is_valid_content,
-    is_valid_default_proposal_data, is_valid_end_epoch,
-    is_valid_pgf_funding_data, is_valid_pgf_stewards_data,
-    is_valid_proposal_period, is_valid_start_epoch, ProposalValidation,
+    is_valid_default_proposal_data, is_valid_end_epoch, is_valid_expedited,
+    is_valid_parameter_change_data, is_valid_pgf_funding_data,
+    is_valid_pgf_stewards_data, is_valid_proposal_period, is_valid_start_epoch,
+    is_valid_wasm_allowlist_data, ProposalValidation,
 };
 use crate::parameters::GovernanceParameters;
-use crate::storage::proposal::PGFTarget;
+use crate::storage::content::{ContentError, ProposalContent};
+use crate::storage::proposal::{
+    AddRemove, PGFTarget, ParameterChange, WasmAllowlist, WasmAllowlistEntry,
+};
 
 #[derive(
     Debug,
@@ -40,6 +45,42 @@ pub struct OnChainProposal {
     pub voting_end_epoch: Epoch,
     /// The epoch in which any changes are executed and become active
     pub activation_epoch: Epoch,
+    /// Whether the proposal is expedited, i.e. voted on for a shorter period
+    /// with a stricter tally
+    #[serde(default)]
+    pub is_expedited: bool,
+}
+
+impl OnChainProposal {
+    /// Return the typed content of the proposal, validated against the
+    /// content schema
+    pub fn parsed_content(&self) -> Result<ProposalContent, ContentError> {
+        ProposalContent::parse(&self.content)
+    }
+
+    /// The minimum voting period that applies to the proposal
+    pub fn min_voting_period(
+        &self,
+        governance_parameters: &GovernanceParameters,
+    ) -> u64 {
+        if self.is_expedited {
+            governance_parameters.min_expedited_proposal_voting_period
+        } else {
+            governance_parameters.min_proposal_voting_period
+        }
+    }
+
+    /// The minimum funds that the author must lock for the proposal
+    pub fn min_fund(
+        &self,
+        governance_parameters: &GovernanceParameters,
+    ) -> token::Amount {
+        if self.is_expedited {
+            governance_parameters.min_expedited_proposal_fund
+        } else {
+            governance_parameters.min_proposal_fund
+        }
+    }
 }
 
 /// PGF default proposal
@@ -74,14 +115,14 @@ impl DefaultProposal {
         is_valid_start_epoch(
             self.proposal.voting_start_epoch,
             current_epoch,
-            governance_parameters.min_proposal_voting_period,
+            self.proposal.min_voting_period(governance_parameters),
         )?;
         is_valid_end_epoch(
             self.proposal.voting_start_epoch,
             self.proposal.voting_end_epoch,
             current_epoch,
-            governance_parameters.min_proposal_voting_period,
-            governance_parameters.min_proposal_voting_period,
+            self.proposal.min_voting_period(governance_parameters),
+            self.proposal.min_voting_period(governance_parameters),
             governance_parameters.max_proposal_period,
         )?;
         is_valid_activation_epoch(
@@ -96,7 +137,7 @@ impl DefaultProposal {
         )?;
         is_valid_author_balance(
             balance,
-            governance_parameters.min_proposal_fund,
+            self.proposal.min_fund(governance_parameters),
         )?;
         is_valid_content(
             &self.proposal.content,
@@ -149,6 +190,7 @@ impl PgfStewardProposal {
         if force {
             return Ok(self);
         }
+        is_valid_expedited(self.proposal.is_expedited, false)?;
         is_valid_start_epoch(
             self.proposal.voting_start_epoch,
             current_epoch,
@@ -222,6 +264,7 @@ impl PgfFundingProposal {
         if force {
             return Ok(self);
         }
+        is_valid_expedited(self.proposal.is_expedited, false)?;
         is_valid_start_epoch(
             self.proposal.voting_start_epoch,
             current_epoch,
@@ -263,6 +306,236 @@ impl TryFrom<&[u8]> for PgfFundingProposal {
     }
 }
 
+/// Parameter change proposal
+#[derive(
+    Debug,
+    Clone,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct ParameterChangeProposal {
+    /// The proposal data
+    pub proposal: OnChainProposal,
+    /// The parameter changes to apply if the proposal passes
+    pub data: Vec<ParameterChange>,
+}
+
+impl ParameterChangeProposal {
+    /// Validate a parameter change proposal
+    pub fn validate(
+        self,
+        governance_parameters: &GovernanceParameters,
+        current_epoch: Epoch,
+        balance: token::Amount,
+        force: bool,
+    ) -> Result<Self, ProposalValidation> {
+        if force {
+            return Ok(self);
+        }
+        is_valid_start_epoch(
+            self.proposal.voting_start_epoch,
+            current_epoch,
+            self.proposal.min_voting_period(governance_parameters),
+        )?;
+        is_valid_end_epoch(
+            self.proposal.voting_start_epoch,
+            self.proposal.voting_end_epoch,
+            current_epoch,
+            self.proposal.min_voting_period(governance_parameters),
+            self.proposal.min_voting_period(governance_parameters),
+            governance_parameters.max_proposal_period,
+        )?;
+        is_valid_activation_epoch(
+            self.proposal.activation_epoch,
+            self.proposal.voting_end_epoch,
+            governance_parameters.min_proposal_grace_epochs,
+        )?;
+        is_valid_proposal_period(
+            self.proposal.voting_start_epoch,
+            self.proposal.activation_epoch,
+            governance_parameters.max_proposal_period,
+        )?;
+        is_valid_author_balance(
+            balance,
+            self.proposal.min_fund(governance_parameters),
+        )?;
+        is_valid_content(
+            &self.proposal.content,
+            governance_parameters.max_proposal_content_size,
+        )?;
+        is_valid_parameter_change_data(&self.data)?;
+
+        Ok(self)
+    }
+}
+
+impl TryFrom<&[u8]> for ParameterChangeProposal {
+    type Error = serde_json::Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        serde_json::from_slice(value)
+    }
+}
+
+/// Wasm allowlist proposal
+#[derive(
+    Debug,
+    Clone,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct WasmAllowlistProposal {
+    /// The proposal data
+    pub proposal: OnChainProposal,
+    /// The wasm allowlist changes to apply if the proposal passes
+    pub data: WasmAllowlistUpdate,
+}
+
+impl WasmAllowlistProposal {
+    /// Validate a wasm allowlist proposal
+    pub fn validate(
+        self,
+        governance_parameters: &GovernanceParameters,
+        current_epoch: Epoch,
+        balance: token::Amount,
+        force: bool,
+    ) -> Result<Self, ProposalValidation> {
+        if force {
+            return Ok(self);
+        }
+        is_valid_start_epoch(
+            self.proposal.voting_start_epoch,
+            current_epoch,
+            self.proposal.min_voting_period(governance_parameters),
+        )?;
+        is_valid_end_epoch(
+            self.proposal.voting_start_epoch,
+            self.proposal.voting_end_epoch,
+            current_epoch,
+            self.proposal.min_voting_period(governance_parameters),
+            self.proposal.min_voting_period(governance_parameters),
+            governance_parameters.max_proposal_period,
+        )?;
+        is_valid_activation_epoch(
+            self.proposal.activation_epoch,
+            self.proposal.voting_end_epoch,
+            governance_parameters.min_proposal_grace_epochs,
+        )?;
+        is_valid_proposal_period(
+            self.proposal.voting_start_epoch,
+            self.proposal.activation_epoch,
+            governance_parameters.max_proposal_period,
+        )?;
+        is_valid_author_balance(
+            balance,
+            self.proposal.min_fund(governance_parameters),
+        )?;
+        is_valid_content(
+            &self.proposal.content,
+            governance_parameters.max_proposal_content_size,
+        )?;
+        is_valid_wasm_allowlist_data(
+            &self.data,
+            governance_parameters.max_proposal_code_size,
+        )?;
+
+        Ok(self)
+    }
+}
+
+impl TryFrom<&[u8]> for WasmAllowlistProposal {
+    type Error = serde_json::Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        serde_json::from_slice(value)
+    }
+}
+
+/// Wasm allowlist proposal extra data
+#[derive(
+    Debug,
+    Clone,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct WasmAllowlistUpdate {
+    /// The wasm codes to upload with the proposal and add to an allowlist
+    #[serde(default)]
+    pub add: Vec<WasmCodeUpload>,
+    /// The wasm codes to remove from an allowlist
+    #[serde(default)]
+    pub remove: Vec<WasmCodeRemoval>,
+}
+
+impl WasmAllowlistUpdate {
+    /// Get the allowlist changes, identifying the uploaded codes by their
+    /// hash
+    pub fn changes(
+        &self,
+    ) -> Result<Vec<AddRemove<WasmAllowlistEntry>>, hash::Error> {
+        let additions = self.add.iter().map(|upload| {
+            Ok(AddRemove::Add(WasmAllowlistEntry {
+                allowlist: upload.allowlist,
+                code_hash: Hash::sha256(&upload.code),
+                name: upload.name.clone(),
+            }))
+        });
+        let removals = self.remove.iter().map(|removal| {
+            Ok(AddRemove::Remove(WasmAllowlistEntry {
+                allowlist: removal.allowlist,
+                code_hash: Hash::try_from(removal.code_hash.as_str())?,
+                name: None,
+            }))
+        });
+        additions.chain(removals).collect()
+    }
+}
+
+/// A wasm code uploaded with a wasm allowlist proposal
+#[derive(
+    Debug,
+    Clone,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct WasmCodeUpload {
+    /// The allowlist to add the code to
+    pub allowlist: WasmAllowlist,
+    /// The name to register the code under, e.g. `tx_transfer.wasm`
+    pub name: Option<String>,
+    /// The wasm code
+    pub code: Vec<u8>,
+}
+
+/// A wasm code removed from an allowlist by a wasm allowlist proposal
+#[derive(
+    Debug,
+    Clone,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct WasmCodeRemoval {
+    /// The allowlist to remove the code from
+    pub allowlist: WasmAllowlist,
+    /// The hex encoded SHA-256 hash of the code
+    pub code_hash: String,
+}
+
 /// PGF stewards
 #[derive(
     Debug,
@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use namada_core::address::Address;
 use namada_core::arith::{self, checked};
@@ -6,7 +6,12 @@ use namada_core::storage::Epoch;
 use namada_core::token;
 use thiserror::Error;
 
-use super::onchain::{PgfFunding, StewardsUpdate};
+use super::onchain::{PgfFunding, StewardsUpdate, WasmAllowlistUpdate};
+use crate::storage::content::{ContentError, ProposalContent};
+use crate::storage::proposal::{
+    validate_wasm_allowlist_changes, ParameterChange,
+};
+use crate::vp::MAX_PARAMETER_CHANGES;
 
 /// This enum represents proposal data
 #[derive(Debug, Error)]
@@ -52,6 +57,9 @@ pub enum ProposalValidation {
          but maximum is {1}"
     )]
     InvalidContentLength(u64, u64),
+    /// The proposal content doesn't follow the content schema
+    #[error("Invalid proposal content: {0}")]
+    InvalidContent(#[from] ContentError),
     /// The proposal wasm code is not valid
     #[error(
         "Invalid proposal extra data: file doesn't exist or content size \
@@ -64,6 +72,15 @@ pub enum ProposalValidation {
     /// The PGF funding data is not valid
     #[error("invalid proposal extra data: cannot be empty.")]
     InvalidPgfFundingExtraData,
+    /// The parameter changes are not valid
+    #[error("Invalid proposal extra data: {0}")]
+    InvalidParameterChangeData(String),
+    /// The wasm allowlist changes are not valid
+    #[error("Invalid proposal extra data: {0}")]
+    InvalidWasmAllowlistData(String),
+    /// The proposal type can't be expedited
+    #[error("Invalid expedited proposal: PGF proposals can't be expedited")]
+    InvalidExpedited,
     #[error("Arithmetic {0}.")]
     Arith(#[from] arith::Error),
 }
@@ -130,6 +147,17 @@ pub fn is_valid_end_epoch(
     }
 }
 
+pub fn is_valid_expedited(
+    is_expedited: bool,
+    can_be_expedited: bool,
+) -> Result<(), ProposalValidation> {
+    if !is_expedited || can_be_expedited {
+        Ok(())
+    } else {
+        Err(ProposalValidation::InvalidExpedited)
+    }
+}
+
 pub fn is_valid_activation_epoch(
     proposal_activation_epoch: Epoch,
     proposal_end_epoch: Epoch,
@@ -180,14 +208,15 @@ pub fn is_valid_content(
         proposal_content_values_length + proposal_content_keys_length
     )?;
 
-    if proposal_content_length <= max_content_length {
-        Ok(())
-    } else {
-        Err(ProposalValidation::InvalidContentLength(
+    if proposal_content_length > max_content_length {
+        return Err(ProposalValidation::InvalidContentLength(
             proposal_content_length,
             max_content_length,
-        ))
+        ));
     }
+
+    ProposalContent::parse(proposal_content)?;
+    Ok(())
 }
 
 pub fn is_valid_default_proposal_data(
@@ -239,3 +268,58 @@ pub fn is_valid_pgf_funding_data(
         Err(ProposalValidation::InvalidPgfFundingExtraData)
     }
 }
+
+pub fn is_valid_parameter_change_data(
+    data: &[ParameterChange],
+) -> Result<(), ProposalValidation> {
+    if data.is_empty() {
+        return Err(ProposalValidation::InvalidParameterChangeData(
+            "cannot be empty.".to_string(),
+        ));
+    }
+    if data.len() > MAX_PARAMETER_CHANGES {
+        return Err(ProposalValidation::InvalidParameterChangeData(format!(
+            "at most {MAX_PARAMETER_CHANGES} changes are allowed, got {}.",
+            data.len()
+        )));
+    }
+    let mut targets = BTreeSet::new();
+    for change in data {
+        change
+            .validate()
+            .map_err(ProposalValidation::InvalidParameterChangeData)?;
+        if !targets.insert(change.target()) {
+            return Err(ProposalValidation::InvalidParameterChangeData(
+                format!(
+                    "parameter {} changed more than once.",
+                    change.target()
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub fn is_valid_wasm_allowlist_data(
+    data: &WasmAllowlistUpdate,
+    max_code_size: u64,
+) -> Result<(), ProposalValidation> {
+    for upload in &data.add {
+        let code_length = upload.code.len() as u64;
+        if code_length > max_code_size {
+            return Err(ProposalValidation::InvalidWasmAllowlistData(format!(
+                "the size of the uploaded {} wasm ({code_length}) is too big \
+                 (max {max_code_size}).",
+                upload.allowlist
+            )));
+        }
+    }
+    let changes = data.changes().map_err(|err| {
+        ProposalValidation::InvalidWasmAllowlistData(format!(
+            "invalid code hash: {err}."
+        ))
+    })?;
+    validate_wasm_allowlist_changes(&changes).map_err(|err| {
+        ProposalValidation::InvalidWasmAllowlistData(format!("{err}."))
+    })
+}
@@ -2,10 +2,18 @@
 
 use std::collections::BTreeMap;
 
-use namada_events::extend::{EventAttributeEntry, ExtendAttributesMap};
+use namada_core::address::Address;
+use namada_core::storage::Epoch;
+use namada_core::token;
+use namada_events::extend::{
+    EventAttributeEntry, EventValue, ExtendAttributesMap,
+};
 use namada_events::{Event, EventLevel, EventToEmit};
 
-use crate::utils::TallyResult as GovTallyResult;
+use crate::storage::vote::ProposalVote as GovVote;
+use crate::utils::{
+    ProposalResult as GovProposalResult, TallyResult as GovTallyResult,
+};
 use crate::ProposalType as GovProposalType;
 
 pub mod types {
@@ -25,6 +33,20 @@ pub mod types {
         "rejected"
     );
 
+    /// Proposal vetoed.
+    pub const PROPOSAL_VETOED: EventType = namada_events::event_type!(
+        GovernanceEvent,
+        PROPOSAL_SUBDOMAIN,
+        "vetoed"
+    );
+
+    /// Proposal cancelled by its author.
+    pub const PROPOSAL_CANCELLED: EventType = namada_events::event_type!(
+        GovernanceEvent,
+        PROPOSAL_SUBDOMAIN,
+        "cancelled"
+    );
+
     /// Proposal passed.
     pub const PROPOSAL_PASSED: EventType = namada_events::event_type!(
         GovernanceEvent,
@@ -36,6 +58,73 @@ pub mod types {
     pub const NEW_PROPOSAL: EventType =
         namada_events::event_type!(GovernanceEvent, PROPOSAL_SUBDOMAIN, "new");
 
+    /// Proposal voting period started.
+    pub const PROPOSAL_VOTING_STARTED: EventType = namada_events::event_type!(
+        GovernanceEvent,
+        PROPOSAL_SUBDOMAIN,
+        "voting_started"
+    );
+
+    /// Vote cast on a proposal.
+    pub const PROPOSAL_VOTE: EventType =
+        namada_events::event_type!(GovernanceEvent, PROPOSAL_SUBDOMAIN, "vote");
+
+    /// Proposal tally finalized.
+    pub const PROPOSAL_TALLIED: EventType = namada_events::event_type!(
+        GovernanceEvent,
+        PROPOSAL_SUBDOMAIN,
+        "tallied"
+    );
+
+    /// Passed proposal executed successfully.
+    pub const PROPOSAL_EXECUTED: EventType = namada_events::event_type!(
+        GovernanceEvent,
+        PROPOSAL_SUBDOMAIN,
+        "executed"
+    );
+
+    /// Execution of a passed proposal failed.
+    pub const PROPOSAL_EXECUTION_FAILED: EventType = namada_events::event_type!(
+        GovernanceEvent,
+        PROPOSAL_SUBDOMAIN,
+        "execution_failed"
+    );
+
+    /// Passed proposal queued in the timelock.
+    pub const PROPOSAL_QUEUED: EventType = namada_events::event_type!(
+        GovernanceEvent,
+        PROPOSAL_SUBDOMAIN,
+        "queued"
+    );
+
+    /// Validator veto cast on a timelocked proposal.
+    pub const PROPOSAL_TIMELOCK_VETO: EventType = namada_events::event_type!(
+        GovernanceEvent,
+        PROPOSAL_SUBDOMAIN,
+        "timelock_veto"
+    );
+
+    /// Timelocked proposal vetoed by a supermajority of validators.
+    pub const PROPOSAL_TIMELOCK_VETOED: EventType = namada_events::event_type!(
+        GovernanceEvent,
+        PROPOSAL_SUBDOMAIN,
+        "timelock_vetoed"
+    );
+
+    /// Proposal locked funds refunded.
+    pub const PROPOSAL_FUNDS_REFUNDED: EventType = namada_events::event_type!(
+        GovernanceEvent,
+        PROPOSAL_SUBDOMAIN,
+        "funds_refunded"
+    );
+
+    /// Proposal locked funds burned.
+    pub const PROPOSAL_FUNDS_BURNED: EventType = namada_events::event_type!(
+        GovernanceEvent,
+        PROPOSAL_SUBDOMAIN,
+        "funds_burned"
+    );
+
     #[cfg(test)]
     mod tests {
         use super::*;
@@ -43,12 +132,16 @@ pub mod types {
         #[test]
         fn test_gov_event_type_as_str() {
             assert_eq!(&*PROPOSAL_PASSED, "governance/proposal/passed");
+            assert_eq!(
+                &*PROPOSAL_EXECUTION_FAILED,
+                "governance/proposal/execution_failed"
+            );
         }
     }
 }
 
 /// Governance event.
-#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub enum GovernanceEvent {
     /// Governance proposal event.
     Proposal {
@@ -96,10 +189,110 @@ impl GovernanceEvent {
             kind: ProposalEventKind::Rejected { has_proposal_code },
         }
     }
+
+    /// Event for a vetoed proposal
+    pub fn vetoed_proposal(proposal_id: u64, has_proposal_code: bool) -> Self {
+        Self::Proposal {
+            id: proposal_id,
+            kind: ProposalEventKind::Vetoed { has_proposal_code },
+        }
+    }
+
+    /// Event for a proposal cancelled by its author
+    pub fn cancelled_proposal(proposal_id: u64) -> Self {
+        Self::Proposal {
+            id: proposal_id,
+            kind: ProposalEventKind::Cancelled,
+        }
+    }
+
+    /// Event for a proposal whose voting period started
+    pub fn voting_started(proposal_id: u64) -> Self {
+        Self::Proposal {
+            id: proposal_id,
+            kind: ProposalEventKind::VotingStarted,
+        }
+    }
+
+    /// Event for a vote cast on a proposal
+    pub fn vote_cast(proposal_id: u64, voter: Address, vote: GovVote) -> Self {
+        Self::Proposal {
+            id: proposal_id,
+            kind: ProposalEventKind::VoteCast { voter, vote },
+        }
+    }
+
+    /// Event for the finalized tally of a proposal
+    pub fn tallied_proposal(
+        proposal_id: u64,
+        result: GovProposalResult,
+    ) -> Self {
+        Self::Proposal {
+            id: proposal_id,
+            kind: ProposalEventKind::Tallied { result },
+        }
+    }
+
+    /// Event for the execution of a passed proposal
+    pub fn executed_proposal(proposal_id: u64, is_successful: bool) -> Self {
+        let kind = if is_successful {
+            ProposalEventKind::Executed
+        } else {
+            ProposalEventKind::ExecutionFailed
+        };
+        Self::Proposal {
+            id: proposal_id,
+            kind,
+        }
+    }
+
+    /// Event for a passed proposal queued in the timelock
+    pub fn queued_proposal(proposal_id: u64, execution_epoch: Epoch) -> Self {
+        Self::Proposal {
+            id: proposal_id,
+            kind: ProposalEventKind::Queued { execution_epoch },
+        }
+    }
+
+    /// Event for a validator veto cast on a timelocked proposal
+    pub fn timelock_veto(proposal_id: u64, validator: Address) -> Self {
+        Self::Proposal {
+            id: proposal_id,
+            kind: ProposalEventKind::TimelockVeto { validator },
+        }
+    }
+
+    /// Event for a timelocked proposal vetoed by the validators
+    pub fn timelock_vetoed_proposal(proposal_id: u64) -> Self {
+        Self::Proposal {
+            id: proposal_id,
+            kind: ProposalEventKind::TimelockVetoed,
+        }
+    }
+
+    /// Event for the refund of the locked funds of a proposal
+    pub fn refunded_funds(
+        proposal_id: u64,
+        target: Address,
+        amount: token::Amount,
+    ) -> Self {
+        Self::Proposal {
+            id: proposal_id,
+            kind: ProposalEventKind::FundsRefunded { target, amount },
+        }
+    }
+
+    /// Event for the burn of the locked funds of a proposal
+    pub fn burned_funds(proposal_id: u64, amount: token::Amount) -> Self {
+        Self::Proposal {
+            id: proposal_id,
+            kind: ProposalEventKind::FundsBurned { amount },
+        }
+    }
 }
 
 /// Proposal event kinds
-#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub enum ProposalEventKind {
     /// New proposal event
     NewProposal {
@@ -118,6 +311,55 @@ pub enum ProposalEventKind {
         /// Does the proposal contain code?
         has_proposal_code: bool,
     },
+    /// Vetoed proposal
+    Vetoed {
+        /// Does the proposal contain code?
+        has_proposal_code: bool,
+    },
+    /// Proposal cancelled by its author
+    Cancelled,
+    /// Voting period of the proposal started
+    VotingStarted,
+    /// Vote cast on the proposal
+    VoteCast {
+        /// The voter
+        voter: Address,
+        /// The vote
+        vote: GovVote,
+    },
+    /// Tally of the proposal finalized
+    Tallied {
+        /// The full result of the tally
+        result: GovProposalResult,
+    },
+    /// Passed proposal executed successfully
+    Executed,
+    /// Execution of a passed proposal failed
+    ExecutionFailed,
+    /// Passed proposal queued in the timelock
+    Queued {
+        /// The epoch at which the proposal is executed, unless vetoed
+        execution_epoch: Epoch,
+    },
+    /// Validator veto cast on a timelocked proposal
+    TimelockVeto {
+        /// The vetoing validator
+        validator: Address,
+    },
+    /// Timelocked proposal vetoed by a supermajority of validators
+    TimelockVetoed,
+    /// Locked funds of the proposal refunded
+    FundsRefunded {
+        /// The refunded address
+        target: Address,
+        /// The refunded amount
+        amount: token::Amount,
+    },
+    /// Locked funds of the proposal burned
+    FundsBurned {
+        /// The burned amount
+        amount: token::Amount,
+    },
 }
 
 impl From<GovernanceEvent> for Event {
@@ -159,6 +401,97 @@ impl From<GovernanceEvent> for Event {
                 );
                 (event_type, attributes)
             }
+            ProposalEventKind::Vetoed { has_proposal_code } => {
+                let event_type = types::PROPOSAL_VETOED;
+                let attributes = ended_governance_proposal_attributes(
+                    GovTallyResult::Vetoed,
+                    proposal_id,
+                    has_proposal_code,
+                    false,
+                );
+                (event_type, attributes)
+            }
+            ProposalEventKind::Cancelled => {
+                let event_type = types::PROPOSAL_CANCELLED;
+                let mut attributes = BTreeMap::new();
+                attributes.with_attribute(ProposalId(proposal_id));
+                (event_type, attributes)
+            }
+            ProposalEventKind::VotingStarted => {
+                let event_type = types::PROPOSAL_VOTING_STARTED;
+                let mut attributes = BTreeMap::new();
+                attributes.with_attribute(ProposalId(proposal_id));
+                (event_type, attributes)
+            }
+            ProposalEventKind::VoteCast { voter, vote } => {
+                let event_type = types::PROPOSAL_VOTE;
+                let mut attributes = BTreeMap::new();
+                attributes
+                    .with_attribute(ProposalId(proposal_id))
+                    .with_attribute(Voter(voter))
+                    .with_attribute(Vote(vote));
+                (event_type, attributes)
+            }
+            ProposalEventKind::Tallied { result } => {
+                let event_type = types::PROPOSAL_TALLIED;
+                let mut attributes = BTreeMap::new();
+                attributes
+                    .with_attribute(ProposalId(proposal_id))
+                    .with_attribute(TallyResult(result.result))
+                    .with_attribute(ProposalResult(result));
+                (event_type, attributes)
+            }
+            ProposalEventKind::Executed => {
+                let event_type = types::PROPOSAL_EXECUTED;
+                let mut attributes = BTreeMap::new();
+                attributes.with_attribute(ProposalId(proposal_id));
+                (event_type, attributes)
+            }
+            ProposalEventKind::ExecutionFailed => {
+                let event_type = types::PROPOSAL_EXECUTION_FAILED;
+                let mut attributes = BTreeMap::new();
+                attributes.with_attribute(ProposalId(proposal_id));
+                (event_type, attributes)
+            }
+            ProposalEventKind::Queued { execution_epoch } => {
+                let event_type = types::PROPOSAL_QUEUED;
+                let mut attributes = BTreeMap::new();
+                attributes
+                    .with_attribute(ProposalId(proposal_id))
+                    .with_attribute(ExecutionEpoch(execution_epoch));
+                (event_type, attributes)
+            }
+            ProposalEventKind::TimelockVeto { validator } => {
+                let event_type = types::PROPOSAL_TIMELOCK_VETO;
+                let mut attributes = BTreeMap::new();
+                attributes
+                    .with_attribute(ProposalId(proposal_id))
+                    .with_attribute(Validator(validator));
+                (event_type, attributes)
+            }
+            ProposalEventKind::TimelockVetoed => {
+                let event_type = types::PROPOSAL_TIMELOCK_VETOED;
+                let mut attributes = BTreeMap::new();
+                attributes.with_attribute(ProposalId(proposal_id));
+                (event_type, attributes)
+            }
+            ProposalEventKind::FundsRefunded { target, amount } => {
+                let event_type = types::PROPOSAL_FUNDS_REFUNDED;
+                let mut attributes = BTreeMap::new();
+                attributes
+                    .with_attribute(ProposalId(proposal_id))
+                    .with_attribute(RefundTarget(target))
+                    .with_attribute(FundsAmount(amount));
+                (event_type, attributes)
+            }
+            ProposalEventKind::FundsBurned { amount } => {
+                let event_type = types::PROPOSAL_FUNDS_BURNED;
+                let mut attributes = BTreeMap::new();
+                attributes
+                    .with_attribute(ProposalId(proposal_id))
+                    .with_attribute(FundsAmount(amount));
+                (event_type, attributes)
+            }
         };
 
         let mut event = Self::new(event_type, EventLevel::Block);
@@ -261,6 +594,104 @@ impl EventAttributeEntry<'static> for HasProposalCode {
     }
 }
 
+/// Extend an [`Event`] with the full result of a tally.
+pub struct ProposalResult(pub GovProposalResult);
+
+impl EventAttributeEntry<'static> for ProposalResult {
+    type Value = EventValue<GovProposalResult>;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "proposal_result";
+
+    fn into_value(self) -> Self::Value {
+        self.0.into()
+    }
+}
+
+/// Extend an [`Event`] with voter data.
+pub struct Voter(pub Address);
+
+impl EventAttributeEntry<'static> for Voter {
+    type Value = Address;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "voter";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
+
+/// Extend an [`Event`] with vote data.
+pub struct Vote(pub GovVote);
+
+impl EventAttributeEntry<'static> for Vote {
+    type Value = EventValue<GovVote>;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "vote";
+
+    fn into_value(self) -> Self::Value {
+        self.0.into()
+    }
+}
+
+/// Extend an [`Event`] with validator data.
+pub struct Validator(pub Address);
+
+impl EventAttributeEntry<'static> for Validator {
+    type Value = Address;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "validator";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
+
+/// Extend an [`Event`] with timelock execution epoch data.
+pub struct ExecutionEpoch(pub Epoch);
+
+impl EventAttributeEntry<'static> for ExecutionEpoch {
+    type Value = Epoch;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "execution_epoch";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
+
+/// Extend an [`Event`] with refund target data.
+pub struct RefundTarget(pub Address);
+
+impl EventAttributeEntry<'static> for RefundTarget {
+    type Value = Address;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "refund_target";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
+
+/// Extend an [`Event`] with proposal funds amount data.
+pub struct FundsAmount(pub token::Amount);
+
+impl EventAttributeEntry<'static> for FundsAmount {
+    type Value = token::Amount;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "amount";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
+
 /// Extend an [`Event`] with proposal code exit status data.
 pub struct ProposalCodeExitStatus(pub bool);
 
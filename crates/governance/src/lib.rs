@@ -36,9 +36,16 @@ pub mod vp;
 
 use namada_state::StorageRead;
 pub use namada_systems::governance::*;
-pub use storage::proposal::{InitProposalData, ProposalType, VoteProposalData};
+pub use storage::content::{ContentError, ProposalContent};
+pub use storage::proposal::{
+    CancelProposalData, DelegateVoteData, InitProposalData, ProposalType,
+    RepresentationScope, VetoProposalData, VoteProposalData,
+};
 pub use storage::vote::ProposalVote;
-pub use storage::{init_proposal, is_proposal_accepted, vote_proposal};
+pub use storage::{
+    cancel_proposal, delegate_vote, init_proposal, init_proposal_wasm_codes,
+    is_proposal_accepted, veto_proposal, vote_proposal,
+};
 
 /// The governance internal address
 pub const ADDRESS: Address = address::GOV;
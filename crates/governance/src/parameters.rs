@@ -1,4 +1,5 @@
 use namada_core::borsh::{BorshDeserialize, BorshSerialize};
+use namada_core::dec::Dec;
 use namada_core::token;
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
@@ -36,6 +37,21 @@ pub struct GovernanceParameters {
     pub min_proposal_grace_epochs: u64,
     /// Maximum number of epochs between current epoch and start epoch
     pub max_proposal_latency: u64,
+    /// Minimum amount of locked funds for an expedited proposal
+    pub min_expedited_proposal_fund: token::Amount,
+    /// Minimum number of epochs between the end epoch and start epoch of an
+    /// expedited proposal
+    pub min_expedited_proposal_voting_period: u64,
+    /// Fraction of the locked funds refunded to the author of a cancelled
+    /// proposal, the rest is burned
+    pub proposal_cancellation_refund_rate: Dec,
+    /// Fraction of the voted power that must vote `NoWithVeto` for a proposal
+    /// to be vetoed, in which case its funds are burned
+    pub veto_threshold: Dec,
+    /// Number of epochs a passed proposal waits in the timelock queue before
+    /// its execution, during which validators can veto it. Zero disables the
+    /// timelock.
+    pub proposal_timelock_epochs: u64,
 }
 
 impl Default for GovernanceParameters {
@@ -48,6 +64,12 @@ impl Default for GovernanceParameters {
             max_proposal_content_size: 10_000,
             min_proposal_grace_epochs: 6,
             max_proposal_latency: 30,
+            min_expedited_proposal_fund: token::Amount::native_whole(2_500),
+            min_expedited_proposal_voting_period: 1,
+            proposal_cancellation_refund_rate: Dec::new(5, 1)
+                .expect("Cannot fail"),
+            veto_threshold: Dec::one_third(),
+            proposal_timelock_epochs: 0,
         }
     }
 }
@@ -55,6 +77,18 @@ impl Default for GovernanceParameters {
 impl GovernanceParameters {
     /// Initialize governance parameters into storage
     pub fn init_storage<S>(&self, storage: &mut S) -> StorageResult<()>
+    where
+        S: StorageRead + StorageWrite,
+    {
+        self.write_storage(storage)?;
+
+        let counter_key = goverance_storage::get_counter_key();
+        storage.write(&counter_key, u64::MIN)
+    }
+
+    /// Write the governance parameters into storage, overwriting the current
+    /// values
+    pub fn write_storage<S>(&self, storage: &mut S) -> StorageResult<()>
     where
         S: StorageRead + StorageWrite,
     {
@@ -66,6 +100,11 @@ impl GovernanceParameters {
             max_proposal_content_size,
             min_proposal_grace_epochs,
             max_proposal_latency,
+            min_expedited_proposal_fund,
+            min_expedited_proposal_voting_period,
+            proposal_cancellation_refund_rate,
+            veto_threshold,
+            proposal_timelock_epochs,
         } = self;
 
         let min_proposal_fund_key =
@@ -101,7 +140,32 @@ impl GovernanceParameters {
             goverance_storage::get_max_proposal_latency_key();
         storage.write(&max_proposal_latency_key, max_proposal_latency)?;
 
-        let counter_key = goverance_storage::get_counter_key();
-        storage.write(&counter_key, u64::MIN)
+        let min_expedited_proposal_fund_key =
+            goverance_storage::get_min_expedited_proposal_fund_key();
+        storage.write(
+            &min_expedited_proposal_fund_key,
+            min_expedited_proposal_fund,
+        )?;
+
+        let min_expedited_proposal_voting_period_key =
+            goverance_storage::get_min_expedited_proposal_voting_period_key();
+        storage.write(
+            &min_expedited_proposal_voting_period_key,
+            min_expedited_proposal_voting_period,
+        )?;
+
+        let proposal_cancellation_refund_rate_key =
+            goverance_storage::get_proposal_cancellation_refund_rate_key();
+        storage.write(
+            &proposal_cancellation_refund_rate_key,
+            proposal_cancellation_refund_rate,
+        )?;
+
+        let veto_threshold_key = goverance_storage::get_veto_threshold_key();
+        storage.write(&veto_threshold_key, veto_threshold)?;
+
+        let proposal_timelock_epochs_key =
+            goverance_storage::get_proposal_timelock_epochs_key();
+        storage.write(&proposal_timelock_epochs_key, proposal_timelock_epochs)
     }
 }
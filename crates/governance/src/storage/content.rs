@@ -0,0 +1,338 @@
+//! Proposal content schema.
+//!
+//! The content of a proposal is stored as a map of fields. Its layout is
+//! described by a versioned schema, so that it can be validated on submission
+//! and rendered consistently by clients.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// The latest version of the proposal content schema. Content without a
+/// version field is read as this version.
+pub const LATEST_CONTENT_VERSION: u64 = 1;
+
+/// The key of the schema version field
+pub const VERSION_KEY: &str = "version";
+/// The key of the title field
+pub const TITLE_KEY: &str = "title";
+/// The key of the abstract field
+pub const ABSTRACT_KEY: &str = "abstract";
+/// The key of the motivation field
+pub const MOTIVATION_KEY: &str = "motivation";
+/// The key of the details field
+pub const DETAILS_KEY: &str = "details";
+/// The key of the authors field
+pub const AUTHORS_KEY: &str = "authors";
+/// The key of the discussion URL field
+pub const DISCUSSIONS_TO_KEY: &str = "discussions-to";
+/// The key of the license field
+pub const LICENSE_KEY: &str = "license";
+/// The key of the required proposals field
+pub const REQUIRES_KEY: &str = "requires";
+/// The key of the creation date field
+pub const CREATED_KEY: &str = "created";
+
+/// The fields of the version 1 of the schema
+const V1_KEYS: [&str; 10] = [
+    VERSION_KEY,
+    TITLE_KEY,
+    ABSTRACT_KEY,
+    MOTIVATION_KEY,
+    DETAILS_KEY,
+    AUTHORS_KEY,
+    DISCUSSIONS_TO_KEY,
+    LICENSE_KEY,
+    REQUIRES_KEY,
+    CREATED_KEY,
+];
+
+/// Proposal content validation errors
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ContentError {
+    /// The version of the schema is not supported
+    #[error(
+        "Unsupported proposal content version {0:?}, the latest version is \
+         {LATEST_CONTENT_VERSION}"
+    )]
+    UnsupportedVersion(String),
+    /// A required field is missing
+    #[error("Missing proposal content field {0:?}")]
+    MissingField(&'static str),
+    /// A field is empty
+    #[error("The proposal content field {0:?} cannot be empty")]
+    EmptyField(String),
+    /// A field is not part of the schema
+    #[error("Unknown proposal content field {0:?}")]
+    UnknownField(String),
+    /// The required proposals are not a list of proposal ids
+    #[error(
+        "Invalid proposal content field \"requires\": {0:?} is not a proposal \
+         id"
+    )]
+    InvalidRequires(String),
+    /// The content couldn't be decoded
+    #[error("Failed to decode the proposal content: {0}")]
+    Decoding(String),
+}
+
+/// The typed content of a proposal
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProposalContent {
+    /// The version of the schema
+    pub version: u64,
+    /// The title of the proposal
+    pub title: String,
+    /// A short summary of the proposal
+    pub r#abstract: String,
+    /// Why the proposal is needed
+    pub motivation: Option<String>,
+    /// The full description of the proposal
+    pub details: Option<String>,
+    /// The authors of the proposal
+    pub authors: Vec<String>,
+    /// Where the proposal is discussed
+    pub discussions_to: Option<String>,
+    /// The license of the proposal
+    pub license: Option<String>,
+    /// The ids of the proposals this proposal depends on
+    pub requires: BTreeSet<u64>,
+    /// The creation date of the proposal
+    pub created: Option<String>,
+}
+
+impl ProposalContent {
+    /// Create the content of the latest schema version with the required
+    /// fields
+    pub fn new(
+        title: impl Into<String>,
+        r#abstract: impl Into<String>,
+    ) -> Self {
+        Self {
+            version: LATEST_CONTENT_VERSION,
+            title: title.into(),
+            r#abstract: r#abstract.into(),
+            motivation: None,
+            details: None,
+            authors: vec![],
+            discussions_to: None,
+            license: None,
+            requires: BTreeSet::new(),
+            created: None,
+        }
+    }
+
+    /// Parse and validate the content of a proposal against its schema
+    pub fn parse(
+        content: &BTreeMap<String, String>,
+    ) -> Result<Self, ContentError> {
+        let version = match content.get(VERSION_KEY) {
+            Some(version) => version
+                .trim()
+                .parse::<u64>()
+                .ok()
+                .filter(|version| *version == LATEST_CONTENT_VERSION)
+                .ok_or_else(|| {
+                    ContentError::UnsupportedVersion(version.clone())
+                })?,
+            None => LATEST_CONTENT_VERSION,
+        };
+
+        for (key, value) in content {
+            if !V1_KEYS.contains(&key.as_str()) {
+                return Err(ContentError::UnknownField(key.clone()));
+            }
+            if value.trim().is_empty() {
+                return Err(ContentError::EmptyField(key.clone()));
+            }
+        }
+
+        let field = |key: &str| content.get(key).map(|value| value.trim());
+        let required = |key: &'static str| {
+            field(key)
+                .map(ToString::to_string)
+                .ok_or(ContentError::MissingField(key))
+        };
+        let optional = |key: &str| field(key).map(ToString::to_string);
+
+        let authors = field(AUTHORS_KEY)
+            .map(|authors| {
+                authors
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|author| !author.is_empty())
+                    .map(ToString::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+        let requires = field(REQUIRES_KEY)
+            .map(|requires| {
+                requires
+                    .split(',')
+                    .map(str::trim)
+                    .map(|id| {
+                        id.parse::<u64>().map_err(|_| {
+                            ContentError::InvalidRequires(id.to_string())
+                        })
+                    })
+                    .collect::<Result<BTreeSet<u64>, _>>()
+            })
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(Self {
+            version,
+            title: required(TITLE_KEY)?,
+            r#abstract: required(ABSTRACT_KEY)?,
+            motivation: optional(MOTIVATION_KEY),
+            details: optional(DETAILS_KEY),
+            authors,
+            discussions_to: optional(DISCUSSIONS_TO_KEY),
+            license: optional(LICENSE_KEY),
+            requires,
+            created: optional(CREATED_KEY),
+        })
+    }
+
+    /// Parse and validate the borsh encoded content of a proposal
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, ContentError> {
+        let content: BTreeMap<String, String> =
+            borsh::BorshDeserialize::try_from_slice(bytes)
+                .map_err(|err| ContentError::Decoding(err.to_string()))?;
+        Self::parse(&content)
+    }
+
+    /// Convert the content to its stored representation
+    pub fn to_map(&self) -> BTreeMap<String, String> {
+        let mut content = BTreeMap::from([
+            (VERSION_KEY.to_string(), self.version.to_string()),
+            (TITLE_KEY.to_string(), self.title.clone()),
+            (ABSTRACT_KEY.to_string(), self.r#abstract.clone()),
+        ]);
+        let optional_fields = [
+            (MOTIVATION_KEY, &self.motivation),
+            (DETAILS_KEY, &self.details),
+            (DISCUSSIONS_TO_KEY, &self.discussions_to),
+            (LICENSE_KEY, &self.license),
+            (CREATED_KEY, &self.created),
+        ];
+        for (key, value) in optional_fields {
+            if let Some(value) = value {
+                content.insert(key.to_string(), value.clone());
+            }
+        }
+        if !self.authors.is_empty() {
+            content.insert(AUTHORS_KEY.to_string(), self.authors.join(", "));
+        }
+        if !self.requires.is_empty() {
+            let requires: Vec<String> =
+                self.requires.iter().map(ToString::to_string).collect();
+            content.insert(REQUIRES_KEY.to_string(), requires.join(", "));
+        }
+        content
+    }
+}
+
+impl Display for ProposalContent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Title: {}", self.title)?;
+        if !self.authors.is_empty() {
+            writeln!(f, "Authors: {}", self.authors.join(", "))?;
+        }
+        if let Some(discussions_to) = &self.discussions_to {
+            writeln!(f, "Discussions: {}", discussions_to)?;
+        }
+        if let Some(license) = &self.license {
+            writeln!(f, "License: {}", license)?;
+        }
+        if !self.requires.is_empty() {
+            let requires: Vec<String> =
+                self.requires.iter().map(ToString::to_string).collect();
+            writeln!(f, "Requires: {}", requires.join(", "))?;
+        }
+        write!(f, "Abstract: {}", self.r#abstract)?;
+        if let Some(motivation) = &self.motivation {
+            write!(f, "\nMotivation: {}", motivation)?;
+        }
+        if let Some(details) = &self.details {
+            write!(f, "\nDetails: {}", details)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn content(fields: &[(&str, &str)]) -> BTreeMap<String, String> {
+        fields
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_proposal_content() {
+        let parsed = ProposalContent::parse(&content(&[
+            (TITLE_KEY, "TheTitle"),
+            (ABSTRACT_KEY, "An abstract"),
+            (AUTHORS_KEY, "alice@example.com, bob@example.com"),
+            (DISCUSSIONS_TO_KEY, "www.github.com/anoma/aip/1"),
+            (REQUIRES_KEY, "2, 5"),
+        ]))
+        .unwrap();
+        assert_eq!(parsed.version, LATEST_CONTENT_VERSION);
+        assert_eq!(parsed.title, "TheTitle");
+        assert_eq!(
+            parsed.authors,
+            vec!["alice@example.com", "bob@example.com"]
+        );
+        assert_eq!(parsed.requires, BTreeSet::from([2, 5]));
+        assert_eq!(parsed.license, None);
+
+        // The stored representation round-trips
+        assert_eq!(ProposalContent::parse(&parsed.to_map()).unwrap(), parsed);
+    }
+
+    #[test]
+    fn test_invalid_proposal_content() {
+        let valid = [(TITLE_KEY, "TheTitle"), (ABSTRACT_KEY, "An abstract")];
+        for (extra, expected) in [
+            (
+                (VERSION_KEY, "2"),
+                ContentError::UnsupportedVersion("2".to_string()),
+            ),
+            (
+                ("summary", "Unknown"),
+                ContentError::UnknownField("summary".to_string()),
+            ),
+            (
+                (LICENSE_KEY, " "),
+                ContentError::EmptyField(LICENSE_KEY.to_string()),
+            ),
+            (
+                (REQUIRES_KEY, "1, two"),
+                ContentError::InvalidRequires("two".to_string()),
+            ),
+        ] {
+            let mut fields = valid.to_vec();
+            fields.push(extra);
+            assert_eq!(
+                ProposalContent::parse(&content(&fields)),
+                Err(expected)
+            );
+        }
+
+        assert_eq!(
+            ProposalContent::parse(&content(&[(TITLE_KEY, "TheTitle")])),
+            Err(ContentError::MissingField(ABSTRACT_KEY))
+        );
+        assert!(matches!(
+            ProposalContent::try_from_bytes(&[1, 2, 3, 4]),
+            Err(ContentError::Decoding(_))
+        ));
+    }
+}
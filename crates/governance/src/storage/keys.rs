@@ -1,7 +1,9 @@
 use namada_core::address::Address;
-use namada_core::storage::{DbKeySeg, Key, KeySeg};
+use namada_core::hash::Hash;
+use namada_core::storage::{DbKeySeg, Epoch, Key, KeySeg};
 use namada_macros::StorageKeys;
 
+use crate::storage::proposal::RepresentationScope;
 use crate::ADDRESS;
 
 /// Storage keys for governance internal address.
@@ -17,6 +19,7 @@ struct Keys {
     activation_epoch: &'static str,
     funds: &'static str,
     proposal_code: &'static str,
+    wasm_code: &'static str,
     committing_epoch: &'static str,
     min_fund: &'static str,
     max_code_size: &'static str,
@@ -25,9 +28,24 @@ struct Keys {
     max_content: &'static str,
     max_latency: &'static str,
     min_grace_epochs: &'static str,
+    min_expedited_fund: &'static str,
+    min_expedited_period: &'static str,
+    expedited: &'static str,
+    cancellation_refund_rate: &'static str,
+    veto_threshold: &'static str,
+    timelock_epochs: &'static str,
+    cancelled: &'static str,
+    voter: &'static str,
+    representative: &'static str,
+    representative_history: &'static str,
+    represented: &'static str,
     counter: &'static str,
     pending: &'static str,
     result: &'static str,
+    settlement: &'static str,
+    timelock: &'static str,
+    timelock_queue: &'static str,
+    timelock_veto: &'static str,
 }
 
 /// Check if key is inside governance address space
@@ -55,6 +73,83 @@ pub fn is_vote_key(key: &Key) -> bool {
     }
 }
 
+/// Check if a key is the key of the vote of a voter, regardless of its
+/// delegations
+pub fn is_voter_vote_key(key: &Key) -> bool {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::StringSeg(id),
+            DbKeySeg::StringSeg(voter),
+            DbKeySeg::AddressSeg(_address),
+        ] if addr == &ADDRESS
+            && prefix == Keys::VALUES.proposal
+            && voter == Keys::VALUES.voter =>
+        {
+            id.parse::<u64>().is_ok()
+        }
+        _ => false,
+    }
+}
+
+/// Check if a key is the key of a governance representative, returning the
+/// delegator address and the scope of the representation
+pub fn is_representative_key(
+    key: &Key,
+) -> Option<(&Address, RepresentationScope)> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::AddressSeg(delegator),
+            DbKeySeg::StringSeg(scope),
+        ] if addr == &ADDRESS && prefix == Keys::VALUES.representative =>
+        {
+            scope.parse().ok().map(|scope| (delegator, scope))
+        }
+        _ => None,
+    }
+}
+
+/// Check if a key records the governance representative of a delegator from
+/// an epoch on, returning the delegator address, the scope of the
+/// representation and the epoch
+pub fn is_representative_history_key(
+    key: &Key,
+) -> Option<(&Address, RepresentationScope, Epoch)> {
+    match &key.segments[..] {
+        [DbKeySeg::AddressSeg(addr), DbKeySeg::StringSeg(prefix), DbKeySeg::AddressSeg(delegator), DbKeySeg::StringSeg(scope), DbKeySeg::StringSeg(epoch)]
+            if addr == &ADDRESS
+                && prefix == Keys::VALUES.representative_history =>
+        {
+            let scope = scope.parse().ok()?;
+            let epoch = Epoch::parse(epoch.clone()).ok()?;
+            Some((delegator, scope, epoch))
+        }
+        _ => None,
+    }
+}
+
+/// Check if a key indexes a delegator under its governance representative,
+/// returning the representative, the scope of the representation and the
+/// delegator address
+pub fn is_represented_key(
+    key: &Key,
+) -> Option<(&Address, RepresentationScope, &Address)> {
+    match &key.segments[..] {
+        [DbKeySeg::AddressSeg(addr), DbKeySeg::StringSeg(prefix), DbKeySeg::AddressSeg(representative), DbKeySeg::StringSeg(scope), DbKeySeg::AddressSeg(delegator)]
+            if addr == &ADDRESS && prefix == Keys::VALUES.represented =>
+        {
+            scope
+                .parse()
+                .ok()
+                .map(|scope| (representative, scope, delegator))
+        }
+        _ => None,
+    }
+}
+
 /// Check if key is author key
 pub fn is_author_key(key: &Key) -> bool {
     match &key.segments[..] {
@@ -91,6 +186,27 @@ pub fn is_proposal_code_key(key: &Key) -> bool {
     }
 }
 
+/// Check if a key is the key of a wasm code uploaded with a wasm allowlist
+/// proposal, returning the hash of the code
+pub fn is_proposal_wasm_code_key(key: &Key) -> Option<Hash> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::StringSeg(id),
+            DbKeySeg::StringSeg(wasm_code),
+            DbKeySeg::StringSeg(code_hash),
+        ] if addr == &ADDRESS
+            && prefix == Keys::VALUES.proposal
+            && wasm_code == Keys::VALUES.wasm_code
+            && id.parse::<u64>().is_ok() =>
+        {
+            Hash::parse(code_hash.clone()).ok()
+        }
+        _ => None,
+    }
+}
+
 /// Check if key is activation epoch key
 pub fn is_activation_epoch_key(key: &Key) -> bool {
     match &key.segments[..] {
@@ -181,6 +297,42 @@ pub fn is_end_epoch_key(key: &Key) -> bool {
     }
 }
 
+/// Check if key is the key of the expedited flag of a proposal
+pub fn is_proposal_expedited_key(key: &Key) -> bool {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::StringSeg(id),
+            DbKeySeg::StringSeg(expedited),
+        ] if addr == &ADDRESS
+            && prefix == Keys::VALUES.proposal
+            && expedited == Keys::VALUES.expedited =>
+        {
+            id.parse::<u64>().is_ok()
+        }
+        _ => false,
+    }
+}
+
+/// Check if key is the key of the cancelled flag of a proposal
+pub fn is_proposal_cancelled_key(key: &Key) -> bool {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::StringSeg(id),
+            DbKeySeg::StringSeg(cancelled),
+        ] if addr == &ADDRESS
+            && prefix == Keys::VALUES.proposal
+            && cancelled == Keys::VALUES.cancelled =>
+        {
+            id.parse::<u64>().is_ok()
+        }
+        _ => false,
+    }
+}
+
 /// Check if key is proposal type key
 pub fn is_proposal_type_key(key: &Key) -> bool {
     match &key.segments[..] {
@@ -271,6 +423,90 @@ pub fn is_min_grace_epochs_key(key: &Key) -> bool {
                     && min_grace_epochs_param == Keys::VALUES.min_grace_epochs)
 }
 
+/// Check if key is a min expedited proposal fund parameter key
+pub fn is_min_expedited_proposal_fund_key(key: &Key) -> bool {
+    matches!(&key.segments[..], [
+             DbKeySeg::AddressSeg(addr),
+             DbKeySeg::StringSeg(min_expedited_fund_param),
+         ] if addr == &ADDRESS
+             && min_expedited_fund_param == Keys::VALUES.min_expedited_fund)
+}
+
+/// Check if key is a min expedited proposal voting period parameter key
+pub fn is_min_expedited_proposal_voting_period_key(key: &Key) -> bool {
+    matches!(&key.segments[..], [
+             DbKeySeg::AddressSeg(addr),
+             DbKeySeg::StringSeg(min_expedited_period_param),
+         ] if addr == &ADDRESS
+             && min_expedited_period_param == Keys::VALUES.min_expedited_period)
+}
+
+/// Check if key is a proposal cancellation refund rate parameter key
+pub fn is_proposal_cancellation_refund_rate_key(key: &Key) -> bool {
+    matches!(&key.segments[..], [
+             DbKeySeg::AddressSeg(addr),
+             DbKeySeg::StringSeg(refund_rate_param),
+         ] if addr == &ADDRESS
+             && refund_rate_param == Keys::VALUES.cancellation_refund_rate)
+}
+
+/// Check if key is a veto threshold parameter key
+pub fn is_veto_threshold_key(key: &Key) -> bool {
+    matches!(&key.segments[..], [
+             DbKeySeg::AddressSeg(addr),
+             DbKeySeg::StringSeg(veto_threshold_param),
+         ] if addr == &ADDRESS
+             && veto_threshold_param == Keys::VALUES.veto_threshold)
+}
+
+/// Check if key is a proposal timelock epochs parameter key
+pub fn is_proposal_timelock_epochs_key(key: &Key) -> bool {
+    matches!(&key.segments[..], [
+             DbKeySeg::AddressSeg(addr),
+             DbKeySeg::StringSeg(timelock_epochs_param),
+         ] if addr == &ADDRESS
+             && timelock_epochs_param == Keys::VALUES.timelock_epochs)
+}
+
+/// Check if a key is the key of a validator veto on a timelocked proposal
+pub fn is_timelock_veto_key(key: &Key) -> bool {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::StringSeg(id),
+            DbKeySeg::StringSeg(timelock_veto),
+            DbKeySeg::AddressSeg(_validator),
+        ] if addr == &ADDRESS
+            && prefix == Keys::VALUES.proposal
+            && timelock_veto == Keys::VALUES.timelock_veto =>
+        {
+            id.parse::<u64>().is_ok()
+        }
+        _ => false,
+    }
+}
+
+/// Check if a key is a key of the timelock queue, returning the execution
+/// epoch and the id of the queued proposal
+pub fn is_timelock_queue_key(key: &Key) -> Option<(u64, u64)> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::StringSeg(timelock_queue),
+            DbKeySeg::StringSeg(epoch),
+            DbKeySeg::StringSeg(id),
+        ] if addr == &ADDRESS
+            && prefix == Keys::VALUES.proposal
+            && timelock_queue == Keys::VALUES.timelock_queue =>
+        {
+            Some((epoch.parse().ok()?, id.parse().ok()?))
+        }
+        _ => None,
+    }
+}
+
 /// Check if key is parameter key
 pub fn is_parameter_key(key: &Key) -> bool {
     is_min_proposal_fund_key(key)
@@ -279,6 +515,11 @@ pub fn is_parameter_key(key: &Key) -> bool {
         || is_min_proposal_voting_period_key(key)
         || is_max_proposal_period_key(key)
         || is_min_grace_epochs_key(key)
+        || is_min_expedited_proposal_fund_key(key)
+        || is_min_expedited_proposal_voting_period_key(key)
+        || is_proposal_cancellation_refund_rate_key(key)
+        || is_veto_threshold_key(key)
+        || is_proposal_timelock_epochs_key(key)
 }
 
 /// Check if key is start epoch or end epoch key
@@ -342,6 +583,41 @@ pub fn get_min_proposal_grace_epochs_key() -> Key {
         .expect("Cannot obtain a storage key")
 }
 
+/// Get minimum expedited proposal fund key
+pub fn get_min_expedited_proposal_fund_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.min_expedited_fund.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get minimum expedited proposal voting period key
+pub fn get_min_expedited_proposal_voting_period_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.min_expedited_period.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get proposal cancellation refund rate key
+pub fn get_proposal_cancellation_refund_rate_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.cancellation_refund_rate.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get veto threshold key
+pub fn get_veto_threshold_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.veto_threshold.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get proposal timelock epochs key
+pub fn get_proposal_timelock_epochs_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.timelock_epochs.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
 /// Get key of proposal ids counter
 pub fn get_counter_key() -> Key {
     Key::from(ADDRESS.to_db_key())
@@ -403,6 +679,24 @@ pub fn get_funds_key(id: u64) -> Key {
         .expect("Cannot obtain a storage key")
 }
 
+/// Get key of the flag marking a proposal as expedited
+pub fn get_proposal_expedited_key(id: u64) -> Key {
+    proposal_prefix()
+        .push(&id.to_string())
+        .expect("Cannot obtain a storage key")
+        .push(&Keys::VALUES.expedited.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get key of the flag marking a proposal as cancelled by its author
+pub fn get_proposal_cancelled_key(id: u64) -> Key {
+    proposal_prefix()
+        .push(&id.to_string())
+        .expect("Cannot obtain a storage key")
+        .push(&Keys::VALUES.cancelled.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
 /// Get proposal activation epoch key
 pub fn get_activation_epoch_key(id: u64) -> Key {
     proposal_prefix()
@@ -459,6 +753,109 @@ pub fn get_vote_proposal_key(
         .expect("Cannot obtain a storage key")
 }
 
+/// Get the prefix of the keys of the votes of the voters for a specific
+/// proposal id
+pub fn get_voter_vote_prefix_key(id: u64) -> Key {
+    proposal_prefix()
+        .push(&id.to_string())
+        .expect("Cannot obtain a storage key")
+        .push(&Keys::VALUES.voter.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the key of the vote of a voter for a specific proposal id, regardless of
+/// its delegations
+pub fn get_voter_vote_key(id: u64, voter_address: &Address) -> Key {
+    get_voter_vote_prefix_key(id)
+        .push(voter_address)
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the prefix of all the governance representative keys
+pub fn get_representative_prefix_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.representative.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the prefix of the governance representative keys of a delegator
+pub fn get_representatives_key(delegator: &Address) -> Key {
+    get_representative_prefix_key()
+        .push(delegator)
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the key of the governance representative of a delegator for the given
+/// scope
+pub fn get_representative_key(
+    delegator: &Address,
+    scope: RepresentationScope,
+) -> Key {
+    get_representatives_key(delegator)
+        .push(&scope.to_string())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the prefix of the keys recording the governance representatives of a
+/// delegator for the given scope over the epochs
+pub fn get_representative_history_prefix_key(
+    delegator: &Address,
+    scope: RepresentationScope,
+) -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.representative_history.to_owned())
+        .expect("Cannot obtain a storage key")
+        .push(delegator)
+        .expect("Cannot obtain a storage key")
+        .push(&scope.to_string())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the key recording the governance representative of a delegator for
+/// the given scope from the given epoch on
+pub fn get_representative_history_key(
+    delegator: &Address,
+    scope: RepresentationScope,
+    epoch: Epoch,
+) -> Key {
+    get_representative_history_prefix_key(delegator, scope)
+        .push(&epoch)
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the prefix of the keys indexing the accounts represented by a
+/// governance representative
+pub fn get_represented_prefix_key(representative: &Address) -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.represented.to_owned())
+        .expect("Cannot obtain a storage key")
+        .push(representative)
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the prefix of the keys indexing the accounts represented by a
+/// governance representative for the given scope
+pub fn get_represented_scope_prefix_key(
+    representative: &Address,
+    scope: RepresentationScope,
+) -> Key {
+    get_represented_prefix_key(representative)
+        .push(&scope.to_string())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the key indexing a delegator under its governance representative for
+/// the given scope
+pub fn get_represented_key(
+    representative: &Address,
+    scope: RepresentationScope,
+    delegator: &Address,
+) -> Key {
+    get_represented_scope_prefix_key(representative, scope)
+        .push(delegator)
+        .expect("Cannot obtain a storage key")
+}
+
 /// Get the proposal execution key
 pub fn get_proposal_execution_key(id: u64) -> Key {
     Key::from(ADDRESS.to_db_key())
@@ -477,6 +874,78 @@ pub fn get_proposal_result_key(id: u64) -> Key {
         .expect("Cannot obtain a storage key")
 }
 
+/// Get the proposal settlement key
+pub fn get_proposal_settlement_key(id: u64) -> Key {
+    proposal_prefix()
+        .push(&id.to_string())
+        .expect("Cannot obtain a storage key")
+        .push(&Keys::VALUES.settlement.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the key of the timelock entry of a passed proposal
+pub fn get_proposal_timelock_key(id: u64) -> Key {
+    proposal_prefix()
+        .push(&id.to_string())
+        .expect("Cannot obtain a storage key")
+        .push(&Keys::VALUES.timelock.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the prefix of the timelock queue
+pub fn get_timelock_queue_prefix() -> Key {
+    proposal_prefix()
+        .push(&Keys::VALUES.timelock_queue.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the prefix of the proposals of the timelock queue executed at the
+/// given epoch
+pub fn get_timelock_queue_epoch_prefix(epoch: u64) -> Key {
+    get_timelock_queue_prefix()
+        .push(&epoch.to_string())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the key of a proposal in the timelock queue
+pub fn get_timelock_queue_key(id: u64, epoch: u64) -> Key {
+    get_timelock_queue_epoch_prefix(epoch)
+        .push(&id.to_string())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the prefix of the validator vetoes on a timelocked proposal
+pub fn get_timelock_veto_prefix_key(id: u64) -> Key {
+    proposal_prefix()
+        .push(&id.to_string())
+        .expect("Cannot obtain a storage key")
+        .push(&Keys::VALUES.timelock_veto.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the prefix of the wasm codes uploaded with a wasm allowlist proposal
+pub fn get_proposal_wasm_code_prefix_key(id: u64) -> Key {
+    proposal_prefix()
+        .push(&id.to_string())
+        .expect("Cannot obtain a storage key")
+        .push(&Keys::VALUES.wasm_code.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the key of a wasm code uploaded with a wasm allowlist proposal
+pub fn get_proposal_wasm_code_key(id: u64, code_hash: &Hash) -> Key {
+    get_proposal_wasm_code_prefix_key(id)
+        .push(code_hash)
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the key of the veto of a validator on a timelocked proposal
+pub fn get_timelock_veto_key(id: u64, validator: &Address) -> Key {
+    get_timelock_veto_prefix_key(id)
+        .push(validator)
+        .expect("Cannot obtain a storage key")
+}
+
 /// Get proposal id from key
 pub fn get_proposal_id(key: &Key) -> Option<u64> {
     match key.get_at(2) {
@@ -530,3 +999,25 @@ pub fn get_voter_address(key: &Key) -> Option<&Address> {
         None => None,
     }
 }
+
+/// Get voter address from the key of the vote of a voter
+pub fn get_voter_vote_address(key: &Key) -> Option<&Address> {
+    match key.get_at(4) {
+        Some(addr) => match addr {
+            DbKeySeg::AddressSeg(res) => Some(res),
+            DbKeySeg::StringSeg(_) => None,
+        },
+        None => None,
+    }
+}
+
+/// Get the validator address from the key of a veto on a timelocked proposal
+pub fn get_timelock_veto_address(key: &Key) -> Option<&Address> {
+    match key.get_at(4) {
+        Some(addr) => match addr {
+            DbKeySeg::AddressSeg(res) => Some(res),
+            DbKeySeg::StringSeg(_) => None,
+        },
+        None => None,
+    }
+}
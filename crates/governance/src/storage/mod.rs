@@ -1,5 +1,7 @@
 //! Governance storage
 
+/// Proposal content schema
+pub mod content;
 /// Governance proposal keys
 pub mod keys;
 /// Proposal structures
@@ -12,20 +14,27 @@ use std::collections::{BTreeMap, BTreeSet};
 use namada_core::address::Address;
 use namada_core::borsh::BorshDeserialize;
 use namada_core::collections::HashSet;
-use namada_core::storage::Epoch;
+use namada_core::dec::Dec;
+use namada_core::hash::Hash;
+use namada_core::storage::{Epoch, Key};
 use namada_core::token;
 use namada_state::{
-    iter_prefix, StorageError, StorageRead, StorageResult, StorageWrite,
+    iter_prefix, ResultExt, StorageError, StorageRead, StorageResult,
+    StorageWrite,
 };
 use namada_systems::trans_token;
 
 use crate::parameters::GovernanceParameters;
 use crate::storage::keys as governance_keys;
 use crate::storage::proposal::{
-    InitProposalData, ProposalType, StorageProposal, VoteProposalData,
+    CancelProposalData, DelegateVoteData, InitProposalData, ProposalType,
+    RepresentationScope, StorageProposal, VetoProposalData, VoteProposalData,
 };
 use crate::storage::vote::ProposalVote;
-use crate::utils::{ProposalResult, Vote};
+use crate::utils::{
+    ProposalLifecycle, ProposalResult, ProposalSettlement, ProposalTimelock,
+    QueuedProposal, RepresentedVote, TallyResult, TallyType, Vote, VotePower,
+};
 use crate::ADDRESS as governance_address;
 
 /// A proposal creation transaction.
@@ -78,6 +87,12 @@ where
         governance_keys::get_activation_epoch_key(proposal_id);
     storage.write(&activation_epoch_key, data.activation_epoch)?;
 
+    if data.is_expedited {
+        let expedited_key =
+            governance_keys::get_proposal_expedited_key(proposal_id);
+        storage.write(&expedited_key, ())?;
+    }
+
     storage.write(
         &counter_key,
         proposal_id
@@ -85,9 +100,13 @@ where
             .expect("Number of proposals should never exceed `u64::MAX`"),
     )?;
 
-    let min_proposal_funds_key = governance_keys::get_min_proposal_fund_key();
-    let min_proposal_funds: token::Amount =
-        storage.read(&min_proposal_funds_key)?.unwrap();
+    let min_proposal_funds: token::Amount = if data.is_expedited {
+        get_min_expedited_proposal_fund(storage)?
+    } else {
+        storage
+            .read(&governance_keys::get_min_proposal_fund_key())?
+            .unwrap()
+    };
 
     let funds_key = governance_keys::get_funds_key(proposal_id);
     storage.write(&funds_key, min_proposal_funds)?;
@@ -96,7 +115,7 @@ where
     let committing_proposals_key =
         governance_keys::get_committing_proposals_key(
             proposal_id,
-            data.activation_epoch.0,
+            data.tally_epoch().0,
         );
     storage.write(&committing_proposals_key, ())?;
 
@@ -111,6 +130,26 @@ where
     Ok(proposal_id)
 }
 
+/// Write the wasm codes uploaded with a wasm allowlist proposal, keyed by
+/// their hash.
+pub fn init_proposal_wasm_codes<S>(
+    storage: &mut S,
+    proposal_id: u64,
+    codes: Vec<Vec<u8>>,
+) -> StorageResult<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    for code in codes {
+        let code_key = governance_keys::get_proposal_wasm_code_key(
+            proposal_id,
+            &Hash::sha256(&code),
+        );
+        storage.write(&code_key, code)?;
+    }
+    Ok(())
+}
+
 /// A proposal vote transaction.
 pub fn vote_proposal<S>(
     storage: &mut S,
@@ -128,7 +167,329 @@ where
         );
         storage.write(&vote_key, data.vote.clone())?;
     }
-    Ok(())
+    // The vote is also recorded independently of the delegations, so that it
+    // applies to the accounts represented by the voter
+    let voter_vote_key =
+        governance_keys::get_voter_vote_key(data.id, &data.voter);
+    storage.write(&voter_vote_key, data.vote)
+}
+
+/// A vote delegation transaction, setting or revoking the governance
+/// representative of the delegator for the given scope.
+pub fn delegate_vote<S>(
+    storage: &mut S,
+    data: DelegateVoteData,
+) -> StorageResult<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    // The representative is recorded per epoch, so that the proposals are
+    // tallied with the representatives at the end of their voting period
+    let current_epoch = storage.get_block_epoch()?;
+    storage.write(
+        &governance_keys::get_representative_history_key(
+            &data.delegator,
+            data.scope,
+            current_epoch,
+        ),
+        data.representative.clone(),
+    )?;
+    let representative_key =
+        governance_keys::get_representative_key(&data.delegator, data.scope);
+    match data.representative {
+        Some(representative) => {
+            // The index of the represented accounts keeps the previous
+            // representatives, whose proposals may not be tallied yet
+            storage.write(
+                &governance_keys::get_represented_key(
+                    &representative,
+                    data.scope,
+                    &data.delegator,
+                ),
+                (),
+            )?;
+            storage.write(&representative_key, representative)
+        }
+        None => storage.delete(&representative_key),
+    }
+}
+
+/// Get the governance representatives of a delegator for each scope
+pub fn get_representatives<S>(
+    storage: &S,
+    delegator: &Address,
+) -> StorageResult<BTreeMap<RepresentationScope, Address>>
+where
+    S: StorageRead,
+{
+    let prefix = governance_keys::get_representatives_key(delegator);
+    let mut representatives = BTreeMap::new();
+    for result in iter_prefix::<Address>(storage, &prefix)? {
+        let (key, representative) = result?;
+        if let Some((_, scope)) = governance_keys::is_representative_key(&key) {
+            representatives.insert(scope, representative);
+        }
+    }
+    Ok(representatives)
+}
+
+/// Get the accounts which directly delegated their voting power to the given
+/// representative, for any scope
+pub fn get_represented_accounts<S>(
+    storage: &S,
+    representative: &Address,
+) -> StorageResult<BTreeSet<Address>>
+where
+    S: StorageRead,
+{
+    let prefix = governance_keys::get_represented_prefix_key(representative);
+    let mut delegators = BTreeSet::new();
+    for (scope, delegator) in
+        get_represented_accounts_by_prefix(storage, &prefix)?
+    {
+        // The index also keeps the accounts which delegated to someone else
+        // since then
+        let current: Option<Address> = storage.read(
+            &governance_keys::get_representative_key(&delegator, scope),
+        )?;
+        if current.as_ref() == Some(representative) {
+            delegators.insert(delegator);
+        }
+    }
+    Ok(delegators)
+}
+
+/// Get the accounts indexed under the given prefix of the index of the
+/// accounts that have been represented, with the scope of the representation
+fn get_represented_accounts_by_prefix<S>(
+    storage: &S,
+    prefix: &Key,
+) -> StorageResult<BTreeSet<(RepresentationScope, Address)>>
+where
+    S: StorageRead,
+{
+    let mut delegators = BTreeSet::new();
+    for result in namada_state::iter_prefix_bytes(storage, prefix)? {
+        let (key, _) = result?;
+        if let Some((_, scope, delegator)) =
+            governance_keys::is_represented_key(&key)
+        {
+            delegators.insert((scope, delegator.clone()));
+        }
+    }
+    Ok(delegators)
+}
+
+/// Get the governance representative of a delegator for a proposal type. A
+/// representative set for the type of the proposal takes precedence over one
+/// set for all proposals.
+pub fn get_representative<S>(
+    storage: &S,
+    delegator: &Address,
+    proposal_type: &ProposalType,
+) -> StorageResult<Option<Address>>
+where
+    S: StorageRead,
+{
+    let scope = RepresentationScope::of_proposal_type(proposal_type);
+    match storage
+        .read(&governance_keys::get_representative_key(delegator, scope))?
+    {
+        Some(representative) => Ok(Some(representative)),
+        None => storage.read(&governance_keys::get_representative_key(
+            delegator,
+            RepresentationScope::All,
+        )),
+    }
+}
+
+/// Get the governance representative of a delegator for the given scope at
+/// the given epoch, from the record of its representatives over the epochs
+fn get_representative_at<S>(
+    storage: &S,
+    delegator: &Address,
+    scope: RepresentationScope,
+    epoch: Epoch,
+) -> StorageResult<Option<Address>>
+where
+    S: StorageRead,
+{
+    let prefix = governance_keys::get_representative_history_prefix_key(
+        delegator, scope,
+    );
+    // The records are iterated by ascending epoch, so the last one that
+    // started by the given epoch applies
+    let mut representative = None;
+    for result in iter_prefix::<Option<Address>>(storage, &prefix)? {
+        let (key, value) = result?;
+        let Some((_, _, since)) =
+            governance_keys::is_representative_history_key(&key)
+        else {
+            continue;
+        };
+        if since <= epoch {
+            representative = value;
+        }
+    }
+    Ok(representative)
+}
+
+/// Get the governance representative of a delegator for a proposal type at
+/// the given epoch. A representative set for the type of the proposal takes
+/// precedence over one set for all proposals.
+pub fn get_representative_at_epoch<S>(
+    storage: &S,
+    delegator: &Address,
+    proposal_type: &ProposalType,
+    epoch: Epoch,
+) -> StorageResult<Option<Address>>
+where
+    S: StorageRead,
+{
+    let scope = RepresentationScope::of_proposal_type(proposal_type);
+    match get_representative_at(storage, delegator, scope, epoch)? {
+        Some(representative) => Ok(Some(representative)),
+        None => get_representative_at(
+            storage,
+            delegator,
+            RepresentationScope::All,
+            epoch,
+        ),
+    }
+}
+
+/// Get the vote cast by a voter on a proposal, if any
+pub fn get_voter_vote<S>(
+    storage: &S,
+    proposal_id: u64,
+    voter: &Address,
+) -> StorageResult<Option<ProposalVote>>
+where
+    S: StorageRead,
+{
+    storage.read(&governance_keys::get_voter_vote_key(proposal_id, voter))
+}
+
+/// Resolve the votes of the accounts that didn't vote on a proposal but
+/// delegated their voting power. The delegation chain is followed until a
+/// representative who voted is found. Accounts whose chain ends without a vote
+/// or loops back are left out. The representatives are the ones at the last
+/// epoch of the voting period, so that a delegation changed after the votes
+/// are known doesn't affect the tally.
+///
+/// The chains are walked backwards from the voters through the index of the
+/// represented accounts, so only the delegations that lead to a vote are read.
+pub fn get_represented_votes<S>(
+    storage: &S,
+    proposal_id: u64,
+) -> StorageResult<Vec<RepresentedVote>>
+where
+    S: StorageRead,
+{
+    let proposal_type: ProposalType = match storage
+        .read(&governance_keys::get_proposal_type_key(proposal_id))?
+    {
+        Some(proposal_type) => proposal_type,
+        None => return Ok(vec![]),
+    };
+
+    let voting_end_epoch: Epoch = match storage
+        .read(&governance_keys::get_voting_end_epoch_key(proposal_id))?
+    {
+        Some(epoch) => epoch,
+        None => return Ok(vec![]),
+    };
+    let scope = RepresentationScope::of_proposal_type(&proposal_type);
+
+    let mut votes = BTreeMap::new();
+    let prefix = governance_keys::get_voter_vote_prefix_key(proposal_id);
+    for result in iter_prefix::<ProposalVote>(storage, &prefix)? {
+        let (key, vote) = result?;
+        let Some(representative) =
+            governance_keys::get_voter_vote_address(&key)
+        else {
+            continue;
+        };
+        // Every account has a single representative for the proposal, so the
+        // accounts that lead to this vote form a tree and none of them is
+        // reached twice. A cycle of delegations without a vote can't be
+        // reached from a voter.
+        let mut pending = vec![representative.clone()];
+        while let Some(current) = pending.pop() {
+            let mut delegators = get_represented_accounts_by_prefix(
+                storage,
+                &governance_keys::get_represented_scope_prefix_key(
+                    &current,
+                    RepresentationScope::All,
+                ),
+            )?;
+            delegators.extend(get_represented_accounts_by_prefix(
+                storage,
+                &governance_keys::get_represented_scope_prefix_key(
+                    &current, scope,
+                ),
+            )?);
+            let delegators: BTreeSet<Address> = delegators
+                .into_iter()
+                .map(|(_, delegator)| delegator)
+                .collect();
+            for delegator in delegators {
+                // A representative set for the type of the proposal takes
+                // precedence over one set for all proposals, and a direct
+                // vote overrides the delegation
+                if get_representative_at_epoch(
+                    storage,
+                    &delegator,
+                    &proposal_type,
+                    voting_end_epoch,
+                )?
+                .as_ref()
+                    != Some(&current)
+                    || get_voter_vote(storage, proposal_id, &delegator)?
+                        .is_some()
+                {
+                    continue;
+                }
+                votes.insert(
+                    delegator.clone(),
+                    RepresentedVote {
+                        delegator: delegator.clone(),
+                        representative: representative.clone(),
+                        data: vote.clone(),
+                    },
+                );
+                pending.push(delegator);
+            }
+        }
+    }
+
+    Ok(votes.into_values().collect())
+}
+
+/// A proposal cancellation transaction. The locked funds are settled when the
+/// proposal reaches its activation epoch.
+pub fn cancel_proposal<S>(
+    storage: &mut S,
+    data: CancelProposalData,
+) -> StorageResult<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let cancelled_key = governance_keys::get_proposal_cancelled_key(data.id);
+    storage.write(&cancelled_key, ())
+}
+
+/// A validator veto on a passed proposal waiting in the timelock queue.
+pub fn veto_proposal<S>(
+    storage: &mut S,
+    data: VetoProposalData,
+) -> StorageResult<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let veto_key =
+        governance_keys::get_timelock_veto_key(data.id, &data.validator);
+    storage.write(&veto_key, ())
 }
 
 /// Write the proposal result to storage.
@@ -168,6 +529,8 @@ where
         storage.read(&activation_epoch_key)?;
     let proposal_type: Option<ProposalType> =
         storage.read(&proposal_type_key)?;
+    let is_expedited = is_proposal_expedited(storage, id)?;
+    let is_cancelled = is_proposal_cancelled(storage, id)?;
 
     let proposal = proposal_type.map(|proposal_type| StorageProposal {
         id,
@@ -177,11 +540,29 @@ where
         voting_start_epoch: voting_start_epoch.unwrap(),
         voting_end_epoch: voting_end_epoch.unwrap(),
         activation_epoch: activation_epoch.unwrap(),
+        is_expedited,
+        is_cancelled,
     });
 
     Ok(proposal)
 }
 
+/// Check if a proposal is expedited
+pub fn is_proposal_expedited<S>(storage: &S, id: u64) -> StorageResult<bool>
+where
+    S: StorageRead,
+{
+    storage.has_key(&governance_keys::get_proposal_expedited_key(id))
+}
+
+/// Check if a proposal has been cancelled by its author
+pub fn is_proposal_cancelled<S>(storage: &S, id: u64) -> StorageResult<bool>
+where
+    S: StorageRead,
+{
+    storage.has_key(&governance_keys::get_proposal_cancelled_key(id))
+}
+
 /// Query all the votes for a proposal_id
 pub fn get_proposal_votes<S>(
     storage: &S,
@@ -250,6 +631,20 @@ where
     storage.read(&proposal_code_key)
 }
 
+/// Get a wasm code uploaded with a wasm allowlist proposal
+pub fn get_proposal_wasm_code<S>(
+    storage: &S,
+    proposal_id: u64,
+    code_hash: &Hash,
+) -> StorageResult<Option<Vec<u8>>>
+where
+    S: StorageRead,
+{
+    let code_key =
+        governance_keys::get_proposal_wasm_code_key(proposal_id, code_hash);
+    storage.read(&code_key)
+}
+
 /// Get the code associated with a proposal
 pub fn get_proposal_author<S>(
     storage: &S,
@@ -293,6 +688,30 @@ where
     let max_proposal_latency: u64 =
         storage.read(&key)?.expect("Parameter should be defined.");
 
+    let min_expedited_proposal_fund = get_min_expedited_proposal_fund(storage)?;
+
+    let min_expedited_proposal_voting_period =
+        get_min_expedited_proposal_voting_period(storage)?;
+
+    // The parameters introduced with proposal cancellation and `NoWithVeto`
+    // votes are missing from the storage of older chains until they're set
+    // by a proposal
+    let key = governance_keys::get_proposal_cancellation_refund_rate_key();
+    let proposal_cancellation_refund_rate: Dec =
+        storage.read(&key)?.unwrap_or_else(|| {
+            GovernanceParameters::default().proposal_cancellation_refund_rate
+        });
+
+    let key = governance_keys::get_veto_threshold_key();
+    let veto_threshold: Dec = storage
+        .read(&key)?
+        .unwrap_or_else(|| GovernanceParameters::default().veto_threshold);
+
+    // Missing from the storage of chains that predate the timelock queue, in
+    // which case passed proposals are not delayed
+    let key = governance_keys::get_proposal_timelock_epochs_key();
+    let proposal_timelock_epochs: u64 = storage.read(&key)?.unwrap_or_default();
+
     Ok(GovernanceParameters {
         min_proposal_fund,
         max_proposal_code_size,
@@ -301,6 +720,11 @@ where
         max_proposal_content_size,
         min_proposal_grace_epochs,
         max_proposal_latency,
+        min_expedited_proposal_fund,
+        min_expedited_proposal_voting_period,
+        proposal_cancellation_refund_rate,
+        veto_threshold,
+        proposal_timelock_epochs,
     })
 }
 
@@ -315,6 +739,62 @@ where
     Ok(max_proposal_period)
 }
 
+/// Get governance "min_expedited_proposal_fund" parameter. Chains initialized
+/// before expedited proposals were introduced don't have it in storage until
+/// it's set by a proposal, in which case the default value applies.
+pub fn get_min_expedited_proposal_fund<S>(
+    storage: &S,
+) -> StorageResult<token::Amount>
+where
+    S: StorageRead,
+{
+    let key = governance_keys::get_min_expedited_proposal_fund_key();
+    Ok(storage.read(&key)?.unwrap_or_else(|| {
+        GovernanceParameters::default().min_expedited_proposal_fund
+    }))
+}
+
+/// Get governance "min_expedited_proposal_voting_period" parameter, with the
+/// default value if it's missing from storage like
+/// [`get_min_expedited_proposal_fund`]
+pub fn get_min_expedited_proposal_voting_period<S>(
+    storage: &S,
+) -> StorageResult<u64>
+where
+    S: StorageRead,
+{
+    let key = governance_keys::get_min_expedited_proposal_voting_period_key();
+    Ok(storage.read(&key)?.unwrap_or_else(|| {
+        GovernanceParameters::default().min_expedited_proposal_voting_period
+    }))
+}
+
+/// The layout of a [`ProposalResult`] stored before `NoWithVeto` votes were
+/// introduced
+#[derive(BorshDeserialize)]
+struct LegacyProposalResult {
+    result: TallyResult,
+    tally_type: TallyType,
+    total_voting_power: VotePower,
+    total_yay_power: VotePower,
+    total_nay_power: VotePower,
+    total_abstain_power: VotePower,
+}
+
+impl From<LegacyProposalResult> for ProposalResult {
+    fn from(result: LegacyProposalResult) -> Self {
+        Self {
+            result: result.result,
+            tally_type: result.tally_type,
+            total_voting_power: result.total_voting_power,
+            total_yay_power: result.total_yay_power,
+            total_nay_power: result.total_nay_power,
+            total_veto_power: VotePower::zero(),
+            total_abstain_power: result.total_abstain_power,
+        }
+    }
+}
+
 /// Get governance proposal result stored in storage if proposal ended
 pub fn get_proposal_result<S>(
     storage: &S,
@@ -324,8 +804,246 @@ where
     S: StorageRead,
 {
     let key = governance_keys::get_proposal_result_key(proposal_id);
-    let proposal_result: Option<ProposalResult> = storage.read(&key)?;
-    Ok(proposal_result)
+    let Some(bytes) = storage.read_bytes(&key)? else {
+        return Ok(None);
+    };
+    let proposal_result = ProposalResult::try_from_slice(&bytes)
+        .or_else(|_| {
+            LegacyProposalResult::try_from_slice(&bytes)
+                .map(ProposalResult::from)
+        })
+        .into_storage_result()?;
+    Ok(Some(proposal_result))
+}
+
+/// Write the settlement of a proposal to storage.
+pub fn write_proposal_settlement<S>(
+    storage: &mut S,
+    proposal_id: u64,
+    settlement: ProposalSettlement,
+) -> StorageResult<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let key = governance_keys::get_proposal_settlement_key(proposal_id);
+    storage.write(&key, settlement)
+}
+
+/// Read the settlement of a proposal, if it was settled
+pub fn get_proposal_settlement<S>(
+    storage: &S,
+    proposal_id: u64,
+) -> StorageResult<Option<ProposalSettlement>>
+where
+    S: StorageRead,
+{
+    let key = governance_keys::get_proposal_settlement_key(proposal_id);
+    storage.read(&key)
+}
+
+/// Read the full lifecycle record of a proposal
+pub fn get_proposal_lifecycle<S>(
+    storage: &S,
+    proposal_id: u64,
+    current_epoch: Epoch,
+) -> StorageResult<Option<ProposalLifecycle>>
+where
+    S: StorageRead,
+{
+    let Some(proposal) = get_proposal_by_id(storage, proposal_id)? else {
+        return Ok(None);
+    };
+    let result = get_proposal_result(storage, proposal_id)?;
+    let settlement = get_proposal_settlement(storage, proposal_id)?;
+    let timelock = get_proposal_timelock(storage, proposal_id)?;
+
+    Ok(Some(ProposalLifecycle::new(
+        proposal,
+        result,
+        settlement,
+        timelock,
+        current_epoch,
+    )))
+}
+
+/// Put a passed proposal in the timelock queue, delaying its execution
+pub fn queue_proposal<S>(
+    storage: &mut S,
+    proposal_id: u64,
+    timelock: ProposalTimelock,
+) -> StorageResult<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let key = governance_keys::get_proposal_timelock_key(proposal_id);
+    storage.write(&key, timelock)?;
+    let queue_key = governance_keys::get_timelock_queue_key(
+        proposal_id,
+        timelock.execution_epoch.0,
+    );
+    storage.write(&queue_key, ())
+}
+
+/// Remove a proposal from the timelock queue. Its timelock entry is kept as
+/// part of its lifecycle record.
+pub fn dequeue_proposal<S>(
+    storage: &mut S,
+    proposal_id: u64,
+    execution_epoch: Epoch,
+) -> StorageResult<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let queue_key =
+        governance_keys::get_timelock_queue_key(proposal_id, execution_epoch.0);
+    storage.delete(&queue_key)
+}
+
+/// Read the timelock entry of a proposal, if it entered the timelock queue
+pub fn get_proposal_timelock<S>(
+    storage: &S,
+    proposal_id: u64,
+) -> StorageResult<Option<ProposalTimelock>>
+where
+    S: StorageRead,
+{
+    let key = governance_keys::get_proposal_timelock_key(proposal_id);
+    storage.read(&key)
+}
+
+/// Get the validators that vetoed a timelocked proposal
+pub fn get_timelock_vetoes<S>(
+    storage: &S,
+    proposal_id: u64,
+) -> StorageResult<BTreeSet<Address>>
+where
+    S: StorageRead,
+{
+    let prefix = governance_keys::get_timelock_veto_prefix_key(proposal_id);
+    let mut vetoes = BTreeSet::new();
+    for key_val in namada_state::iter_prefix_bytes(storage, &prefix)? {
+        let (key, _) = key_val?;
+        if let Some(validator) =
+            governance_keys::get_timelock_veto_address(&key)
+        {
+            vetoes.insert(validator.clone());
+        }
+    }
+    Ok(vetoes)
+}
+
+/// Read a proposal waiting in the timelock queue, if any
+pub fn get_queued_proposal<S>(
+    storage: &S,
+    proposal_id: u64,
+) -> StorageResult<Option<QueuedProposal>>
+where
+    S: StorageRead,
+{
+    let Some(timelock) = get_proposal_timelock(storage, proposal_id)? else {
+        return Ok(None);
+    };
+    let queue_key = governance_keys::get_timelock_queue_key(
+        proposal_id,
+        timelock.execution_epoch.0,
+    );
+    if !storage.has_key(&queue_key)? {
+        return Ok(None);
+    }
+    Ok(Some(QueuedProposal {
+        id: proposal_id,
+        timelock,
+        vetoes: get_timelock_vetoes(storage, proposal_id)?,
+    }))
+}
+
+/// Get all the proposals waiting in the timelock queue, ordered by execution
+/// epoch
+pub fn get_queued_proposals<S>(
+    storage: &S,
+) -> StorageResult<Vec<QueuedProposal>>
+where
+    S: StorageRead,
+{
+    let prefix = governance_keys::get_timelock_queue_prefix();
+    let mut queue = BTreeSet::new();
+    for key_val in namada_state::iter_prefix_bytes(storage, &prefix)? {
+        let (key, _) = key_val?;
+        if let Some(entry) = governance_keys::is_timelock_queue_key(&key) {
+            queue.insert(entry);
+        }
+    }
+
+    let mut proposals = Vec::with_capacity(queue.len());
+    for (_epoch, proposal_id) in queue {
+        if let Some(proposal) = get_queued_proposal(storage, proposal_id)? {
+            proposals.push(proposal);
+        }
+    }
+    Ok(proposals)
+}
+
+/// Load the timelocked proposals to execute in the current epoch
+pub fn load_timelocked_proposals<S>(
+    storage: &S,
+    current_epoch: Epoch,
+) -> StorageResult<BTreeSet<u64>>
+where
+    S: StorageRead,
+{
+    let prefix =
+        governance_keys::get_timelock_queue_epoch_prefix(current_epoch.0);
+    let mut ids = BTreeSet::new();
+    for key_val in namada_state::iter_prefix_bytes(storage, &prefix)? {
+        let (key, _) = key_val?;
+        if let Some((epoch, proposal_id)) =
+            governance_keys::is_timelock_queue_key(&key)
+        {
+            if epoch == current_epoch.0 {
+                ids.insert(proposal_id);
+            }
+        }
+    }
+    Ok(ids)
+}
+
+/// Load the proposals whose voting period starts in the current epoch.
+///
+/// Proposal ids are assigned in creation order and a voting period starts at
+/// most `max_proposal_latency` epochs after the creation of its proposal, so
+/// the proposals are scanned from the most recent one until one that was
+/// created too early to start voting in the current epoch.
+pub fn load_proposals_starting_voting<S>(
+    storage: &S,
+    current_epoch: Epoch,
+) -> StorageResult<BTreeSet<u64>>
+where
+    S: StorageRead,
+{
+    let key = governance_keys::get_max_proposal_latency_key();
+    let max_proposal_latency: u64 =
+        storage.read(&key)?.expect("Parameter should be defined.");
+    let counter: u64 = storage
+        .read(&governance_keys::get_counter_key())?
+        .unwrap_or_default();
+
+    let mut ids = BTreeSet::new();
+    for id in (0..counter).rev() {
+        let Some(voting_start_epoch) = storage
+            .read::<Epoch>(&governance_keys::get_voting_start_epoch_key(id))?
+        else {
+            continue;
+        };
+        if voting_start_epoch == current_epoch {
+            ids.insert(id);
+        } else if voting_start_epoch.0.saturating_add(max_proposal_latency)
+            < current_epoch.0
+        {
+            break;
+        }
+    }
+
+    Ok(ids)
 }
 
 /// Load proposals for execution in the current epoch.
@@ -1,9 +1,12 @@
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Display;
+use std::str::FromStr;
 
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use itertools::Itertools;
 use namada_core::address::Address;
+use namada_core::chain::ProposalBytes;
+use namada_core::dec::Dec;
 use namada_core::hash::Hash;
 use namada_core::ibc::core::host::types::identifiers::{ChannelId, PortId};
 use namada_core::storage::Epoch;
@@ -14,12 +17,15 @@ use namada_migrations::*;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use super::content::{ContentError, ProposalContent};
 use super::vote::ProposalVote;
 use crate::cli::onchain::{
-    DefaultProposal, PgfAction, PgfContinuous, PgfFundingProposal, PgfRetro,
-    PgfSteward, PgfStewardProposal, StewardsUpdate,
+    DefaultProposal, ParameterChangeProposal, PgfAction, PgfContinuous,
+    PgfFundingProposal, PgfRetro, PgfSteward, PgfStewardProposal,
+    StewardsUpdate, WasmAllowlistProposal,
 };
 use crate::utils::{ProposalStatus, TallyType};
+use crate::vp::MAX_WASM_ALLOWLIST_CHANGES;
 
 #[allow(missing_docs)]
 #[derive(Debug, Error)]
@@ -35,7 +41,6 @@ pub enum ProposalError {
     PartialEq,
     BorshSchema,
     BorshSerialize,
-    BorshDeserialize,
     BorshDeserializer,
     Serialize,
     Deserialize,
@@ -53,9 +58,67 @@ pub struct InitProposalData {
     pub voting_end_epoch: Epoch,
     /// The epoch in which any changes are executed and become active
     pub activation_epoch: Epoch,
+    /// Whether the proposal is expedited, with a shorter voting period, a
+    /// larger deposit and a stricter tally
+    pub is_expedited: bool,
+}
+
+impl BorshDeserialize for InitProposalData {
+    fn deserialize_reader<R: std::io::Read>(
+        reader: &mut R,
+    ) -> std::io::Result<Self> {
+        use std::io::{Error, ErrorKind};
+        let content: Hash = BorshDeserialize::deserialize_reader(reader)?;
+        let author: Address = BorshDeserialize::deserialize_reader(reader)?;
+        let r#type: ProposalType =
+            BorshDeserialize::deserialize_reader(reader)?;
+        let voting_start_epoch: Epoch =
+            BorshDeserialize::deserialize_reader(reader)?;
+        let voting_end_epoch: Epoch =
+            BorshDeserialize::deserialize_reader(reader)?;
+        let activation_epoch: Epoch =
+            BorshDeserialize::deserialize_reader(reader)?;
+        // The data encoded before expedited proposals were introduced ends
+        // here, in which case the proposal is not expedited
+        let mut flag = [0_u8; 1];
+        let is_expedited = match reader.read(&mut flag)? {
+            0 => false,
+            _ => match flag[0] {
+                0 => false,
+                1 => true,
+                value => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        format!("Error decoding the expedited flag: {value}"),
+                    ));
+                }
+            },
+        };
+        Ok(Self {
+            content,
+            author,
+            r#type,
+            voting_start_epoch,
+            voting_end_epoch,
+            activation_epoch,
+            is_expedited,
+        })
+    }
 }
 
 impl InitProposalData {
+    /// Get the epoch at which the proposal is tallied. An expedited proposal
+    /// is tallied as soon as its voting period ends, and executed right away
+    /// if it passes. The other proposals are tallied at their activation
+    /// epoch.
+    pub fn tally_epoch(&self) -> Epoch {
+        if self.is_expedited {
+            self.voting_end_epoch.next()
+        } else {
+            self.activation_epoch
+        }
+    }
+
     /// Get the hash of the corresponding extra data section
     pub fn get_section_code_hash(&self) -> Option<Hash> {
         match self.r#type {
@@ -63,6 +126,21 @@ impl InitProposalData {
             _ => None,
         }
     }
+
+    /// Get the hashes of the wasm codes uploaded with a wasm allowlist
+    /// proposal
+    pub fn get_wasm_allowlist_code_hashes(&self) -> Vec<Hash> {
+        match &self.r#type {
+            ProposalType::WasmAllowlist(changes) => changes
+                .iter()
+                .filter_map(|change| match change {
+                    AddRemove::Add(entry) => Some(entry.code_hash),
+                    AddRemove::Remove(_) => None,
+                })
+                .collect(),
+            _ => vec![],
+        }
+    }
 }
 
 /// A tx data type to hold vote proposal data
@@ -86,6 +164,148 @@ pub struct VoteProposalData {
     pub voter: Address,
 }
 
+/// A tx data type to hold the data of a proposal cancelled by its author
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSchema,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct CancelProposalData {
+    /// The proposal id
+    pub id: u64,
+    /// The proposal author address
+    pub author: Address,
+}
+
+/// A tx data type to hold the veto of a validator on a passed proposal waiting
+/// in the timelock queue
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSchema,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct VetoProposalData {
+    /// The proposal id
+    pub id: u64,
+    /// The vetoing validator address
+    pub validator: Address,
+}
+
+/// The proposals on which a governance representative votes on behalf of the
+/// accounts that delegated their voting power to it
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    BorshSchema,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub enum RepresentationScope {
+    /// Any proposal which has no representative set for its own type
+    All,
+    /// Default proposals
+    Default,
+    /// Default proposals with wasm code
+    DefaultWithWasm,
+    /// PGF stewards proposals
+    PGFSteward,
+    /// PGF funding proposals
+    PGFPayment,
+    /// Parameter change proposals
+    ParameterChange,
+    /// Wasm allowlist proposals
+    WasmAllowlist,
+}
+
+impl RepresentationScope {
+    /// Get the scope specific to the given proposal type
+    pub fn of_proposal_type(proposal_type: &ProposalType) -> Self {
+        match proposal_type {
+            ProposalType::Default => Self::Default,
+            ProposalType::DefaultWithWasm(_) => Self::DefaultWithWasm,
+            ProposalType::PGFSteward(_) => Self::PGFSteward,
+            ProposalType::PGFPayment(_) => Self::PGFPayment,
+            ProposalType::ParameterChange(_) => Self::ParameterChange,
+            ProposalType::WasmAllowlist(_) => Self::WasmAllowlist,
+        }
+    }
+}
+
+impl Display for RepresentationScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::All => write!(f, "all"),
+            Self::Default => write!(f, "default"),
+            Self::DefaultWithWasm => write!(f, "default_with_wasm"),
+            Self::PGFSteward => write!(f, "pgf_steward"),
+            Self::PGFPayment => write!(f, "pgf_payment"),
+            Self::ParameterChange => write!(f, "parameter_change"),
+            Self::WasmAllowlist => write!(f, "wasm_allowlist"),
+        }
+    }
+}
+
+impl FromStr for RepresentationScope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "all" => Ok(Self::All),
+            "default" => Ok(Self::Default),
+            "default_with_wasm" => Ok(Self::DefaultWithWasm),
+            "pgf_steward" => Ok(Self::PGFSteward),
+            "pgf_payment" => Ok(Self::PGFPayment),
+            "parameter_change" => Ok(Self::ParameterChange),
+            "wasm_allowlist" => Ok(Self::WasmAllowlist),
+            _ => Err(format!("invalid representation scope {s}")),
+        }
+    }
+}
+
+/// A tx data type to delegate the governance voting power of an account to a
+/// representative, or to revoke such a delegation
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSchema,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct DelegateVoteData {
+    /// The address delegating its voting power
+    pub delegator: Address,
+    /// The representative voting on behalf of the delegator, or `None` to
+    /// revoke the delegation
+    pub representative: Option<Address>,
+    /// The proposals the delegation applies to
+    pub scope: RepresentationScope,
+}
+
 impl TryFrom<DefaultProposal> for InitProposalData {
     type Error = ProposalError;
 
@@ -100,6 +320,7 @@ impl TryFrom<DefaultProposal> for InitProposalData {
             voting_start_epoch: value.proposal.voting_start_epoch,
             voting_end_epoch: value.proposal.voting_end_epoch,
             activation_epoch: value.proposal.activation_epoch,
+            is_expedited: value.proposal.is_expedited,
         })
     }
 }
@@ -118,6 +339,7 @@ impl TryFrom<PgfStewardProposal> for InitProposalData {
             voting_start_epoch: value.proposal.voting_start_epoch,
             voting_end_epoch: value.proposal.voting_end_epoch,
             activation_epoch: value.proposal.activation_epoch,
+            is_expedited: value.proposal.is_expedited,
         })
     }
 }
@@ -157,6 +379,46 @@ impl TryFrom<PgfFundingProposal> for InitProposalData {
             voting_start_epoch: value.proposal.voting_start_epoch,
             voting_end_epoch: value.proposal.voting_end_epoch,
             activation_epoch: value.proposal.activation_epoch,
+            is_expedited: value.proposal.is_expedited,
+        })
+    }
+}
+
+impl TryFrom<ParameterChangeProposal> for InitProposalData {
+    type Error = ProposalError;
+
+    fn try_from(value: ParameterChangeProposal) -> Result<Self, Self::Error> {
+        Ok(InitProposalData {
+            content: Hash::default(),
+            author: value.proposal.author,
+            r#type: ProposalType::ParameterChange(
+                value.data.into_iter().collect(),
+            ),
+            voting_start_epoch: value.proposal.voting_start_epoch,
+            voting_end_epoch: value.proposal.voting_end_epoch,
+            activation_epoch: value.proposal.activation_epoch,
+            is_expedited: value.proposal.is_expedited,
+        })
+    }
+}
+
+impl TryFrom<WasmAllowlistProposal> for InitProposalData {
+    type Error = ProposalError;
+
+    fn try_from(value: WasmAllowlistProposal) -> Result<Self, Self::Error> {
+        let changes = value
+            .data
+            .changes()
+            .map_err(|_| ProposalError::InvalidProposalData)?;
+
+        Ok(InitProposalData {
+            content: Hash::default(),
+            author: value.proposal.author,
+            r#type: ProposalType::WasmAllowlist(changes.into_iter().collect()),
+            voting_start_epoch: value.proposal.voting_start_epoch,
+            voting_end_epoch: value.proposal.voting_end_epoch,
+            activation_epoch: value.proposal.activation_epoch,
+            is_expedited: value.proposal.is_expedited,
         })
     }
 }
@@ -213,6 +475,11 @@ pub enum ProposalType {
     PGFSteward(BTreeSet<AddRemove<Address>>),
     /// PGF funding proposal
     PGFPayment(BTreeSet<PGFAction>),
+    /// Protocol parameters change proposal
+    ParameterChange(BTreeSet<ParameterChange>),
+    /// Wasm allowlist proposal, adding uploaded wasm codes to or removing
+    /// code hashes from the tx and vp allowlists
+    WasmAllowlist(BTreeSet<AddRemove<WasmAllowlistEntry>>),
 }
 
 /// An add or remove action for PGF
@@ -449,6 +716,15 @@ impl ProposalType {
         matches!(self, ProposalType::DefaultWithWasm(_))
     }
 
+    /// Check if a proposal of this type can be expedited. PGF proposals are
+    /// tallied with their own rules and cannot be expedited.
+    pub fn can_be_expedited(&self) -> bool {
+        !matches!(
+            self,
+            ProposalType::PGFSteward(_) | ProposalType::PGFPayment(_)
+        )
+    }
+
     fn format_data(&self) -> String {
         match self {
             ProposalType::DefaultWithWasm(hash) => format!("Hash: {}", &hash),
@@ -467,6 +743,20 @@ impl ProposalType {
                     .map(|action| format!("\n  {}", &action))
                     .join("")
             ),
+            ProposalType::ParameterChange(changes) => format!(
+                "Changes:{}",
+                changes
+                    .iter()
+                    .map(|change| format!("\n  {}", &change))
+                    .join("")
+            ),
+            ProposalType::WasmAllowlist(changes) => format!(
+                "Changes:{}",
+                changes
+                    .iter()
+                    .map(|change| format!("\n  {}", &change))
+                    .join("")
+            ),
         }
     }
 }
@@ -478,6 +768,8 @@ impl Display for ProposalType {
             ProposalType::DefaultWithWasm(_) => write!(f, "Default with Wasm"),
             ProposalType::PGFSteward(_) => write!(f, "PGF steward"),
             ProposalType::PGFPayment(_) => write!(f, "PGF funding"),
+            ProposalType::ParameterChange(_) => write!(f, "Parameter change"),
+            ProposalType::WasmAllowlist(_) => write!(f, "Wasm allowlist"),
         }
     }
 }
@@ -546,6 +838,721 @@ impl Display for PGFAction {
     }
 }
 
+/// A change of a single protocol parameter, applied natively when a
+/// parameter change proposal passes. Only the parameters that can be safely
+/// modified on a live chain are included.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSchema,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+pub enum ParameterChange {
+    /// A change of a parameter of `namada_parameters`
+    Protocol(ProtocolParameterChange),
+    /// A change of a proof-of-stake parameter
+    Pos(PosParameterChange),
+    /// A change of a governance parameter
+    Governance(GovernanceParameterChange),
+    /// A change of a PGF parameter
+    Pgf(PgfParameterChange),
+    /// A change of an IBC parameter
+    Ibc(IbcParameterChange),
+}
+
+/// A change of a parameter of `namada_parameters`
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSchema,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+pub enum ProtocolParameterChange {
+    /// Max payload size, in bytes, for a mempool tx
+    MaxTxBytes(u32),
+    /// Max payload size, in bytes, for a tx batch proposal
+    MaxProposalBytes(ProposalBytes),
+    /// Max gas for block
+    MaxBlockGas(u64),
+    /// The gas limit for a masp transaction paying fees
+    MaspFeePaymentGasLimit(u64),
+    /// Gas scale
+    GasScale(u64),
+    /// The cost per gas unit of a token allowed for fee payment. `None`
+    /// removes the token from the tokens allowed for fee payment.
+    MinimumGasPrice {
+        /// The fee token
+        token: Address,
+        /// The new minimum gas price
+        price: Option<token::Amount>,
+    },
+    /// Enable the native token transfer
+    IsNativeTokenTransferable(bool),
+}
+
+/// A change of a proof-of-stake parameter
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSchema,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+pub enum PosParameterChange {
+    /// A maximum number of consensus validators
+    MaxValidatorSlots(u64),
+    /// Amount of tokens rewarded to a validator for proposing a block
+    BlockProposerReward(Dec),
+    /// Amount of tokens rewarded to each validator that voted on a block
+    /// proposal
+    BlockVoteReward(Dec),
+    /// Maximum staking rewards rate per annum
+    MaxInflationRate(Dec),
+    /// Target ratio of staked NAM tokens to total NAM tokens
+    TargetStakedRatio(Dec),
+    /// Fraction of validator's stake that should be slashed on a duplicate
+    /// vote
+    DuplicateVoteMinSlashRate(Dec),
+    /// Fraction of validator's stake that should be slashed on a light client
+    /// attack
+    LightClientAttackMinSlashRate(Dec),
+    /// Number of epochs above and below (separately) the current epoch to
+    /// consider when doing cubic slashing
+    CubicSlashingWindowLength(u64),
+    /// The minimum amount of bonded tokens that a validator needs to be in
+    /// either the `consensus` or `below_capacity` validator sets
+    ValidatorStakeThreshold(token::Amount),
+    /// The minimum amount of tokens that a validator needs to have self-bonded
+    MinSelfBond(token::Amount),
+    /// The maximum fraction of the total stake that a validator can have
+    MaxValidatorStakeShare(Dec),
+    /// The length, in blocks, of the sliding window for consensus validators
+    /// inactivity verification
+    LivenessWindowCheck(u64),
+    /// The minimum required activity of consesus validators over the
+    /// `liveness_window_check`
+    LivenessThreshold(Dec),
+}
+
+/// A change of a governance parameter
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSchema,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+pub enum GovernanceParameterChange {
+    /// Minimum amount of locked funds
+    MinProposalFund(token::Amount),
+    /// Maximum kibibyte length for proposal code
+    MaxProposalCodeSize(u64),
+    /// Minimum number of epochs between the proposal end epoch and start epoch
+    MinProposalVotingPeriod(u64),
+    /// Maximum number of epochs between the proposal start epoch and
+    /// activation epoch
+    MaxProposalPeriod(u64),
+    /// Maximum number of characters for proposal content
+    MaxProposalContentSize(u64),
+    /// Minimum number of epochs between the end and activation epochs
+    MinProposalGraceEpochs(u64),
+    /// Maximum number of epochs between current epoch and start epoch
+    MaxProposalLatency(u64),
+    /// Minimum amount of locked funds for an expedited proposal
+    MinExpeditedProposalFund(token::Amount),
+    /// Minimum number of epochs between the end epoch and start epoch of an
+    /// expedited proposal
+    MinExpeditedProposalVotingPeriod(u64),
+    /// Fraction of the locked funds refunded to the author of a cancelled
+    /// proposal
+    ProposalCancellationRefundRate(Dec),
+    /// Fraction of the voted power that must vote `NoWithVeto` to veto a
+    /// proposal
+    VetoThreshold(Dec),
+    /// Number of epochs a passed proposal waits in the timelock queue before
+    /// its execution
+    ProposalTimelockEpochs(u64),
+}
+
+/// A change of a PGF parameter
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSchema,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+pub enum PgfParameterChange {
+    /// The pgf funding inflation rate
+    PgfInflationRate(Dec),
+    /// The pgf stewards inflation rate
+    StewardsInflationRate(Dec),
+    /// The maximum number of pgf stewards at once
+    MaximumNumberOfStewards(u64),
+}
+
+/// A change of an IBC parameter
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSchema,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+pub enum IbcParameterChange {
+    /// Default supply limit of each token
+    DefaultMintLimit(token::Amount),
+    /// Default per-epoch throughput limit of each token
+    DefaultPerEpochThroughputLimit(token::Amount),
+    /// The supply limit of a token, overriding the default
+    MintLimit {
+        /// The limited token
+        token: Address,
+        /// The new limit
+        limit: token::Amount,
+    },
+    /// The per-epoch throughput limit of a token, overriding the default
+    PerEpochThroughputLimit {
+        /// The limited token
+        token: Address,
+        /// The new limit
+        limit: token::Amount,
+    },
+}
+
+impl ParameterChange {
+    /// The name of the changed parameter. Two changes with the same target
+    /// modify the same parameter.
+    pub fn target(&self) -> String {
+        match self {
+            ParameterChange::Protocol(change) => {
+                let name = match change {
+                    ProtocolParameterChange::MaxTxBytes(_) => "max_tx_bytes",
+                    ProtocolParameterChange::MaxProposalBytes(_) => {
+                        "max_proposal_bytes"
+                    }
+                    ProtocolParameterChange::MaxBlockGas(_) => "max_block_gas",
+                    ProtocolParameterChange::MaspFeePaymentGasLimit(_) => {
+                        "masp_fee_payment_gas_limit"
+                    }
+                    ProtocolParameterChange::GasScale(_) => "gas_scale",
+                    ProtocolParameterChange::MinimumGasPrice {
+                        token, ..
+                    } => return format!("protocol.minimum_gas_price.{token}"),
+                    ProtocolParameterChange::IsNativeTokenTransferable(_) => {
+                        "is_native_token_transferable"
+                    }
+                };
+                format!("protocol.{name}")
+            }
+            ParameterChange::Pos(change) => {
+                let name = match change {
+                    PosParameterChange::MaxValidatorSlots(_) => {
+                        "max_validator_slots"
+                    }
+                    PosParameterChange::BlockProposerReward(_) => {
+                        "block_proposer_reward"
+                    }
+                    PosParameterChange::BlockVoteReward(_) => {
+                        "block_vote_reward"
+                    }
+                    PosParameterChange::MaxInflationRate(_) => {
+                        "max_inflation_rate"
+                    }
+                    PosParameterChange::TargetStakedRatio(_) => {
+                        "target_staked_ratio"
+                    }
+                    PosParameterChange::DuplicateVoteMinSlashRate(_) => {
+                        "duplicate_vote_min_slash_rate"
+                    }
+                    PosParameterChange::LightClientAttackMinSlashRate(_) => {
+                        "light_client_attack_min_slash_rate"
+                    }
+                    PosParameterChange::CubicSlashingWindowLength(_) => {
+                        "cubic_slashing_window_length"
+                    }
+                    PosParameterChange::ValidatorStakeThreshold(_) => {
+                        "validator_stake_threshold"
+                    }
+                    PosParameterChange::MinSelfBond(_) => "min_self_bond",
+                    PosParameterChange::MaxValidatorStakeShare(_) => {
+                        "max_validator_stake_share"
+                    }
+                    PosParameterChange::LivenessWindowCheck(_) => {
+                        "liveness_window_check"
+                    }
+                    PosParameterChange::LivenessThreshold(_) => {
+                        "liveness_threshold"
+                    }
+                };
+                format!("pos.{name}")
+            }
+            ParameterChange::Governance(change) => {
+                let name = match change {
+                    GovernanceParameterChange::MinProposalFund(_) => {
+                        "min_proposal_fund"
+                    }
+                    GovernanceParameterChange::MaxProposalCodeSize(_) => {
+                        "max_proposal_code_size"
+                    }
+                    GovernanceParameterChange::MinProposalVotingPeriod(_) => {
+                        "min_proposal_voting_period"
+                    }
+                    GovernanceParameterChange::MaxProposalPeriod(_) => {
+                        "max_proposal_period"
+                    }
+                    GovernanceParameterChange::MaxProposalContentSize(_) => {
+                        "max_proposal_content_size"
+                    }
+                    GovernanceParameterChange::MinProposalGraceEpochs(_) => {
+                        "min_proposal_grace_epochs"
+                    }
+                    GovernanceParameterChange::MaxProposalLatency(_) => {
+                        "max_proposal_latency"
+                    }
+                    GovernanceParameterChange::MinExpeditedProposalFund(_) => {
+                        "min_expedited_proposal_fund"
+                    }
+                    GovernanceParameterChange::MinExpeditedProposalVotingPeriod(
+                        _,
+                    ) => "min_expedited_proposal_voting_period",
+                    GovernanceParameterChange::ProposalCancellationRefundRate(
+                        _,
+                    ) => "proposal_cancellation_refund_rate",
+                    GovernanceParameterChange::VetoThreshold(_) => {
+                        "veto_threshold"
+                    }
+                    GovernanceParameterChange::ProposalTimelockEpochs(_) => {
+                        "proposal_timelock_epochs"
+                    }
+                };
+                format!("governance.{name}")
+            }
+            ParameterChange::Pgf(change) => {
+                let name = match change {
+                    PgfParameterChange::PgfInflationRate(_) => {
+                        "pgf_inflation_rate"
+                    }
+                    PgfParameterChange::StewardsInflationRate(_) => {
+                        "stewards_inflation_rate"
+                    }
+                    PgfParameterChange::MaximumNumberOfStewards(_) => {
+                        "maximum_number_of_stewards"
+                    }
+                };
+                format!("pgf.{name}")
+            }
+            ParameterChange::Ibc(change) => match change {
+                IbcParameterChange::DefaultMintLimit(_) => {
+                    "ibc.default_mint_limit".to_string()
+                }
+                IbcParameterChange::DefaultPerEpochThroughputLimit(_) => {
+                    "ibc.default_per_epoch_throughput_limit".to_string()
+                }
+                IbcParameterChange::MintLimit { token, .. } => {
+                    format!("ibc.mint_limit.{token}")
+                }
+                IbcParameterChange::PerEpochThroughputLimit {
+                    token, ..
+                } => format!("ibc.per_epoch_throughput_limit.{token}"),
+            },
+        }
+    }
+
+    /// Check the new value of the parameter on its own. Constraints that
+    /// involve other parameters are checked when the change is applied.
+    pub fn validate(&self) -> Result<(), String> {
+        let is_fraction = |value: &Dec| {
+            if value.is_negative() || *value > Dec::one() {
+                Err(format!(
+                    "{} must be between 0 and 1, got {value}",
+                    self.target()
+                ))
+            } else {
+                Ok(())
+            }
+        };
+        let is_non_zero = |is_zero: bool| {
+            if is_zero {
+                Err(format!("{} must not be zero", self.target()))
+            } else {
+                Ok(())
+            }
+        };
+        match self {
+            ParameterChange::Protocol(change) => match change {
+                ProtocolParameterChange::MaxTxBytes(value) => {
+                    is_non_zero(*value == 0)
+                }
+                ProtocolParameterChange::MaxBlockGas(value)
+                | ProtocolParameterChange::GasScale(value) => {
+                    is_non_zero(*value == 0)
+                }
+                ProtocolParameterChange::MaxProposalBytes(_)
+                | ProtocolParameterChange::MaspFeePaymentGasLimit(_)
+                | ProtocolParameterChange::MinimumGasPrice { .. }
+                | ProtocolParameterChange::IsNativeTokenTransferable(_) => {
+                    Ok(())
+                }
+            },
+            ParameterChange::Pos(change) => match change {
+                PosParameterChange::MaxValidatorSlots(value)
+                | PosParameterChange::LivenessWindowCheck(value) => {
+                    is_non_zero(*value == 0)
+                }
+                PosParameterChange::MaxValidatorStakeShare(value) => {
+                    is_non_zero(value.is_zero())?;
+                    is_fraction(value)
+                }
+                PosParameterChange::BlockProposerReward(value)
+                | PosParameterChange::BlockVoteReward(value)
+                | PosParameterChange::MaxInflationRate(value)
+                | PosParameterChange::TargetStakedRatio(value)
+                | PosParameterChange::DuplicateVoteMinSlashRate(value)
+                | PosParameterChange::LightClientAttackMinSlashRate(value)
+                | PosParameterChange::LivenessThreshold(value) => {
+                    is_fraction(value)
+                }
+                PosParameterChange::CubicSlashingWindowLength(_)
+                | PosParameterChange::ValidatorStakeThreshold(_)
+                | PosParameterChange::MinSelfBond(_) => Ok(()),
+            },
+            ParameterChange::Governance(change) => match change {
+                GovernanceParameterChange::MinProposalVotingPeriod(value)
+                | GovernanceParameterChange::MaxProposalPeriod(value)
+                | GovernanceParameterChange::MaxProposalContentSize(value)
+                | GovernanceParameterChange::MaxProposalCodeSize(value)
+                | GovernanceParameterChange::MinExpeditedProposalVotingPeriod(
+                    value,
+                ) => is_non_zero(*value == 0),
+                GovernanceParameterChange::MinProposalFund(_)
+                | GovernanceParameterChange::MinExpeditedProposalFund(_)
+                | GovernanceParameterChange::MinProposalGraceEpochs(_)
+                | GovernanceParameterChange::MaxProposalLatency(_)
+                | GovernanceParameterChange::ProposalTimelockEpochs(_) => {
+                    Ok(())
+                }
+                GovernanceParameterChange::ProposalCancellationRefundRate(
+                    value,
+                )
+                | GovernanceParameterChange::VetoThreshold(value) => {
+                    is_fraction(value)
+                }
+            },
+            ParameterChange::Pgf(change) => match change {
+                PgfParameterChange::PgfInflationRate(value)
+                | PgfParameterChange::StewardsInflationRate(value) => {
+                    is_fraction(value)
+                }
+                PgfParameterChange::MaximumNumberOfStewards(_) => Ok(()),
+            },
+            ParameterChange::Ibc(_) => Ok(()),
+        }
+    }
+}
+
+impl Display for ParameterChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let target = self.target();
+        match self {
+            ParameterChange::Protocol(change) => match change {
+                ProtocolParameterChange::MaxTxBytes(value) => {
+                    write!(f, "{target} = {value}")
+                }
+                ProtocolParameterChange::MaxProposalBytes(value) => {
+                    write!(f, "{target} = {}", value.get())
+                }
+                ProtocolParameterChange::MaxBlockGas(value)
+                | ProtocolParameterChange::MaspFeePaymentGasLimit(value)
+                | ProtocolParameterChange::GasScale(value) => {
+                    write!(f, "{target} = {value}")
+                }
+                ProtocolParameterChange::MinimumGasPrice {
+                    price: Some(price),
+                    ..
+                } => write!(f, "{target} = {price}"),
+                ProtocolParameterChange::MinimumGasPrice {
+                    price: None,
+                    ..
+                } => write!(f, "{target} removed"),
+                ProtocolParameterChange::IsNativeTokenTransferable(value) => {
+                    write!(f, "{target} = {value}")
+                }
+            },
+            ParameterChange::Pos(change) => match change {
+                PosParameterChange::MaxValidatorSlots(value)
+                | PosParameterChange::CubicSlashingWindowLength(value)
+                | PosParameterChange::LivenessWindowCheck(value) => {
+                    write!(f, "{target} = {value}")
+                }
+                PosParameterChange::BlockProposerReward(value)
+                | PosParameterChange::BlockVoteReward(value)
+                | PosParameterChange::MaxInflationRate(value)
+                | PosParameterChange::TargetStakedRatio(value)
+                | PosParameterChange::DuplicateVoteMinSlashRate(value)
+                | PosParameterChange::LightClientAttackMinSlashRate(value)
+                | PosParameterChange::MaxValidatorStakeShare(value)
+                | PosParameterChange::LivenessThreshold(value) => {
+                    write!(f, "{target} = {value}")
+                }
+                PosParameterChange::ValidatorStakeThreshold(value)
+                | PosParameterChange::MinSelfBond(value) => {
+                    write!(f, "{target} = {}", value.to_string_native())
+                }
+            },
+            ParameterChange::Governance(change) => match change {
+                GovernanceParameterChange::MinProposalFund(value)
+                | GovernanceParameterChange::MinExpeditedProposalFund(value) => {
+                    write!(f, "{target} = {}", value.to_string_native())
+                }
+                GovernanceParameterChange::MaxProposalCodeSize(value)
+                | GovernanceParameterChange::MinProposalVotingPeriod(value)
+                | GovernanceParameterChange::MaxProposalPeriod(value)
+                | GovernanceParameterChange::MaxProposalContentSize(value)
+                | GovernanceParameterChange::MinProposalGraceEpochs(value)
+                | GovernanceParameterChange::MaxProposalLatency(value)
+                | GovernanceParameterChange::MinExpeditedProposalVotingPeriod(
+                    value,
+                )
+                | GovernanceParameterChange::ProposalTimelockEpochs(value) => {
+                    write!(f, "{target} = {value}")
+                }
+                GovernanceParameterChange::ProposalCancellationRefundRate(
+                    value,
+                )
+                | GovernanceParameterChange::VetoThreshold(value) => {
+                    write!(f, "{target} = {value}")
+                }
+            },
+            ParameterChange::Pgf(change) => match change {
+                PgfParameterChange::PgfInflationRate(value)
+                | PgfParameterChange::StewardsInflationRate(value) => {
+                    write!(f, "{target} = {value}")
+                }
+                PgfParameterChange::MaximumNumberOfStewards(value) => {
+                    write!(f, "{target} = {value}")
+                }
+            },
+            ParameterChange::Ibc(change) => match change {
+                IbcParameterChange::DefaultMintLimit(limit)
+                | IbcParameterChange::DefaultPerEpochThroughputLimit(limit)
+                | IbcParameterChange::MintLimit { limit, .. }
+                | IbcParameterChange::PerEpochThroughputLimit {
+                    limit, ..
+                } => write!(f, "{target} = {limit}"),
+            },
+        }
+    }
+}
+
+/// The wasm allowlist modified by a wasm allowlist proposal
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    BorshSchema,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+pub enum WasmAllowlist {
+    /// The allowlist of transaction wasm codes
+    Tx,
+    /// The allowlist of validity predicate wasm codes
+    Vp,
+}
+
+impl WasmAllowlist {
+    /// The prefix of the names of the wasm codes in this allowlist
+    pub fn name_prefix(&self) -> &'static str {
+        match self {
+            WasmAllowlist::Tx => "tx_",
+            WasmAllowlist::Vp => "vp_",
+        }
+    }
+}
+
+impl Display for WasmAllowlist {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WasmAllowlist::Tx => write!(f, "tx"),
+            WasmAllowlist::Vp => write!(f, "vp"),
+        }
+    }
+}
+
+/// A wasm code hash added to or removed from one of the wasm allowlists
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSchema,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+pub struct WasmAllowlistEntry {
+    /// The allowlist of the code
+    pub allowlist: WasmAllowlist,
+    /// The SHA-256 hash of the wasm code
+    pub code_hash: Hash,
+    /// The name under which added code is registered, e.g.
+    /// `tx_transfer.wasm`. When set, the name is pointed to the new code.
+    /// Always `None` for removals.
+    pub name: Option<String>,
+}
+
+impl WasmAllowlistEntry {
+    /// Check that the name of the entry, if any, is a valid wasm name for its
+    /// allowlist
+    pub fn validate(&self) -> Result<(), String> {
+        let Some(name) = &self.name else {
+            return Ok(());
+        };
+        let prefix = self.allowlist.name_prefix();
+        let is_valid_stem = name
+            .strip_prefix(prefix)
+            .and_then(|name| name.strip_suffix(".wasm"))
+            .is_some_and(|stem| {
+                !stem.is_empty()
+                    && stem.chars().all(|c| {
+                        c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'
+                    })
+            });
+        if is_valid_stem {
+            Ok(())
+        } else {
+            Err(format!(
+                "invalid {} wasm name {name}, expected \"{prefix}<name>.wasm\"",
+                self.allowlist
+            ))
+        }
+    }
+}
+
+impl Display for WasmAllowlistEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.name {
+            Some(name) => {
+                write!(f, "{} {} ({name})", self.allowlist, self.code_hash)
+            }
+            None => write!(f, "{} {}", self.allowlist, self.code_hash),
+        }
+    }
+}
+
+/// Validate the changes of a wasm allowlist proposal. There must be between
+/// one and [`MAX_WASM_ALLOWLIST_CHANGES`] changes, a code hash can only be
+/// changed once per allowlist, a name can only be assigned once and removals
+/// can't carry a name.
+pub fn validate_wasm_allowlist_changes<'a>(
+    changes: impl IntoIterator<Item = &'a AddRemove<WasmAllowlistEntry>>,
+) -> Result<(), String> {
+    let mut targets = BTreeSet::new();
+    let mut names = BTreeSet::new();
+    for change in changes {
+        let entry = match change {
+            AddRemove::Add(entry) => entry,
+            AddRemove::Remove(entry) => {
+                if entry.name.is_some() {
+                    return Err(format!(
+                        "the removal of {} wasm {} can't set a name",
+                        entry.allowlist, entry.code_hash
+                    ));
+                }
+                entry
+            }
+        };
+        entry.validate()?;
+        if !targets.insert((entry.allowlist, entry.code_hash)) {
+            return Err(format!(
+                "{} wasm {} changed more than once",
+                entry.allowlist, entry.code_hash
+            ));
+        }
+        if let Some(name) = &entry.name {
+            if !names.insert(name) {
+                return Err(format!(
+                    "wasm name {name} assigned more than once"
+                ));
+            }
+        }
+    }
+    if targets.is_empty() {
+        return Err("cannot be empty".to_string());
+    }
+    if targets.len() > MAX_WASM_ALLOWLIST_CHANGES {
+        return Err(format!(
+            "at most {MAX_WASM_ALLOWLIST_CHANGES} changes are allowed, got {}",
+            targets.len()
+        ));
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize, BorshDeserializer)]
 /// Proposal representation when fetched from the storage
 pub struct StorageProposal {
@@ -563,6 +1570,10 @@ pub struct StorageProposal {
     pub voting_end_epoch: Epoch,
     /// The epoch from which this changes are executed
     pub activation_epoch: Epoch,
+    /// Whether the proposal is expedited
+    pub is_expedited: bool,
+    /// Whether the proposal has been cancelled by its author
+    pub is_cancelled: bool,
 }
 
 impl StorageProposal {
@@ -572,7 +1583,9 @@ impl StorageProposal {
         current_epoch: Epoch,
         is_validator: bool,
     ) -> bool {
-        if is_validator {
+        if self.is_cancelled {
+            false
+        } else if is_validator {
             crate::utils::is_valid_validator_voting_period(
                 current_epoch,
                 self.voting_start_epoch,
@@ -585,14 +1598,28 @@ impl StorageProposal {
         }
     }
 
+    /// Check if the proposal can still be cancelled by its author, i.e. it
+    /// hasn't been cancelled yet and its voting period hasn't started
+    pub fn can_be_cancelled(&self, current_epoch: Epoch) -> bool {
+        !self.is_cancelled && current_epoch < self.voting_start_epoch
+    }
+
+    /// Return the typed content of the proposal, validated against the
+    /// content schema
+    pub fn parsed_content(&self) -> Result<ProposalContent, ContentError> {
+        ProposalContent::parse(&self.content)
+    }
+
     /// Return the type of tally for the proposal
     pub fn get_tally_type(&self, is_steward: bool) -> TallyType {
-        TallyType::from(self.r#type.clone(), is_steward)
+        TallyType::from(self.r#type.clone(), is_steward, self.is_expedited)
     }
 
     /// Return the status of a proposal
     pub fn get_status(&self, current_epoch: Epoch) -> ProposalStatus {
-        if self.voting_start_epoch > current_epoch {
+        if self.is_cancelled {
+            ProposalStatus::Cancelled
+        } else if self.voting_start_epoch > current_epoch {
             ProposalStatus::Pending
         } else if self.voting_start_epoch <= current_epoch
             && current_epoch < self.voting_end_epoch
@@ -613,6 +1640,7 @@ Content: {:?}
 Start Epoch: {}
 End Epoch: {}
 Activation Epoch: {}
+Expedited: {}
 Status: {}
 Data: {}",
             self.id,
@@ -622,6 +1650,7 @@ Data: {}",
             self.voting_start_epoch,
             self.voting_end_epoch,
             self.activation_epoch,
+            self.is_expedited,
             self.get_status(current_epoch),
             self.r#type.format_data()
         )
@@ -658,11 +1687,12 @@ impl Display for StorageProposal {
 /// Testing helpers and and strategies for governance proposals
 pub mod testing {
     use namada_core::address::testing::arb_non_internal_address;
+    use namada_core::dec::testing::arb_dec;
     use namada_core::hash::testing::arb_hash;
     use namada_core::storage::testing::arb_epoch;
     use namada_core::token::testing::arb_amount;
     use proptest::prelude::*;
-    use proptest::{collection, prop_compose};
+    use proptest::{collection, option, prop_compose};
 
     use super::*;
     use crate::storage::vote::testing::arb_proposal_vote;
@@ -743,6 +1773,65 @@ pub mod testing {
         ]
     }
 
+    /// Generate an arbitrary parameter change
+    pub fn arb_parameter_change() -> impl Strategy<Value = ParameterChange> {
+        prop_oneof![
+            any::<u64>().prop_map(|gas| ParameterChange::Protocol(
+                ProtocolParameterChange::MaxBlockGas(gas)
+            )),
+            (arb_non_internal_address(), option::of(arb_amount())).prop_map(
+                |(token, price)| ParameterChange::Protocol(
+                    ProtocolParameterChange::MinimumGasPrice { token, price }
+                )
+            ),
+            arb_dec().prop_map(|rate| ParameterChange::Pos(
+                PosParameterChange::MaxInflationRate(rate)
+            )),
+            arb_amount().prop_map(|amount| ParameterChange::Pos(
+                PosParameterChange::MinSelfBond(amount)
+            )),
+            any::<u64>().prop_map(|period| ParameterChange::Governance(
+                GovernanceParameterChange::MaxProposalPeriod(period)
+            )),
+            arb_amount().prop_map(|amount| ParameterChange::Governance(
+                GovernanceParameterChange::MinExpeditedProposalFund(amount)
+            )),
+            arb_dec().prop_map(|threshold| ParameterChange::Governance(
+                GovernanceParameterChange::VetoThreshold(threshold)
+            )),
+            arb_dec().prop_map(|rate| ParameterChange::Pgf(
+                PgfParameterChange::PgfInflationRate(rate)
+            )),
+            (arb_non_internal_address(), arb_amount()).prop_map(
+                |(token, limit)| ParameterChange::Ibc(
+                    IbcParameterChange::MintLimit { token, limit }
+                )
+            ),
+        ]
+    }
+
+    /// Generate an arbitrary wasm allowlist
+    pub fn arb_wasm_allowlist() -> impl Strategy<Value = WasmAllowlist> {
+        prop_oneof![Just(WasmAllowlist::Tx), Just(WasmAllowlist::Vp)]
+    }
+
+    prop_compose! {
+        /// Generate an arbitrary wasm allowlist entry
+        pub fn arb_wasm_allowlist_entry()(
+            allowlist in arb_wasm_allowlist(),
+            code_hash in arb_hash(),
+            name in option::of("[a-z_]{1,32}"),
+        ) -> WasmAllowlistEntry {
+            WasmAllowlistEntry {
+                allowlist,
+                code_hash,
+                name: name.map(|name| {
+                    format!("{}{name}.wasm", allowlist.name_prefix())
+                }),
+            }
+        }
+    }
+
     /// Generate an arbitrary proposal type
     pub fn arb_proposal_type() -> impl Strategy<Value = ProposalType> {
         prop_oneof![
@@ -755,6 +1844,13 @@ pub mod testing {
             .prop_map(ProposalType::PGFSteward),
             collection::btree_set(arb_pgf_action(), 0..10)
                 .prop_map(ProposalType::PGFPayment),
+            collection::btree_set(arb_parameter_change(), 0..10)
+                .prop_map(ProposalType::ParameterChange),
+            collection::btree_set(
+                arb_add_remove(arb_wasm_allowlist_entry()),
+                0..10,
+            )
+            .prop_map(ProposalType::WasmAllowlist),
         ]
     }
 
@@ -767,6 +1863,7 @@ pub mod testing {
             voting_start_epoch in arb_epoch(),
             voting_end_epoch in arb_epoch(),
             activation_epoch in arb_epoch(),
+            is_expedited in any::<bool>(),
         ) -> InitProposalData {
             InitProposalData {
                 content,
@@ -775,6 +1872,7 @@ pub mod testing {
                 voting_start_epoch,
                 voting_end_epoch,
                 activation_epoch,
+                is_expedited,
             }
         }
     }
@@ -1,6 +1,8 @@
 use std::fmt::Display;
+use std::str::FromStr;
 
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use namada_core::dec::Dec;
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
@@ -26,6 +28,69 @@ pub enum ProposalVote {
     Nay,
     /// Abstain
     Abstain,
+    /// The voting power is split between the options by weight
+    Weighted(WeightedVote),
+    /// No, and burn the proposal funds if enough of the voting power agrees.
+    /// Counts as `Nay` in the tally.
+    NoWithVeto,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    BorshSchema,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+/// A vote that splits the voting power between the options by weight, e.g.
+/// for a custodian voting on behalf of many clients. The weights must be
+/// non-negative and add up to 1.
+pub struct WeightedVote {
+    /// The weight of yes
+    pub yay: Dec,
+    /// The weight of no
+    pub nay: Dec,
+    /// The weight of abstain
+    pub abstain: Dec,
+    /// The weight of no with veto
+    pub no_with_veto: Dec,
+}
+
+impl WeightedVote {
+    /// Check that the weights are non-negative and add up to 1
+    pub fn validate(&self) -> Result<(), String> {
+        if [self.yay, self.nay, self.abstain, self.no_with_veto]
+            .iter()
+            .any(|weight| weight.is_negative())
+        {
+            return Err("weights must not be negative".to_string());
+        }
+        let total = self
+            .yay
+            .checked_add(self.nay)
+            .and_then(|total| total.checked_add(self.abstain))
+            .and_then(|total| total.checked_add(self.no_with_veto));
+        if total != Some(Dec::one()) {
+            return Err(format!("weights must add up to 1, got {self}"));
+        }
+        Ok(())
+    }
+}
+
+impl Display for WeightedVote {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "yay:{},nay:{},abstain:{},no_with_veto:{}",
+            self.yay, self.nay, self.abstain, self.no_with_veto
+        )
+    }
 }
 
 impl ProposalVote {
@@ -44,6 +109,20 @@ impl ProposalVote {
         matches!(self, ProposalVote::Abstain)
     }
 
+    /// Check if a vote is no with veto
+    pub fn is_no_with_veto(&self) -> bool {
+        matches!(self, ProposalVote::NoWithVeto)
+    }
+
+    /// Check that a weighted vote's weights are valid. Other votes are always
+    /// valid.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            ProposalVote::Weighted(weighted) => weighted.validate(),
+            _ => Ok(()),
+        }
+    }
+
     /// Check if two votes are equal, returns an error if the variants of the
     /// two instances are different
     #[allow(clippy::match_like_matches_macro)]
@@ -52,6 +131,10 @@ impl ProposalVote {
             (ProposalVote::Yay, ProposalVote::Yay) => true,
             (ProposalVote::Nay, ProposalVote::Nay) => true,
             (ProposalVote::Abstain, ProposalVote::Abstain) => true,
+            (ProposalVote::NoWithVeto, ProposalVote::NoWithVeto) => true,
+            (ProposalVote::Weighted(this), ProposalVote::Weighted(other)) => {
+                this == other
+            }
             _ => false,
         }
     }
@@ -63,6 +146,8 @@ impl Display for ProposalVote {
             ProposalVote::Yay => write!(f, "yay"),
             ProposalVote::Nay => write!(f, "nay"),
             ProposalVote::Abstain => write!(f, "abstain"),
+            ProposalVote::Weighted(weighted) => write!(f, "{weighted}"),
+            ProposalVote::NoWithVeto => write!(f, "no_with_veto"),
         }
     }
 }
@@ -70,13 +155,46 @@ impl Display for ProposalVote {
 impl TryFrom<String> for ProposalVote {
     type Error = String;
 
+    /// Parses "yay", "nay", "abstain" or "no_with_veto", or a weighted vote as
+    /// comma-separated "OPTION:WEIGHT" entries, e.g. "yay:0.7,nay:0.3". The
+    /// weight of an omitted option is 0.
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        match value.trim().to_lowercase().as_str() {
-            "yay" => Ok(ProposalVote::Yay),
-            "nay" => Ok(ProposalVote::Nay),
-            "abstain" => Ok(ProposalVote::Abstain),
-            _ => Err("invalid vote".to_string()),
+        let value = value.trim().to_lowercase();
+        match value.as_str() {
+            "yay" => return Ok(ProposalVote::Yay),
+            "nay" => return Ok(ProposalVote::Nay),
+            "abstain" => return Ok(ProposalVote::Abstain),
+            "no_with_veto" => return Ok(ProposalVote::NoWithVeto),
+            _ => {}
         }
+        if !value.contains(':') {
+            return Err("invalid vote".to_string());
+        }
+        let mut weighted = WeightedVote {
+            yay: Dec::zero(),
+            nay: Dec::zero(),
+            abstain: Dec::zero(),
+            no_with_veto: Dec::zero(),
+        };
+        for entry in value.split(',') {
+            let (option, weight) = entry.split_once(':').ok_or_else(|| {
+                format!("invalid weighted vote entry {entry}")
+            })?;
+            let weight = Dec::from_str(weight.trim())
+                .map_err(|_| format!("invalid vote weight {weight}"))?;
+            let field = match option.trim() {
+                "yay" => &mut weighted.yay,
+                "nay" => &mut weighted.nay,
+                "abstain" => &mut weighted.abstain,
+                "no_with_veto" => &mut weighted.no_with_veto,
+                option => return Err(format!("invalid vote option {option}")),
+            };
+            *field = field
+                .checked_add(weight)
+                .ok_or_else(|| "vote weight overflow".to_string())?;
+        }
+        weighted.validate()?;
+        Ok(ProposalVote::Weighted(weighted))
     }
 }
 
@@ -93,6 +211,24 @@ pub mod testing {
             Just(ProposalVote::Yay),
             Just(ProposalVote::Nay),
             Just(ProposalVote::Abstain),
+            Just(ProposalVote::NoWithVeto),
+            arb_weighted_vote().prop_map(ProposalVote::Weighted),
         ]
     }
+
+    /// Generate an arbitrary valid weighted vote
+    pub fn arb_weighted_vote() -> impl Strategy<Value = WeightedVote> {
+        (0..=100_i128)
+            .prop_flat_map(|yay| (Just(yay), 0..=(100 - yay)))
+            .prop_flat_map(|(yay, nay)| {
+                (Just(yay), Just(nay), 0..=(100 - yay - nay))
+            })
+            .prop_map(|(yay, nay, no_with_veto)| WeightedVote {
+                yay: Dec::new(yay, 2).expect("Test failed"),
+                nay: Dec::new(nay, 2).expect("Test failed"),
+                abstain: Dec::new(100 - yay - nay - no_with_veto, 2)
+                    .expect("Test failed"),
+                no_with_veto: Dec::new(no_with_veto, 2).expect("Test failed"),
+            })
+    }
 }
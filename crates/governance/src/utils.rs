@@ -1,3 +1,4 @@
+use std::collections::BTreeSet;
 use std::fmt::Display;
 use std::str::FromStr;
 
@@ -12,7 +13,9 @@ use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
 
-use super::storage::proposal::ProposalType;
+use serde::{Deserialize, Serialize};
+
+use super::storage::proposal::{ProposalType, StorageProposal};
 use super::storage::vote::ProposalVote;
 
 /// Proposal status
@@ -23,6 +26,8 @@ pub enum ProposalStatus {
     OnGoing,
     /// Ended proposal status
     Ended,
+    /// Proposal cancelled by its author before voting started
+    Cancelled,
 }
 
 impl Display for ProposalStatus {
@@ -31,6 +36,7 @@ impl Display for ProposalStatus {
             ProposalStatus::Pending => write!(f, "pending"),
             ProposalStatus::OnGoing => write!(f, "on-going"),
             ProposalStatus::Ended => write!(f, "ended"),
+            ProposalStatus::Cancelled => write!(f, "cancelled"),
         }
     }
 }
@@ -63,10 +69,42 @@ impl Vote {
     }
 }
 
+/// A vote cast on behalf of an account by its governance representative
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, BorshDeserializer)]
+pub struct RepresentedVote {
+    /// The account which delegated its voting power
+    pub delegator: Address,
+    /// The representative whose vote applies, at the end of the delegation
+    /// chain
+    pub representative: Address,
+    /// The vote of the representative
+    pub data: ProposalVote,
+}
+
+impl Display for RepresentedVote {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Voter: {} (represented by {})",
+            self.delegator, self.representative
+        )?;
+        write!(f, "Vote: {}", self.data)
+    }
+}
+
 /// Represents a tally type that describes the voting requirements for a
 /// proposal to pass.
 #[derive(
-    Copy, Debug, Clone, BorshSerialize, BorshDeserialize, BorshDeserializer,
+    Copy,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
 )]
 pub enum TallyType {
     /// The `yay` votes are at least 2/3 of the non-abstain votes, and 2/3 of
@@ -78,11 +116,22 @@ pub enum TallyType {
     /// Either less than 1/3 of the total voting power voted, or there are more
     /// `yay` votes than `nay` votes
     LessOneHalfOverOneThirdNay,
+    /// The `yay` votes are at least 2/3 of the total voting power, used for
+    /// expedited proposals
+    TwoThirdsOfTotal,
 }
 
 impl TallyType {
-    /// The type of tally used for each proposal type
-    pub fn from(proposal_type: ProposalType, is_steward: bool) -> Self {
+    /// The type of tally used for each proposal type. Expedited proposals are
+    /// always tallied with [`TallyType::TwoThirdsOfTotal`].
+    pub fn from(
+        proposal_type: ProposalType,
+        is_steward: bool,
+        is_expedited: bool,
+    ) -> Self {
+        if is_expedited {
+            return TallyType::TwoThirdsOfTotal;
+        }
         match (proposal_type, is_steward) {
             (ProposalType::Default, _) => TallyType::TwoThirds,
             (ProposalType::DefaultWithWasm(_), _) => TallyType::TwoThirds,
@@ -93,19 +142,33 @@ impl TallyType {
             (ProposalType::PGFPayment(_), false) => {
                 TallyType::OneHalfOverOneThird
             }
+            (ProposalType::ParameterChange(_), _) => TallyType::TwoThirds,
+            (ProposalType::WasmAllowlist(_), _) => TallyType::TwoThirds,
         }
     }
 }
 
 /// The result of a proposal
 #[derive(
-    Copy, Clone, Debug, BorshSerialize, BorshDeserialize, BorshDeserializer,
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
 )]
 pub enum TallyResult {
     /// Proposal was accepted with the associated value
     Passed,
     /// Proposal was rejected
     Rejected,
+    /// Proposal was rejected and its funds burned, because enough of the
+    /// voting power voted `NoWithVeto`
+    Vetoed,
 }
 
 impl Display for TallyResult {
@@ -113,6 +176,7 @@ impl Display for TallyResult {
         match self {
             TallyResult::Passed => write!(f, "passed"),
             TallyResult::Rejected => write!(f, "rejected"),
+            TallyResult::Vetoed => write!(f, "vetoed"),
         }
     }
 }
@@ -124,9 +188,10 @@ impl FromStr for TallyResult {
         match s {
             "passed" => Ok(Self::Passed),
             "rejected" => Ok(Self::Rejected),
+            "vetoed" => Ok(Self::Vetoed),
             t => Err(format!(
-                "Tally result value of {t:?} does not match \"passed\" nor \
-                 \"rejected\""
+                "Tally result value of {t:?} does not match \"passed\", \
+                 \"rejected\" nor \"vetoed\""
             )),
         }
     }
@@ -183,6 +248,10 @@ impl TallyResult {
 
                 less_than_one_third || more_than_half_voted_yay
             }
+            TallyType::TwoThirdsOfTotal => {
+                yay_voting_power
+                    >= total_voting_power.mul_ceil(Dec::two_thirds())?
+            }
         };
 
         Ok(if passed { Self::Passed } else { Self::Rejected })
@@ -199,7 +268,16 @@ impl TallyResult {
 
 /// The result with votes of a proposal
 #[derive(
-    Clone, Debug, Copy, BorshDeserialize, BorshSerialize, BorshDeserializer,
+    Clone,
+    Debug,
+    Copy,
+    PartialEq,
+    Eq,
+    BorshDeserialize,
+    BorshSerialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
 )]
 pub struct ProposalResult {
     /// The result of a proposal
@@ -210,8 +288,10 @@ pub struct ProposalResult {
     pub total_voting_power: VotePower,
     /// The total voting power from yay votes
     pub total_yay_power: VotePower,
-    /// The total voting power from nay votes
+    /// The total voting power from nay votes, including the ones with veto
     pub total_nay_power: VotePower,
+    /// The total voting power from nay votes with veto
+    pub total_veto_power: VotePower,
     /// The total voting power from abstained votes
     pub total_abstain_power: VotePower,
 }
@@ -246,7 +326,7 @@ impl ProposalResult {
 impl Display for ProposalResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let threshold = match self.tally_type {
-            TallyType::TwoThirds => {
+            TallyType::TwoThirds | TallyType::TwoThirdsOfTotal => {
                 self.total_voting_power.mul_ceil(Dec::two_thirds())
             }
             TallyType::LessOneHalfOverOneThirdNay => Ok(token::Amount::zero()),
@@ -261,12 +341,13 @@ impl Display for ProposalResult {
 
         write!(
             f,
-            "{} with {} yay votes, {} nay votes and {} abstain votes, total \
-             voting power: {}, threshold (fraction) of total voting power \
-             needed to tally: {} ({})",
+            "{} with {} yay votes, {} nay votes ({} with veto) and {} abstain \
+             votes, total voting power: {}, threshold (fraction) of total \
+             voting power needed to tally: {} ({})",
             self.result,
             self.total_yay_power.to_string_native(),
             self.total_nay_power.to_string_native(),
+            self.total_veto_power.to_string_native(),
             self.total_abstain_power.to_string_native(),
             self.total_voting_power.to_string_native(),
             threshold.to_string_native(),
@@ -275,6 +356,246 @@ impl Display for ProposalResult {
     }
 }
 
+/// The settlement of a proposal at its activation epoch: the outcome of its
+/// execution and what happened to its locked funds
+#[derive(
+    Clone,
+    Debug,
+    Copy,
+    PartialEq,
+    Eq,
+    BorshDeserialize,
+    BorshSerialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct ProposalSettlement {
+    /// The epoch at which the proposal was settled
+    pub epoch: Epoch,
+    /// Whether the execution of a passed proposal succeeded, `None` if the
+    /// proposal was not executed
+    pub execution: Option<bool>,
+    /// The locked funds refunded to the author
+    pub refunded: token::Amount,
+    /// The locked funds burned
+    pub burned: token::Amount,
+}
+
+/// The entry of a passed proposal in the timelock queue
+#[derive(
+    Clone,
+    Debug,
+    Copy,
+    PartialEq,
+    Eq,
+    BorshDeserialize,
+    BorshSerialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct ProposalTimelock {
+    /// The epoch at which the proposal passed and entered the queue
+    pub queued_epoch: Epoch,
+    /// The epoch at which the proposal is executed, unless validators veto it
+    pub execution_epoch: Epoch,
+}
+
+/// Check if the voting power of the validators which vetoed a timelocked
+/// proposal is a supermajority, i.e. at least 2/3 of the total voting power
+pub fn is_timelock_veto_supermajority(
+    veto_voting_power: VotePower,
+    total_voting_power: VotePower,
+) -> Result<bool, arith::Error> {
+    Ok(!veto_voting_power.is_zero()
+        && veto_voting_power
+            >= total_voting_power.mul_ceil(Dec::two_thirds())?)
+}
+
+/// A passed proposal waiting in the timelock queue
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    BorshDeserialize,
+    BorshSerialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct QueuedProposal {
+    /// The proposal id
+    pub id: u64,
+    /// The timelock of the proposal
+    pub timelock: ProposalTimelock,
+    /// The validators that vetoed the proposal
+    pub vetoes: BTreeSet<Address>,
+}
+
+impl Display for QueuedProposal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Proposal {} queued at epoch {}, executed at epoch {}, vetoed by \
+             {} validator(s)",
+            self.id,
+            self.timelock.queued_epoch,
+            self.timelock.execution_epoch,
+            self.vetoes.len()
+        )
+    }
+}
+
+/// The stage of a proposal in its lifecycle
+#[derive(
+    Clone,
+    Debug,
+    Copy,
+    PartialEq,
+    Eq,
+    BorshDeserialize,
+    BorshSerialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub enum ProposalStage {
+    /// The voting period hasn't started yet
+    Pending,
+    /// The proposal can be voted on
+    Voting,
+    /// The voting period has ended, the proposal is tallied at its activation
+    /// epoch
+    AwaitingTally,
+    /// The proposal was cancelled by its author
+    Cancelled,
+    /// The proposal was rejected
+    Rejected,
+    /// The proposal was rejected with a veto
+    Vetoed,
+    /// The proposal passed, but its execution was not recorded
+    Passed,
+    /// The proposal passed and waits in the timelock queue
+    Queued,
+    /// The proposal passed but was vetoed by validators in the timelock queue
+    TimelockVetoed,
+    /// The proposal passed and was executed successfully
+    Executed,
+    /// The proposal passed but its execution failed
+    ExecutionFailed,
+}
+
+impl Display for ProposalStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProposalStage::Pending => write!(f, "pending"),
+            ProposalStage::Voting => write!(f, "voting"),
+            ProposalStage::AwaitingTally => write!(f, "awaiting tally"),
+            ProposalStage::Cancelled => write!(f, "cancelled"),
+            ProposalStage::Rejected => write!(f, "rejected"),
+            ProposalStage::Vetoed => write!(f, "vetoed"),
+            ProposalStage::Passed => write!(f, "passed"),
+            ProposalStage::Queued => write!(f, "queued"),
+            ProposalStage::TimelockVetoed => write!(f, "timelock vetoed"),
+            ProposalStage::Executed => write!(f, "executed"),
+            ProposalStage::ExecutionFailed => write!(f, "execution failed"),
+        }
+    }
+}
+
+/// The full lifecycle record of a proposal
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, BorshDeserializer)]
+pub struct ProposalLifecycle {
+    /// The proposal
+    pub proposal: StorageProposal,
+    /// The current stage of the proposal
+    pub stage: ProposalStage,
+    /// The result of the tally, if the proposal was tallied
+    pub result: Option<ProposalResult>,
+    /// The settlement of the proposal, if it was settled
+    pub settlement: Option<ProposalSettlement>,
+    /// The timelock of the proposal, if it entered the timelock queue
+    pub timelock: Option<ProposalTimelock>,
+}
+
+impl ProposalLifecycle {
+    /// Build the lifecycle record of a proposal from its stored data
+    pub fn new(
+        proposal: StorageProposal,
+        result: Option<ProposalResult>,
+        settlement: Option<ProposalSettlement>,
+        timelock: Option<ProposalTimelock>,
+        current_epoch: Epoch,
+    ) -> Self {
+        let stage = if proposal.is_cancelled {
+            ProposalStage::Cancelled
+        } else if let Some(result) = &result {
+            match (result.result, settlement.map(|s| s.execution)) {
+                (TallyResult::Rejected, _) => ProposalStage::Rejected,
+                (TallyResult::Vetoed, _) => ProposalStage::Vetoed,
+                (TallyResult::Passed, None) if timelock.is_some() => {
+                    ProposalStage::Queued
+                }
+                (TallyResult::Passed, Some(None)) if timelock.is_some() => {
+                    ProposalStage::TimelockVetoed
+                }
+                (TallyResult::Passed, None | Some(None)) => {
+                    ProposalStage::Passed
+                }
+                (TallyResult::Passed, Some(Some(true))) => {
+                    ProposalStage::Executed
+                }
+                (TallyResult::Passed, Some(Some(false))) => {
+                    ProposalStage::ExecutionFailed
+                }
+            }
+        } else if current_epoch < proposal.voting_start_epoch {
+            ProposalStage::Pending
+        } else if current_epoch < proposal.voting_end_epoch {
+            ProposalStage::Voting
+        } else {
+            ProposalStage::AwaitingTally
+        };
+
+        Self {
+            proposal,
+            stage,
+            result,
+            settlement,
+            timelock,
+        }
+    }
+}
+
+impl Display for ProposalLifecycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.proposal)?;
+        write!(f, "Stage: {}", self.stage)?;
+        if let Some(result) = &self.result {
+            write!(f, "\nResult: {}", result)?;
+        }
+        if let Some(timelock) = &self.timelock {
+            write!(
+                f,
+                "\nTimelocked from epoch {} to epoch {}",
+                timelock.queued_epoch, timelock.execution_epoch
+            )?;
+        }
+        if let Some(settlement) = &self.settlement {
+            write!(
+                f,
+                "\nSettled at epoch {}: {} refunded, {} burned",
+                settlement.epoch,
+                settlement.refunded.to_string_native(),
+                settlement.burned.to_string_native()
+            )?;
+        }
+        Ok(())
+    }
+}
+
 /// Proposal structure holding votes information necessary to compute the
 /// outcome
 #[derive(Default, Debug, Clone)]
@@ -326,28 +647,46 @@ impl ProposalVotes {
     }
 }
 
-/// Compute the result of a proposal
+/// Split a voting power between yay, nay, abstain and veto according to a
+/// vote. Veto power is also counted as nay. For a weighted vote, any rounding
+/// remainder goes to abstain so that the yay, nay and abstain powers always add
+/// up to the given power.
+fn split_vote_power(
+    vote: &ProposalVote,
+    power: VotePower,
+) -> Result<[VotePower; 4], arith::Error> {
+    let zero = VotePower::zero();
+    Ok(match vote {
+        ProposalVote::Yay => [power, zero, zero, zero],
+        ProposalVote::Nay => [zero, power, zero, zero],
+        ProposalVote::Abstain => [zero, zero, power, zero],
+        ProposalVote::NoWithVeto => [zero, power, zero, power],
+        ProposalVote::Weighted(weighted) => {
+            let yay = power.mul_floor(weighted.yay)?;
+            let veto = power.mul_floor(weighted.no_with_veto)?;
+            let nay = checked!(power.mul_floor(weighted.nay)? + veto)?;
+            let abstain = checked!(power - yay - nay)?;
+            [yay, nay, abstain, veto]
+        }
+    })
+}
+
+/// Compute the result of a proposal. The proposal is vetoed if more than the
+/// `veto_threshold` fraction of the voted power voted `NoWithVeto`.
 pub fn compute_proposal_result(
     votes: ProposalVotes,
     total_voting_power: VotePower,
     tally_type: TallyType,
+    veto_threshold: Dec,
 ) -> Result<ProposalResult, arith::Error> {
     let mut yay_voting_power = VotePower::default();
     let mut nay_voting_power = VotePower::default();
     let mut abstain_voting_power = VotePower::default();
-
-    for (address, vote_power) in votes.validator_voting_power {
-        let vote_type = votes.validators_vote.get(&address);
-        if let Some(vote) = vote_type {
-            if vote.is_yay() {
-                checked!(yay_voting_power += vote_power)?;
-            } else if vote.is_nay() {
-                checked!(nay_voting_power += vote_power)?;
-            } else if vote.is_abstain() {
-                checked!(abstain_voting_power += vote_power)?;
-            }
-        }
-    }
+    let mut veto_voting_power = VotePower::default();
+    // The voting power delegated to each validator by delegators who voted
+    // differently from it and so override its vote for that power
+    let mut overridden_voting_power: HashMap<Address, VotePower> =
+        HashMap::default();
 
     for (delegator, delegations) in votes.delegator_voting_power {
         let delegator_vote = match votes.delegators_vote.get(&delegator) {
@@ -355,61 +694,58 @@ pub fn compute_proposal_result(
             None => continue,
         };
         for (validator, vote_power) in delegations {
-            let validator_vote = votes.validators_vote.get(&validator);
-            if let Some(validator_vote) = validator_vote {
-                let validator_vote_is_same_side =
-                    validator_vote.is_same_side(delegator_vote);
-
-                if !validator_vote_is_same_side {
-                    if delegator_vote.is_yay() {
-                        yay_voting_power =
-                            checked!(yay_voting_power + vote_power)?;
-                        if validator_vote.is_nay() {
-                            nay_voting_power =
-                                checked!(nay_voting_power - vote_power)?;
-                        } else if validator_vote.is_abstain() {
-                            abstain_voting_power =
-                                checked!(abstain_voting_power - vote_power)?;
-                        }
-                    } else if delegator_vote.is_nay() {
-                        nay_voting_power =
-                            checked!(nay_voting_power + vote_power)?;
-                        if validator_vote.is_yay() {
-                            yay_voting_power =
-                                checked!(yay_voting_power - vote_power)?;
-                        } else if validator_vote.is_abstain() {
-                            abstain_voting_power =
-                                checked!(abstain_voting_power - vote_power)?;
-                        }
-                    } else if delegator_vote.is_abstain() {
-                        abstain_voting_power =
-                            checked!(abstain_voting_power + vote_power)?;
-                        if validator_vote.is_yay() {
-                            yay_voting_power =
-                                checked!(yay_voting_power - vote_power)?;
-                        } else if validator_vote.is_nay() {
-                            nay_voting_power =
-                                checked!(nay_voting_power - vote_power)?;
-                        }
-                    }
+            if let Some(validator_vote) = votes.validators_vote.get(&validator)
+            {
+                if validator_vote.is_same_side(delegator_vote) {
+                    continue;
                 }
-            } else if delegator_vote.is_yay() {
-                checked!(yay_voting_power += vote_power)?;
-            } else if delegator_vote.is_nay() {
-                checked!(nay_voting_power += vote_power)?;
-            } else if delegator_vote.is_abstain() {
-                checked!(abstain_voting_power += vote_power)?;
+                let overridden = overridden_voting_power
+                    .get(&validator)
+                    .copied()
+                    .unwrap_or_default();
+                overridden_voting_power
+                    .insert(validator, checked!(overridden + vote_power)?);
             }
+            let [yay, nay, abstain, veto] =
+                split_vote_power(delegator_vote, vote_power)?;
+            checked!(yay_voting_power += yay)?;
+            checked!(nay_voting_power += nay)?;
+            checked!(abstain_voting_power += abstain)?;
+            checked!(veto_voting_power += veto)?;
         }
     }
 
-    let tally_result = TallyResult::new(
-        &tally_type,
-        yay_voting_power,
-        nay_voting_power,
-        abstain_voting_power,
-        total_voting_power,
-    )?;
+    for (address, vote_power) in votes.validator_voting_power {
+        let vote_type = votes.validators_vote.get(&address);
+        if let Some(vote) = vote_type {
+            let overridden = overridden_voting_power
+                .get(&address)
+                .copied()
+                .unwrap_or_default();
+            let [yay, nay, abstain, veto] =
+                split_vote_power(vote, checked!(vote_power - overridden)?)?;
+            checked!(yay_voting_power += yay)?;
+            checked!(nay_voting_power += nay)?;
+            checked!(abstain_voting_power += abstain)?;
+            checked!(veto_voting_power += veto)?;
+        }
+    }
+
+    let total_voted_power =
+        checked!(yay_voting_power + nay_voting_power + abstain_voting_power)?;
+    let is_vetoed = !veto_voting_power.is_zero()
+        && veto_voting_power > total_voted_power.mul_floor(veto_threshold)?;
+    let tally_result = if is_vetoed {
+        TallyResult::Vetoed
+    } else {
+        TallyResult::new(
+            &tally_type,
+            yay_voting_power,
+            nay_voting_power,
+            abstain_voting_power,
+            total_voting_power,
+        )?
+    };
 
     Ok(ProposalResult {
         result: tally_result,
@@ -417,6 +753,7 @@ pub fn compute_proposal_result(
         total_voting_power,
         total_yay_power: yay_voting_power,
         total_nay_power: nay_voting_power,
+        total_veto_power: veto_voting_power,
         total_abstain_power: abstain_voting_power,
     })
 }
@@ -454,6 +791,28 @@ mod test {
     use namada_core::address;
 
     use super::*;
+    use crate::storage::vote::WeightedVote;
+
+    #[test]
+    fn test_timelock_veto_supermajority() {
+        let total = token::Amount::from_u64(300);
+        for (veto, expected) in [(0, false), (199, false), (200, true)] {
+            assert_eq!(
+                is_timelock_veto_supermajority(
+                    token::Amount::from_u64(veto),
+                    total
+                )
+                .unwrap(),
+                expected
+            );
+        }
+        // Without any voting power, there is no veto
+        assert!(!is_timelock_veto_supermajority(
+            token::Amount::zero(),
+            token::Amount::zero()
+        )
+        .unwrap());
+    }
 
     #[test]
     fn test_proposal_result_no_votes_should_fail() {
@@ -468,6 +827,7 @@ mod test {
                 proposal_votes.clone(),
                 token::Amount::from_u64(1),
                 tally_type,
+                Dec::one_third(),
             )
             .unwrap();
             let _result = if matches!(
@@ -506,6 +866,7 @@ mod test {
                 proposal_votes.clone(),
                 validator_voting_power,
                 tally_type,
+                Dec::one_third(),
             )
             .unwrap();
             assert!(
@@ -555,6 +916,7 @@ mod test {
                 proposal_votes.clone(),
                 validator_voting_power,
                 tally_type,
+                Dec::one_third(),
             )
             .unwrap();
             assert!(
@@ -604,6 +966,7 @@ mod test {
                 proposal_votes.clone(),
                 validator_voting_power,
                 tally_type,
+                Dec::one_third(),
             )
             .unwrap();
             assert!(
@@ -665,6 +1028,7 @@ mod test {
                 proposal_votes.clone(),
                 validator_voting_power,
                 tally_type,
+                Dec::one_third(),
             )
             .unwrap();
             assert!(
@@ -733,6 +1097,7 @@ mod test {
                 proposal_votes.clone(),
                 validator_voting_power,
                 tally_type,
+                Dec::one_third(),
             )
             .unwrap();
             assert!(
@@ -792,6 +1157,7 @@ mod test {
                 proposal_votes.clone(),
                 validator_voting_power,
                 tally_type,
+                Dec::one_third(),
             )
             .unwrap();
             assert!(
@@ -849,6 +1215,7 @@ mod test {
                 proposal_votes.clone(),
                 validator_voting_power.add(validator_voting_power_two),
                 tally_type,
+                Dec::one_third(),
             )
             .unwrap();
             let _result = if matches!(
@@ -922,6 +1289,7 @@ mod test {
                 proposal_votes.clone(),
                 validator_voting_power.add(validator_voting_power_two),
                 tally_type,
+                Dec::one_third(),
             )
             .unwrap();
             let _result =
@@ -990,6 +1358,7 @@ mod test {
             proposal_votes.clone(),
             validator_voting_power.add(validator_voting_power_two),
             TallyType::TwoThirds,
+            Dec::one_third(),
         )
         .unwrap();
 
@@ -1059,6 +1428,7 @@ mod test {
             proposal_votes.clone(),
             validator_voting_power.add(validator_voting_power_two),
             TallyType::TwoThirds,
+            Dec::one_third(),
         )
         .unwrap();
 
@@ -1115,6 +1485,7 @@ mod test {
             proposal_votes.clone(),
             delegator_voting_power_two.add(delegator_voting_power),
             TallyType::TwoThirds,
+            Dec::one_third(),
         )
         .unwrap();
 
@@ -1168,6 +1539,7 @@ mod test {
             proposal_votes.clone(),
             token::Amount::from(200),
             TallyType::TwoThirds,
+            Dec::one_third(),
         )
         .unwrap();
 
@@ -1223,6 +1595,7 @@ mod test {
             proposal_votes.clone(),
             token::Amount::from(403),
             TallyType::OneHalfOverOneThird,
+            Dec::one_third(),
         )
         .unwrap();
 
@@ -1278,6 +1651,7 @@ mod test {
             proposal_votes.clone(),
             token::Amount::from(402),
             TallyType::OneHalfOverOneThird,
+            Dec::one_third(),
         )
         .unwrap();
 
@@ -1333,6 +1707,7 @@ mod test {
             proposal_votes.clone(),
             token::Amount::from(100),
             TallyType::LessOneHalfOverOneThirdNay,
+            Dec::one_third(),
         )
         .unwrap();
 
@@ -1390,6 +1765,7 @@ mod test {
             proposal_votes.clone(),
             token::Amount::from(271),
             TallyType::LessOneHalfOverOneThirdNay,
+            Dec::one_third(),
         )
         .unwrap();
 
@@ -1418,6 +1794,194 @@ mod test {
         assert!(!proposal_result.two_thirds_nay_over_two_thirds_total())
     }
 
+    #[test]
+    fn test_proposal_weighted_votes() {
+        let mut proposal_votes = ProposalVotes::default();
+
+        let validator_address = address::testing::established_address_1();
+        let validator_voting_power = token::Amount::from_u64(100);
+        proposal_votes.add_validator(
+            &validator_address,
+            validator_voting_power,
+            ProposalVote::try_from("yay:0.6,nay:0.4".to_string()).unwrap(),
+        );
+
+        let delegator_address = address::testing::established_address_2();
+        let delegator_voting_power = token::Amount::from_u64(50);
+        proposal_votes.add_delegator(
+            &delegator_address,
+            &validator_address,
+            delegator_voting_power,
+            ProposalVote::Weighted(WeightedVote {
+                yay: Dec::zero(),
+                nay: Dec::from_str("0.25").unwrap(),
+                abstain: Dec::from_str("0.75").unwrap(),
+                no_with_veto: Dec::zero(),
+            }),
+        );
+
+        let proposal_result = compute_proposal_result(
+            proposal_votes,
+            validator_voting_power,
+            TallyType::OneHalfOverOneThird,
+            Dec::one_third(),
+        )
+        .unwrap();
+
+        // The validator keeps a 60/40 split of the remaining 50 and the
+        // delegator's 50 is split 0/12/38, with the rounding going to abstain
+        assert_eq!(
+            proposal_result.total_yay_power,
+            token::Amount::from_u64(30),
+            "yay"
+        );
+        assert_eq!(
+            proposal_result.total_nay_power,
+            token::Amount::from_u64(32),
+            "nay"
+        );
+        assert_eq!(
+            proposal_result.total_abstain_power,
+            token::Amount::from_u64(38),
+            "abstain"
+        );
+    }
+
+    #[test]
+    fn test_proposal_two_thirds_of_total() {
+        let total_voting_power = token::Amount::from_u64(100);
+
+        for (yay_voting_power, expect_passed) in [(66, false), (67, true)] {
+            let mut proposal_votes = ProposalVotes::default();
+            proposal_votes.add_validator(
+                &address::testing::established_address_1(),
+                token::Amount::from_u64(yay_voting_power),
+                ProposalVote::Yay,
+            );
+
+            let proposal_result = compute_proposal_result(
+                proposal_votes,
+                total_voting_power,
+                TallyType::TwoThirdsOfTotal,
+                Dec::one_third(),
+            )
+            .unwrap();
+            assert_eq!(
+                matches!(proposal_result.result, TallyResult::Passed),
+                expect_passed,
+                "{yay_voting_power}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_proposal_no_with_veto() {
+        let total_voting_power = token::Amount::from_u64(100);
+
+        // The veto votes count as nay and veto the proposal once they are more
+        // than a third of the voted power
+        for (veto_voting_power, expected) in
+            [(30, TallyResult::Rejected), (34, TallyResult::Vetoed)]
+        {
+            let mut proposal_votes = ProposalVotes::default();
+            proposal_votes.add_validator(
+                &address::testing::established_address_1(),
+                token::Amount::from_u64(100 - veto_voting_power),
+                ProposalVote::Abstain,
+            );
+            proposal_votes.add_validator(
+                &address::testing::established_address_2(),
+                token::Amount::from_u64(veto_voting_power),
+                ProposalVote::NoWithVeto,
+            );
+
+            let proposal_result = compute_proposal_result(
+                proposal_votes,
+                total_voting_power,
+                TallyType::OneHalfOverOneThird,
+                Dec::one_third(),
+            )
+            .unwrap();
+            assert_eq!(
+                proposal_result.result.to_string(),
+                expected.to_string(),
+                "{veto_voting_power}"
+            );
+            assert_eq!(
+                proposal_result.total_nay_power,
+                token::Amount::from_u64(veto_voting_power)
+            );
+            assert_eq!(
+                proposal_result.total_veto_power,
+                token::Amount::from_u64(veto_voting_power)
+            );
+        }
+    }
+
+    #[test]
+    fn test_proposal_weighted_no_with_veto() {
+        let total_voting_power = token::Amount::from_u64(100);
+
+        // The weight of no with veto counts as nay and towards the veto
+        let mut proposal_votes = ProposalVotes::default();
+        proposal_votes.add_validator(
+            &address::testing::established_address_1(),
+            total_voting_power,
+            ProposalVote::try_from(
+                "yay:0.5,nay:0.1,no_with_veto:0.4".to_string(),
+            )
+            .unwrap(),
+        );
+
+        let proposal_result = compute_proposal_result(
+            proposal_votes,
+            total_voting_power,
+            TallyType::OneHalfOverOneThird,
+            Dec::one_third(),
+        )
+        .unwrap();
+        assert_eq!(
+            proposal_result.result.to_string(),
+            TallyResult::Vetoed.to_string()
+        );
+        assert_eq!(
+            proposal_result.total_yay_power,
+            token::Amount::from_u64(50)
+        );
+        assert_eq!(
+            proposal_result.total_nay_power,
+            token::Amount::from_u64(50)
+        );
+        assert_eq!(
+            proposal_result.total_veto_power,
+            token::Amount::from_u64(40)
+        );
+        assert!(proposal_result.total_abstain_power.is_zero());
+    }
+
+    #[test]
+    fn test_invalid_weighted_votes() {
+        for vote in [
+            "yay:0.6,nay:0.3",
+            "yay:0.5,no_with_veto:0.6",
+            "yay:0.6,nay:0.6,abstain:-0.2",
+            "yay:1,maybe:0",
+            "yay:one",
+        ] {
+            assert!(
+                ProposalVote::try_from(vote.to_string()).is_err(),
+                "{vote}"
+            );
+        }
+        let vote = ProposalVote::try_from(" Yay:0.5, Abstain:0.5 ".to_string())
+            .unwrap();
+        assert_eq!(
+            ProposalVote::try_from(vote.to_string()).unwrap(),
+            vote,
+            "weighted votes must round-trip through display"
+        );
+    }
+
     #[test]
     fn test_validator_voting_period() {
         assert!(!is_valid_validator_voting_period(
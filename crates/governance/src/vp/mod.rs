@@ -9,6 +9,7 @@ use std::marker::PhantomData;
 use borsh::BorshDeserialize;
 use namada_core::arith::{self, checked};
 use namada_core::booleans::{BoolResultUnitExt, ResultBoolExt};
+use namada_core::hash::Hash;
 use namada_core::storage;
 use namada_core::storage::Epoch;
 use namada_state::{StateRead, StorageRead};
@@ -21,9 +22,15 @@ use thiserror::Error;
 
 use self::utils::ReadType;
 use crate::address::{Address, InternalAddress};
-use crate::storage::proposal::{AddRemove, PGFAction, ProposalType};
-use crate::storage::{is_proposal_accepted, keys as gov_storage};
-use crate::utils::is_valid_validator_voting_period;
+use crate::storage::content::ProposalContent;
+use crate::storage::proposal::{
+    validate_wasm_allowlist_changes, AddRemove, PGFAction, ProposalType,
+};
+use crate::storage::{
+    get_min_expedited_proposal_fund, get_min_expedited_proposal_voting_period,
+    is_proposal_accepted, keys as gov_storage,
+};
+use crate::utils::{is_valid_validator_voting_period, ProposalTimelock};
 use crate::ProposalVote;
 
 /// for handling Governance NativeVP errors
@@ -35,6 +42,12 @@ pub const ADDRESS: Address = Address::Internal(InternalAddress::Governance);
 /// The maximum number of item in a pgf proposal
 pub const MAX_PGF_ACTIONS: usize = 20;
 
+/// The maximum number of changes in a parameter change proposal
+pub const MAX_PARAMETER_CHANGES: usize = 20;
+
+/// The maximum number of changes in a wasm allowlist proposal
+pub const MAX_WASM_ALLOWLIST_CHANGES: usize = 20;
+
 #[allow(missing_docs)]
 #[derive(Error, Debug)]
 pub enum Error {
@@ -141,6 +154,39 @@ where
                             ));
                         }
                     }
+                    GovAction::CancelProposal { id: _, author } => {
+                        if !verifiers.contains(&author) {
+                            tracing::info!(
+                                "Unauthorized GovAction::CancelProposal"
+                            );
+                            return Err(Error::Unauthorized(
+                                "CancelProposal",
+                                author,
+                            ));
+                        }
+                    }
+                    GovAction::DelegateVote { delegator } => {
+                        if !verifiers.contains(&delegator) {
+                            tracing::info!(
+                                "Unauthorized GovAction::DelegateVote"
+                            );
+                            return Err(Error::Unauthorized(
+                                "DelegateVote",
+                                delegator,
+                            ));
+                        }
+                    }
+                    GovAction::VetoProposal { id: _, validator } => {
+                        if !verifiers.contains(&validator) {
+                            tracing::info!(
+                                "Unauthorized GovAction::VetoProposal"
+                            );
+                            return Err(Error::Unauthorized(
+                                "VetoProposal",
+                                validator,
+                            ));
+                        }
+                    }
                 },
                 _ => {
                     // Other actions are not relevant to Governance VP
@@ -167,6 +213,9 @@ where
                 (KeyType::PROPOSAL_CODE, Some(proposal_id)) => {
                     self.is_valid_proposal_code(proposal_id)
                 }
+                (KeyType::PROPOSAL_WASM_CODE, Some(proposal_id)) => {
+                    self.is_valid_proposal_wasm_code(proposal_id, key)
+                }
                 (KeyType::ACTIVATION_EPOCH, Some(proposal_id)) => {
                     self.is_valid_activation_epoch(proposal_id)
                 }
@@ -182,6 +231,27 @@ where
                 (KeyType::AUTHOR, Some(proposal_id)) => {
                     self.is_valid_author(proposal_id, verifiers)
                 }
+                (KeyType::EXPEDITED, Some(proposal_id)) => {
+                    self.is_valid_expedited(proposal_id)
+                }
+                (KeyType::CANCELLED, Some(proposal_id)) => {
+                    self.is_valid_cancellation(proposal_id, verifiers)
+                }
+                (KeyType::VOTER, Some(proposal_id)) => {
+                    self.is_valid_voter_vote_key(proposal_id, key, verifiers)
+                }
+                (KeyType::TIMELOCK_VETO, Some(proposal_id)) => {
+                    self.is_valid_timelock_veto(proposal_id, key, verifiers)
+                }
+                (KeyType::REPRESENTATIVE, _) => {
+                    self.is_valid_representative(key, verifiers)
+                }
+                (KeyType::REPRESENTATIVE_HISTORY, _) => {
+                    self.is_valid_representative_history(key, verifiers)
+                }
+                (KeyType::REPRESENTED, _) => {
+                    self.is_valid_represented(key, verifiers)
+                }
                 (KeyType::COUNTER, _) => self.is_valid_counter(set_count),
                 (KeyType::PROPOSAL_COMMIT, _) => {
                     self.is_valid_proposal_commit()
@@ -313,16 +383,35 @@ where
             validator.clone(),
         );
 
-        if self
-            .force_read::<ProposalVote>(&vote_key, ReadType::Post)
-            .is_err()
-        {
+        let vote =
+            match self.force_read::<ProposalVote>(&vote_key, ReadType::Post) {
+                Ok(vote) => vote,
+                Err(_) => {
+                    return Err(native_vp::Error::new_alloc(format!(
+                        "Vote key is not valid: {key}"
+                    ))
+                    .into());
+                }
+            };
+        if let Err(msg) = vote.validate() {
             return Err(native_vp::Error::new_alloc(format!(
-                "Vote key is not valid: {key}"
+                "Invalid weighted vote for key {key}: {msg}"
             ))
             .into());
         }
 
+        let cancelled_key =
+            gov_storage::get_proposal_cancelled_key(proposal_id);
+        if self.ctx.has_key_post(&cancelled_key)? {
+            let error = native_vp::Error::new_alloc(format!(
+                "Proposal with id {proposal_id} has been cancelled and cannot \
+                 be voted on."
+            ))
+            .into();
+            tracing::info!("{error}");
+            return Err(error);
+        }
+
         // No checks for the target validators, since ultimately whether the
         // vote counts or not is determined by the validator state at the end
         // epoch
@@ -414,6 +503,16 @@ where
             tracing::info!("{error}");
             return Err(error);
         }
+
+        // Check the content against the content schema
+        if let Err(err) = ProposalContent::try_from_bytes(&post_content_bytes) {
+            let error = native_vp::Error::new_alloc(format!(
+                "Invalid content of proposal {proposal_id}: {err}"
+            ))
+            .into();
+            tracing::info!("{error}");
+            return Err(error);
+        }
         Ok(())
     }
 
@@ -578,6 +677,62 @@ where
                     .into()
                 })
             }
+            ProposalType::ParameterChange(changes) => {
+                if changes.is_empty() {
+                    return Err(native_vp::Error::new_const(
+                        "Parameter change proposal without any change",
+                    )
+                    .into());
+                }
+                if changes.len() > MAX_PARAMETER_CHANGES {
+                    return Err(native_vp::Error::new_alloc(format!(
+                        "Maximum number of parameter changes \
+                         ({MAX_PARAMETER_CHANGES}) exceeded ({})",
+                        changes.len()
+                    ))
+                    .into());
+                }
+
+                let mut targets = BTreeSet::new();
+                for change in &changes {
+                    change.validate().map_err(native_vp::Error::new_alloc)?;
+                    if !targets.insert(change.target()) {
+                        return Err(native_vp::Error::new_alloc(format!(
+                            "Parameter {} changed more than once",
+                            change.target()
+                        ))
+                        .into());
+                    }
+                }
+                Ok(())
+            }
+            ProposalType::WasmAllowlist(changes) => {
+                validate_wasm_allowlist_changes(&changes).map_err(|err| {
+                    native_vp::Error::new_alloc(format!(
+                        "Invalid wasm allowlist proposal: {err}"
+                    ))
+                })?;
+
+                // The code of every added hash must be uploaded with the
+                // proposal
+                for change in &changes {
+                    if let AddRemove::Add(entry) = change {
+                        let code_key = gov_storage::get_proposal_wasm_code_key(
+                            proposal_id,
+                            &entry.code_hash,
+                        );
+                        if !self.ctx.has_key_post(&code_key)? {
+                            return Err(native_vp::Error::new_alloc(format!(
+                                "Proposal with id {proposal_id} adds {} wasm \
+                                 {} without uploading its code.",
+                                entry.allowlist, entry.code_hash
+                            ))
+                            .into());
+                        }
+                    }
+                }
+                Ok(())
+            }
             // Default proposal condition are checked already for all other
             // proposals.
             // default_with_wasm proposal needs to check only for valid code
@@ -632,6 +787,75 @@ where
         Ok(())
     }
 
+    /// Validate the wasm code uploaded with a wasm allowlist proposal
+    pub fn is_valid_proposal_wasm_code(
+        &self,
+        proposal_id: u64,
+        key: &storage::Key,
+    ) -> Result<()> {
+        let code_hash = gov_storage::is_proposal_wasm_code_key(key).ok_or(
+            native_vp::Error::new_alloc(format!(
+                "Failed to parse a code hash from the wasm code key {key}",
+            )),
+        )?;
+
+        let proposal_type_key = gov_storage::get_proposal_type_key(proposal_id);
+        let proposal_type: ProposalType =
+            self.force_read(&proposal_type_key, ReadType::Post)?;
+        let is_added_code = match &proposal_type {
+            ProposalType::WasmAllowlist(changes) => {
+                changes.iter().any(|change| {
+                    matches!(
+                        change,
+                        AddRemove::Add(entry) if entry.code_hash == code_hash
+                    )
+                })
+            }
+            _ => false,
+        };
+        if !is_added_code {
+            return Err(native_vp::Error::new_alloc(format!(
+                "Proposal with id {proposal_id} uploaded wasm code \
+                 {code_hash} which it doesn't add to a wasm allowlist.",
+            ))
+            .into());
+        }
+
+        if self.ctx.has_key_pre(key)? {
+            return Err(native_vp::Error::new_alloc(format!(
+                "Proposal with id {proposal_id} already had wasm code \
+                 {code_hash} written to storage.",
+            ))
+            .into());
+        }
+
+        let max_code_size_parameter_key =
+            gov_storage::get_max_proposal_code_size_key();
+        let max_proposal_length: usize =
+            self.force_read(&max_code_size_parameter_key, ReadType::Pre)?;
+        let post_code: Vec<u8> = self.force_read(key, ReadType::Post)?;
+
+        if post_code.len() > max_proposal_length {
+            return Err(native_vp::Error::new_alloc(format!(
+                "Proposal with id {proposal_id} wrote wasm code {code_hash} \
+                 with length {} to storage, but the max allowed length is \
+                 {max_proposal_length}.",
+                post_code.len(),
+            ))
+            .into());
+        }
+
+        if Hash::sha256(&post_code) != code_hash {
+            return Err(native_vp::Error::new_alloc(format!(
+                "Proposal with id {proposal_id} wrote wasm code that doesn't \
+                 match its hash {code_hash}.",
+            ))
+            .into());
+        }
+
+        Ok(())
+    }
+
     /// Validate an activation_epoch key
     pub fn is_valid_activation_epoch(&self, proposal_id: u64) -> Result<()> {
         let start_epoch_key =
@@ -664,9 +888,15 @@ where
         let max_proposal_period: u64 =
             self.force_read(&max_proposal_period, ReadType::Pre)?;
 
+        // An expedited proposal is tallied as soon as its voting period ends
+        let tally_epoch = if self.is_expedited(proposal_id)? {
+            end_epoch.next()
+        } else {
+            activation_epoch
+        };
         let committing_epoch_key = gov_storage::get_committing_proposals_key(
             proposal_id,
-            activation_epoch.into(),
+            tally_epoch.into(),
         );
         let has_post_committing_epoch =
             self.ctx.has_key_post(&committing_epoch_key)?;
@@ -716,8 +946,6 @@ where
         let start_epoch_key =
             gov_storage::get_voting_start_epoch_key(proposal_id);
         let end_epoch_key = gov_storage::get_voting_end_epoch_key(proposal_id);
-        let min_period_parameter_key =
-            gov_storage::get_min_proposal_voting_period_key();
         let max_latency_paramater_key =
             gov_storage::get_max_proposal_latency_key();
 
@@ -751,8 +979,7 @@ where
             self.force_read(&start_epoch_key, ReadType::Post)?;
         let end_epoch: Epoch =
             self.force_read(&end_epoch_key, ReadType::Post)?;
-        let min_period: u64 =
-            self.force_read(&min_period_parameter_key, ReadType::Pre)?;
+        let min_period = self.min_voting_period(proposal_id)?;
 
         if end_epoch <= start_epoch {
             return Err(native_vp::Error::new_alloc(format!(
@@ -804,8 +1031,6 @@ where
         let start_epoch_key =
             gov_storage::get_voting_start_epoch_key(proposal_id);
         let end_epoch_key = gov_storage::get_voting_end_epoch_key(proposal_id);
-        let min_period_parameter_key =
-            gov_storage::get_min_proposal_voting_period_key();
         let max_period_parameter_key =
             gov_storage::get_max_proposal_period_key();
 
@@ -837,8 +1062,7 @@ where
             self.force_read(&start_epoch_key, ReadType::Post)?;
         let end_epoch: Epoch =
             self.force_read(&end_epoch_key, ReadType::Post)?;
-        let min_period: u64 =
-            self.force_read(&min_period_parameter_key, ReadType::Pre)?;
+        let min_period = self.min_voting_period(proposal_id)?;
         let max_period: u64 =
             self.force_read(&max_period_parameter_key, ReadType::Pre)?;
 
@@ -877,10 +1101,15 @@ where
         let funds_key = gov_storage::get_funds_key(proposal_id);
         let balance_key =
             TokenKeys::balance_key(native_token_address, self.ctx.address);
-        let min_funds_parameter_key = gov_storage::get_min_proposal_fund_key();
-
         let min_funds_parameter: token::Amount =
-            self.force_read(&min_funds_parameter_key, ReadType::Pre)?;
+            if self.is_expedited(proposal_id)? {
+                get_min_expedited_proposal_fund(&self.ctx.pre())?
+            } else {
+                self.force_read(
+                    &gov_storage::get_min_proposal_fund_key(),
+                    ReadType::Pre,
+                )?
+            };
         let pre_balance: Option<token::Amount> =
             self.ctx.pre().read(&balance_key)?;
         let post_balance: token::Amount =
@@ -923,21 +1152,432 @@ where
                     )))
                 })?;
 
-                let is_valid_funds = post_balance >= pre_balance
-                    && checked!(post_balance - pre_balance)
-                        .map_err(|e| Error::NativeVpError(e.into()))?
-                        == post_funds;
-                is_valid_funds.ok_or_else(|| {
-                    native_vp::Error::new_alloc(format!(
-                        "Invalid funds {} have been written to storage",
-                        post_funds.native_denominated()
-                    ))
-                    .into()
-                })
+                let is_valid_funds = post_balance >= pre_balance
+                    && checked!(post_balance - pre_balance)
+                        .map_err(|e| Error::NativeVpError(e.into()))?
+                        == post_funds;
+                is_valid_funds.ok_or_else(|| {
+                    native_vp::Error::new_alloc(format!(
+                        "Invalid funds {} have been written to storage",
+                        post_funds.native_denominated()
+                    ))
+                    .into()
+                })
+            },
+        )
+    }
+
+    /// Validate an expedited key. A proposal can only be marked as expedited
+    /// when it is initialized, and only if its type allows it.
+    fn is_valid_expedited(&self, proposal_id: u64) -> Result<()> {
+        let expedited_key =
+            gov_storage::get_proposal_expedited_key(proposal_id);
+        let start_epoch_key =
+            gov_storage::get_voting_start_epoch_key(proposal_id);
+        let proposal_type_key = gov_storage::get_proposal_type_key(proposal_id);
+
+        let is_being_initialized = !self.ctx.has_key_pre(&expedited_key)?
+            && self.ctx.has_key_post(&expedited_key)?
+            && !self.ctx.has_key_pre(&start_epoch_key)?;
+        if !is_being_initialized {
+            let error = native_vp::Error::new_alloc(format!(
+                "Proposal with id {proposal_id} can only be marked as \
+                 expedited when it is initialized."
+            ))
+            .into();
+            tracing::info!("{error}");
+            return Err(error);
+        }
+
+        let proposal_type: ProposalType =
+            self.force_read(&proposal_type_key, ReadType::Post)?;
+        proposal_type.can_be_expedited().ok_or_else(|| {
+            native_vp::Error::new_alloc(format!(
+                "Proposal with id {proposal_id} of type {proposal_type} \
+                 cannot be expedited."
+            ))
+            .into()
+        })
+    }
+
+    /// Validate a cancelled key. Only the author of an existing proposal can
+    /// cancel it, and only before its voting period starts.
+    fn is_valid_cancellation(
+        &self,
+        proposal_id: u64,
+        verifiers: &BTreeSet<Address>,
+    ) -> Result<()> {
+        let cancelled_key =
+            gov_storage::get_proposal_cancelled_key(proposal_id);
+        let start_epoch_key =
+            gov_storage::get_voting_start_epoch_key(proposal_id);
+        let author_key = gov_storage::get_author_key(proposal_id);
+
+        let is_newly_cancelled = !self.ctx.has_key_pre(&cancelled_key)?
+            && self.ctx.has_key_post(&cancelled_key)?;
+        if !is_newly_cancelled {
+            let error = native_vp::Error::new_alloc(format!(
+                "Proposal with id {proposal_id} cannot be uncancelled or \
+                 cancelled twice."
+            ))
+            .into();
+            tracing::info!("{error}");
+            return Err(error);
+        }
+
+        let current_epoch = self.ctx.get_block_epoch()?;
+        let start_epoch: Epoch =
+            self.force_read(&start_epoch_key, ReadType::Pre)?;
+        if current_epoch >= start_epoch {
+            let error = native_vp::Error::new_alloc(format!(
+                "Proposal with id {proposal_id} can only be cancelled before \
+                 its voting start epoch {start_epoch}, current epoch is \
+                 {current_epoch}."
+            ))
+            .into();
+            tracing::info!("{error}");
+            return Err(error);
+        }
+
+        let author: Address = self.force_read(&author_key, ReadType::Pre)?;
+        verifiers
+            .contains(&author)
+            .ok_or_else(|| Error::Unauthorized("CancelProposal", author))
+    }
+
+    /// Validate a validator veto on a passed proposal. The proposal must be
+    /// waiting in the timelock queue and only a validator can veto it, once.
+    fn is_valid_timelock_veto(
+        &'view self,
+        proposal_id: u64,
+        key: &storage::Key,
+        verifiers: &BTreeSet<Address>,
+    ) -> Result<()> {
+        let is_newly_vetoed =
+            !self.ctx.has_key_pre(key)? && self.ctx.has_key_post(key)?;
+        if !is_newly_vetoed {
+            let error = native_vp::Error::new_alloc(format!(
+                "A veto on the proposal with id {proposal_id} cannot be \
+                 withdrawn or cast twice."
+            ))
+            .into();
+            tracing::info!("{error}");
+            return Err(error);
+        }
+
+        let timelock_key = gov_storage::get_proposal_timelock_key(proposal_id);
+        let timelock: Option<ProposalTimelock> =
+            self.ctx.pre().read(&timelock_key)?;
+        let Some(timelock) = timelock else {
+            let error = native_vp::Error::new_alloc(format!(
+                "Proposal with id {proposal_id} is not in the timelock queue."
+            ))
+            .into();
+            tracing::info!("{error}");
+            return Err(error);
+        };
+        let queue_key = gov_storage::get_timelock_queue_key(
+            proposal_id,
+            timelock.execution_epoch.0,
+        );
+        let current_epoch = self.ctx.get_block_epoch()?;
+        if !self.ctx.has_key_pre(&queue_key)?
+            || current_epoch >= timelock.execution_epoch
+        {
+            let error = native_vp::Error::new_alloc(format!(
+                "Proposal with id {proposal_id} can only be vetoed before its \
+                 execution epoch {}, current epoch is {current_epoch}.",
+                timelock.execution_epoch
+            ))
+            .into();
+            tracing::info!("{error}");
+            return Err(error);
+        }
+
+        let validator = gov_storage::get_timelock_veto_address(key)
+            .ok_or_else(|| {
+                native_vp::Error::new_alloc(format!(
+                    "Failed to parse a validator from the veto key {key}"
+                ))
+            })?;
+        if !self.is_validator(verifiers, validator, validator)? {
+            return Err(Error::Unauthorized("VetoProposal", validator.clone()));
+        }
+        Ok(())
+    }
+
+    /// Validate the key of the vote of a voter, regardless of its delegations.
+    /// This vote applies to the accounts represented by the voter.
+    fn is_valid_voter_vote_key(
+        &'view self,
+        proposal_id: u64,
+        key: &storage::Key,
+        verifiers: &BTreeSet<Address>,
+    ) -> Result<()> {
+        let counter_key = gov_storage::get_counter_key();
+        let voting_start_epoch_key =
+            gov_storage::get_voting_start_epoch_key(proposal_id);
+        let voting_end_epoch_key =
+            gov_storage::get_voting_end_epoch_key(proposal_id);
+        let cancelled_key =
+            gov_storage::get_proposal_cancelled_key(proposal_id);
+
+        let voter = gov_storage::get_voter_vote_address(key).ok_or(
+            native_vp::Error::new_alloc(format!(
+                "Failed to parse a voter from the vote key {key}",
+            )),
+        )?;
+
+        let pre_counter: u64 = self.force_read(&counter_key, ReadType::Pre)?;
+        if pre_counter <= proposal_id {
+            let error = native_vp::Error::new_alloc(format!(
+                "Invalid proposal ID. Expected {pre_counter} or lower, got \
+                 {proposal_id}"
+            ))
+            .into();
+            tracing::info!("{error}");
+            return Err(error);
+        }
+
+        let vote: ProposalVote = self.force_read(key, ReadType::Post)?;
+        if let Err(msg) = vote.validate() {
+            return Err(native_vp::Error::new_alloc(format!(
+                "Invalid weighted vote for key {key}: {msg}"
+            ))
+            .into());
+        }
+
+        if self.ctx.has_key_post(&cancelled_key)? {
+            let error = native_vp::Error::new_alloc(format!(
+                "Proposal with id {proposal_id} has been cancelled and cannot \
+                 be voted on."
+            ))
+            .into();
+            tracing::info!("{error}");
+            return Err(error);
+        }
+
+        if !verifiers.contains(voter) {
+            return Err(Error::Unauthorized("VoteProposal", voter.clone()));
+        }
+
+        let current_epoch = self.ctx.get_block_epoch()?;
+        let pre_voting_start_epoch: Epoch =
+            self.force_read(&voting_start_epoch_key, ReadType::Pre)?;
+        let pre_voting_end_epoch: Epoch =
+            self.force_read(&voting_end_epoch_key, ReadType::Pre)?;
+        let is_validator = PoS::is_validator(&self.ctx.pre(), voter)?;
+        self.is_valid_voting_window(
+            current_epoch,
+            pre_voting_start_epoch,
+            pre_voting_end_epoch,
+            is_validator,
+        )
+        .ok_or_else(|| {
+            native_vp::Error::new_alloc(format!(
+                "Voted outside voting window. Current epoch: {current_epoch}, \
+                 start: {pre_voting_start_epoch}, end: {pre_voting_end_epoch}."
+            ))
+            .into()
+        })
+    }
+
+    /// Validate a governance representative key. Only the delegator can set
+    /// or revoke its representative, a validator can't delegate its voting
+    /// power and nobody can represent itself. The change must be recorded at
+    /// the current epoch and indexed under the new representative.
+    fn is_valid_representative(
+        &'view self,
+        key: &storage::Key,
+        verifiers: &BTreeSet<Address>,
+    ) -> Result<()> {
+        let (delegator, scope) = gov_storage::is_representative_key(key)
+            .ok_or(native_vp::Error::new_alloc(format!(
+                "Failed to parse a delegator from the representative key {key}",
+            )))?;
+
+        if !verifiers.contains(delegator) {
+            return Err(Error::Unauthorized("DelegateVote", delegator.clone()));
+        }
+
+        let representative: Option<Address> = self.ctx.post().read(key)?;
+        let current_epoch = self.ctx.get_block_epoch()?;
+        let recorded: Option<Option<Address>> = self.ctx.post().read(
+            &gov_storage::get_representative_history_key(
+                delegator,
+                scope,
+                current_epoch,
+            ),
+        )?;
+        if recorded.as_ref() != Some(&representative) {
+            let error = native_vp::Error::new_alloc(format!(
+                "The representative of {delegator} is not recorded at epoch \
+                 {current_epoch}."
+            ))
+            .into();
+            tracing::info!("{error}");
+            return Err(error);
+        }
+        let Some(representative) = representative else {
+            // Revoking a delegation is always allowed
+            return Ok(());
+        };
+
+        if !self.ctx.has_key_post(&gov_storage::get_represented_key(
+            &representative,
+            scope,
+            delegator,
+        ))? {
+            let error = native_vp::Error::new_alloc(format!(
+                "Account {delegator} is not indexed as represented by \
+                 {representative}."
+            ))
+            .into();
+            tracing::info!("{error}");
+            return Err(error);
+        }
+
+        if &representative == delegator {
+            let error = native_vp::Error::new_alloc(format!(
+                "Address {delegator} cannot be its own representative."
+            ))
+            .into();
+            tracing::info!("{error}");
+            return Err(error);
+        }
+
+        let is_validator = PoS::is_validator(&self.ctx.pre(), delegator)?;
+        (!is_validator).ok_or_else(|| {
+            let error = native_vp::Error::new_alloc(format!(
+                "Validator {delegator} votes with its own stake and cannot \
+                 delegate its voting power."
+            ))
+            .into();
+            tracing::info!("{error}");
+            error
+        })
+    }
+
+    /// Validate a key recording the governance representative of a delegator
+    /// from an epoch on. Only the delegator can change it, only at the current
+    /// epoch and only to its current representative. The past records are
+    /// needed to tally the proposals and can't be removed.
+    fn is_valid_representative_history(
+        &'view self,
+        key: &storage::Key,
+        verifiers: &BTreeSet<Address>,
+    ) -> Result<()> {
+        let (delegator, scope, epoch) =
+            gov_storage::is_representative_history_key(key).ok_or(
+                native_vp::Error::new_alloc(format!(
+                    "Failed to parse a delegator from the representative \
+                     history key {key}",
+                )),
+            )?;
+
+        if !verifiers.contains(delegator) {
+            return Err(Error::Unauthorized("DelegateVote", delegator.clone()));
+        }
+
+        let current_epoch = self.ctx.get_block_epoch()?;
+        if epoch != current_epoch {
+            let error = native_vp::Error::new_alloc(format!(
+                "The representative of {delegator} can only be recorded at \
+                 the current epoch {current_epoch}, got {epoch}."
+            ))
+            .into();
+            tracing::info!("{error}");
+            return Err(error);
+        }
+
+        let recorded: Option<Option<Address>> = self.ctx.post().read(key)?;
+        let post_representative: Option<Address> = self
+            .ctx
+            .post()
+            .read(&gov_storage::get_representative_key(delegator, scope))?;
+        (recorded == Some(post_representative)).ok_or_else(|| {
+            let error = native_vp::Error::new_alloc(format!(
+                "The recorded representative of {delegator} doesn't match its \
+                 current representative."
+            ))
+            .into();
+            tracing::info!("{error}");
+            error
+        })
+    }
+
+    /// Validate a key indexing a delegator under a governance representative
+    /// it delegated to. Only the delegator can change it, it can only be
+    /// added along with the delegation to the indexed representative, and it
+    /// can't be removed as long as the tally may need it.
+    fn is_valid_represented(
+        &'view self,
+        key: &storage::Key,
+        verifiers: &BTreeSet<Address>,
+    ) -> Result<()> {
+        let (representative, scope, delegator) =
+            gov_storage::is_represented_key(key).ok_or(
+                native_vp::Error::new_alloc(format!(
+                    "Failed to parse a delegator from the represented key \
+                     {key}",
+                )),
+            )?;
+
+        if !verifiers.contains(delegator) {
+            return Err(Error::Unauthorized("DelegateVote", delegator.clone()));
+        }
+
+        if !self.ctx.has_key_post(key)? {
+            let error = native_vp::Error::new_alloc(format!(
+                "Account {delegator} cannot be removed from the index of the \
+                 accounts represented by {representative}."
+            ))
+            .into();
+            tracing::info!("{error}");
+            return Err(error);
+        }
+        if self.ctx.has_key_pre(key)? {
+            return Ok(());
+        }
+
+        let post_representative: Option<Address> = self
+            .ctx
+            .post()
+            .read(&gov_storage::get_representative_key(delegator, scope))?;
+        (post_representative.as_ref() == Some(representative)).ok_or_else(
+            || {
+                let error = native_vp::Error::new_alloc(format!(
+                    "Account {delegator} cannot be indexed as represented by \
+                     {representative}, which is not its representative."
+                ))
+                .into();
+                tracing::info!("{error}");
+                error
             },
         )
     }
 
+    /// Check if a proposal is expedited in the posterior state
+    fn is_expedited(&self, proposal_id: u64) -> Result<bool> {
+        Ok(self
+            .ctx
+            .has_key_post(&gov_storage::get_proposal_expedited_key(
+                proposal_id,
+            ))?)
+    }
+
+    /// Get the minimum voting period that applies to a proposal
+    fn min_voting_period(&self, proposal_id: u64) -> Result<u64> {
+        if self.is_expedited(proposal_id)? {
+            Ok(get_min_expedited_proposal_voting_period(&self.ctx.pre())?)
+        } else {
+            self.force_read(
+                &gov_storage::get_min_proposal_voting_period_key(),
+                ReadType::Pre,
+            )
+        }
+    }
+
     /// Validate a balance key
     fn is_valid_balance(&self, native_token_address: &Address) -> Result<()> {
         let balance_key =
@@ -1160,6 +1800,8 @@ enum KeyType {
     #[allow(non_camel_case_types)]
     PROPOSAL_CODE,
     #[allow(non_camel_case_types)]
+    PROPOSAL_WASM_CODE,
+    #[allow(non_camel_case_types)]
     TYPE,
     #[allow(non_camel_case_types)]
     PROPOSAL_COMMIT,
@@ -1176,8 +1818,22 @@ enum KeyType {
     #[allow(non_camel_case_types)]
     AUTHOR,
     #[allow(non_camel_case_types)]
+    EXPEDITED,
+    #[allow(non_camel_case_types)]
+    CANCELLED,
+    #[allow(non_camel_case_types)]
+    VOTER,
+    #[allow(non_camel_case_types)]
+    REPRESENTATIVE,
+    #[allow(non_camel_case_types)]
+    TIMELOCK_VETO,
+    #[allow(non_camel_case_types)]
     PARAMETER,
     #[allow(non_camel_case_types)]
+    REPRESENTATIVE_HISTORY,
+    #[allow(non_camel_case_types)]
+    REPRESENTED,
+    #[allow(non_camel_case_types)]
     UNKNOWN_GOVERNANCE,
     #[allow(non_camel_case_types)]
     UNKNOWN,
@@ -1196,6 +1852,8 @@ impl KeyType {
             Self::TYPE
         } else if gov_storage::is_proposal_code_key(key) {
             Self::PROPOSAL_CODE
+        } else if gov_storage::is_proposal_wasm_code_key(key).is_some() {
+            Self::PROPOSAL_WASM_CODE
         } else if gov_storage::is_activation_epoch_key(key) {
             KeyType::ACTIVATION_EPOCH
         } else if gov_storage::is_start_epoch_key(key) {
@@ -1208,6 +1866,20 @@ impl KeyType {
             KeyType::FUNDS
         } else if gov_storage::is_author_key(key) {
             KeyType::AUTHOR
+        } else if gov_storage::is_proposal_expedited_key(key) {
+            KeyType::EXPEDITED
+        } else if gov_storage::is_proposal_cancelled_key(key) {
+            KeyType::CANCELLED
+        } else if gov_storage::is_voter_vote_key(key) {
+            KeyType::VOTER
+        } else if gov_storage::is_timelock_veto_key(key) {
+            KeyType::TIMELOCK_VETO
+        } else if gov_storage::is_representative_key(key).is_some() {
+            KeyType::REPRESENTATIVE
+        } else if gov_storage::is_representative_history_key(key).is_some() {
+            KeyType::REPRESENTATIVE_HISTORY
+        } else if gov_storage::is_represented_key(key).is_some() {
+            KeyType::REPRESENTED
         } else if gov_storage::is_counter_key(key) {
             KeyType::COUNTER
         } else if gov_storage::is_parameter_key(key) {
@@ -1226,7 +1898,7 @@ impl KeyType {
 #[cfg(test)]
 mod test {
     use std::cell::RefCell;
-    use std::collections::BTreeSet;
+    use std::collections::{BTreeMap, BTreeSet};
 
     use assert_matches::assert_matches;
     use namada_core::address::testing::{
@@ -1234,6 +1906,7 @@ mod test {
     };
     use namada_core::address::Address;
     use namada_core::borsh::BorshSerializeExt;
+    use namada_core::hash::Hash;
     use namada_core::key::testing::keypair_1;
     use namada_core::key::RefTo;
     use namada_core::parameters::Parameters;
@@ -1258,13 +1931,25 @@ mod test {
     use namada_vm::{wasm, WasmCacheRwAccess};
     use namada_vp::native_vp::{Ctx, CtxPreStorageRead, NativeVp};
 
+    use crate::parameters::GovernanceParameters;
     use crate::storage::keys::{
         get_activation_epoch_key, get_author_key, get_committing_proposals_key,
-        get_content_key, get_counter_key, get_funds_key, get_proposal_type_key,
-        get_vote_proposal_key, get_voting_end_epoch_key,
+        get_content_key, get_counter_key, get_funds_key,
+        get_proposal_cancelled_key, get_proposal_expedited_key,
+        get_proposal_timelock_key, get_proposal_type_key,
+        get_proposal_wasm_code_key, get_representative_history_key,
+        get_representative_key, get_represented_key, get_timelock_queue_key,
+        get_timelock_veto_key, get_vote_proposal_key, get_voting_end_epoch_key,
         get_voting_start_epoch_key,
     };
-    use crate::{ProposalType, ProposalVote, ADDRESS};
+    use crate::storage::proposal::{
+        AddRemove, WasmAllowlist, WasmAllowlistEntry,
+    };
+    use crate::utils::ProposalTimelock;
+    use crate::{
+        ProposalContent, ProposalType, ProposalVote, RepresentationScope,
+        ADDRESS,
+    };
 
     type CA = WasmCacheRwAccess;
     type Eval<S> = VpEvalWasm<<S as StateRead>::D, <S as StateRead>::H, CA>;
@@ -1506,7 +2191,12 @@ mod test {
             .unwrap();
         state
             .write_log_mut()
-            .write(&content_key, vec![1, 2, 3, 4])
+            .write(
+                &content_key,
+                ProposalContent::new("TheTitle", "An abstract")
+                    .to_map()
+                    .serialize_to_vec(),
+            )
             .unwrap();
         state
             .write_log_mut()
@@ -1725,6 +2415,308 @@ mod test {
         assert_eq!(counter, 0);
     }
 
+    #[test]
+    fn test_governance_proposal_invalid_content_failed() {
+        let mut state = init_storage();
+
+        let proposal_id = 0;
+        let activation_epoch = 19;
+
+        let keys_changed = get_proposal_keys(proposal_id, activation_epoch);
+
+        let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+            &TxGasMeter::new(u64::MAX),
+        ));
+        let (vp_wasm_cache, _vp_cache_dir) =
+            wasm::compilation_cache::common::testing::vp_cache();
+
+        let tx_index = TxIndex::default();
+
+        let signer = keypair_1();
+        let signer_address = Address::from(&signer.clone().ref_to());
+        let verifiers = BTreeSet::from([signer_address.clone()]);
+
+        initialize_account_balance(
+            &mut state,
+            &signer_address.clone(),
+            token::Amount::native_whole(510),
+        );
+        initialize_account_balance(
+            &mut state,
+            &ADDRESS,
+            token::Amount::native_whole(0),
+        );
+        state.commit_block().unwrap();
+
+        let tx_code = vec![];
+        let tx_data = vec![];
+
+        let mut tx = Tx::from_type(TxType::Raw);
+        tx.header.chain_id = state.in_mem().chain_id.clone();
+        tx.set_code(Code::new(tx_code, None));
+        tx.set_data(Data::new(tx_data));
+        tx.add_section(Section::Authorization(Authorization::new(
+            vec![tx.header_hash()],
+            [(0, keypair_1())].into_iter().collect(),
+            None,
+        )));
+
+        init_proposal(
+            &mut state,
+            proposal_id,
+            500,
+            3,
+            9,
+            19,
+            &signer_address,
+            false,
+        );
+
+        // Overwrite the content with a map missing the required abstract
+        let content =
+            BTreeMap::from([("title".to_string(), "TheTitle".to_string())]);
+        state
+            .write_log_mut()
+            .write(&get_content_key(proposal_id), content.serialize_to_vec())
+            .unwrap();
+
+        let batched_tx = tx.batch_ref_first_tx().unwrap();
+        let ctx = Ctx::new(
+            &ADDRESS,
+            &state,
+            batched_tx.tx,
+            batched_tx.cmt,
+            &tx_index,
+            &gas_meter,
+            &keys_changed,
+            &verifiers,
+            vp_wasm_cache,
+        );
+
+        let governance_vp = GovernanceVp::new(ctx);
+        assert_matches!(
+            governance_vp.validate_tx(&batched_tx, &keys_changed, &verifiers),
+            Err(_)
+        );
+    }
+
+    #[test]
+    fn test_governance_wasm_allowlist_proposal() {
+        let proposal_id = 0;
+        let activation_epoch = 19;
+        let max_code_size = usize::try_from(
+            GovernanceParameters::default().max_proposal_code_size,
+        )
+        .unwrap();
+        let code = vec![1_u8; 100];
+        let too_big_code = vec![1_u8; max_code_size + 1];
+
+        // The code added to the allowlist, the code uploaded with the proposal
+        // and whether the proposal is valid
+        let cases = [
+            (code.clone(), Some(code.clone()), true),
+            (code.clone(), Some(vec![2_u8; 100]), false),
+            (code, None, false),
+            (too_big_code.clone(), Some(too_big_code), false),
+        ];
+        for (added_code, uploaded_code, is_valid) in cases {
+            let mut state = init_storage();
+            let mut keys_changed =
+                get_proposal_keys(proposal_id, activation_epoch);
+
+            let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+                &TxGasMeter::new(u64::MAX),
+            ));
+            let (vp_wasm_cache, _vp_cache_dir) =
+                wasm::compilation_cache::common::testing::vp_cache();
+
+            let tx_index = TxIndex::default();
+
+            let signer = keypair_1();
+            let signer_address = Address::from(&signer.clone().ref_to());
+            let verifiers = BTreeSet::from([signer_address.clone()]);
+
+            initialize_account_balance(
+                &mut state,
+                &signer_address.clone(),
+                token::Amount::native_whole(510),
+            );
+            initialize_account_balance(
+                &mut state,
+                &ADDRESS,
+                token::Amount::native_whole(0),
+            );
+            state.commit_block().unwrap();
+
+            let mut tx = Tx::from_type(TxType::Raw);
+            tx.header.chain_id = state.in_mem().chain_id.clone();
+            tx.set_code(Code::new(vec![], None));
+            tx.set_data(Data::new(vec![]));
+            tx.add_section(Section::Authorization(Authorization::new(
+                vec![tx.header_hash()],
+                [(0, keypair_1())].into_iter().collect(),
+                None,
+            )));
+
+            init_proposal(
+                &mut state,
+                proposal_id,
+                500,
+                3,
+                9,
+                19,
+                &signer_address,
+                false,
+            );
+
+            let code_hash = Hash::sha256(&added_code);
+            let proposal_type =
+                ProposalType::WasmAllowlist(BTreeSet::from([AddRemove::Add(
+                    WasmAllowlistEntry {
+                        allowlist: WasmAllowlist::Tx,
+                        code_hash,
+                        name: Some("tx_transfer.wasm".to_string()),
+                    },
+                )]));
+            state
+                .write_log_mut()
+                .write(
+                    &get_proposal_type_key(proposal_id),
+                    proposal_type.serialize_to_vec(),
+                )
+                .unwrap();
+            if let Some(uploaded_code) = uploaded_code {
+                let code_key =
+                    get_proposal_wasm_code_key(proposal_id, &code_hash);
+                state
+                    .write_log_mut()
+                    .write(&code_key, uploaded_code.serialize_to_vec())
+                    .unwrap();
+                keys_changed.insert(code_key);
+            }
+
+            let batched_tx = tx.batch_ref_first_tx().unwrap();
+            let ctx = Ctx::new(
+                &ADDRESS,
+                &state,
+                batched_tx.tx,
+                batched_tx.cmt,
+                &tx_index,
+                &gas_meter,
+                &keys_changed,
+                &verifiers,
+                vp_wasm_cache,
+            );
+
+            let governance_vp = GovernanceVp::new(ctx);
+            assert_eq!(
+                governance_vp
+                    .validate_tx(&batched_tx, &keys_changed, &verifiers)
+                    .is_ok(),
+                is_valid
+            );
+        }
+    }
+
+    #[test]
+    fn test_governance_expedited_proposal() {
+        // An expedited proposal can have a shorter voting period, but must
+        // lock the larger expedited minimum funds and be tallied right after
+        // its voting period ends
+        for (funds, tally_epoch, expect_accepted) in
+            [(2_499, 5, false), (2_500, 5, true), (2_500, 19, false)]
+        {
+            let mut state = init_storage();
+
+            let proposal_id = 0;
+            let activation_epoch = 19;
+
+            let expedited_key = get_proposal_expedited_key(proposal_id);
+            let committing_key =
+                get_committing_proposals_key(proposal_id, tally_epoch);
+            let mut keys_changed = get_proposal_keys(proposal_id, tally_epoch);
+            keys_changed.insert(expedited_key.clone());
+
+            let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+                &TxGasMeter::new(u64::MAX),
+            ));
+            let (vp_wasm_cache, _vp_cache_dir) =
+                wasm::compilation_cache::common::testing::vp_cache();
+
+            let tx_index = TxIndex::default();
+
+            let signer = keypair_1();
+            let signer_address = Address::from(&signer.clone().ref_to());
+            let verifiers = BTreeSet::from([signer_address.clone()]);
+
+            initialize_account_balance(
+                &mut state,
+                &signer_address.clone(),
+                token::Amount::native_whole(3_000),
+            );
+            initialize_account_balance(
+                &mut state,
+                &ADDRESS,
+                token::Amount::native_whole(0),
+            );
+            state.commit_block().unwrap();
+
+            let mut tx = Tx::from_type(TxType::Raw);
+            tx.header.chain_id = state.in_mem().chain_id.clone();
+            tx.set_code(Code::new(vec![], None));
+            tx.set_data(Data::new(vec![]));
+            tx.add_section(Section::Authorization(Authorization::new(
+                vec![tx.header_hash()],
+                [(0, keypair_1())].into_iter().collect(),
+                None,
+            )));
+
+            init_proposal(
+                &mut state,
+                proposal_id,
+                funds,
+                3,
+                4,
+                activation_epoch,
+                &signer_address,
+                true,
+            );
+            state
+                .write_log_mut()
+                .write(&expedited_key, ().serialize_to_vec())
+                .unwrap();
+            state
+                .write_log_mut()
+                .write(&committing_key, ().serialize_to_vec())
+                .unwrap();
+
+            let batched_tx = tx.batch_ref_first_tx().unwrap();
+            let ctx = Ctx::new(
+                &ADDRESS,
+                &state,
+                batched_tx.tx,
+                batched_tx.cmt,
+                &tx_index,
+                &gas_meter,
+                &keys_changed,
+                &verifiers,
+                vp_wasm_cache,
+            );
+
+            let governance_vp = GovernanceVp::new(ctx);
+            let result = governance_vp.validate_tx(
+                &batched_tx,
+                &keys_changed,
+                &verifiers,
+            );
+            assert_eq!(
+                result.is_ok(),
+                expect_accepted,
+                "{funds}, {tally_epoch}: {result:?}"
+            );
+        }
+    }
+
     #[test]
     fn test_governance_proposal_more_funds_accepted() {
         let mut state = init_storage();
@@ -2378,6 +3370,359 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_governance_cancel_proposal() {
+        // A proposal can only be cancelled by its author, before its voting
+        // period starts
+        for (epoch, by_author, expect_accepted) in
+            [(1, true, true), (1, false, false), (3, true, false)]
+        {
+            let mut state = init_storage();
+
+            let proposal_id = 0;
+            let activation_epoch = 19;
+
+            let keys_changed = get_proposal_keys(proposal_id, activation_epoch);
+
+            let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+                &TxGasMeter::new(u64::MAX),
+            ));
+            let (vp_wasm_cache, _vp_cache_dir) =
+                wasm::compilation_cache::common::testing::vp_cache();
+
+            let tx_index = TxIndex::default();
+
+            let signer = keypair_1();
+            let signer_address = Address::from(&signer.clone().ref_to());
+            let verifiers = BTreeSet::from([signer_address.clone()]);
+
+            initialize_account_balance(
+                &mut state,
+                &signer_address.clone(),
+                token::Amount::native_whole(510),
+            );
+            initialize_account_balance(
+                &mut state,
+                &ADDRESS,
+                token::Amount::native_whole(0),
+            );
+            state.commit_block().unwrap();
+
+            let mut tx = Tx::from_type(TxType::Raw);
+            tx.header.chain_id = state.in_mem().chain_id.clone();
+            tx.set_code(Code::new(vec![], None));
+            tx.set_data(Data::new(vec![]));
+            tx.add_section(Section::Authorization(Authorization::new(
+                vec![tx.header_hash()],
+                [(0, keypair_1())].into_iter().collect(),
+                None,
+            )));
+
+            init_proposal(
+                &mut state,
+                proposal_id,
+                500,
+                3,
+                9,
+                19,
+                &signer_address,
+                false,
+            );
+
+            let batched_tx = tx.batch_ref_first_tx().unwrap();
+            let ctx = Ctx::new(
+                &ADDRESS,
+                &state,
+                batched_tx.tx,
+                batched_tx.cmt,
+                &tx_index,
+                &gas_meter,
+                &keys_changed,
+                &verifiers,
+                vp_wasm_cache.clone(),
+            );
+            let governance_vp = GovernanceVp::new(ctx);
+            assert_matches!(
+                governance_vp.validate_tx(
+                    &batched_tx,
+                    &keys_changed,
+                    &verifiers
+                ),
+                Ok(_)
+            );
+
+            state.write_log_mut().commit_batch();
+            state.commit_block().unwrap();
+
+            let height = state.in_mem().get_block_height().0 + (epoch * 2);
+            update_epoch_to(&mut state, epoch, height);
+
+            let author = if by_author {
+                signer_address.clone()
+            } else {
+                established_address_1()
+            };
+            let cancelled_key = get_proposal_cancelled_key(proposal_id);
+            state
+                .push_action(Action::Gov(GovAction::CancelProposal {
+                    id: proposal_id,
+                    author: author.clone(),
+                }))
+                .unwrap();
+            state
+                .write_log_mut()
+                .write(&cancelled_key, ().serialize_to_vec())
+                .unwrap();
+
+            let keys_changed = BTreeSet::from([cancelled_key]);
+            let verifiers = BTreeSet::from([author]);
+
+            let batched_tx = tx.batch_ref_first_tx().unwrap();
+            let ctx = Ctx::new(
+                &ADDRESS,
+                &state,
+                batched_tx.tx,
+                batched_tx.cmt,
+                &tx_index,
+                &gas_meter,
+                &keys_changed,
+                &verifiers,
+                vp_wasm_cache,
+            );
+            let governance_vp = GovernanceVp::new(ctx);
+            let result = governance_vp.validate_tx(
+                &batched_tx,
+                &keys_changed,
+                &verifiers,
+            );
+            assert_eq!(
+                result.is_ok(),
+                expect_accepted,
+                "{epoch}, {by_author}: {result:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_governance_delegate_vote() {
+        let signer = keypair_1();
+        let delegator = Address::from(&signer.ref_to());
+        // An account can delegate to another address or revoke its
+        // delegation, but not represent itself, delegate on behalf of
+        // someone else, or leave its delegation out of the index of the
+        // represented accounts or out of the record of its representatives
+        for (representative, authorized, indexed, recorded, expect_accepted) in [
+            (Some(established_address_3()), true, true, true, true),
+            (None, true, true, true, true),
+            (Some(delegator.clone()), true, true, true, false),
+            (Some(established_address_3()), false, true, true, false),
+            (Some(established_address_3()), true, false, true, false),
+            (Some(established_address_3()), true, true, false, false),
+            (None, true, true, false, false),
+        ] {
+            let mut state = init_storage();
+
+            let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+                &TxGasMeter::new(u64::MAX),
+            ));
+            let (vp_wasm_cache, _vp_cache_dir) =
+                wasm::compilation_cache::common::testing::vp_cache();
+
+            let tx_index = TxIndex::default();
+
+            let mut tx = Tx::from_type(TxType::Raw);
+            tx.header.chain_id = state.in_mem().chain_id.clone();
+            tx.set_code(Code::new(vec![], None));
+            tx.set_data(Data::new(vec![]));
+            tx.add_section(Section::Authorization(Authorization::new(
+                vec![tx.header_hash()],
+                [(0, keypair_1())].into_iter().collect(),
+                None,
+            )));
+
+            let representative_key =
+                get_representative_key(&delegator, RepresentationScope::All);
+            state
+                .push_action(Action::Gov(GovAction::DelegateVote {
+                    delegator: delegator.clone(),
+                }))
+                .unwrap();
+            let mut keys_changed = BTreeSet::from([representative_key.clone()]);
+            if recorded {
+                let history_key = get_representative_history_key(
+                    &delegator,
+                    RepresentationScope::All,
+                    state.get_block_epoch().unwrap(),
+                );
+                state
+                    .write_log_mut()
+                    .write(&history_key, representative.serialize_to_vec())
+                    .unwrap();
+                keys_changed.insert(history_key);
+            }
+            match &representative {
+                Some(representative) => {
+                    state
+                        .write_log_mut()
+                        .write(
+                            &representative_key,
+                            representative.serialize_to_vec(),
+                        )
+                        .unwrap();
+                    if indexed {
+                        let represented_key = get_represented_key(
+                            representative,
+                            RepresentationScope::All,
+                            &delegator,
+                        );
+                        state
+                            .write_log_mut()
+                            .write(&represented_key, ().serialize_to_vec())
+                            .unwrap();
+                        keys_changed.insert(represented_key);
+                    }
+                }
+                None => {
+                    state.write_log_mut().delete(&representative_key).unwrap();
+                }
+            }
+
+            let verifiers = if authorized {
+                BTreeSet::from([delegator.clone()])
+            } else {
+                BTreeSet::new()
+            };
+
+            let batched_tx = tx.batch_ref_first_tx().unwrap();
+            let ctx = Ctx::new(
+                &ADDRESS,
+                &state,
+                batched_tx.tx,
+                batched_tx.cmt,
+                &tx_index,
+                &gas_meter,
+                &keys_changed,
+                &verifiers,
+                vp_wasm_cache,
+            );
+            let governance_vp = GovernanceVp::new(ctx);
+            let result = governance_vp.validate_tx(
+                &batched_tx,
+                &keys_changed,
+                &verifiers,
+            );
+            assert_eq!(
+                result.is_ok(),
+                expect_accepted,
+                "{representative:?}, {authorized}, {indexed}, {recorded}: \
+                 {result:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_governance_timelock_veto() {
+        let signer = keypair_1();
+        let non_validator = Address::from(&signer.ref_to());
+        // Only a validator can veto a queued proposal, before its execution
+        // epoch
+        for (epoch, by_validator, expect_accepted) in
+            [(1, true, true), (1, false, false), (3, true, false)]
+        {
+            let mut state = init_storage();
+
+            let proposal_id = 0;
+            let timelock = ProposalTimelock {
+                queued_epoch: Epoch(0),
+                execution_epoch: Epoch(3),
+            };
+            state
+                .write_log_mut()
+                .write(
+                    &get_proposal_timelock_key(proposal_id),
+                    timelock.serialize_to_vec(),
+                )
+                .unwrap();
+            state
+                .write_log_mut()
+                .write(
+                    &get_timelock_queue_key(
+                        proposal_id,
+                        timelock.execution_epoch.0,
+                    ),
+                    ().serialize_to_vec(),
+                )
+                .unwrap();
+            state.write_log_mut().commit_batch();
+            state.commit_block().unwrap();
+
+            let height = state.in_mem().get_block_height().0 + (epoch * 2);
+            update_epoch_to(&mut state, epoch, height);
+
+            let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+                &TxGasMeter::new(u64::MAX),
+            ));
+            let (vp_wasm_cache, _vp_cache_dir) =
+                wasm::compilation_cache::common::testing::vp_cache();
+
+            let tx_index = TxIndex::default();
+
+            let mut tx = Tx::from_type(TxType::Raw);
+            tx.header.chain_id = state.in_mem().chain_id.clone();
+            tx.set_code(Code::new(vec![], None));
+            tx.set_data(Data::new(vec![]));
+            tx.add_section(Section::Authorization(Authorization::new(
+                vec![tx.header_hash()],
+                [(0, keypair_1())].into_iter().collect(),
+                None,
+            )));
+
+            let validator = if by_validator {
+                established_address_1()
+            } else {
+                non_validator.clone()
+            };
+            let veto_key = get_timelock_veto_key(proposal_id, &validator);
+            state
+                .push_action(Action::Gov(GovAction::VetoProposal {
+                    id: proposal_id,
+                    validator: validator.clone(),
+                }))
+                .unwrap();
+            state
+                .write_log_mut()
+                .write(&veto_key, ().serialize_to_vec())
+                .unwrap();
+
+            let keys_changed = BTreeSet::from([veto_key]);
+            let verifiers = BTreeSet::from([validator]);
+
+            let batched_tx = tx.batch_ref_first_tx().unwrap();
+            let ctx = Ctx::new(
+                &ADDRESS,
+                &state,
+                batched_tx.tx,
+                batched_tx.cmt,
+                &tx_index,
+                &gas_meter,
+                &keys_changed,
+                &verifiers,
+                vp_wasm_cache,
+            );
+            let governance_vp = GovernanceVp::new(ctx);
+            let result = governance_vp.validate_tx(
+                &batched_tx,
+                &keys_changed,
+                &verifiers,
+            );
+            assert_eq!(
+                result.is_ok(),
+                expect_accepted,
+                "{epoch}, {by_validator}: {result:?}"
+            );
+        }
+    }
+
     #[test]
     fn test_goverance_vote_validator_out_of_voting_window_fail() {
         let mut state = init_storage();
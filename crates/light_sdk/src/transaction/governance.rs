@@ -1,5 +1,5 @@
 use namada_sdk::address::Address;
-use namada_sdk::governance::{ProposalType, ProposalVote};
+use namada_sdk::governance::{ProposalType, ProposalVote, RepresentationScope};
 use namada_sdk::hash::Hash;
 use namada_sdk::key::common;
 use namada_sdk::storage::Epoch;
@@ -12,6 +12,9 @@ use crate::transaction;
 
 const TX_INIT_PROPOSAL_WASM: &str = "tx_init_proposal.wasm";
 const TX_VOTE_PROPOSAL: &str = "tx_vote_proposal.wasm";
+const TX_CANCEL_PROPOSAL: &str = "tx_cancel_proposal.wasm";
+const TX_VETO_PROPOSAL: &str = "tx_veto_proposal.wasm";
+const TX_DELEGATE_VOTE: &str = "tx_delegate_vote.wasm";
 
 /// Transaction to initialize a governance proposal
 #[derive(Debug, Clone)]
@@ -27,6 +30,7 @@ impl InitProposal {
         voting_start_epoch: Epoch,
         voting_end_epoch: Epoch,
         activation_epoch: Epoch,
+        is_expedited: bool,
         args: GlobalArgs,
     ) -> Self {
         let init_proposal = namada_sdk::governance::InitProposalData {
@@ -36,6 +40,7 @@ impl InitProposal {
             voting_start_epoch,
             voting_end_epoch,
             activation_epoch,
+            is_expedited,
         };
 
         Self(transaction::build_tx(
@@ -174,3 +179,231 @@ impl VoteProposal {
         self.0.validate_tx()
     }
 }
+
+/// Transaction to cancel a governance proposal
+pub struct CancelProposal(Tx);
+
+impl CancelProposal {
+    /// Build a raw CancelProposal transaction from the given parameters
+    pub fn new(id: u64, author: Address, args: GlobalArgs) -> Self {
+        let cancel_proposal =
+            namada_sdk::governance::CancelProposalData { id, author };
+
+        Self(transaction::build_tx(
+            args,
+            cancel_proposal,
+            TX_CANCEL_PROPOSAL.to_string(),
+        ))
+    }
+
+    /// Get the bytes to sign for the given transaction
+    pub fn get_sign_bytes(&self) -> Vec<Hash> {
+        transaction::get_sign_bytes(&self.0)
+    }
+
+    /// Attach the provided signatures to the tx
+    pub fn attach_signatures(
+        self,
+        signer: common::PublicKey,
+        signature: common::Signature,
+    ) -> Self {
+        Self(transaction::attach_raw_signatures(
+            self.0, signer, signature,
+        ))
+    }
+
+    /// Attach the fee data to the tx
+    pub fn attach_fee(
+        self,
+        fee: DenominatedAmount,
+        token: Address,
+        fee_payer: common::PublicKey,
+        gas_limit: GasLimit,
+    ) -> Self {
+        Self(attach_fee(self.0, fee, token, fee_payer, gas_limit))
+    }
+
+    /// Get the bytes of the fee data to sign
+    pub fn get_fee_sig_bytes(&self) -> Hash {
+        transaction::get_wrapper_sign_bytes(&self.0)
+    }
+
+    /// Attach a signature of the fee to the tx
+    pub fn attach_fee_signature(
+        self,
+        signer: common::PublicKey,
+        signature: common::Signature,
+    ) -> Self {
+        Self(attach_fee_signature(self.0, signer, signature))
+    }
+
+    /// Generates the protobuf encoding of this transaction
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes()
+    }
+
+    /// Gets the inner transaction without the domain wrapper
+    pub fn payload(self) -> Tx {
+        self.0
+    }
+
+    /// Validate this wrapper transaction
+    pub fn validate_tx(&self) -> Result<Option<&Authorization>, TxError> {
+        self.0.validate_tx()
+    }
+}
+
+/// Transaction for a validator to veto a timelocked governance proposal
+pub struct VetoProposal(Tx);
+
+impl VetoProposal {
+    /// Build a raw VetoProposal transaction from the given parameters
+    pub fn new(id: u64, validator: Address, args: GlobalArgs) -> Self {
+        let veto_proposal =
+            namada_sdk::governance::VetoProposalData { id, validator };
+
+        Self(transaction::build_tx(
+            args,
+            veto_proposal,
+            TX_VETO_PROPOSAL.to_string(),
+        ))
+    }
+
+    /// Get the bytes to sign for the given transaction
+    pub fn get_sign_bytes(&self) -> Vec<Hash> {
+        transaction::get_sign_bytes(&self.0)
+    }
+
+    /// Attach the provided signatures to the tx
+    pub fn attach_signatures(
+        self,
+        signer: common::PublicKey,
+        signature: common::Signature,
+    ) -> Self {
+        Self(transaction::attach_raw_signatures(
+            self.0, signer, signature,
+        ))
+    }
+
+    /// Attach the fee data to the tx
+    pub fn attach_fee(
+        self,
+        fee: DenominatedAmount,
+        token: Address,
+        fee_payer: common::PublicKey,
+        gas_limit: GasLimit,
+    ) -> Self {
+        Self(attach_fee(self.0, fee, token, fee_payer, gas_limit))
+    }
+
+    /// Get the bytes of the fee data to sign
+    pub fn get_fee_sig_bytes(&self) -> Hash {
+        transaction::get_wrapper_sign_bytes(&self.0)
+    }
+
+    /// Attach a signature of the fee to the tx
+    pub fn attach_fee_signature(
+        self,
+        signer: common::PublicKey,
+        signature: common::Signature,
+    ) -> Self {
+        Self(attach_fee_signature(self.0, signer, signature))
+    }
+
+    /// Generates the protobuf encoding of this transaction
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes()
+    }
+
+    /// Gets the inner transaction without the domain wrapper
+    pub fn payload(self) -> Tx {
+        self.0
+    }
+
+    /// Validate this wrapper transaction
+    pub fn validate_tx(&self) -> Result<Option<&Authorization>, TxError> {
+        self.0.validate_tx()
+    }
+}
+
+/// Transaction to delegate the governance voting power of an account
+pub struct DelegateVote(Tx);
+
+impl DelegateVote {
+    /// Build a raw DelegateVote transaction from the given parameters. A
+    /// `None` representative revokes the delegation for the given scope.
+    pub fn new(
+        delegator: Address,
+        representative: Option<Address>,
+        scope: RepresentationScope,
+        args: GlobalArgs,
+    ) -> Self {
+        let delegate_vote = namada_sdk::governance::DelegateVoteData {
+            delegator,
+            representative,
+            scope,
+        };
+
+        Self(transaction::build_tx(
+            args,
+            delegate_vote,
+            TX_DELEGATE_VOTE.to_string(),
+        ))
+    }
+
+    /// Get the bytes to sign for the given transaction
+    pub fn get_sign_bytes(&self) -> Vec<Hash> {
+        transaction::get_sign_bytes(&self.0)
+    }
+
+    /// Attach the provided signatures to the tx
+    pub fn attach_signatures(
+        self,
+        signer: common::PublicKey,
+        signature: common::Signature,
+    ) -> Self {
+        Self(transaction::attach_raw_signatures(
+            self.0, signer, signature,
+        ))
+    }
+
+    /// Attach the fee data to the tx
+    pub fn attach_fee(
+        self,
+        fee: DenominatedAmount,
+        token: Address,
+        fee_payer: common::PublicKey,
+        gas_limit: GasLimit,
+    ) -> Self {
+        Self(attach_fee(self.0, fee, token, fee_payer, gas_limit))
+    }
+
+    /// Get the bytes of the fee data to sign
+    pub fn get_fee_sig_bytes(&self) -> Hash {
+        transaction::get_wrapper_sign_bytes(&self.0)
+    }
+
+    /// Attach a signature of the fee to the tx
+    pub fn attach_fee_signature(
+        self,
+        signer: common::PublicKey,
+        signature: common::Signature,
+    ) -> Self {
+        Self(attach_fee_signature(self.0, signer, signature))
+    }
+
+    /// Generates the protobuf encoding of this transaction
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes()
+    }
+
+    /// Gets the inner transaction without the domain wrapper
+    pub fn payload(self) -> Tx {
+        self.0
+    }
+
+    /// Validate this wrapper transaction
+    pub fn validate_tx(&self) -> Result<Option<&Authorization>, TxError> {
+        self.0.validate_tx()
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use namada_sdk::address::Address;
 use namada_sdk::dec::Dec;
 use namada_sdk::hash::Hash;
@@ -466,6 +468,10 @@ impl ChangeMetaData {
         avatar: Option<String>,
         name: Option<String>,
         commission_rate: Option<Dec>,
+        security_contact: Option<String>,
+        social_handles: BTreeMap<String, String>,
+        identity_proof: Option<Hash>,
+        extensions: BTreeMap<String, String>,
         args: GlobalArgs,
     ) -> Self {
         let init_proposal = namada_sdk::tx::data::pos::MetaDataChange {
@@ -477,6 +483,10 @@ impl ChangeMetaData {
             avatar,
             name,
             commission_rate,
+            security_contact,
+            social_handles,
+            identity_proof,
+            extensions,
         };
 
         Self(transaction::build_tx(
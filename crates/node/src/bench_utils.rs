@@ -29,7 +29,7 @@ use namada_sdk::events::extend::{
 use namada_sdk::events::Event;
 use namada_sdk::gas::TxGasMeter;
 use namada_sdk::governance::storage::proposal::ProposalType;
-use namada_sdk::governance::InitProposalData;
+use namada_sdk::governance::{InitProposalData, ProposalContent};
 use namada_sdk::ibc::apps::transfer::types::msgs::transfer::MsgTransfer as IbcMsgTransfer;
 use namada_sdk::ibc::apps::transfer::types::packet::PacketData;
 use namada_sdk::ibc::apps::transfer::types::PrefixedCoin;
@@ -69,6 +69,7 @@ use namada_sdk::ibc::storage::{
 use namada_sdk::ibc::MsgTransfer;
 use namada_sdk::io::StdIo;
 use namada_sdk::key::common::SecretKey;
+use namada_sdk::masp::utils::ScanConfig;
 use namada_sdk::masp::{
     self, ContextSyncStatus, ExtendedViewingKey, MaspTransferData, MaspTxRefs,
     PaymentAddress, ShieldedContext, ShieldedUtils, TransferSource,
@@ -250,7 +251,9 @@ impl Default for BenchShell {
 
         // Initialize governance proposal
         let content_section = Section::ExtraData(Code::new(
-            vec![],
+            ProposalContent::new("Title", "Abstract")
+                .to_map()
+                .serialize_to_vec(),
             Some(TX_INIT_PROPOSAL_WASM.to_string()),
         ));
         let voting_start_epoch =
@@ -264,6 +267,7 @@ impl Default for BenchShell {
                 voting_start_epoch,
                 voting_end_epoch: voting_start_epoch.unchecked_add(3_u64),
                 activation_epoch: voting_start_epoch.unchecked_add(9_u64),
+                is_expedited: false,
             },
             None,
             Some(vec![content_section]),
@@ -1087,6 +1091,7 @@ impl BenchShieldedCtx {
                 &StdIo,
                 None,
                 None,
+                ScanConfig::default(),
                 &[spending_key.into()],
                 &[],
             ))
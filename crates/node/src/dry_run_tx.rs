@@ -1,18 +1,22 @@
 //! The ledger modules
 
 use std::cell::RefCell;
+use std::collections::BTreeMap;
 
-use namada_sdk::borsh::BorshSerializeExt;
+use namada_sdk::borsh::{BorshDeserialize, BorshSerializeExt};
 use namada_sdk::gas::{GasMetering, TxGasMeter};
+use namada_sdk::governance::storage::{self as gov_api, keys as gov_storage};
 use namada_sdk::parameters;
 use namada_sdk::queries::{EncodedResponseQuery, RequestQuery};
 use namada_sdk::state::{
-    DBIter, ResultExt, StorageHasher, StorageResult, TxIndex, DB,
+    DBIter, ResultExt, StateRead, StorageError, StorageHasher, StorageRead,
+    StorageResult, StorageWrite, TxIndex, DB,
 };
 use namada_sdk::tx::data::{
-    DryRunResult, ExtendedTxResult, GasLimit, TxResult, TxType,
+    DryRunResult, ExtendedTxResult, GasLimit, ProposalDryRunResult,
+    StorageDiff, TxResult, TxType,
 };
-use namada_sdk::tx::Tx;
+use namada_sdk::tx::{Code, Data, Tx};
 use namada_vm::wasm::{TxCache, VpCache};
 use namada_vm::WasmCacheAccess;
 
@@ -134,6 +138,82 @@ where
     })
 }
 
+/// Dry run the wasm code attached to a governance proposal in the same context
+/// as its execution after a successful vote, reporting the result, the storage
+/// modifications, the emitted events and the gas used. Like a tx dry-run, it
+/// always runs against the state of the last committed block, so the height of
+/// the request is ignored.
+pub fn dry_run_proposal<D, H, CA>(
+    mut state: namada_sdk::state::TempWlState<'static, D, H>,
+    mut vp_wasm_cache: VpCache<CA>,
+    mut tx_wasm_cache: TxCache<CA>,
+    request: &RequestQuery,
+) -> StorageResult<EncodedResponseQuery>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+    CA: 'static + WasmCacheAccess + Sync,
+{
+    let id = u64::try_from_slice(&request.data).into_storage_result()?;
+    let proposal_code =
+        gov_api::get_proposal_code(&state, id)?.ok_or_else(|| {
+            StorageError::new_alloc(format!(
+                "Proposal {id} does not exist or has no wasm code"
+            ))
+        })?;
+    let gas_scale = parameters::get_gas_scale(&state)?;
+
+    // Mark the proposal as being executed, as the protocol does before running
+    // the code of an accepted proposal
+    state.write(&gov_storage::get_proposal_execution_key(id), ())?;
+
+    let mut tx = Tx::from_type(TxType::Raw);
+    tx.header.chain_id = state.in_mem().chain_id.clone();
+    tx.set_data(Data::new(id.serialize_to_vec()));
+    tx.set_code(Code::new(proposal_code, None));
+    // Ok to unwrap cause we constructed the tx above
+    let cmt = tx.first_commitments().unwrap().to_owned();
+
+    // No gas limit for governance proposal
+    let tx_gas_meter = RefCell::new(TxGasMeter::new(u64::MAX));
+    let result = protocol::apply_wasm_tx(
+        &tx.batch_ref_tx(&cmt),
+        &TxIndex::default(),
+        ShellParams::new(
+            &tx_gas_meter,
+            &mut state,
+            &mut vp_wasm_cache,
+            &mut tx_wasm_cache,
+        ),
+    )
+    .map_err(|err| err.to_string());
+
+    let mut storage_diff = BTreeMap::new();
+    if let Ok(result) = &result {
+        for key in &result.changed_keys {
+            let (before, _gas) = state.db_read(key)?;
+            let after = state.read_bytes(key)?;
+            storage_diff.insert(key.clone(), StorageDiff { before, after });
+        }
+    }
+    let gas_used = tx_gas_meter
+        .borrow()
+        .get_tx_consumed_gas()
+        .get_whole_gas_units(gas_scale);
+    let dry_run_result = ProposalDryRunResult {
+        result,
+        storage_diff,
+        gas_used,
+    };
+
+    Ok(EncodedResponseQuery {
+        data: dry_run_result.serialize_to_vec(),
+        proof: None,
+        info: Default::default(),
+        height: state.in_mem().get_last_block_height(),
+    })
+}
+
 #[cfg(test)]
 mod test {
     use borsh::BorshDeserialize;
@@ -260,6 +340,16 @@ mod test {
                     self.tx_wasm_cache.clone(),
                     &request,
                 )
+            } else if request.path == RPC.shell().dry_run_proposal_path() {
+                dry_run_proposal(
+                    // Safe for the same reasons as the dry-run of a tx above
+                    unsafe {
+                        self.state.read_only().with_static_temp_write_log()
+                    },
+                    self.vp_wasm_cache.clone(),
+                    self.tx_wasm_cache.clone(),
+                    &request,
+                )
             } else {
                 let ctx = RequestCtx {
                     state: self.state.read_only(),
@@ -394,4 +484,46 @@ mod test {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_dry_run_proposal() -> StorageResult<()> {
+        let mut client = TestClient::new(RPC);
+        let proposal_code_key =
+            namada_sdk::governance::storage::keys::get_proposal_code_key;
+        StorageWrite::write(
+            &mut client.state,
+            &proposal_code_key(0),
+            TestWasms::TxNoOp.read_bytes(),
+        )?;
+        StorageWrite::write(
+            &mut client.state,
+            &proposal_code_key(1),
+            TestWasms::TxFail.read_bytes(),
+        )?;
+        client.state.commit_tx_batch();
+        client.state.commit_block().unwrap();
+
+        // The code of the first proposal is applied successfully
+        let dry_run =
+            namada_sdk::rpc::dry_run_proposal(&client, 0).await.unwrap();
+        assert!(dry_run.is_accepted());
+        assert!(dry_run.storage_diff.is_empty());
+
+        // The code of the second proposal fails
+        let dry_run =
+            namada_sdk::rpc::dry_run_proposal(&client, 1).await.unwrap();
+        assert!(!dry_run.is_accepted());
+        assert!(dry_run.result.is_err());
+
+        // The dry runs must not have modified the state
+        let execution_key =
+            namada_sdk::governance::storage::keys::get_proposal_execution_key;
+        assert!(!client.state.has_key(&execution_key(0))?);
+        assert!(!client.state.has_key(&execution_key(1))?);
+
+        // There's no proposal with code for the given id
+        assert!(namada_sdk::rpc::dry_run_proposal(&client, 2).await.is_err());
+
+        Ok(())
+    }
 }
@@ -33,7 +33,7 @@ use std::thread;
 
 use byte_unit::Byte;
 use data_encoding::HEXUPPER;
-pub use dry_run_tx::dry_run_tx;
+pub use dry_run_tx::{dry_run_proposal, dry_run_tx};
 use futures::future::TryFutureExt;
 use namada_apps_lib::cli::args;
 use namada_apps_lib::config::utils::{
@@ -121,6 +121,9 @@ where
         if new_epoch {
             // Apply PoS and PGF inflation
             self.apply_inflation(current_epoch, emit_events)?;
+            // Start signing with any rotated validator keys that have just
+            // become active
+            self.update_validator_keys();
         }
 
         let mut stats = InternalStats::default();
@@ -1836,6 +1839,7 @@ mod test_finalize_block {
                 voting_end_epoch: Epoch::default().next(),
                 activation_epoch: Epoch::default().next(),
                 r#type: ProposalType::Default,
+                is_expedited: false,
             };
 
             namada_sdk::governance::init_proposal::<_, token::Store<_>>(
@@ -1,4 +1,6 @@
+use namada_sdk::arith::checked;
 use namada_sdk::collections::HashMap;
+use namada_sdk::dec::Dec;
 use namada_sdk::events::extend::{ComposeEvent, Height, UserAccount};
 use namada_sdk::events::{EmitEvents, EventLevel};
 use namada_sdk::governance::event::GovernanceEvent;
@@ -6,21 +8,31 @@ use namada_sdk::governance::pgf::storage::keys as pgf_storage;
 use namada_sdk::governance::pgf::storage::steward::StewardDetail;
 use namada_sdk::governance::pgf::{storage as pgf, ADDRESS};
 use namada_sdk::governance::storage::proposal::{
-    AddRemove, PGFAction, PGFTarget, ProposalType, StoragePgfFunding,
+    AddRemove, GovernanceParameterChange, IbcParameterChange, PGFAction,
+    PGFTarget, ParameterChange, PgfParameterChange, PosParameterChange,
+    ProposalType, ProtocolParameterChange, StoragePgfFunding, WasmAllowlist,
+    WasmAllowlistEntry,
 };
 use namada_sdk::governance::storage::{keys as gov_storage, load_proposals};
 use namada_sdk::governance::utils::{
-    compute_proposal_result, ProposalVotes, TallyResult, TallyType, VotePower,
+    compute_proposal_result, is_timelock_veto_supermajority,
+    ProposalSettlement, ProposalTimelock, ProposalVotes, TallyResult,
+    TallyType, VotePower,
 };
 use namada_sdk::governance::{
     storage as gov_api, ProposalVote, ADDRESS as gov_address,
 };
-use namada_sdk::proof_of_stake::bond_amount;
+use namada_sdk::hash::Hash;
 use namada_sdk::proof_of_stake::parameters::PosParams;
+use namada_sdk::proof_of_stake::queries::find_delegation_validators;
 use namada_sdk::proof_of_stake::storage::{
-    read_total_active_stake, read_validator_stake, validator_state_handle,
+    read_owned_pos_params, read_total_active_stake, read_validator_stake,
+    validator_state_handle, write_pos_params_without_sub_keys,
 };
 use namada_sdk::proof_of_stake::types::{BondId, ValidatorState};
+use namada_sdk::proof_of_stake::{
+    bond_amount, is_validator, storage_key as pos_storage_key,
+};
 use namada_sdk::state::StorageWrite;
 use namada_sdk::storage::Epoch;
 use namada_sdk::token::event::{TokenEvent, TokenOperation};
@@ -42,6 +54,12 @@ where
     H: 'static + StorageHasher + Sync,
 {
     if is_new_epoch {
+        for id in gov_api::load_proposals_starting_voting(
+            &shell.state,
+            current_epoch,
+        )? {
+            events.emit(GovernanceEvent::voting_started(id));
+        }
         load_and_execute_governance_proposals(shell, events, current_epoch)?;
     }
     Ok(())
@@ -62,10 +80,24 @@ where
     D: DB + for<'iter> DBIter<'iter> + Sync + 'static,
     H: StorageHasher + Sync + 'static,
 {
+    let timelocked_ids =
+        gov_api::load_timelocked_proposals(&shell.state, current_epoch)?;
     let proposal_ids = load_proposals(&shell.state, current_epoch)?;
 
-    let proposals_result =
-        execute_governance_proposals(shell, events, proposal_ids)?;
+    let mut proposals_result = execute_timelocked_proposals(
+        shell,
+        events,
+        current_epoch,
+        timelocked_ids,
+    )?;
+    let tallied_result = execute_governance_proposals(
+        shell,
+        events,
+        current_epoch,
+        proposal_ids,
+    )?;
+    proposals_result.passed.extend(tallied_result.passed);
+    proposals_result.rejected.extend(tallied_result.rejected);
 
     Ok(proposals_result)
 }
@@ -73,6 +105,7 @@ where
 fn execute_governance_proposals<D, H>(
     shell: &mut Shell<D, H>,
     events: &mut impl EmitEvents,
+    current_epoch: Epoch,
     proposal_ids: BTreeSet<u64>,
 ) -> Result<ProposalsResult>
 where
@@ -81,6 +114,7 @@ where
 {
     let mut proposals_result = ProposalsResult::default();
     let params = read_pos_params(&shell.state)?;
+    let gov_params = gov_api::get_parameters(&shell.state)?;
 
     for id in proposal_ids {
         let proposal_funds_key = gov_storage::get_funds_key(id);
@@ -97,12 +131,42 @@ where
         let proposal_author: Address =
             force_read(&shell.state, &proposal_author_key)?;
 
+        // A cancelled proposal is not tallied, its deposit is only settled
+        if gov_api::is_proposal_cancelled(&shell.state, id)? {
+            let (refunded, burned) = settle_cancelled_proposal(
+                &mut shell.state,
+                events,
+                id,
+                &proposal_author,
+                funds,
+                gov_params.proposal_cancellation_refund_rate,
+            )?;
+            gov_api::write_proposal_settlement(
+                &mut shell.state,
+                id,
+                ProposalSettlement {
+                    epoch: current_epoch,
+                    execution: None,
+                    refunded,
+                    burned,
+                },
+            )?;
+            events.emit(GovernanceEvent::cancelled_proposal(id));
+            tracing::info!(
+                "Governance proposal {} has been cancelled by its author.",
+                id
+            );
+            continue;
+        }
+
         let is_steward = pgf::is_steward(&shell.state, &proposal_author)?;
+        let is_expedited = gov_api::is_proposal_expedited(&shell.state, id)?;
 
         let total_active_voting_power =
             read_total_active_stake(&shell.state, &params, proposal_end_epoch)?;
 
-        let tally_type = TallyType::from(proposal_type.clone(), is_steward);
+        let tally_type =
+            TallyType::from(proposal_type.clone(), is_steward, is_expedited);
         let votes = compute_proposal_votes(
             &shell.state,
             &params,
@@ -113,102 +177,61 @@ where
             votes,
             total_active_voting_power,
             tally_type,
+            gov_params.veto_threshold,
         )
         .expect("Proposal result calculation must not over/underflow");
+
+        // An expedited proposal that doesn't reach its stricter threshold is
+        // not rejected, but keeps being voted on as a standard proposal
+        if is_expedited
+            && matches!(proposal_result.result, TallyResult::Rejected)
+        {
+            let activation_epoch = fallback_expedited_proposal(
+                &mut shell.state,
+                id,
+                current_epoch,
+            )?;
+            tracing::info!(
+                "Expedited governance proposal #{} did not pass, it has been \
+                 converted to a standard proposal with activation epoch {}.",
+                id,
+                activation_epoch
+            );
+            continue;
+        }
         gov_api::write_proposal_result(&mut shell.state, id, proposal_result)?;
+        events.emit(GovernanceEvent::tallied_proposal(id, proposal_result));
 
-        let transfer_address = match proposal_result.result {
+        let (transfer_address, execution) = match proposal_result.result {
             TallyResult::Passed => {
-                let proposal_event = match proposal_type {
-                    ProposalType::Default => {
-                        let proposal_code =
-                            gov_api::get_proposal_code(&shell.state, id)?
-                                .unwrap_or_default();
-                        let _result = execute_default_proposal(
-                            shell,
-                            id,
-                            proposal_code.clone(),
-                        )?;
-                        tracing::info!(
-                            "Governance proposal #{} (default) has passed.",
-                            id,
-                        );
-
-                        GovernanceEvent::passed_proposal(id, false, false)
-                    }
-                    ProposalType::DefaultWithWasm(_) => {
-                        let proposal_code =
-                            gov_api::get_proposal_code(&shell.state, id)?
-                                .unwrap_or_default();
-                        let result = execute_default_proposal(
-                            shell,
-                            id,
-                            proposal_code.clone(),
-                        )?;
-                        tracing::info!(
-                            "Governance proposal #{} (default with wasm) has \
-                             passed and been executed, wasm execution: {}.",
-                            id,
-                            if result { "successful" } else { "unsuccessful" }
-                        );
-
-                        GovernanceEvent::passed_proposal(id, true, result)
-                    }
-                    ProposalType::PGFSteward(stewards) => {
-                        let result = execute_pgf_steward_proposal(
-                            &mut shell.state,
-                            stewards,
-                        )?;
-                        tracing::info!(
-                            "Governance proposal #{} for PGF stewards has \
-                             been executed. {}.",
-                            id,
-                            if result {
-                                "State changes have been applied successfully"
-                            } else {
-                                "FAILURE trying to apply the state changes - \
-                                 no state change occurred"
-                            }
-                        );
-
-                        GovernanceEvent::passed_proposal(id, false, false)
-                    }
-                    ProposalType::PGFPayment(payments) => {
-                        let native_token = &shell.state.get_native_token()?;
-                        let _result = execute_pgf_funding_proposal(
-                            &mut shell.state,
-                            events,
-                            native_token,
-                            payments,
-                            id,
-                        )?;
-                        tracing::info!(
-                            "Governance proposal #{} for PGF funding has \
-                             passed and been executed.",
-                            id
-                        );
+                if gov_params.proposal_timelock_epochs > 0 {
+                    let execution_epoch = queue_passed_proposal(
+                        &mut shell.state,
+                        id,
+                        current_epoch,
+                        gov_params.proposal_timelock_epochs,
+                    )?;
+                    events.emit(GovernanceEvent::queued_proposal(
+                        id,
+                        execution_epoch,
+                    ));
+                    tracing::info!(
+                        "Governance proposal #{} has passed, it has been \
+                         queued for execution at epoch {}.",
+                        id,
+                        execution_epoch
+                    );
+                    continue;
+                }
 
-                        GovernanceEvent::passed_proposal(id, false, false)
-                    }
-                };
-                events.emit(proposal_event);
+                let is_successful =
+                    execute_passed_proposal(shell, events, id, proposal_type)?;
                 proposals_result.passed.push(id);
 
-                // Take events that could have been emitted by PGF
-                // over IBC, governance proposal execution, etc
-                let current_height =
-                    shell.state.in_mem().get_last_block_height().next_height();
-
-                events.emit_many(
-                    shell
-                        .state
-                        .write_log_mut()
-                        .take_events()
-                        .into_iter()
-                        .map(|event| event.with(Height(current_height))),
-                );
-
-                gov_api::get_proposal_author(&shell.state, id)?
+                (
+                    gov_api::get_proposal_author(&shell.state, id)?,
+                    Some(is_successful),
+                )
             }
             TallyResult::Rejected => {
                 if let ProposalType::PGFPayment(_) = proposal_type {
@@ -240,68 +263,474 @@ where
                     id
                 );
 
-                None
+                (None, None)
+            }
+            TallyResult::Vetoed => {
+                let proposal_event = GovernanceEvent::vetoed_proposal(
+                    id,
+                    matches!(proposal_type, ProposalType::DefaultWithWasm(_)),
+                );
+                events.emit(proposal_event);
+                proposals_result.rejected.push(id);
+
+                tracing::info!(
+                    "Governance proposal {} has been vetoed, its locked funds \
+                     are burned.",
+                    id
+                );
+
+                (None, None)
             }
         };
 
-        let native_token = shell.state.get_native_token()?;
-        if let Some(address) = transfer_address {
-            token::transfer(
-                &mut shell.state,
-                &native_token,
-                &gov_address,
-                &address,
-                funds,
-            )?;
+        settle_proposal(
+            &mut shell.state,
+            events,
+            id,
+            transfer_address,
+            funds,
+            current_epoch,
+            execution,
+        )?;
+    }
 
-            const DESCRIPTOR: &str = "governance-locked-funds-refund";
-
-            let final_gov_balance =
-                read_balance(&shell.state, &native_token, &gov_address)?.into();
-            let final_target_balance =
-                read_balance(&shell.state, &native_token, &address)?.into();
-
-            events.emit(TokenEvent {
-                descriptor: DESCRIPTOR.into(),
-                level: EventLevel::Block,
-                operation: TokenOperation::transfer(
-                    UserAccount::Internal(gov_address),
-                    UserAccount::Internal(address),
-                    native_token.clone(),
-                    funds.into(),
-                    final_gov_balance,
-                    Some(final_target_balance),
-                ),
-            });
-        } else {
-            token::burn_tokens(
-                &mut shell.state,
-                &native_token,
-                &gov_address,
-                funds,
-            )?;
+    Ok(proposals_result)
+}
 
-            const DESCRIPTOR: &str = "governance-locked-funds-burn";
+/// Execute the proposals whose timelock ends at the current epoch, unless a
+/// supermajority of the validators vetoed them
+fn execute_timelocked_proposals<D, H>(
+    shell: &mut Shell<D, H>,
+    events: &mut impl EmitEvents,
+    current_epoch: Epoch,
+    proposal_ids: BTreeSet<u64>,
+) -> Result<ProposalsResult>
+where
+    D: DB + for<'iter> DBIter<'iter> + Sync + 'static,
+    H: StorageHasher + Sync + 'static,
+{
+    let mut proposals_result = ProposalsResult::default();
+    let params = read_pos_params(&shell.state)?;
 
-            let final_gov_balance =
-                read_balance(&shell.state, &native_token, &gov_address)?.into();
+    for id in proposal_ids {
+        let proposal_funds_key = gov_storage::get_funds_key(id);
+        let proposal_type_key = gov_storage::get_proposal_type_key(id);
 
-            events.emit(TokenEvent {
-                descriptor: DESCRIPTOR.into(),
-                level: EventLevel::Block,
-                operation: TokenOperation::Burn {
-                    token: native_token.clone(),
-                    amount: funds.into(),
-                    target_account: UserAccount::Internal(gov_address),
-                    post_balance: final_gov_balance,
-                },
-            });
-        }
+        let funds: token::Amount =
+            force_read(&shell.state, &proposal_funds_key)?;
+        let proposal_type: ProposalType =
+            force_read(&shell.state, &proposal_type_key)?;
+
+        let (transfer_address, execution) =
+            if is_timelock_vetoed(&shell.state, &params, id, current_epoch)? {
+                events.emit(GovernanceEvent::timelock_vetoed_proposal(id));
+                proposals_result.rejected.push(id);
+
+                tracing::info!(
+                    "Timelocked governance proposal {} has been vetoed by a \
+                     supermajority of validators, its locked funds are burned.",
+                    id
+                );
+
+                (None, None)
+            } else {
+                let is_successful =
+                    execute_passed_proposal(shell, events, id, proposal_type)?;
+                proposals_result.passed.push(id);
+
+                (
+                    gov_api::get_proposal_author(&shell.state, id)?,
+                    Some(is_successful),
+                )
+            };
+
+        // Only leave the queue once the outcome is known, so that the tx batch
+        // of the proposal code, which is dropped on failure, can't include it
+        gov_api::dequeue_proposal(&mut shell.state, id, current_epoch)?;
+
+        settle_proposal(
+            &mut shell.state,
+            events,
+            id,
+            transfer_address,
+            funds,
+            current_epoch,
+            execution,
+        )?;
     }
 
     Ok(proposals_result)
 }
 
+/// Put a passed proposal in the timelock queue. Returns the epoch at which it
+/// will be executed.
+fn queue_passed_proposal<S>(
+    storage: &mut S,
+    proposal_id: u64,
+    current_epoch: Epoch,
+    timelock_epochs: u64,
+) -> namada_sdk::state::StorageResult<Epoch>
+where
+    S: StorageRead + StorageWrite,
+{
+    let execution_epoch = Epoch(checked!(current_epoch.0 + timelock_epochs)?);
+    gov_api::queue_proposal(
+        storage,
+        proposal_id,
+        ProposalTimelock {
+            queued_epoch: current_epoch,
+            execution_epoch,
+        },
+    )?;
+    Ok(execution_epoch)
+}
+
+/// Check whether the validators that vetoed a timelocked proposal hold a
+/// supermajority of the active voting power at the given epoch
+fn is_timelock_vetoed<S>(
+    storage: &S,
+    params: &PosParams,
+    proposal_id: u64,
+    epoch: Epoch,
+) -> namada_sdk::state::StorageResult<bool>
+where
+    S: StorageRead,
+{
+    let total_active_voting_power =
+        read_total_active_stake(storage, params, epoch)?;
+
+    let mut veto_voting_power = token::Amount::zero();
+    for validator in gov_api::get_timelock_vetoes(storage, proposal_id)? {
+        if !is_tallied_validator(
+            storage,
+            params,
+            proposal_id,
+            &validator,
+            epoch,
+        )? {
+            continue;
+        }
+        let validator_stake =
+            read_validator_stake(storage, params, &validator, epoch)?;
+        veto_voting_power = checked!(veto_voting_power + validator_stake)?;
+    }
+
+    Ok(is_timelock_veto_supermajority(
+        veto_voting_power,
+        total_active_voting_power,
+    )?)
+}
+
+/// Refund the locked funds of a proposal to the given address, or burn them if
+/// there is none, and record the settlement of the proposal
+fn settle_proposal<S>(
+    storage: &mut S,
+    events: &mut impl EmitEvents,
+    proposal_id: u64,
+    transfer_address: Option<Address>,
+    funds: token::Amount,
+    current_epoch: Epoch,
+    execution: Option<bool>,
+) -> namada_sdk::state::StorageResult<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let (refunded, burned) = if let Some(address) = transfer_address {
+        refund_locked_funds(storage, events, proposal_id, address, funds)?;
+        (funds, token::Amount::zero())
+    } else {
+        burn_locked_funds(storage, events, proposal_id, funds)?;
+        (token::Amount::zero(), funds)
+    };
+    gov_api::write_proposal_settlement(
+        storage,
+        proposal_id,
+        ProposalSettlement {
+            epoch: current_epoch,
+            execution,
+            refunded,
+            burned,
+        },
+    )
+}
+
+/// Execute a passed proposal, emitting its execution events. Returns whether
+/// the execution was successful.
+fn execute_passed_proposal<D, H>(
+    shell: &mut Shell<D, H>,
+    events: &mut impl EmitEvents,
+    id: u64,
+    proposal_type: ProposalType,
+) -> Result<bool>
+where
+    D: DB + for<'iter> DBIter<'iter> + Sync + 'static,
+    H: StorageHasher + Sync + 'static,
+{
+    let (proposal_event, is_successful) = match proposal_type {
+        ProposalType::Default => {
+            let proposal_code = gov_api::get_proposal_code(&shell.state, id)?
+                .unwrap_or_default();
+            let _result =
+                execute_default_proposal(shell, id, proposal_code.clone())?;
+            tracing::info!("Governance proposal #{} (default) has passed.", id,);
+
+            // There is no code to execute
+            (GovernanceEvent::passed_proposal(id, false, false), true)
+        }
+        ProposalType::DefaultWithWasm(_) => {
+            let proposal_code = gov_api::get_proposal_code(&shell.state, id)?
+                .unwrap_or_default();
+            let result =
+                execute_default_proposal(shell, id, proposal_code.clone())?;
+            tracing::info!(
+                "Governance proposal #{} (default with wasm) has passed and \
+                 been executed, wasm execution: {}.",
+                id,
+                if result { "successful" } else { "unsuccessful" }
+            );
+
+            (GovernanceEvent::passed_proposal(id, true, result), result)
+        }
+        ProposalType::PGFSteward(stewards) => {
+            let result =
+                execute_pgf_steward_proposal(&mut shell.state, stewards)?;
+            tracing::info!(
+                "Governance proposal #{} for PGF stewards has been executed. \
+                 {}.",
+                id,
+                if result {
+                    "State changes have been applied successfully"
+                } else {
+                    "FAILURE trying to apply the state changes - no state \
+                     change occurred"
+                }
+            );
+
+            (GovernanceEvent::passed_proposal(id, false, false), result)
+        }
+        ProposalType::ParameterChange(changes) => {
+            let result =
+                execute_parameter_change_proposal(&mut shell.state, changes)?;
+            tracing::info!(
+                "Governance proposal #{} for parameter changes has been \
+                 executed. {}.",
+                id,
+                if result {
+                    "State changes have been applied successfully"
+                } else {
+                    "FAILURE trying to apply the state changes - no state \
+                     change occurred"
+                }
+            );
+
+            (GovernanceEvent::passed_proposal(id, false, false), result)
+        }
+        ProposalType::WasmAllowlist(changes) => {
+            let result =
+                execute_wasm_allowlist_proposal(&mut shell.state, id, changes)?;
+            tracing::info!(
+                "Governance proposal #{} for wasm allowlist changes has been \
+                 executed. {}.",
+                id,
+                if result {
+                    "State changes have been applied successfully"
+                } else {
+                    "FAILURE trying to apply the state changes - no state \
+                     change occurred"
+                }
+            );
+
+            (GovernanceEvent::passed_proposal(id, false, false), result)
+        }
+        ProposalType::PGFPayment(payments) => {
+            let native_token = &shell.state.get_native_token()?;
+            let result = execute_pgf_funding_proposal(
+                &mut shell.state,
+                events,
+                native_token,
+                payments,
+                id,
+            )?;
+            tracing::info!(
+                "Governance proposal #{} for PGF funding has passed and been \
+                 executed.",
+                id
+            );
+
+            (GovernanceEvent::passed_proposal(id, false, false), result)
+        }
+    };
+    events.emit(proposal_event);
+    events.emit(GovernanceEvent::executed_proposal(id, is_successful));
+
+    // Take events that could have been emitted by PGF
+    // over IBC, governance proposal execution, etc
+    let current_height =
+        shell.state.in_mem().get_last_block_height().next_height();
+
+    events.emit_many(
+        shell
+            .state
+            .write_log_mut()
+            .take_events()
+            .into_iter()
+            .map(|event| event.with(Height(current_height))),
+    );
+
+    Ok(is_successful)
+}
+
+/// Settle the deposit of a cancelled proposal: the author is refunded the
+/// given share of the locked funds and the remainder is burned. Returns the
+/// refunded and burned amounts.
+fn settle_cancelled_proposal<S>(
+    storage: &mut S,
+    events: &mut impl EmitEvents,
+    proposal_id: u64,
+    author: &Address,
+    funds: token::Amount,
+    refund_rate: Dec,
+) -> namada_sdk::state::StorageResult<(token::Amount, token::Amount)>
+where
+    S: StorageRead + StorageWrite,
+{
+    let refund = funds.mul_floor(refund_rate)?;
+    let burn = checked!(funds - refund)?;
+
+    refund_locked_funds(storage, events, proposal_id, author.clone(), refund)?;
+    burn_locked_funds(storage, events, proposal_id, burn)?;
+    Ok((refund, burn))
+}
+
+/// Transfer locked proposal funds from governance back to the given address
+fn refund_locked_funds<S>(
+    storage: &mut S,
+    events: &mut impl EmitEvents,
+    proposal_id: u64,
+    address: Address,
+    amount: token::Amount,
+) -> namada_sdk::state::StorageResult<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let native_token = storage.get_native_token()?;
+    token::transfer(storage, &native_token, &gov_address, &address, amount)?;
+
+    const DESCRIPTOR: &str = "governance-locked-funds-refund";
+
+    let final_gov_balance =
+        read_balance(storage, &native_token, &gov_address)?.into();
+    let final_target_balance =
+        read_balance(storage, &native_token, &address)?.into();
+
+    events.emit(TokenEvent {
+        descriptor: DESCRIPTOR.into(),
+        level: EventLevel::Block,
+        operation: TokenOperation::transfer(
+            UserAccount::Internal(gov_address),
+            UserAccount::Internal(address.clone()),
+            native_token.clone(),
+            amount.into(),
+            final_gov_balance,
+            Some(final_target_balance),
+        ),
+    });
+    events.emit(GovernanceEvent::refunded_funds(
+        proposal_id,
+        address,
+        amount,
+    ));
+
+    Ok(())
+}
+
+/// Burn locked proposal funds from the governance account
+fn burn_locked_funds<S>(
+    storage: &mut S,
+    events: &mut impl EmitEvents,
+    proposal_id: u64,
+    amount: token::Amount,
+) -> namada_sdk::state::StorageResult<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let native_token = storage.get_native_token()?;
+    token::burn_tokens(storage, &native_token, &gov_address, amount)?;
+
+    const DESCRIPTOR: &str = "governance-locked-funds-burn";
+
+    let final_gov_balance =
+        read_balance(storage, &native_token, &gov_address)?.into();
+
+    events.emit(TokenEvent {
+        descriptor: DESCRIPTOR.into(),
+        level: EventLevel::Block,
+        operation: TokenOperation::Burn {
+            token: native_token.clone(),
+            amount: amount.into(),
+            target_account: UserAccount::Internal(gov_address),
+            post_balance: final_gov_balance,
+        },
+    });
+    events.emit(GovernanceEvent::burned_funds(proposal_id, amount));
+
+    Ok(())
+}
+
+/// Convert an expedited proposal that failed its tally at the end of its
+/// voting period into a standard one, extending its voting period to at least
+/// the standard minimum and rescheduling its tally at the new activation
+/// epoch. Returns the new activation epoch.
+fn fallback_expedited_proposal<S>(
+    storage: &mut S,
+    proposal_id: u64,
+    current_epoch: Epoch,
+) -> namada_sdk::state::StorageResult<Epoch>
+where
+    S: StorageRead + StorageWrite,
+{
+    let gov_params = gov_api::get_parameters(storage)?;
+    let voting_start_epoch: Epoch = force_read(
+        storage,
+        &gov_storage::get_voting_start_epoch_key(proposal_id),
+    )?;
+
+    // The new voting period must end in the future so that the standard
+    // minimum is honored and votes can still be cast
+    let voting_end_epoch = std::cmp::max(
+        Epoch(checked!(
+            voting_start_epoch.0 + gov_params.min_proposal_voting_period
+        )?),
+        current_epoch.next(),
+    );
+    let new_activation_epoch = Epoch(checked!(
+        voting_end_epoch.0 + gov_params.min_proposal_grace_epochs
+    )?);
+
+    storage.write(
+        &gov_storage::get_voting_end_epoch_key(proposal_id),
+        voting_end_epoch,
+    )?;
+    storage.write(
+        &gov_storage::get_activation_epoch_key(proposal_id),
+        new_activation_epoch,
+    )?;
+    storage.delete(&gov_storage::get_proposal_expedited_key(proposal_id))?;
+    storage.delete(&gov_storage::get_committing_proposals_key(
+        proposal_id,
+        current_epoch.0,
+    ))?;
+    storage.write(
+        &gov_storage::get_committing_proposals_key(
+            proposal_id,
+            new_activation_epoch.0,
+        ),
+        (),
+    )?;
+
+    Ok(new_activation_epoch)
+}
+
 fn compute_proposal_votes<S>(
     storage: &S,
     params: &PosParams,
@@ -327,22 +756,13 @@ where
     for vote in votes {
         // Skip votes involving jailed or inactive validators
         let validator = vote.validator.clone();
-        let validator_state =
-            validator_state_handle(&validator).get(storage, epoch, params)?;
-
-        if matches!(
-            validator_state,
-            Some(ValidatorState::Jailed) | Some(ValidatorState::Inactive)
-        ) {
-            continue;
-        }
-        if validator_state.is_none() {
-            tracing::error!(
-                "While computing votes for proposal id {proposal_id} in epoch \
-                 {epoch}, encountered validator {validator} that has no \
-                 stored state. Please report this as a bug. Skipping this \
-                 vote."
-            );
+        if !is_tallied_validator(
+            storage,
+            params,
+            proposal_id,
+            &validator,
+            epoch,
+        )? {
             continue;
         }
 
@@ -378,6 +798,39 @@ where
         }
     }
 
+    // Tally the delegators that did not vote directly with the vote of their
+    // representative, using the stake of their own bonds
+    for vote in gov_api::get_represented_votes(storage, proposal_id)? {
+        let delegator = vote.delegator;
+        if is_validator(storage, &delegator)? {
+            continue;
+        }
+        for validator in
+            find_delegation_validators(storage, &delegator, &epoch)?
+        {
+            if !is_tallied_validator(
+                storage,
+                params,
+                proposal_id,
+                &validator,
+                epoch,
+            )? {
+                continue;
+            }
+            let bond_id = BondId {
+                source: delegator.clone(),
+                validator: validator.clone(),
+            };
+            if let Ok(stake) = bond_amount(storage, &bond_id, epoch) {
+                delegators_vote.insert(delegator.clone(), vote.data.clone());
+                delegator_voting_power
+                    .entry(delegator.clone())
+                    .or_default()
+                    .insert(validator, stake);
+            }
+        }
+    }
+
     Ok(ProposalVotes {
         validators_vote,
         validator_voting_power,
@@ -386,7 +839,39 @@ where
     })
 }
 
-fn execute_default_proposal<D, H>(
+/// Check that the votes involving the given validator count towards the tally,
+/// i.e. that the validator is neither jailed nor inactive.
+fn is_tallied_validator<S>(
+    storage: &S,
+    params: &PosParams,
+    proposal_id: u64,
+    validator: &Address,
+    epoch: Epoch,
+) -> namada_sdk::state::StorageResult<bool>
+where
+    S: StorageRead,
+{
+    let validator_state =
+        validator_state_handle(validator).get(storage, epoch, params)?;
+
+    if matches!(
+        validator_state,
+        Some(ValidatorState::Jailed) | Some(ValidatorState::Inactive)
+    ) {
+        return Ok(false);
+    }
+    if validator_state.is_none() {
+        tracing::error!(
+            "While computing votes for proposal id {proposal_id} in epoch \
+             {epoch}, encountered validator {validator} that has no stored \
+             state. Please report this as a bug. Skipping this vote."
+        );
+        return Ok(false);
+    }
+    Ok(true)
+}
+
+fn execute_default_proposal<D, H>(
     shell: &mut Shell<D, H>,
     id: u64,
     proposal_code: Vec<u8>,
@@ -504,6 +989,489 @@ where
     Ok(true)
 }
 
+/// Apply the changes of a parameter change proposal. The changes are only
+/// written if the resulting parameters are all valid, otherwise no change is
+/// applied and `false` is returned. Only the storage keys of the changed
+/// parameters are written.
+fn execute_parameter_change_proposal<S>(
+    storage: &mut S,
+    changes: BTreeSet<ParameterChange>,
+) -> Result<bool>
+where
+    S: StorageRead + StorageWrite,
+{
+    let mut protocol_params = parameters::read(storage)?;
+    let mut pos_params = read_owned_pos_params(storage)?;
+    let mut gov_params = gov_api::get_parameters(storage)?;
+    // The default IBC limits are stored together, so they're only read when
+    // one of them is changed
+    let is_ibc_changed = changes.iter().any(|change| {
+        matches!(
+            change,
+            ParameterChange::Ibc(
+                IbcParameterChange::DefaultMintLimit(_)
+                    | IbcParameterChange::DefaultPerEpochThroughputLimit(_)
+            )
+        )
+    });
+    let mut ibc_params = if is_ibc_changed {
+        let Some(ibc_params) = storage.read::<ibc::parameters::IbcParameters>(
+            &ibc::storage::params_key(),
+        )?
+        else {
+            tracing::warn!(
+                "Parameter change cannot be applied, the IBC parameters are \
+                 missing from storage"
+            );
+            return Ok(false);
+        };
+        Some(ibc_params)
+    } else {
+        None
+    };
+
+    // Apply the changes to the parameters that are validated together
+    for change in changes.iter().cloned() {
+        match change {
+            ParameterChange::Protocol(change) => match change {
+                ProtocolParameterChange::MaxTxBytes(value) => {
+                    protocol_params.max_tx_bytes = value
+                }
+                ProtocolParameterChange::MaxProposalBytes(value) => {
+                    protocol_params.max_proposal_bytes = value
+                }
+                ProtocolParameterChange::MaxBlockGas(value) => {
+                    protocol_params.max_block_gas = value
+                }
+                ProtocolParameterChange::MaspFeePaymentGasLimit(value) => {
+                    protocol_params.masp_fee_payment_gas_limit = value
+                }
+                ProtocolParameterChange::GasScale(value) => {
+                    protocol_params.gas_scale = value
+                }
+                ProtocolParameterChange::MinimumGasPrice {
+                    token,
+                    price: Some(price),
+                } => {
+                    protocol_params.minimum_gas_price.insert(token, price);
+                }
+                ProtocolParameterChange::MinimumGasPrice {
+                    token,
+                    price: None,
+                } => {
+                    protocol_params.minimum_gas_price.remove(&token);
+                }
+                ProtocolParameterChange::IsNativeTokenTransferable(value) => {
+                    protocol_params.is_native_token_transferable = value
+                }
+            },
+            ParameterChange::Pos(change) => match change {
+                PosParameterChange::MaxValidatorSlots(value) => {
+                    pos_params.max_validator_slots = value
+                }
+                PosParameterChange::BlockProposerReward(value) => {
+                    pos_params.block_proposer_reward = value
+                }
+                PosParameterChange::BlockVoteReward(value) => {
+                    pos_params.block_vote_reward = value
+                }
+                PosParameterChange::MaxInflationRate(value) => {
+                    pos_params.max_inflation_rate = value
+                }
+                PosParameterChange::TargetStakedRatio(value) => {
+                    pos_params.target_staked_ratio = value
+                }
+                PosParameterChange::DuplicateVoteMinSlashRate(value) => {
+                    pos_params.duplicate_vote_min_slash_rate = value
+                }
+                PosParameterChange::LightClientAttackMinSlashRate(value) => {
+                    pos_params.light_client_attack_min_slash_rate = value
+                }
+                PosParameterChange::CubicSlashingWindowLength(value) => {
+                    pos_params.cubic_slashing_window_length = value
+                }
+                PosParameterChange::ValidatorStakeThreshold(value) => {
+                    pos_params.validator_stake_threshold = value
+                }
+                PosParameterChange::MinSelfBond(value) => {
+                    pos_params.min_self_bond = value
+                }
+                PosParameterChange::MaxValidatorStakeShare(value) => {
+                    pos_params.max_validator_stake_share = value
+                }
+                PosParameterChange::LivenessWindowCheck(value) => {
+                    pos_params.liveness_window_check = value
+                }
+                PosParameterChange::LivenessThreshold(value) => {
+                    pos_params.liveness_threshold = value
+                }
+            },
+            ParameterChange::Governance(change) => match change {
+                GovernanceParameterChange::MinProposalFund(value) => {
+                    gov_params.min_proposal_fund = value
+                }
+                GovernanceParameterChange::MaxProposalCodeSize(value) => {
+                    gov_params.max_proposal_code_size = value
+                }
+                GovernanceParameterChange::MinProposalVotingPeriod(value) => {
+                    gov_params.min_proposal_voting_period = value
+                }
+                GovernanceParameterChange::MaxProposalPeriod(value) => {
+                    gov_params.max_proposal_period = value
+                }
+                GovernanceParameterChange::MaxProposalContentSize(value) => {
+                    gov_params.max_proposal_content_size = value
+                }
+                GovernanceParameterChange::MinProposalGraceEpochs(value) => {
+                    gov_params.min_proposal_grace_epochs = value
+                }
+                GovernanceParameterChange::MaxProposalLatency(value) => {
+                    gov_params.max_proposal_latency = value
+                }
+                GovernanceParameterChange::MinExpeditedProposalFund(value) => {
+                    gov_params.min_expedited_proposal_fund = value
+                }
+                GovernanceParameterChange::MinExpeditedProposalVotingPeriod(
+                    value,
+                ) => gov_params.min_expedited_proposal_voting_period = value,
+                GovernanceParameterChange::ProposalCancellationRefundRate(
+                    value,
+                ) => gov_params.proposal_cancellation_refund_rate = value,
+                GovernanceParameterChange::VetoThreshold(value) => {
+                    gov_params.veto_threshold = value
+                }
+                GovernanceParameterChange::ProposalTimelockEpochs(value) => {
+                    gov_params.proposal_timelock_epochs = value
+                }
+            },
+            ParameterChange::Ibc(IbcParameterChange::DefaultMintLimit(
+                value,
+            )) => {
+                if let Some(ibc_params) = ibc_params.as_mut() {
+                    ibc_params.default_mint_limit = value;
+                }
+            }
+            ParameterChange::Ibc(
+                IbcParameterChange::DefaultPerEpochThroughputLimit(value),
+            ) => {
+                if let Some(ibc_params) = ibc_params.as_mut() {
+                    ibc_params.default_per_epoch_throughput_limit = value;
+                }
+            }
+            // The other parameters are not constrained by one another
+            ParameterChange::Pgf(_)
+            | ParameterChange::Ibc(
+                IbcParameterChange::MintLimit { .. }
+                | IbcParameterChange::PerEpochThroughputLimit { .. },
+            ) => {}
+        }
+    }
+
+    // Check the constraints between parameters before writing anything
+    let pos_errors = pos_params.validate();
+    if !pos_errors.is_empty() {
+        tracing::warn!(
+            "Parameter change would result in invalid PoS parameters: {}",
+            pos_errors
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        return Ok(false);
+    }
+    if gov_params.min_proposal_voting_period > gov_params.max_proposal_period {
+        tracing::warn!(
+            "Parameter change would result in a minimum proposal voting \
+             period {} greater than the maximum proposal period {}",
+            gov_params.min_proposal_voting_period,
+            gov_params.max_proposal_period
+        );
+        return Ok(false);
+    }
+    if gov_params.min_expedited_proposal_voting_period
+        > gov_params.min_proposal_voting_period
+        || gov_params.min_expedited_proposal_fund < gov_params.min_proposal_fund
+    {
+        tracing::warn!(
+            "Parameter change would result in expedited proposals having a \
+             longer minimum voting period or a smaller minimum fund than \
+             standard proposals"
+        );
+        return Ok(false);
+    }
+
+    // Write the storage key of each changed parameter
+    let mut is_pos_changed = false;
+    for change in changes {
+        match change {
+            ParameterChange::Protocol(change) => match change {
+                ProtocolParameterChange::MaxTxBytes(value) => storage.write(
+                    &parameters::storage::get_max_tx_bytes_key(),
+                    value,
+                )?,
+                ProtocolParameterChange::MaxProposalBytes(value) => storage
+                    .write(
+                        &parameters::storage::get_max_proposal_bytes_key(),
+                        value,
+                    )?,
+                ProtocolParameterChange::MaxBlockGas(value) => storage.write(
+                    &parameters::storage::get_max_block_gas_key(),
+                    value,
+                )?,
+                ProtocolParameterChange::MaspFeePaymentGasLimit(value) => {
+                    storage.write(
+                        &parameters::storage::get_masp_fee_payment_gas_limit_key(),
+                        value,
+                    )?
+                }
+                ProtocolParameterChange::GasScale(value) => storage
+                    .write(&parameters::storage::get_gas_scale_key(), value)?,
+                // The minimum gas prices of all the tokens are stored
+                // together
+                ProtocolParameterChange::MinimumGasPrice { .. } => storage
+                    .write(
+                        &parameters::storage::get_gas_cost_key(),
+                        &protocol_params.minimum_gas_price,
+                    )?,
+                ProtocolParameterChange::IsNativeTokenTransferable(value) => {
+                    storage.write(
+                        &parameters::storage::get_native_token_transferable_key(),
+                        value,
+                    )?
+                }
+            },
+            ParameterChange::Pos(change) => match change {
+                PosParameterChange::MinSelfBond(value) => storage.write(
+                    &pos_storage_key::params_min_self_bond_key(),
+                    value,
+                )?,
+                PosParameterChange::MaxValidatorStakeShare(value) => storage
+                    .write(
+                        &pos_storage_key::params_max_validator_stake_share_key(
+                        ),
+                        value,
+                    )?,
+                // The other PoS parameters are stored together
+                PosParameterChange::MaxValidatorSlots(_)
+                | PosParameterChange::BlockProposerReward(_)
+                | PosParameterChange::BlockVoteReward(_)
+                | PosParameterChange::MaxInflationRate(_)
+                | PosParameterChange::TargetStakedRatio(_)
+                | PosParameterChange::DuplicateVoteMinSlashRate(_)
+                | PosParameterChange::LightClientAttackMinSlashRate(_)
+                | PosParameterChange::CubicSlashingWindowLength(_)
+                | PosParameterChange::ValidatorStakeThreshold(_)
+                | PosParameterChange::LivenessWindowCheck(_)
+                | PosParameterChange::LivenessThreshold(_) => {
+                    is_pos_changed = true
+                }
+            },
+            ParameterChange::Governance(change) => match change {
+                GovernanceParameterChange::MinProposalFund(value) => storage
+                    .write(&gov_storage::get_min_proposal_fund_key(), value)?,
+                GovernanceParameterChange::MaxProposalCodeSize(value) => {
+                    storage.write(
+                        &gov_storage::get_max_proposal_code_size_key(),
+                        value,
+                    )?
+                }
+                GovernanceParameterChange::MinProposalVotingPeriod(value) => {
+                    storage.write(
+                        &gov_storage::get_min_proposal_voting_period_key(),
+                        value,
+                    )?
+                }
+                GovernanceParameterChange::MaxProposalPeriod(value) => storage
+                    .write(&gov_storage::get_max_proposal_period_key(), value)?,
+                GovernanceParameterChange::MaxProposalContentSize(value) => {
+                    storage.write(
+                        &gov_storage::get_max_proposal_content_key(),
+                        value,
+                    )?
+                }
+                GovernanceParameterChange::MinProposalGraceEpochs(value) => {
+                    storage.write(
+                        &gov_storage::get_min_proposal_grace_epochs_key(),
+                        value,
+                    )?
+                }
+                GovernanceParameterChange::MaxProposalLatency(value) => {
+                    storage.write(
+                        &gov_storage::get_max_proposal_latency_key(),
+                        value,
+                    )?
+                }
+                GovernanceParameterChange::MinExpeditedProposalFund(value) => {
+                    storage.write(
+                        &gov_storage::get_min_expedited_proposal_fund_key(),
+                        value,
+                    )?
+                }
+                GovernanceParameterChange::MinExpeditedProposalVotingPeriod(
+                    value,
+                ) => storage.write(
+                    &gov_storage::get_min_expedited_proposal_voting_period_key(),
+                    value,
+                )?,
+                GovernanceParameterChange::ProposalCancellationRefundRate(
+                    value,
+                ) => storage.write(
+                    &gov_storage::get_proposal_cancellation_refund_rate_key(),
+                    value,
+                )?,
+                GovernanceParameterChange::VetoThreshold(value) => storage
+                    .write(&gov_storage::get_veto_threshold_key(), value)?,
+                GovernanceParameterChange::ProposalTimelockEpochs(value) => {
+                    storage.write(
+                        &gov_storage::get_proposal_timelock_epochs_key(),
+                        value,
+                    )?
+                }
+            },
+            ParameterChange::Pgf(change) => match change {
+                PgfParameterChange::PgfInflationRate(value) => storage.write(
+                    &pgf_storage::get_pgf_inflation_rate_key(),
+                    value,
+                )?,
+                PgfParameterChange::StewardsInflationRate(value) => storage
+                    .write(
+                        &pgf_storage::get_steward_inflation_rate_key(),
+                        value,
+                    )?,
+                PgfParameterChange::MaximumNumberOfStewards(value) => storage
+                    .write(
+                        &pgf_storage::get_maximum_number_of_pgf_steward_key(),
+                        value,
+                    )?,
+            },
+            ParameterChange::Ibc(change) => match change {
+                IbcParameterChange::MintLimit { token, limit } => storage
+                    .write(&ibc::storage::mint_limit_key(&token), limit)?,
+                IbcParameterChange::PerEpochThroughputLimit {
+                    token,
+                    limit,
+                } => storage
+                    .write(&ibc::storage::throughput_limit_key(&token), limit)?,
+                // The default limits are stored together
+                IbcParameterChange::DefaultMintLimit(_)
+                | IbcParameterChange::DefaultPerEpochThroughputLimit(_) => {}
+            },
+        }
+    }
+    if is_pos_changed {
+        write_pos_params_without_sub_keys(storage, &pos_params)?;
+    }
+    if let Some(ibc_params) = ibc_params {
+        ibc_params.init_storage(storage)?;
+    }
+
+    Ok(true)
+}
+
+/// Upload the wasm codes of a wasm allowlist proposal and update the
+/// allowlists. All the changes are checked before anything is written, so
+/// that they're activated together or not at all.
+fn execute_wasm_allowlist_proposal<S>(
+    storage: &mut S,
+    proposal_id: u64,
+    changes: BTreeSet<AddRemove<WasmAllowlistEntry>>,
+) -> Result<bool>
+where
+    S: StorageRead + StorageWrite,
+{
+    let protocol_params = parameters::read(storage)?;
+    let mut tx_allowlist = protocol_params.tx_allowlist;
+    let mut vp_allowlist = protocol_params.vp_allowlist;
+    let mut codes = vec![];
+
+    // Additions are ordered before removals, so a code can be replaced
+    // without the allowlist ever becoming empty
+    for change in changes {
+        match change {
+            AddRemove::Add(entry) => {
+                let Some(code) = gov_api::get_proposal_wasm_code(
+                    storage,
+                    proposal_id,
+                    &entry.code_hash,
+                )?
+                else {
+                    tracing::warn!(
+                        "Missing the code of {} wasm {} added by the proposal",
+                        entry.allowlist,
+                        entry.code_hash
+                    );
+                    return Ok(false);
+                };
+                if Hash::sha256(&code) != entry.code_hash {
+                    tracing::warn!(
+                        "The uploaded code of {} wasm {} doesn't match its \
+                         hash",
+                        entry.allowlist,
+                        entry.code_hash
+                    );
+                    return Ok(false);
+                }
+                let allowlist = match entry.allowlist {
+                    WasmAllowlist::Tx => &mut tx_allowlist,
+                    WasmAllowlist::Vp => &mut vp_allowlist,
+                };
+                // An empty allowlist allows any code, in which case the code
+                // is only uploaded
+                let code_hash = entry.code_hash.to_string().to_lowercase();
+                if !allowlist.is_empty() && !allowlist.contains(&code_hash) {
+                    allowlist.push(code_hash);
+                }
+                codes.push((entry, code));
+            }
+            AddRemove::Remove(entry) => {
+                let allowlist = match entry.allowlist {
+                    WasmAllowlist::Tx => &mut tx_allowlist,
+                    WasmAllowlist::Vp => &mut vp_allowlist,
+                };
+                let code_hash = entry.code_hash.to_string().to_lowercase();
+                let Some(position) =
+                    allowlist.iter().position(|hash| hash == &code_hash)
+                else {
+                    tracing::warn!(
+                        "{} wasm {} can't be removed as it isn't in the \
+                         allowlist",
+                        entry.allowlist,
+                        entry.code_hash
+                    );
+                    return Ok(false);
+                };
+                allowlist.remove(position);
+                if allowlist.is_empty() {
+                    tracing::warn!(
+                        "Removing {} wasm {} would empty the allowlist and \
+                         allow any {} wasm",
+                        entry.allowlist,
+                        entry.code_hash,
+                        entry.allowlist
+                    );
+                    return Ok(false);
+                }
+            }
+        }
+    }
+
+    for (entry, code) in codes {
+        let code_len = u64::try_from(code.len())
+            .map_err(|err| Error::BadProposal(proposal_id, err.to_string()))?;
+        storage.write(&Key::wasm_code(&entry.code_hash), code)?;
+        storage.write(&Key::wasm_code_len(&entry.code_hash), code_len)?;
+        if let Some(name) = entry.name {
+            storage.write(&Key::wasm_hash(&name), entry.code_hash)?;
+            storage.write(&Key::wasm_code_name(name), entry.code_hash)?;
+        }
+    }
+    parameters::update_tx_allowlist_parameter(storage, tx_allowlist)?;
+    parameters::update_vp_allowlist_parameter(storage, vp_allowlist)?;
+
+    Ok(true)
+}
+
 fn execute_pgf_funding_proposal<D, H>(
     state: &mut WlState<D, H>,
     events: &mut impl EmitEvents,
@@ -612,3 +1580,1034 @@ where
 
     Ok(true)
 }
+
+#[cfg(test)]
+mod test {
+    use namada_sdk::address;
+    use namada_sdk::governance::event::types as gov_event_types;
+    use namada_sdk::governance::parameters::GovernanceParameters;
+    use namada_sdk::governance::storage::proposal::{
+        CancelProposalData, DelegateVoteData, InitProposalData,
+        RepresentationScope, VetoProposalData, VoteProposalData,
+    };
+    use namada_sdk::governance::utils::ProposalStage;
+    use namada_sdk::proof_of_stake::storage::read_pos_params;
+
+    use super::*;
+    use crate::shell::test_utils::setup;
+
+    /// Test that the changes of a parameter change proposal are applied
+    /// together, and not at all if any resulting parameter is invalid or
+    /// missing from storage
+    #[test]
+    fn test_execute_parameter_change_proposal() {
+        let (mut shell, _broadcaster, _, _eth_control) = setup();
+        let inflation_rate = Dec::new(5, 2).unwrap();
+
+        let changes = BTreeSet::from([
+            ParameterChange::Pos(PosParameterChange::MaxInflationRate(
+                inflation_rate,
+            )),
+            ParameterChange::Governance(
+                GovernanceParameterChange::MaxProposalLatency(10),
+            ),
+            ParameterChange::Pgf(PgfParameterChange::MaximumNumberOfStewards(
+                7,
+            )),
+            ParameterChange::Protocol(ProtocolParameterChange::MaxBlockGas(
+                1_000,
+            )),
+        ]);
+        assert!(execute_parameter_change_proposal(&mut shell.state, changes)
+            .unwrap());
+        let pos_params = read_owned_pos_params(&shell.state).unwrap();
+        assert_eq!(pos_params.max_inflation_rate, inflation_rate);
+        let gov_params = gov_api::get_parameters(&shell.state).unwrap();
+        assert_eq!(gov_params.max_proposal_latency, 10);
+        let maximum_number_of_stewards: u64 = shell
+            .state
+            .read(&pgf_storage::get_maximum_number_of_pgf_steward_key())
+            .unwrap()
+            .unwrap();
+        assert_eq!(maximum_number_of_stewards, 7);
+        let protocol_params = parameters::read(&shell.state).unwrap();
+        assert_eq!(protocol_params.max_block_gas, 1_000);
+
+        // Too many validator slots for Tendermint's maximum voting power
+        let changes = BTreeSet::from([
+            ParameterChange::Governance(
+                GovernanceParameterChange::MaxProposalLatency(20),
+            ),
+            ParameterChange::Pos(PosParameterChange::MaxValidatorSlots(
+                u64::MAX,
+            )),
+        ]);
+        assert!(
+            !execute_parameter_change_proposal(&mut shell.state, changes)
+                .unwrap()
+        );
+        let gov_params = gov_api::get_parameters(&shell.state).unwrap();
+        assert_eq!(gov_params.max_proposal_latency, 10);
+        let new_pos_params = read_owned_pos_params(&shell.state).unwrap();
+        assert_eq!(
+            new_pos_params.max_validator_slots,
+            pos_params.max_validator_slots
+        );
+
+        // The default IBC limits can't be changed without the IBC parameters
+        shell.state.delete(&ibc::storage::params_key()).unwrap();
+        let token = address::testing::nam();
+        let limit = token::Amount::native_whole(100);
+        let changes = BTreeSet::from([
+            ParameterChange::Ibc(IbcParameterChange::DefaultMintLimit(limit)),
+            ParameterChange::Ibc(IbcParameterChange::MintLimit {
+                token: token.clone(),
+                limit,
+            }),
+        ]);
+        assert!(
+            !execute_parameter_change_proposal(&mut shell.state, changes)
+                .unwrap()
+        );
+        let mint_limit_key = ibc::storage::mint_limit_key(&token);
+        assert!(!shell.state.has_key(&mint_limit_key).unwrap());
+
+        // The limit of a token is stored under its own key
+        let changes = BTreeSet::from([ParameterChange::Ibc(
+            IbcParameterChange::MintLimit {
+                token: token.clone(),
+                limit,
+            },
+        )]);
+        assert!(execute_parameter_change_proposal(&mut shell.state, changes)
+            .unwrap());
+        let mint_limit: token::Amount =
+            shell.state.read(&mint_limit_key).unwrap().unwrap();
+        assert_eq!(mint_limit, limit);
+    }
+
+    /// Test that the governance data of a chain that predates expedited
+    /// proposals can still be read
+    #[test]
+    fn test_pre_expedited_governance_data() {
+        let (mut shell, _broadcaster, _, _eth_control) = setup();
+        shell
+            .state
+            .delete(&gov_storage::get_min_expedited_proposal_fund_key())
+            .unwrap();
+        shell
+            .state
+            .delete(&gov_storage::get_min_expedited_proposal_voting_period_key())
+            .unwrap();
+        let gov_params = gov_api::get_parameters(&shell.state).unwrap();
+        let default_params = GovernanceParameters::default();
+        assert_eq!(
+            gov_params.min_expedited_proposal_fund,
+            default_params.min_expedited_proposal_fund
+        );
+        assert_eq!(
+            gov_params.min_expedited_proposal_voting_period,
+            default_params.min_expedited_proposal_voting_period
+        );
+
+        // Tx data encoded without the expedited flag
+        let proposal = InitProposalData {
+            content: Hash::default(),
+            author: address::testing::established_address_1(),
+            r#type: ProposalType::Default,
+            voting_start_epoch: Epoch(3),
+            voting_end_epoch: Epoch(6),
+            activation_epoch: Epoch(12),
+            is_expedited: false,
+        };
+        let mut data = proposal.serialize_to_vec();
+        assert_eq!(data.pop(), Some(0));
+        assert_eq!(InitProposalData::try_from_slice(&data).unwrap(), proposal);
+    }
+
+    /// Test that the governance data of a chain that predates `NoWithVeto`
+    /// votes can still be read
+    #[test]
+    fn test_pre_veto_governance_data() {
+        let (mut shell, _broadcaster, _, _eth_control) = setup();
+        shell
+            .state
+            .delete(&gov_storage::get_proposal_cancellation_refund_rate_key())
+            .unwrap();
+        shell
+            .state
+            .delete(&gov_storage::get_veto_threshold_key())
+            .unwrap();
+        let gov_params = gov_api::get_parameters(&shell.state).unwrap();
+        let default_params = GovernanceParameters::default();
+        assert_eq!(
+            gov_params.proposal_cancellation_refund_rate,
+            default_params.proposal_cancellation_refund_rate
+        );
+        assert_eq!(gov_params.veto_threshold, default_params.veto_threshold);
+
+        // A result stored without the veto power
+        let id = 0;
+        let (total, yay, nay, abstain) = (
+            VotePower::from(100_u64),
+            VotePower::from(70_u64),
+            VotePower::from(20_u64),
+            VotePower::from(10_u64),
+        );
+        shell
+            .state
+            .write(
+                &gov_storage::get_proposal_result_key(id),
+                (
+                    TallyResult::Passed,
+                    TallyType::OneHalfOverOneThird,
+                    total,
+                    yay,
+                    nay,
+                    abstain,
+                ),
+            )
+            .unwrap();
+        let proposal_result = gov_api::get_proposal_result(&shell.state, id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(proposal_result.result, TallyResult::Passed);
+        assert_eq!(proposal_result.total_nay_power, nay);
+        assert_eq!(proposal_result.total_veto_power, VotePower::zero());
+        assert_eq!(proposal_result.total_abstain_power, abstain);
+    }
+
+    /// Test that the uploaded codes of a wasm allowlist proposal are written
+    /// together with the allowlist changes, and nothing is written if a
+    /// change can't be applied
+    #[test]
+    fn test_execute_wasm_allowlist_proposal() {
+        let (mut shell, _broadcaster, _, _eth_control) = setup();
+        let proposal_id = 0;
+        let old_tx_hash = Hash::sha256(b"old tx_transfer");
+        parameters::update_tx_allowlist_parameter(
+            &mut shell.state,
+            vec![old_tx_hash.to_string()],
+        )
+        .unwrap();
+        parameters::update_vp_allowlist_parameter(&mut shell.state, vec![])
+            .unwrap();
+
+        let tx_code = b"new tx_transfer".to_vec();
+        let tx_hash = Hash::sha256(&tx_code);
+        let vp_code = b"new vp".to_vec();
+        let vp_hash = Hash::sha256(&vp_code);
+        gov_api::init_proposal_wasm_codes(
+            &mut shell.state,
+            proposal_id,
+            vec![tx_code.clone(), vp_code.clone()],
+        )
+        .unwrap();
+
+        // Replace the tx code and upload a vp while the vp allowlist is empty
+        let changes = BTreeSet::from([
+            AddRemove::Add(WasmAllowlistEntry {
+                allowlist: WasmAllowlist::Tx,
+                code_hash: tx_hash,
+                name: Some("tx_transfer.wasm".to_string()),
+            }),
+            AddRemove::Remove(WasmAllowlistEntry {
+                allowlist: WasmAllowlist::Tx,
+                code_hash: old_tx_hash,
+                name: None,
+            }),
+            AddRemove::Add(WasmAllowlistEntry {
+                allowlist: WasmAllowlist::Vp,
+                code_hash: vp_hash,
+                name: None,
+            }),
+        ]);
+        assert!(execute_wasm_allowlist_proposal(
+            &mut shell.state,
+            proposal_id,
+            changes
+        )
+        .unwrap());
+        let protocol_params = parameters::read(&shell.state).unwrap();
+        assert_eq!(
+            protocol_params.tx_allowlist,
+            vec![tx_hash.to_string().to_lowercase()]
+        );
+        assert!(protocol_params.vp_allowlist.is_empty());
+        let code: Vec<u8> = shell
+            .state
+            .read(&Key::wasm_code(&tx_hash))
+            .unwrap()
+            .unwrap();
+        assert_eq!(code, tx_code);
+        let code: Vec<u8> = shell
+            .state
+            .read(&Key::wasm_code(&vp_hash))
+            .unwrap()
+            .unwrap();
+        assert_eq!(code, vp_code);
+        let named_hash: Hash = shell
+            .state
+            .read(&Key::wasm_hash("tx_transfer.wasm"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(named_hash, tx_hash);
+
+        // Removing the last code of the allowlist would allow any tx
+        let changes = BTreeSet::from([AddRemove::Remove(WasmAllowlistEntry {
+            allowlist: WasmAllowlist::Tx,
+            code_hash: tx_hash,
+            name: None,
+        })]);
+        assert!(!execute_wasm_allowlist_proposal(
+            &mut shell.state,
+            proposal_id,
+            changes
+        )
+        .unwrap());
+
+        // The code of an addition must have been uploaded
+        let missing_hash = Hash::sha256(b"missing");
+        let changes = BTreeSet::from([
+            AddRemove::Add(WasmAllowlistEntry {
+                allowlist: WasmAllowlist::Tx,
+                code_hash: missing_hash,
+                name: None,
+            }),
+            AddRemove::Add(WasmAllowlistEntry {
+                allowlist: WasmAllowlist::Tx,
+                code_hash: vp_hash,
+                name: None,
+            }),
+        ]);
+        assert!(!execute_wasm_allowlist_proposal(
+            &mut shell.state,
+            proposal_id,
+            changes
+        )
+        .unwrap());
+        let new_protocol_params = parameters::read(&shell.state).unwrap();
+        assert_eq!(
+            new_protocol_params.tx_allowlist,
+            protocol_params.tx_allowlist
+        );
+    }
+
+    /// Test that an expedited proposal which doesn't pass its stricter tally
+    /// is converted into a standard proposal instead of being rejected
+    #[test]
+    fn test_expedited_proposal_fallback() {
+        let (mut shell, _broadcaster, _, _eth_control) = setup();
+        let gov_params = gov_api::get_parameters(&shell.state).unwrap();
+        let validator = shell.mode.get_validator_address().unwrap().clone();
+        let balance_key = token::storage_key::balance_key(
+            &shell.state.in_mem().native_token,
+            &validator,
+        );
+        shell
+            .state
+            .write(&balance_key, gov_params.min_expedited_proposal_fund)
+            .unwrap();
+
+        let proposal = InitProposalData {
+            content: Hash::default(),
+            author: validator.clone(),
+            r#type: ProposalType::Default,
+            voting_start_epoch: Epoch(0),
+            voting_end_epoch: Epoch(1),
+            activation_epoch: Epoch(5),
+            is_expedited: true,
+        };
+        let id = gov_api::init_proposal::<_, token::Store<_>>(
+            &mut shell.state,
+            &proposal,
+            vec![],
+            None,
+        )
+        .unwrap();
+        assert!(gov_api::is_proposal_expedited(&shell.state, id).unwrap());
+        // The expedited tally happens right after the voting period ends
+        assert_eq!(
+            load_proposals(&shell.state, Epoch(2)).unwrap(),
+            BTreeSet::from([id])
+        );
+        assert_eq!(
+            load_proposals(&shell.state, Epoch(5)).unwrap(),
+            BTreeSet::new()
+        );
+
+        // Nobody voted, so the expedited tally can't pass
+        let mut events = vec![];
+        let result = execute_governance_proposals(
+            &mut shell,
+            &mut events,
+            Epoch(2),
+            BTreeSet::from([id]),
+        )
+        .unwrap();
+        assert!(result.passed.is_empty());
+        assert!(result.rejected.is_empty());
+        assert!(events.is_empty());
+
+        let stored = gov_api::get_proposal_by_id(&shell.state, id)
+            .unwrap()
+            .unwrap();
+        let voting_end_epoch =
+            Epoch(gov_params.min_proposal_voting_period).max(Epoch(3));
+        let activation_epoch =
+            Epoch(voting_end_epoch.0 + gov_params.min_proposal_grace_epochs);
+        assert!(!stored.is_expedited);
+        assert_eq!(stored.voting_end_epoch, voting_end_epoch);
+        assert_eq!(stored.activation_epoch, activation_epoch);
+        assert!(gov_api::get_proposal_result(&shell.state, id)
+            .unwrap()
+            .is_none());
+        assert_eq!(
+            load_proposals(&shell.state, Epoch(2)).unwrap(),
+            BTreeSet::new()
+        );
+        assert_eq!(
+            load_proposals(&shell.state, activation_epoch).unwrap(),
+            BTreeSet::from([id])
+        );
+    }
+
+    /// Test that an expedited proposal which passes its tally is executed at
+    /// the end of its voting period, before its activation epoch
+    #[test]
+    fn test_expedited_proposal_early_execution() {
+        let (mut shell, _broadcaster, _, _eth_control) = setup();
+        let gov_params = gov_api::get_parameters(&shell.state).unwrap();
+        let validator = shell.mode.get_validator_address().unwrap().clone();
+        let balance_key = token::storage_key::balance_key(
+            &shell.state.in_mem().native_token,
+            &validator,
+        );
+        shell
+            .state
+            .write(&balance_key, gov_params.min_expedited_proposal_fund)
+            .unwrap();
+
+        let proposal = InitProposalData {
+            content: Hash::default(),
+            author: validator.clone(),
+            r#type: ProposalType::Default,
+            voting_start_epoch: Epoch(0),
+            voting_end_epoch: Epoch(1),
+            activation_epoch: Epoch(5),
+            is_expedited: true,
+        };
+        let id = gov_api::init_proposal::<_, token::Store<_>>(
+            &mut shell.state,
+            &proposal,
+            vec![],
+            None,
+        )
+        .unwrap();
+        gov_api::vote_proposal(
+            &mut shell.state,
+            VoteProposalData {
+                id,
+                vote: ProposalVote::Yay,
+                voter: validator.clone(),
+            },
+            [validator.clone()].into_iter().collect(),
+        )
+        .unwrap();
+
+        let proposals = load_proposals(&shell.state, Epoch(2)).unwrap();
+        assert_eq!(proposals, BTreeSet::from([id]));
+        let mut events = vec![];
+        let result = execute_governance_proposals(
+            &mut shell,
+            &mut events,
+            Epoch(2),
+            proposals,
+        )
+        .unwrap();
+        assert_eq!(result.passed, vec![id]);
+        assert!(result.rejected.is_empty());
+        let proposal_result = gov_api::get_proposal_result(&shell.state, id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(proposal_result.result, TallyResult::Passed);
+        assert!(gov_api::is_proposal_expedited(&shell.state, id).unwrap());
+    }
+
+    /// Test that the deposit of a cancelled proposal is partially refunded
+    /// to its author and that the proposal is not tallied
+    #[test]
+    fn test_cancelled_proposal_settlement() {
+        let (mut shell, _broadcaster, _, _eth_control) = setup();
+        let gov_params = gov_api::get_parameters(&shell.state).unwrap();
+        let native_token = shell.state.in_mem().native_token.clone();
+        let validator = shell.mode.get_validator_address().unwrap().clone();
+        let balance_key =
+            token::storage_key::balance_key(&native_token, &validator);
+        shell
+            .state
+            .write(&balance_key, gov_params.min_proposal_fund)
+            .unwrap();
+
+        let proposal = InitProposalData {
+            content: Hash::default(),
+            author: validator.clone(),
+            r#type: ProposalType::Default,
+            voting_start_epoch: Epoch(1),
+            voting_end_epoch: Epoch(2),
+            activation_epoch: Epoch(3),
+            is_expedited: false,
+        };
+        let id = gov_api::init_proposal::<_, token::Store<_>>(
+            &mut shell.state,
+            &proposal,
+            vec![],
+            None,
+        )
+        .unwrap();
+        gov_api::cancel_proposal(
+            &mut shell.state,
+            CancelProposalData {
+                id,
+                author: validator.clone(),
+            },
+        )
+        .unwrap();
+
+        let mut events = vec![];
+        let result = execute_governance_proposals(
+            &mut shell,
+            &mut events,
+            Epoch(3),
+            BTreeSet::from([id]),
+        )
+        .unwrap();
+        assert!(result.passed.is_empty());
+        assert!(result.rejected.is_empty());
+        // The token and governance events of the refund and the burn, and the
+        // cancellation event
+        assert_eq!(events.len(), 5);
+        assert!(gov_api::get_proposal_result(&shell.state, id)
+            .unwrap()
+            .is_none());
+
+        let refund = gov_params
+            .min_proposal_fund
+            .mul_floor(gov_params.proposal_cancellation_refund_rate)
+            .unwrap();
+        assert_eq!(
+            read_balance(&shell.state, &native_token, &validator).unwrap(),
+            refund
+        );
+        assert!(read_balance(&shell.state, &native_token, &gov_address)
+            .unwrap()
+            .is_zero());
+
+        let lifecycle =
+            gov_api::get_proposal_lifecycle(&shell.state, id, Epoch(3))
+                .unwrap()
+                .unwrap();
+        assert_eq!(lifecycle.stage, ProposalStage::Cancelled);
+        let settlement = lifecycle.settlement.unwrap();
+        assert_eq!(settlement.execution, None);
+        assert_eq!(settlement.refunded, refund);
+        assert_eq!(
+            settlement.burned,
+            checked!(gov_params.min_proposal_fund - refund).unwrap()
+        );
+    }
+
+    /// Test the lifecycle events and record of a proposal that nobody voted
+    /// on
+    #[test]
+    fn test_rejected_proposal_lifecycle() {
+        let (mut shell, _broadcaster, _, _eth_control) = setup();
+        let gov_params = gov_api::get_parameters(&shell.state).unwrap();
+        let validator = shell.mode.get_validator_address().unwrap().clone();
+        let balance_key = token::storage_key::balance_key(
+            &shell.state.in_mem().native_token,
+            &validator,
+        );
+        shell
+            .state
+            .write(&balance_key, gov_params.min_proposal_fund)
+            .unwrap();
+
+        let proposal = InitProposalData {
+            content: Hash::default(),
+            author: validator.clone(),
+            r#type: ProposalType::Default,
+            voting_start_epoch: Epoch(1),
+            voting_end_epoch: Epoch(2),
+            activation_epoch: Epoch(3),
+            is_expedited: false,
+        };
+        let id = gov_api::init_proposal::<_, token::Store<_>>(
+            &mut shell.state,
+            &proposal,
+            vec![],
+            None,
+        )
+        .unwrap();
+
+        for (epoch, stage) in [
+            (0, ProposalStage::Pending),
+            (1, ProposalStage::Voting),
+            (2, ProposalStage::AwaitingTally),
+        ] {
+            let lifecycle =
+                gov_api::get_proposal_lifecycle(&shell.state, id, Epoch(epoch))
+                    .unwrap()
+                    .unwrap();
+            assert_eq!(lifecycle.stage, stage);
+        }
+        assert_eq!(
+            gov_api::load_proposals_starting_voting(&shell.state, Epoch(1))
+                .unwrap(),
+            BTreeSet::from([id])
+        );
+
+        let mut events = vec![];
+        let result = execute_governance_proposals(
+            &mut shell,
+            &mut events,
+            Epoch(3),
+            BTreeSet::from([id]),
+        )
+        .unwrap();
+        assert_eq!(result.rejected, vec![id]);
+        let event_types: Vec<_> =
+            events.iter().map(|event| event.kind().clone()).collect();
+        assert_eq!(
+            event_types,
+            vec![
+                gov_event_types::PROPOSAL_TALLIED,
+                gov_event_types::PROPOSAL_REJECTED,
+                token::event::types::BURN,
+                gov_event_types::PROPOSAL_FUNDS_BURNED,
+            ]
+        );
+
+        let lifecycle =
+            gov_api::get_proposal_lifecycle(&shell.state, id, Epoch(3))
+                .unwrap()
+                .unwrap();
+        assert_eq!(lifecycle.stage, ProposalStage::Rejected);
+        assert!(matches!(
+            lifecycle.result.map(|result| result.result),
+            Some(TallyResult::Rejected)
+        ));
+        let settlement = lifecycle.settlement.unwrap();
+        assert_eq!(settlement.epoch, Epoch(3));
+        assert_eq!(settlement.execution, None);
+        assert!(settlement.refunded.is_zero());
+        assert_eq!(settlement.burned, gov_params.min_proposal_fund);
+    }
+
+    /// Test that passed proposals are not delayed on a chain that predates
+    /// the timelock queue
+    #[test]
+    fn test_pre_timelock_governance_params() {
+        let (mut shell, _broadcaster, _, _eth_control) = setup();
+        shell
+            .state
+            .delete(&gov_storage::get_proposal_timelock_epochs_key())
+            .unwrap();
+        let gov_params = gov_api::get_parameters(&shell.state).unwrap();
+        assert_eq!(gov_params.proposal_timelock_epochs, 0);
+    }
+
+    /// Test that a passed proposal waits in the timelock queue, and is then
+    /// either executed or discarded if the validators vetoed it
+    #[test]
+    fn test_timelocked_proposal() {
+        for is_vetoed in [false, true] {
+            let (mut shell, _broadcaster, _, _eth_control) = setup();
+            let mut gov_params = gov_api::get_parameters(&shell.state).unwrap();
+            gov_params.proposal_timelock_epochs = 2;
+            gov_params.write_storage(&mut shell.state).unwrap();
+            let native_token = shell.state.in_mem().native_token.clone();
+            let validator = shell.mode.get_validator_address().unwrap().clone();
+            shell
+                .state
+                .write(
+                    &token::storage_key::balance_key(&native_token, &validator),
+                    gov_params.min_proposal_fund,
+                )
+                .unwrap();
+
+            let proposal = InitProposalData {
+                content: Hash::default(),
+                author: validator.clone(),
+                r#type: ProposalType::Default,
+                voting_start_epoch: Epoch(1),
+                voting_end_epoch: Epoch(2),
+                activation_epoch: Epoch(3),
+                is_expedited: false,
+            };
+            let id = gov_api::init_proposal::<_, token::Store<_>>(
+                &mut shell.state,
+                &proposal,
+                vec![],
+                None,
+            )
+            .unwrap();
+            gov_api::vote_proposal(
+                &mut shell.state,
+                VoteProposalData {
+                    id,
+                    vote: ProposalVote::Yay,
+                    voter: validator.clone(),
+                },
+                [validator.clone()].into_iter().collect(),
+            )
+            .unwrap();
+
+            // The passed proposal is queued instead of being executed
+            let mut events = vec![];
+            let result = execute_governance_proposals(
+                &mut shell,
+                &mut events,
+                Epoch(3),
+                BTreeSet::from([id]),
+            )
+            .unwrap();
+            assert!(result.passed.is_empty());
+            assert!(result.rejected.is_empty());
+            let event_types: Vec<_> =
+                events.iter().map(|event| event.kind().clone()).collect();
+            assert_eq!(
+                event_types,
+                vec![
+                    gov_event_types::PROPOSAL_TALLIED,
+                    gov_event_types::PROPOSAL_QUEUED,
+                ]
+            );
+            let lifecycle =
+                gov_api::get_proposal_lifecycle(&shell.state, id, Epoch(3))
+                    .unwrap()
+                    .unwrap();
+            assert_eq!(lifecycle.stage, ProposalStage::Queued);
+            let queue = gov_api::get_queued_proposals(&shell.state).unwrap();
+            assert_eq!(queue.len(), 1);
+            assert_eq!(queue[0].timelock.execution_epoch, Epoch(5));
+            assert_eq!(
+                read_balance(&shell.state, &native_token, &gov_address)
+                    .unwrap(),
+                gov_params.min_proposal_fund
+            );
+
+            if is_vetoed {
+                gov_api::veto_proposal(
+                    &mut shell.state,
+                    VetoProposalData {
+                        id,
+                        validator: validator.clone(),
+                    },
+                )
+                .unwrap();
+            }
+
+            assert!(gov_api::load_timelocked_proposals(&shell.state, Epoch(4))
+                .unwrap()
+                .is_empty());
+            let timelocked_ids =
+                gov_api::load_timelocked_proposals(&shell.state, Epoch(5))
+                    .unwrap();
+            assert_eq!(timelocked_ids, BTreeSet::from([id]));
+
+            let mut events = vec![];
+            let result = execute_timelocked_proposals(
+                &mut shell,
+                &mut events,
+                Epoch(5),
+                timelocked_ids,
+            )
+            .unwrap();
+            assert!(gov_api::get_queued_proposals(&shell.state)
+                .unwrap()
+                .is_empty());
+
+            let lifecycle =
+                gov_api::get_proposal_lifecycle(&shell.state, id, Epoch(5))
+                    .unwrap()
+                    .unwrap();
+            let settlement = lifecycle.settlement.unwrap();
+            assert_eq!(settlement.epoch, Epoch(5));
+            if is_vetoed {
+                assert_eq!(result.rejected, vec![id]);
+                assert!(events.iter().any(|event| {
+                    *event.kind() == gov_event_types::PROPOSAL_TIMELOCK_VETOED
+                }));
+                assert_eq!(lifecycle.stage, ProposalStage::TimelockVetoed);
+                assert_eq!(settlement.execution, None);
+                assert_eq!(settlement.burned, gov_params.min_proposal_fund);
+            } else {
+                assert_eq!(result.passed, vec![id]);
+                assert!(events.iter().any(|event| {
+                    *event.kind() == gov_event_types::PROPOSAL_EXECUTED
+                }));
+                assert_eq!(lifecycle.stage, ProposalStage::Executed);
+                assert_eq!(settlement.execution, Some(true));
+                assert_eq!(settlement.refunded, gov_params.min_proposal_fund);
+            }
+        }
+    }
+
+    /// Test that a delegator is tallied with the vote of the end of its
+    /// representation chain, unless it votes directly
+    #[test]
+    fn test_compute_represented_votes() {
+        let (mut shell, _broadcaster, _, _eth_control) = setup();
+        let native_token = shell.state.in_mem().native_token.clone();
+        let validator = shell.mode.get_validator_address().unwrap().clone();
+        let delegator = address::testing::established_address_1();
+        let first_representative = address::testing::established_address_2();
+        let second_representative = address::testing::established_address_3();
+        let params = read_pos_params(&shell.state).unwrap();
+        let gov_params = gov_api::get_parameters(&shell.state).unwrap();
+        shell
+            .state
+            .write(
+                &token::storage_key::balance_key(&native_token, &validator),
+                gov_params.min_proposal_fund,
+            )
+            .unwrap();
+
+        let bond = token::Amount::native_whole(100);
+        let balance_key =
+            token::storage_key::balance_key(&native_token, &delegator);
+        shell.state.write(&balance_key, bond).unwrap();
+        namada_sdk::proof_of_stake::bond_tokens(
+            &mut shell.state,
+            Some(&delegator),
+            &validator,
+            bond,
+            Epoch(0),
+            None,
+        )
+        .unwrap();
+        let epoch = Epoch(params.pipeline_len);
+
+        let proposal = InitProposalData {
+            content: Hash::default(),
+            author: validator.clone(),
+            r#type: ProposalType::Default,
+            voting_start_epoch: Epoch(0),
+            voting_end_epoch: epoch,
+            activation_epoch: epoch.next(),
+            is_expedited: false,
+        };
+        let id = gov_api::init_proposal::<_, token::Store<_>>(
+            &mut shell.state,
+            &proposal,
+            vec![],
+            None,
+        )
+        .unwrap();
+
+        // The delegator is represented through a chain of representatives
+        for (delegator, representative, scope) in [
+            (&delegator, &first_representative, RepresentationScope::All),
+            (
+                &first_representative,
+                &second_representative,
+                RepresentationScope::Default,
+            ),
+        ] {
+            gov_api::delegate_vote(
+                &mut shell.state,
+                DelegateVoteData {
+                    delegator: delegator.clone(),
+                    representative: Some(representative.clone()),
+                    scope,
+                },
+            )
+            .unwrap();
+        }
+        gov_api::vote_proposal(
+            &mut shell.state,
+            VoteProposalData {
+                id,
+                vote: ProposalVote::Yay,
+                voter: second_representative.clone(),
+            },
+            Default::default(),
+        )
+        .unwrap();
+
+        let votes =
+            compute_proposal_votes(&shell.state, &params, id, epoch).unwrap();
+        assert!(matches!(
+            votes.delegators_vote.get(&delegator),
+            Some(ProposalVote::Yay)
+        ));
+        assert_eq!(
+            votes.delegator_voting_power[&delegator].get(&validator),
+            Some(&bond)
+        );
+        // The representatives have no bonds, so they have no voting power
+        assert!(!votes.delegators_vote.contains_key(&second_representative));
+
+        // A direct vote overrides the delegation
+        gov_api::vote_proposal(
+            &mut shell.state,
+            VoteProposalData {
+                id,
+                vote: ProposalVote::Nay,
+                voter: delegator.clone(),
+            },
+            [validator.clone()].into_iter().collect(),
+        )
+        .unwrap();
+        let votes =
+            compute_proposal_votes(&shell.state, &params, id, epoch).unwrap();
+        assert!(matches!(
+            votes.delegators_vote.get(&delegator),
+            Some(ProposalVote::Nay)
+        ));
+        assert_eq!(
+            votes.delegator_voting_power[&delegator].get(&validator),
+            Some(&bond)
+        );
+
+        // Changing the representative moves the delegator in the index of the
+        // represented accounts
+        gov_api::delegate_vote(
+            &mut shell.state,
+            DelegateVoteData {
+                delegator: delegator.clone(),
+                representative: Some(second_representative.clone()),
+                scope: RepresentationScope::All,
+            },
+        )
+        .unwrap();
+        assert!(gov_api::get_represented_accounts(
+            &shell.state,
+            &first_representative
+        )
+        .unwrap()
+        .is_empty());
+        assert_eq!(
+            gov_api::get_represented_accounts(
+                &shell.state,
+                &second_representative
+            )
+            .unwrap(),
+            BTreeSet::from([delegator, first_representative])
+        );
+    }
+
+    /// Test that changing the representative after the end of the voting
+    /// period, once the votes are known, doesn't change the tally
+    #[test]
+    fn test_represented_votes_after_voting_period() {
+        let (mut shell, _broadcaster, _, _eth_control) = setup();
+        let native_token = shell.state.in_mem().native_token.clone();
+        let validator = shell.mode.get_validator_address().unwrap().clone();
+        let delegator = address::testing::established_address_1();
+        let yay_representative = address::testing::established_address_2();
+        let nay_representative = address::testing::established_address_3();
+        let params = read_pos_params(&shell.state).unwrap();
+        let gov_params = gov_api::get_parameters(&shell.state).unwrap();
+        shell
+            .state
+            .write(
+                &token::storage_key::balance_key(&native_token, &validator),
+                gov_params.min_proposal_fund,
+            )
+            .unwrap();
+
+        let bond = token::Amount::native_whole(100);
+        let balance_key =
+            token::storage_key::balance_key(&native_token, &delegator);
+        shell.state.write(&balance_key, bond).unwrap();
+        namada_sdk::proof_of_stake::bond_tokens(
+            &mut shell.state,
+            Some(&delegator),
+            &validator,
+            bond,
+            Epoch(0),
+            None,
+        )
+        .unwrap();
+        let voting_end_epoch = Epoch(params.pipeline_len);
+
+        let proposal = InitProposalData {
+            content: Hash::default(),
+            author: validator.clone(),
+            r#type: ProposalType::Default,
+            voting_start_epoch: Epoch(0),
+            voting_end_epoch,
+            activation_epoch: Epoch(voting_end_epoch.0 + 2),
+            is_expedited: false,
+        };
+        let id = gov_api::init_proposal::<_, token::Store<_>>(
+            &mut shell.state,
+            &proposal,
+            vec![],
+            None,
+        )
+        .unwrap();
+
+        gov_api::delegate_vote(
+            &mut shell.state,
+            DelegateVoteData {
+                delegator: delegator.clone(),
+                representative: Some(yay_representative.clone()),
+                scope: RepresentationScope::All,
+            },
+        )
+        .unwrap();
+        for (voter, vote) in [
+            (&yay_representative, ProposalVote::Yay),
+            (&nay_representative, ProposalVote::Nay),
+        ] {
+            gov_api::vote_proposal(
+                &mut shell.state,
+                VoteProposalData {
+                    id,
+                    vote,
+                    voter: voter.clone(),
+                },
+                Default::default(),
+            )
+            .unwrap();
+        }
+
+        // During the grace period, the delegator switches to the
+        // representative who voted the other way
+        shell.state.in_mem_mut().block.epoch = voting_end_epoch.next();
+        gov_api::delegate_vote(
+            &mut shell.state,
+            DelegateVoteData {
+                delegator: delegator.clone(),
+                representative: Some(nay_representative.clone()),
+                scope: RepresentationScope::All,
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            gov_api::get_represented_accounts(
+                &shell.state,
+                &nay_representative
+            )
+            .unwrap(),
+            BTreeSet::from([delegator.clone()])
+        );
+
+        // The proposal is still tallied with the representative at the end
+        // of its voting period
+        let votes =
+            compute_proposal_votes(&shell.state, &params, id, voting_end_epoch)
+                .unwrap();
+        assert!(matches!(
+            votes.delegators_vote.get(&delegator),
+            Some(ProposalVote::Yay)
+        ));
+        assert_eq!(
+            votes.delegator_voting_power[&delegator].get(&validator),
+            Some(&bond)
+        );
+    }
+}
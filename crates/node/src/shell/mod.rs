@@ -536,6 +536,8 @@ where
                             keys: ValidatorKeys {
                                 protocol_keypair,
                                 eth_bridge_keypair,
+                                next_protocol_keypair: None,
+                                next_eth_bridge_keypair: None,
                             },
                         },
                         broadcast_sender,
@@ -610,6 +612,7 @@ where
             blocks_between_snapshots: config.shell.blocks_between_snapshots,
         };
         shell.update_eth_oracle(&Default::default());
+        shell.update_validator_keys();
         shell
     }
 
@@ -964,6 +967,53 @@ where
         }
     }
 
+    /// If this node is a validator with rotated protocol or Ethereum bridge
+    /// keypairs in its wallet, start signing with them once their public keys
+    /// have become active on chain.
+    ///
+    /// This method must be safe to call before ABCI `InitChain` has been
+    /// called, in which case nothing is updated.
+    fn update_validator_keys(&mut self) {
+        let ShellMode::Validator { data, .. } = &mut self.mode else {
+            return;
+        };
+        if data.keys.next_protocol_keypair.is_none()
+            && data.keys.next_eth_bridge_keypair.is_none()
+        {
+            return;
+        }
+        let Ok(params) = read_pos_params(&self.state) else {
+            tracing::debug!(
+                "Not updating validator keys as storage has not been \
+                 initialized"
+            );
+            return;
+        };
+        let (current_epoch, _gas) = self.state.in_mem().get_current_epoch();
+        let protocol_pk =
+            proof_of_stake::storage::validator_protocol_key_handle(
+                &data.address,
+            )
+            .get(&self.state, current_epoch, &params);
+        let eth_bridge_pk =
+            proof_of_stake::storage::validator_eth_hot_key_handle(
+                &data.address,
+            )
+            .get(&self.state, current_epoch, &params);
+        let (Ok(Some(protocol_pk)), Ok(Some(eth_bridge_pk))) =
+            (protocol_pk, eth_bridge_pk)
+        else {
+            return;
+        };
+        if data.keys.activate_next_keys(&protocol_pk, &eth_bridge_pk) {
+            tracing::info!(
+                %current_epoch,
+                "Switched to the rotated validator protocol or Ethereum \
+                 bridge keys"
+            );
+        }
+    }
+
     /// Validate a transaction request. On success, the transaction will
     /// included in the mempool and propagated to peers, otherwise it will be
     /// rejected.
@@ -3,7 +3,7 @@
 use namada_sdk::queries::{RequestCtx, ResponseQuery, RPC};
 
 use super::*;
-use crate::dry_run_tx;
+use crate::{dry_run_proposal, dry_run_tx};
 
 impl<D, H> Shell<D, H>
 where
@@ -28,6 +28,14 @@ where
                 self.tx_wasm_cache.read_only(),
                 &query,
             )
+        } else if query.path == RPC.shell().dry_run_proposal_path() {
+            dry_run_proposal(
+                // Safe for the same reasons as the dry-run of a tx above
+                unsafe { self.state.read_only().with_static_temp_write_log() },
+                self.vp_wasm_cache.read_only(),
+                self.tx_wasm_cache.read_only(),
+                &query,
+            )
         } else {
             let ctx = RequestCtx {
                 state: self.state.read_only(),
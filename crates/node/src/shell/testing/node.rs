@@ -61,7 +61,7 @@ use crate::shims::abcipp_shim_types::shim::request::{
     FinalizeBlock, ProcessedTx,
 };
 use crate::shims::abcipp_shim_types::shim::response::TxResult;
-use crate::{dry_run_tx, storage};
+use crate::{dry_run_proposal, dry_run_tx, storage};
 
 /// Mock Ethereum oracle used for testing purposes.
 struct MockEthOracle {
@@ -754,6 +754,16 @@ impl<'a> Client for &'a MockNode {
                 borrowed.tx_wasm_cache.read_only(),
                 &request,
             )
+        } else if request.path == RPC.shell().dry_run_proposal_path() {
+            dry_run_proposal(
+                // Safe for the same reasons as the dry-run of a tx above
+                unsafe {
+                    borrowed.state.read_only().with_static_temp_write_log()
+                },
+                borrowed.vp_wasm_cache.read_only(),
+                borrowed.tx_wasm_cache.read_only(),
+                &request,
+            )
         } else {
             let ctx = RequestCtx {
                 state: &borrowed.state,
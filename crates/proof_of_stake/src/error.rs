@@ -46,6 +46,16 @@ pub enum BondError {
     InactiveValidator(Address),
     #[error("Voting power overflow: {0}")]
     VotingPowerOverflow(TryFromIntError),
+    #[error(
+        "The stake of validator {0} would be {1}, which is over its cap of \
+         {2} set by the maximum validator stake share"
+    )]
+    StakeCapExceeded(Address, String, String),
+    #[error(
+        "The self-bond of validator {0} would be {1}, which is below the \
+         minimum of {2}"
+    )]
+    SelfBondBelowMin(Address, String, String),
 }
 
 #[allow(missing_docs)]
@@ -65,6 +75,12 @@ pub enum UnbondError {
     VotingPowerOverflow(TryFromIntError),
     #[error("Trying to unbond from a frozen validator: {0}")]
     ValidatorIsFrozen(Address),
+    #[error(
+        "Validator {0} cannot unbond its self-bond below the minimum of {2}. \
+         The remaining self-bond would be {1}. Deactivate the validator first \
+         to unbond more."
+    )]
+    SelfBondBelowMin(Address, String, String),
 }
 
 #[allow(missing_docs)]
@@ -110,6 +126,25 @@ pub enum CommissionRateChangeError {
     CannotWrite(Address),
     #[error("Cannot read storage for validator {0}")]
     CannotRead(Address),
+    #[error(
+        "Scheduled commission rate change at epoch {0} must be after the \
+         pipeline epoch {1}"
+    )]
+    ScheduledTooEarly(Epoch, Epoch),
+    #[error(
+        "Scheduled commission rate change at epoch {0} is not after the \
+         previous one"
+    )]
+    ScheduleNotOrdered(Epoch),
+    #[error(
+        "Commission schedule of {0} changes exceeds the maximum of {1} changes"
+    )]
+    ScheduleTooLong(usize, usize),
+    #[error(
+        "Validator {0} has a commission schedule pending, which has to be \
+         cancelled before changing its commission rate"
+    )]
+    SchedulePending(Address),
 }
 
 #[allow(missing_docs)]
@@ -137,6 +172,11 @@ pub enum RedelegationError {
     DelegatorIsValidator,
     #[error("The address {0} must be a validator")]
     NotAValidator(Address),
+    #[error(
+        "The stake of validator {0} would be {1}, which is over its cap of \
+         {2} set by the maximum validator stake share"
+    )]
+    StakeCapExceeded(Address, String, String),
 }
 
 #[allow(missing_docs)]
@@ -167,6 +207,20 @@ pub enum ReactivationError {
 pub enum MetadataError {
     #[error("The validator email cannot be removed")]
     CannotRemoveEmail,
+    #[error(
+        "The validator metadata field {0} is too long, must be within {1} \
+         characters"
+    )]
+    TooLong(String, u64),
+    #[error(
+        "The validator metadata key {0:?} must be non-empty and consist only \
+         of ASCII alphanumeric characters, '-', '_' or '.'"
+    )]
+    InvalidKey(String),
+    #[error("The validator has more than {0} social handles")]
+    TooManySocialHandles(usize),
+    #[error("The validator has more than {0} metadata extensions")]
+    TooManyExtensions(usize),
 }
 
 #[allow(missing_docs)]
@@ -176,6 +230,59 @@ pub enum ConsensusKeyChangeError {
     MustBeEd25519,
 }
 
+#[allow(missing_docs)]
+#[derive(Error, Debug)]
+pub enum KeyChangeError {
+    #[error("The given address {0} is not a validator address")]
+    NotAValidator(Address),
+    #[error("At least one new key must be given")]
+    NoKeys,
+    #[error(
+        "The Ethereum hot and cold keys are required to be secp256k1 keys"
+    )]
+    EthKeyMustBeSecp256k1,
+}
+
+#[allow(missing_docs)]
+#[derive(Error, Debug)]
+pub enum AutoCompoundError {
+    #[error("The given address {0} is not a validator address")]
+    NotAValidator(Address),
+    #[error(
+        "The source address {0} is a validator address and cannot delegate"
+    )]
+    SourceMustNotBeAValidator(Address),
+    #[error("No bond from {0} to validator {1} to compound the rewards of")]
+    NoBond(Address, Address),
+}
+
+#[allow(missing_docs)]
+#[derive(Error, Debug)]
+pub enum SlashSimulationError {
+    #[error("The given address {0} is not a validator address")]
+    NotAValidator(Address),
+    #[error(
+        "An infraction committed in epoch {0} cannot be slashed anymore. The \
+         earliest infraction epoch whose slash is still pending is {1}"
+    )]
+    InfractionTooOld(Epoch, Epoch),
+    #[error(
+        "The infraction epoch {0} must not be after the current epoch {1}"
+    )]
+    InfractionInFuture(Epoch, Epoch),
+}
+
+#[allow(missing_docs)]
+#[derive(Error, Debug)]
+pub enum RebalanceError {
+    #[error("The delegator must not be a validator")]
+    DelegatorIsValidator,
+    #[error("The address {0} must be a validator")]
+    NotAValidator(Address),
+    #[error("The validator {0} is given more than once in the targets")]
+    DuplicateTarget(Address),
+}
+
 impl From<BecomeValidatorError> for namada_storage::Error {
     fn from(err: BecomeValidatorError) -> Self {
         Self::new(err)
@@ -247,3 +354,27 @@ impl From<ConsensusKeyChangeError> for namada_storage::Error {
         Self::new(err)
     }
 }
+
+impl From<KeyChangeError> for namada_storage::Error {
+    fn from(err: KeyChangeError) -> Self {
+        Self::new(err)
+    }
+}
+
+impl From<AutoCompoundError> for namada_storage::Error {
+    fn from(err: AutoCompoundError) -> Self {
+        Self::new(err)
+    }
+}
+
+impl From<SlashSimulationError> for namada_storage::Error {
+    fn from(err: SlashSimulationError) -> Self {
+        Self::new(err)
+    }
+}
+
+impl From<RebalanceError> for namada_storage::Error {
+    fn from(err: RebalanceError) -> Self {
+        Self::new(err)
+    }
+}
@@ -1,6 +1,7 @@
 //! Proof of Stake events.
 
 use namada_core::address::Address;
+use namada_core::dec::Dec;
 use namada_core::token;
 use namada_core::uint::Uint;
 use namada_events::extend::{ComposeEvent, EventAttributeEntry};
@@ -15,6 +16,14 @@ pub mod types {
 
     /// Slash event.
     pub const SLASH: EventType = event_type!(PosEvent, "slash");
+
+    /// Commission rate change event.
+    pub const COMMISSION_RATE_CHANGE: EventType =
+        event_type!(PosEvent, "commission-rate-change");
+
+    /// Rewards compounding event.
+    pub const REWARDS_COMPOUNDED: EventType =
+        event_type!(PosEvent, "rewards-compounded");
 }
 
 /// Proof of Stake event.
@@ -27,6 +36,22 @@ pub enum PosEvent {
         /// Amount of tokens that have been slashed.
         amount: token::Amount,
     },
+    /// A scheduled commission rate change took effect.
+    CommissionRateChange {
+        /// The address of the validator.
+        validator: Address,
+        /// The new commission rate.
+        rate: Dec,
+    },
+    /// Rewards automatically re-bonded at the start of an epoch.
+    RewardsCompounded {
+        /// The owner of the bond.
+        source: Address,
+        /// The validator of the bond.
+        validator: Address,
+        /// Amount of reward tokens that have been bonded.
+        amount: token::Amount,
+    },
 }
 
 impl EventToEmit for PosEvent {
@@ -42,6 +67,21 @@ impl From<PosEvent> for Event {
                     .with(SlashedAmount(&amount.into()))
                     .into()
             }
+            PosEvent::CommissionRateChange { validator, rate } => {
+                Event::new(types::COMMISSION_RATE_CHANGE, EventLevel::Block)
+                    .with(CommissionValidator(validator))
+                    .with(CommissionRate(rate))
+                    .into()
+            }
+            PosEvent::RewardsCompounded {
+                source,
+                validator,
+                amount,
+            } => Event::new(types::REWARDS_COMPOUNDED, EventLevel::Block)
+                .with(BondSource(source))
+                .with(BondValidator(validator))
+                .with(CompoundedAmount(&amount.into()))
+                .into(),
         }
     }
 }
@@ -73,3 +113,73 @@ impl<'amt> EventAttributeEntry<'amt> for SlashedAmount<'amt> {
         self.0
     }
 }
+
+/// Extend an [`Event`] with the validator whose commission rate changed.
+pub struct CommissionValidator(pub Address);
+
+impl EventAttributeEntry<'static> for CommissionValidator {
+    type Value = Address;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "commission-validator";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
+
+/// Extend an [`Event`] with commission rate data.
+pub struct CommissionRate(pub Dec);
+
+impl EventAttributeEntry<'static> for CommissionRate {
+    type Value = Dec;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "commission-rate";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
+
+/// Extend an [`Event`] with the source of a bond.
+pub struct BondSource(pub Address);
+
+impl EventAttributeEntry<'static> for BondSource {
+    type Value = Address;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "bond-source";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
+
+/// Extend an [`Event`] with the validator of a bond.
+pub struct BondValidator(pub Address);
+
+impl EventAttributeEntry<'static> for BondValidator {
+    type Value = Address;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "bond-validator";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
+
+/// Extend an [`Event`] with compounded rewards amount data.
+pub struct CompoundedAmount<'amt>(pub &'amt Uint);
+
+impl<'amt> EventAttributeEntry<'amt> for CompoundedAmount<'amt> {
+    type Value = &'amt Uint;
+    type ValueOwned = Uint;
+
+    const KEY: &'static str = "compounded-amount";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
@@ -19,9 +19,11 @@
 
 pub mod epoched;
 pub mod event;
+pub mod metadata;
 pub mod parameters;
 pub mod pos_queries;
 pub mod queries;
+pub mod rebalance;
 pub mod rewards;
 pub mod slashing;
 pub mod storage;
@@ -51,6 +53,7 @@ pub use namada_core::storage::{Epoch, Key, KeySeg};
 use namada_core::tendermint::abci::types::Misbehavior;
 use namada_events::EmitEvents;
 use namada_storage::collections::lazy_map::{self, Collectable, LazyMap};
+use namada_storage::collections::lazy_set;
 use namada_storage::{OptionExt, StorageRead, StorageWrite};
 pub use namada_systems::proof_of_stake::*;
 pub use namada_trans_token as token;
@@ -70,26 +73,28 @@ use crate::slashing::{
     compute_amount_after_slashing_withdraw, find_validator_slashes,
 };
 use crate::storage::{
-    below_capacity_validator_set_handle, bond_handle,
-    consensus_validator_set_handle, delegation_targets_handle,
-    delegator_redelegated_bonds_handle, delegator_redelegated_unbonds_handle,
-    get_last_reward_claim_epoch, liveness_missed_votes_handle,
+    auto_compound_bonds_handle, below_capacity_validator_set_handle,
+    bond_handle, commission_schedules_handle, consensus_validator_set_handle,
+    delegation_targets_handle, delegator_redelegated_bonds_handle,
+    delegator_redelegated_unbonds_handle, get_last_reward_claim_epoch,
+    has_min_self_bond, liveness_history_handle, liveness_missed_votes_handle,
     liveness_sum_missed_votes_handle, read_consensus_validator_set_addresses,
     read_non_pos_owned_params, read_pos_params,
     read_validator_last_slash_epoch, read_validator_max_commission_rate_change,
-    read_validator_stake, total_bonded_handle, total_consensus_stake_handle,
-    total_unbonded_handle, try_insert_consensus_key, unbond_handle,
-    update_total_deltas, update_validator_deltas, validator_addresses_handle,
+    read_validator_self_bond, read_validator_stake, total_bonded_handle,
+    total_consensus_stake_handle, total_unbonded_handle,
+    try_insert_consensus_key, unbond_handle, update_total_deltas,
+    update_validator_deltas, validator_addresses_handle,
     validator_commission_rate_handle, validator_consensus_key_handle,
     validator_deltas_handle, validator_eth_cold_key_handle,
     validator_eth_hot_key_handle, validator_incoming_redelegations_handle,
     validator_outgoing_redelegations_handle, validator_protocol_key_handle,
     validator_rewards_products_handle, validator_set_positions_handle,
-    validator_slashes_handle, validator_state_handle,
+    validator_slashes_handle, validator_stake_over_cap, validator_state_handle,
     validator_total_redelegated_bonded_handle,
     validator_total_redelegated_unbonded_handle,
     write_last_pos_inflation_amount, write_last_reward_claim_epoch,
-    write_last_staked_ratio, write_pos_params,
+    write_last_staked_ratio, write_metadata_schema_version, write_pos_params,
     write_validator_address_raw_hash, write_validator_avatar,
     write_validator_description, write_validator_discord_handle,
     write_validator_email, write_validator_max_commission_rate_change,
@@ -98,9 +103,9 @@ use crate::storage::{
 use crate::storage_key::{bonds_for_source_prefix, is_bond_key};
 use crate::types::{
     BondId, ConsensusValidator, ConsensusValidatorSet,
-    EagerRedelegatedBondsMap, RedelegatedBondsOrUnbonds, RedelegatedTokens,
-    ResultSlashing, Slash, Unbonds, ValidatorMetaData, ValidatorSetUpdate,
-    ValidatorState, VoteInfo,
+    EagerRedelegatedBondsMap, EpochRewards, RedelegatedBondsOrUnbonds,
+    RedelegatedTokens, ResultSlashing, Slash, Unbonds, ValidatorLiveness,
+    ValidatorMetaData, ValidatorSetUpdate, ValidatorState, VoteInfo,
 };
 use crate::validator_set_update::{
     copy_validator_sets_and_positions, insert_validator_into_validator_set,
@@ -170,6 +175,8 @@ where
     below_capacity_validator_set_handle().init(storage, current_epoch)?;
     validator_set_positions_handle().init(storage, current_epoch)?;
     validator_addresses_handle().init(storage, current_epoch)?;
+    // The genesis validators' metadata is written in the current schema
+    write_metadata_schema_version(storage, metadata::METADATA_SCHEMA_VERSION)?;
     tracing::debug!("Finished genesis");
     Ok(())
 }
@@ -253,7 +260,10 @@ where
 
 /// Self-bond tokens to a validator when `source` is `None` or equal to
 /// the `validator` address, or delegate tokens from the `source` to the
-/// `validator`.
+/// `validator`. The bond must not take the validator's stake over the cap set
+/// by the `max_validator_stake_share` PoS parameter and a self-bond must leave
+/// the validator with at least the `min_self_bond`, unless it's applied with
+/// an offset (at genesis).
 pub fn bond_tokens<S>(
     storage: &mut S,
     source: Option<&Address>,
@@ -262,6 +272,62 @@ pub fn bond_tokens<S>(
     current_epoch: Epoch,
     offset_opt: Option<u64>,
 ) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    bond_tokens_unchecked(
+        storage,
+        source,
+        validator,
+        amount,
+        current_epoch,
+        offset_opt,
+    )?;
+
+    if offset_opt.is_none() && !amount.is_zero() {
+        let params = read_pos_params(storage)?;
+        let pipeline_epoch = checked!(current_epoch + params.pipeline_len)?;
+        if let Some((stake, cap)) = validator_stake_over_cap(
+            storage,
+            &params,
+            validator,
+            pipeline_epoch,
+        )? {
+            return Err(BondError::StakeCapExceeded(
+                validator.clone(),
+                stake.to_string_native(),
+                cap.to_string_native(),
+            )
+            .into());
+        }
+
+        // A self-bond must give the validator at least the minimum self-bond
+        if source.map_or(true, |source| source == validator)
+            && !has_min_self_bond(storage, &params, validator, pipeline_epoch)?
+        {
+            let self_bond =
+                read_validator_self_bond(storage, validator, pipeline_epoch)?;
+            return Err(BondError::SelfBondBelowMin(
+                validator.clone(),
+                self_bond.to_string_native(),
+                params.min_self_bond.to_string_native(),
+            )
+            .into());
+        }
+    }
+    Ok(())
+}
+
+/// Bond tokens without checking the validator's stake cap and the resulting
+/// self-bond against the minimum.
+fn bond_tokens_unchecked<S>(
+    storage: &mut S,
+    source: Option<&Address>,
+    validator: &Address,
+    amount: token::Amount,
+    current_epoch: Epoch,
+    offset_opt: Option<u64>,
+) -> Result<()>
 where
     S: StorageRead + StorageWrite,
 {
@@ -469,6 +535,30 @@ where
         .into());
     }
 
+    // Make sure a validator keeps the minimum self-bond, unless it's jailed or
+    // inactive
+    if source == validator && !params.min_self_bond.is_zero() {
+        let remaining_self_bond = checked!(remaining_at_pipeline - amount)?;
+        if remaining_self_bond < params.min_self_bond {
+            let pipeline_state = validator_state_handle(validator).get(
+                storage,
+                pipeline_epoch,
+                &params,
+            )?;
+            if !matches!(
+                pipeline_state,
+                Some(ValidatorState::Jailed | ValidatorState::Inactive)
+            ) {
+                return Err(UnbondError::SelfBondBelowMin(
+                    validator.clone(),
+                    remaining_self_bond.to_string_native(),
+                    params.min_self_bond.to_string_native(),
+                )
+                .into());
+            }
+        }
+    }
+
     if tracing::level_enabled!(tracing::Level::DEBUG) {
         let bonds = find_bonds(storage, source, validator)?;
         tracing::debug!("\nBonds before decrementing: {bonds:#?}");
@@ -1444,6 +1534,64 @@ where
     Ok(())
 }
 
+/// Rotate a validator's protocol key and its Ethereum hot and cold keys. Each
+/// given key takes effect at the pipeline epoch, while the keys that are not
+/// given are left unchanged.
+pub fn change_validator_keys<S>(
+    storage: &mut S,
+    validator: &Address,
+    protocol_key: Option<&common::PublicKey>,
+    eth_hot_key: Option<&common::PublicKey>,
+    eth_cold_key: Option<&common::PublicKey>,
+    current_epoch: Epoch,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    tracing::debug!("Changing protocol or eth keys for validator {validator}");
+
+    if !is_validator(storage, validator)? {
+        return Err(KeyChangeError::NotAValidator(validator.clone()).into());
+    }
+    if protocol_key.is_none() && eth_hot_key.is_none() && eth_cold_key.is_none()
+    {
+        return Err(KeyChangeError::NoKeys.into());
+    }
+    for eth_key in [eth_hot_key, eth_cold_key].into_iter().flatten() {
+        if !matches!(eth_key, common::PublicKey::Secp256k1(_)) {
+            return Err(KeyChangeError::EthKeyMustBeSecp256k1.into());
+        }
+    }
+
+    let params = read_pos_params(storage)?;
+    if let Some(protocol_key) = protocol_key {
+        validator_protocol_key_handle(validator).set(
+            storage,
+            protocol_key.clone(),
+            current_epoch,
+            params.pipeline_len,
+        )?;
+    }
+    if let Some(eth_hot_key) = eth_hot_key {
+        validator_eth_hot_key_handle(validator).set(
+            storage,
+            eth_hot_key.clone(),
+            current_epoch,
+            params.pipeline_len,
+        )?;
+    }
+    if let Some(eth_cold_key) = eth_cold_key {
+        validator_eth_cold_key_handle(validator).set(
+            storage,
+            eth_cold_key.clone(),
+            current_epoch,
+            params.pipeline_len,
+        )?;
+    }
+
+    Ok(())
+}
+
 /// Withdraw tokens from those that have been unbonded from proof-of-stake
 pub fn withdraw_tokens<S>(
     storage: &mut S,
@@ -1615,6 +1763,18 @@ where
                 CommissionRateChangeError::NoMaxSetInStorage(validator.clone())
             })?;
 
+    // A direct change would invalidate the max rate change checks of the
+    // scheduled changes
+    if !commission_schedules_handle()
+        .at(validator)
+        .is_empty(storage)?
+    {
+        return Err(CommissionRateChangeError::SchedulePending(
+            validator.clone(),
+        )
+        .into());
+    }
+
     let params = read_pos_params(storage)?;
     let commission_handle = validator_commission_rate_handle(validator);
     let pipeline_epoch = checked!(current_epoch + params.pipeline_len)?;
@@ -1645,6 +1805,174 @@ where
     commission_handle.set(storage, new_rate, current_epoch, params.pipeline_len)
 }
 
+/// The maximum number of changes in a validator's commission schedule.
+pub const MAX_COMMISSION_SCHEDULE_LEN: usize = 32;
+
+/// Publish a schedule of future commission rate changes of a validator,
+/// replacing its previously scheduled changes that are after the pipeline
+/// epoch. The changes must be ordered by their epochs, which must be after the
+/// pipeline epoch, and each one must be within the validator's max commission
+/// rate change from the rate preceding it. An empty schedule cancels the
+/// pending changes.
+///
+/// The changes are written to the validator's epoched commission rates once
+/// they enter the pipeline, at the start of every epoch.
+pub fn change_validator_commission_schedule<S>(
+    storage: &mut S,
+    validator: &Address,
+    schedule: Vec<(Epoch, Dec)>,
+    current_epoch: Epoch,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    if schedule.len() > MAX_COMMISSION_SCHEDULE_LEN {
+        return Err(CommissionRateChangeError::ScheduleTooLong(
+            schedule.len(),
+            MAX_COMMISSION_SCHEDULE_LEN,
+        )
+        .into());
+    }
+
+    let max_change =
+        read_validator_max_commission_rate_change(storage, validator)?
+            .ok_or_else(|| {
+                CommissionRateChangeError::NoMaxSetInStorage(validator.clone())
+            })?;
+
+    let params = read_pos_params(storage)?;
+    let pipeline_epoch = checked!(current_epoch + params.pipeline_len)?;
+    let mut prev_rate = validator_commission_rate_handle(validator)
+        .get(storage, pipeline_epoch, &params)?
+        .expect("Could not find a rate in given epoch");
+
+    let mut prev_epoch = pipeline_epoch;
+    for &(epoch, rate) in &schedule {
+        if epoch <= pipeline_epoch {
+            return Err(CommissionRateChangeError::ScheduledTooEarly(
+                epoch,
+                pipeline_epoch,
+            )
+            .into());
+        }
+        if epoch <= prev_epoch {
+            return Err(
+                CommissionRateChangeError::ScheduleNotOrdered(epoch).into()
+            );
+        }
+        if rate.is_negative() {
+            return Err(CommissionRateChangeError::NegativeRate(
+                rate,
+                validator.clone(),
+            )
+            .into());
+        }
+        if rate > Dec::one() {
+            return Err(CommissionRateChangeError::LargerThanOne(
+                rate,
+                validator.clone(),
+            )
+            .into());
+        }
+        let change_from_prev = rate.abs_diff(prev_rate)?;
+        if change_from_prev > max_change {
+            return Err(CommissionRateChangeError::RateChangeTooLarge(
+                change_from_prev,
+                validator.clone(),
+            )
+            .into());
+        }
+        prev_epoch = epoch;
+        prev_rate = rate;
+    }
+
+    // Keep the changes already written to the epoched commission rates, they
+    // are only removed once they take effect
+    let handle = commission_schedules_handle().at(validator);
+    let pending = handle
+        .iter(storage)?
+        .map(|res| res.map(|(epoch, _rate)| epoch))
+        .filter(|res| {
+            res.as_ref().map_or(true, |epoch| *epoch > pipeline_epoch)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    for epoch in pending {
+        handle.remove(storage, &epoch)?;
+    }
+    for (epoch, rate) in schedule {
+        handle.insert(storage, epoch, rate)?;
+    }
+    Ok(())
+}
+
+/// Find the scheduled commission rate changes of a validator that have not
+/// taken effect yet in the given epoch.
+pub fn read_commission_schedule<S>(
+    storage: &S,
+    validator: &Address,
+    epoch: Epoch,
+) -> Result<BTreeMap<Epoch, Dec>>
+where
+    S: StorageRead,
+{
+    commission_schedules_handle()
+        .at(validator)
+        .iter(storage)?
+        .filter(|res| {
+            res.as_ref()
+                .map_or(true, |(change_epoch, _rate)| *change_epoch > epoch)
+        })
+        .collect()
+}
+
+/// Apply the scheduled commission rate changes of validators. Called at the
+/// start of every epoch to write the changes that enter the pipeline to the
+/// epoched commission rates and to remove the ones taking effect, for which
+/// an event is emitted.
+fn apply_commission_schedules<S>(
+    storage: &mut S,
+    events: &mut impl EmitEvents,
+    params: &PosParams,
+    current_epoch: Epoch,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let pipeline_epoch = checked!(current_epoch + params.pipeline_len)?;
+    let handle = commission_schedules_handle();
+    let changes = handle
+        .iter(storage)?
+        .map(|res| {
+            let (
+                lazy_map::NestedSubKey::Data {
+                    key: validator,
+                    nested_sub_key: lazy_map::SubKey::Data(epoch),
+                },
+                rate,
+            ) = res?;
+            Ok((validator, epoch, rate))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    for (validator, epoch, rate) in changes {
+        if epoch == pipeline_epoch {
+            validator_commission_rate_handle(&validator).set(
+                storage,
+                rate,
+                current_epoch,
+                params.pipeline_len,
+            )?;
+        } else if epoch <= current_epoch {
+            handle.at(&validator).remove(storage, &epoch)?;
+            events.emit(event::PosEvent::CommissionRateChange {
+                validator,
+                rate,
+            });
+        }
+    }
+    Ok(())
+}
+
 fn bond_amounts_for_query<S>(
     storage: &S,
     params: &PosParams,
@@ -2100,6 +2428,41 @@ where
         .map(|o| o.expect("Total consensus stake could not be retrieved."))
 }
 
+/// Check if redelegating the delegator's bonds from the source validator
+/// would be a chained redelegation. A redelegation is "chained" if:
+/// 1. the source validator holds bonded tokens that themselves were
+/// redelegated to the src validator
+/// 2. given the latest epoch at which the most recently redelegated tokens
+/// started contributing to the src validator's voting power, these tokens
+/// can still be slashed
+pub fn is_chained_redelegation<S>(
+    storage: &S,
+    params: &OwnedPosParams,
+    delegator: &Address,
+    src_validator: &Address,
+    current_epoch: Epoch,
+) -> Result<bool>
+where
+    S: StorageRead,
+{
+    let src_redel_end_epoch =
+        validator_incoming_redelegations_handle(src_validator)
+            .get(storage, delegator)?;
+    if let Some(end_epoch) = src_redel_end_epoch {
+        let last_contrib_epoch =
+            end_epoch.prev().expect("End epoch cannot be 0");
+        // If the source validator's slashes that would cause slash on
+        // redelegation are now outdated (would have to be processed before or
+        // on start of the current epoch), the redelegation can be redelegated
+        // again
+        Ok(checked!(
+            last_contrib_epoch + params.slash_processing_epoch_offset()
+        )? > current_epoch)
+    } else {
+        Ok(false)
+    }
+}
+
 /// Redelegate bonded tokens from a source validator to a destination validator
 pub fn redelegate_tokens<S>(
     storage: &mut S,
@@ -2147,29 +2510,15 @@ where
 
     let params = read_pos_params(storage)?;
     let pipeline_epoch = checked!(current_epoch + params.pipeline_len)?;
-    let src_redel_end_epoch =
-        validator_incoming_redelegations_handle(src_validator)
-            .get(storage, delegator)?;
 
-    // Forbid chained redelegations. A redelegation is "chained" if:
-    // 1. the source validator holds bonded tokens that themselves were
-    // redelegated to the src validator
-    // 2. given the latest epoch at which the most recently redelegated tokens
-    // started contributing to the src validator's voting power, these tokens
-    // cannot be slashed anymore
-    let is_not_chained = if let Some(end_epoch) = src_redel_end_epoch {
-        let last_contrib_epoch =
-            end_epoch.prev().expect("End epoch cannot be 0");
-        // If the source validator's slashes that would cause slash on
-        // redelegation are now outdated (would have to be processed before or
-        // on start of the current epoch), the redelegation can be redelegated
-        // again
-        checked!(last_contrib_epoch + params.slash_processing_epoch_offset())?
-            <= current_epoch
-    } else {
-        true
-    };
-    if !is_not_chained {
+    // Forbid chained redelegations
+    if is_chained_redelegation(
+        storage,
+        &params,
+        delegator,
+        src_validator,
+        current_epoch,
+    )? {
         return Err(RedelegationError::IsChainedRedelegation.into());
     }
 
@@ -2325,6 +2674,21 @@ where
         !is_jailed_or_inactive_at_pipeline,
     )?;
 
+    // The dest validator's stake must not go over the cap
+    if let Some((stake, cap)) = validator_stake_over_cap(
+        storage,
+        &params,
+        dest_validator,
+        pipeline_epoch,
+    )? {
+        return Err(RedelegationError::StakeCapExceeded(
+            dest_validator.clone(),
+            stake.to_string_native(),
+            cap.to_string_native(),
+        )
+        .into());
+    }
+
     Ok(())
 }
 
@@ -2473,6 +2837,10 @@ where
     Ok(())
 }
 
+/// The number of past epochs for which the per-epoch liveness history of
+/// validators is kept.
+pub const LIVENESS_HISTORY_LEN: u64 = 100;
+
 /// Remove liveness data from storage for all validators that are not in the
 /// current consensus validator set.
 pub fn prune_liveness_data<S>(
@@ -2523,6 +2891,7 @@ where
         read_consensus_validator_set_addresses(storage, votes_epoch)?;
     let liveness_missed_votes = liveness_missed_votes_handle();
     let liveness_sum_missed_votes = liveness_sum_missed_votes_handle();
+    let liveness_history = liveness_history_handle();
 
     // Get the addresses of the validators who voted
     let vote_addresses = votes
@@ -2555,7 +2924,19 @@ where
         }
 
         // Evaluate new vote
-        if !vote_addresses.contains(&cons_validator) {
+        let missed_vote = !vote_addresses.contains(&cons_validator);
+        let history = liveness_history.at(&cons_validator);
+        let mut epoch_liveness =
+            history.get(storage, &votes_epoch)?.unwrap_or_default();
+        epoch_liveness.expected_votes =
+            checked!(epoch_liveness.expected_votes + 1)?;
+        if missed_vote {
+            epoch_liveness.missed_votes =
+                checked!(epoch_liveness.missed_votes + 1)?;
+        }
+        history.insert(storage, votes_epoch, epoch_liveness)?;
+
+        if missed_vote {
             // Insert the height of the missing vote in storage
             liveness_missed_votes
                 .at(&cons_validator)
@@ -2591,18 +2972,47 @@ where
     Ok(())
 }
 
-/// Jail validators who failed to match the liveness threshold
-pub fn jail_for_liveness<S>(
+/// Remove the per-epoch liveness history older than
+/// [`LIVENESS_HISTORY_LEN`] epochs before the current epoch.
+pub fn prune_liveness_history<S>(
     storage: &mut S,
-    params: &PosParams,
     current_epoch: Epoch,
-    jail_epoch: Epoch,
 ) -> Result<()>
 where
     S: StorageRead + StorageWrite,
 {
+    let Some(oldest_epoch) = current_epoch.checked_sub(LIVENESS_HISTORY_LEN)
+    else {
+        return Ok(());
+    };
+    let liveness_history = liveness_history_handle();
+
+    let to_prune = liveness_history
+        .iter(storage)?
+        .filter_map(|entry| {
+            let (
+                lazy_map::NestedSubKey::Data {
+                    key: validator,
+                    nested_sub_key: lazy_map::SubKey::Data(epoch),
+                },
+                _,
+            ) = entry.ok()?;
+            (epoch < oldest_epoch).then_some((validator, epoch))
+        })
+        .collect::<Vec<_>>();
+
+    for (validator, epoch) in to_prune {
+        liveness_history.at(&validator).remove(storage, &epoch)?;
+    }
+
+    Ok(())
+}
+
+/// The number of missed votes within the sliding liveness window at which a
+/// validator gets jailed.
+pub fn liveness_missed_votes_threshold(params: &PosParams) -> Result<u64> {
     // Derive the actual missing votes limit from the percentage
-    let missing_votes_threshold = checked!(
+    let threshold = checked!(
         (Dec::one() - params.liveness_threshold) * params.liveness_window_check
     )?
     .to_uint()
@@ -2612,6 +3022,59 @@ where
         )
     })?
     .as_u64();
+    Ok(threshold)
+}
+
+/// Read the liveness of a validator, including its per-epoch history for up
+/// to `num_epochs` past epochs (or all the kept history, if not given).
+pub fn read_validator_liveness<S>(
+    storage: &S,
+    params: &PosParams,
+    validator: &Address,
+    current_epoch: Epoch,
+    num_epochs: Option<u64>,
+) -> Result<ValidatorLiveness>
+where
+    S: StorageRead,
+{
+    let num_epochs = num_epochs
+        .unwrap_or(LIVENESS_HISTORY_LEN)
+        .min(LIVENESS_HISTORY_LEN);
+    let oldest_epoch =
+        current_epoch.checked_sub(num_epochs).unwrap_or_default();
+
+    let window_missed_votes = liveness_sum_missed_votes_handle()
+        .get(storage, validator)?
+        .unwrap_or_default();
+    let history = liveness_history_handle()
+        .at(validator)
+        .iter(storage)?
+        .filter(|entry| {
+            entry
+                .as_ref()
+                .map_or(true, |(epoch, _)| *epoch >= oldest_epoch)
+        })
+        .collect::<Result<BTreeMap<_, _>>>()?;
+
+    Ok(ValidatorLiveness {
+        window_missed_votes,
+        window_missed_votes_threshold: liveness_missed_votes_threshold(params)?,
+        window_len: params.liveness_window_check,
+        history,
+    })
+}
+
+/// Jail validators who failed to match the liveness threshold
+pub fn jail_for_liveness<S>(
+    storage: &mut S,
+    params: &PosParams,
+    current_epoch: Epoch,
+    jail_epoch: Epoch,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let missing_votes_threshold = liveness_missed_votes_threshold(params)?;
 
     // Jail inactive validators
     let validators_to_jail = liveness_sum_missed_votes_handle()
@@ -2917,6 +3380,269 @@ where
     Ok(res)
 }
 
+/// Reconstruct the rewards earned by a bond in each epoch of the inclusive
+/// range from `start_epoch` to `end_epoch` from the validator's rewards
+/// products. The range is capped at the last epoch whose rewards products have
+/// been computed, i.e. the one before `current_epoch`.
+///
+/// Only the bonds that are still held are included - the rewards of the
+/// amounts that have been unbonded have been moved to the rewards counter.
+pub fn query_rewards_history<S>(
+    storage: &S,
+    source: Option<&Address>,
+    validator: &Address,
+    start_epoch: Epoch,
+    end_epoch: Epoch,
+    current_epoch: Epoch,
+) -> Result<BTreeMap<Epoch, EpochRewards>>
+where
+    S: StorageRead,
+{
+    let source = source.unwrap_or(validator);
+    let Some(last_rewarded_epoch) = current_epoch.prev() else {
+        return Ok(BTreeMap::new());
+    };
+    let end_epoch = cmp::min(end_epoch, last_rewarded_epoch);
+    if start_epoch > end_epoch {
+        return Ok(BTreeMap::new());
+    }
+
+    let params = read_pos_params(storage)?;
+    let bond_id = BondId {
+        source: source.clone(),
+        validator: validator.clone(),
+    };
+    let bond_amounts =
+        bond_amounts_for_rewards(storage, &bond_id, start_epoch, end_epoch)?;
+
+    // The bonded amounts before slashing
+    let mut raw_bond_amounts: BTreeMap<Epoch, token::Amount> = BTreeMap::new();
+    for next in bond_handle(source, validator)
+        .get_data_handler()
+        .iter(storage)?
+    {
+        let (start, delta) = next?;
+        for ep in Epoch::iter_bounds_inclusive(start_epoch, end_epoch) {
+            if start <= ep {
+                let amount = raw_bond_amounts.entry(ep).or_default();
+                *amount = checked!(amount + delta)?;
+            }
+        }
+    }
+
+    let rewards_products = validator_rewards_products_handle(validator);
+    let commission_rates = validator_commission_rate_handle(validator);
+    let mut history = BTreeMap::new();
+    for (ep, bond_amount) in bond_amounts {
+        let rewards_product =
+            rewards_products.get(storage, &ep)?.unwrap_or_default();
+        let rewards = bond_amount.mul_floor(rewards_product)?;
+        let commission_rate = commission_rates.get(storage, ep, &params)?;
+        // The rewards product is already net of the commission, so the
+        // rewards before the commission are recovered from it
+        let commission = match commission_rate {
+            Some(rate) if rate < Dec::one() => {
+                let gross_product =
+                    checked!(rewards_product / (Dec::one() - rate))?;
+                let gross_rewards = bond_amount.mul_floor(gross_product)?;
+                Some(gross_rewards.checked_sub(rewards).unwrap_or_default())
+            }
+            _ => None,
+        };
+        let slashed_amount = raw_bond_amounts
+            .get(&ep)
+            .copied()
+            .unwrap_or_default()
+            .checked_sub(bond_amount)
+            .unwrap_or_default();
+
+        history.insert(
+            ep,
+            EpochRewards {
+                bond_amount,
+                slashed_amount,
+                rewards_product,
+                rewards,
+                commission_rate,
+                commission,
+            },
+        );
+    }
+
+    Ok(history)
+}
+
+/// Opt in or out of having the rewards of a bond automatically re-bonded to
+/// its validator at the start of every epoch. Opting in requires the bond to
+/// exist at the pipeline epoch.
+pub fn set_auto_compound<S>(
+    storage: &mut S,
+    source: Option<&Address>,
+    validator: &Address,
+    enabled: bool,
+    current_epoch: Epoch,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let source = source.unwrap_or(validator);
+    let handle = auto_compound_bonds_handle();
+    if !enabled {
+        handle.at(source).remove(storage, validator)?;
+        return Ok(());
+    }
+
+    if !is_validator(storage, validator)? {
+        return Err(AutoCompoundError::NotAValidator(validator.clone()).into());
+    }
+    if source != validator && is_validator(storage, source)? {
+        return Err(AutoCompoundError::SourceMustNotBeAValidator(
+            source.clone(),
+        )
+        .into());
+    }
+    let params = read_pos_params(storage)?;
+    let pipeline_epoch = checked!(current_epoch + params.pipeline_len)?;
+    let bond = bond_handle(source, validator)
+        .get_sum(storage, pipeline_epoch, &params)?
+        .unwrap_or_default();
+    if bond.is_zero() {
+        return Err(AutoCompoundError::NoBond(
+            source.clone(),
+            validator.clone(),
+        )
+        .into());
+    }
+
+    handle.at(source).insert(storage, validator.clone())?;
+    Ok(())
+}
+
+/// Check if the rewards of a bond are automatically re-bonded.
+pub fn is_auto_compounding<S>(
+    storage: &S,
+    source: Option<&Address>,
+    validator: &Address,
+) -> Result<bool>
+where
+    S: StorageRead,
+{
+    let source = source.unwrap_or(validator);
+    auto_compound_bonds_handle()
+        .at(source)
+        .contains(storage, validator)
+}
+
+/// Re-bond the rewards of the bonds that opted in to auto-compounding. Only
+/// called at the start of a new epoch, before the rewards products of the last
+/// epoch are computed, hence the rewards are claimed up to the epoch before
+/// the last one and the remaining ones are compounded in the next epoch. The
+/// rewards are bonded at the pipeline offset like regular bonds.
+fn compound_rewards<S>(
+    storage: &mut S,
+    events: &mut impl EmitEvents,
+    params: &PosParams,
+    current_epoch: Epoch,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let Some(claim_epoch) = current_epoch.prev() else {
+        return Ok(());
+    };
+    let handle = auto_compound_bonds_handle();
+    let bonds = handle
+        .iter(storage)?
+        .map(|res| {
+            let (
+                lazy_map::NestedSubKey::Data {
+                    key: source,
+                    nested_sub_key: lazy_set::SubKey::Data(validator),
+                },
+                (),
+            ) = res?;
+            Ok(BondId { source, validator })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let staking_token = staking_token_address(storage);
+    let pipeline_epoch = checked!(current_epoch + params.pipeline_len)?;
+    for BondId { source, validator } in bonds {
+        // The rewards of a bond that has been fully unbonded are left to be
+        // claimed manually and the bond is opted out
+        let bond = bond_handle(&source, &validator)
+            .get_sum(storage, pipeline_epoch, params)?
+            .unwrap_or_default();
+        let is_delegating_validator =
+            source != validator && is_validator(storage, &source)?;
+        if bond.is_zero() || is_delegating_validator {
+            tracing::debug!(
+                "Opting out bond {source} --> {validator} from \
+                 auto-compounding"
+            );
+            handle.at(&source).remove(storage, &validator)?;
+            continue;
+        }
+        // Do not add stake to a validator with slashes pending processing,
+        // the rewards stay claimable until it is unfrozen
+        if is_validator_frozen(storage, &validator, current_epoch, params)? {
+            continue;
+        }
+        // Nor to a validator over its stake cap, the rewards stay claimable
+        if validator_stake_over_cap(
+            storage,
+            params,
+            &validator,
+            pipeline_epoch,
+        )?
+        .is_some()
+        {
+            continue;
+        }
+
+        let mut amount = compute_current_rewards_from_bonds(
+            storage,
+            &source,
+            &validator,
+            claim_epoch,
+        )?;
+        checked!(
+            amount += take_rewards_from_counter(storage, &source, &validator)?
+        )?;
+        write_last_reward_claim_epoch(
+            storage,
+            &source,
+            &validator,
+            claim_epoch,
+        )?;
+        if amount.is_zero() {
+            continue;
+        }
+
+        tracing::debug!(
+            "Compounding {} rewards of bond {source} --> {validator}",
+            amount.to_string_native()
+        );
+        token::transfer(storage, &staking_token, &ADDRESS, &source, amount)?;
+        // Compounded rewards may take the validator's stake just over the cap
+        // and don't have to bring a self-bond up to the minimum
+        bond_tokens_unchecked(
+            storage,
+            Some(&source),
+            &validator,
+            amount,
+            current_epoch,
+            None,
+        )?;
+        events.emit(event::PosEvent::RewardsCompounded {
+            source,
+            validator,
+            amount,
+        });
+    }
+    Ok(())
+}
+
 /// Jail a validator by removing it from and updating the validator sets and
 /// changing a its state to `Jailed`. Validators are jailed for liveness and for
 /// misbehaving.
@@ -3043,6 +3769,9 @@ where
         // Compute the total stake of the consensus validator set and record
         // it in storage
         compute_and_store_total_consensus_stake(storage, current_epoch)?;
+
+        // Migrate the validator metadata written in a previous schema, if any
+        metadata::migrate_validator_metadata(storage, current_epoch)?;
     }
 
     // Invariant: Has to be applied before `record_slashes_from_evidence`
@@ -3086,6 +3815,26 @@ where
             );
             panic!("Error while processing slashes");
         }
+
+        // Invariant: Compound rewards after processing slashes so that the
+        // slashed bond amounts are used to compute the rewards.
+        compound_rewards(storage, events, &pos_params, current_epoch)?;
+
+        apply_commission_schedules(
+            storage,
+            events,
+            &pos_params,
+            current_epoch,
+        )?;
+
+        // Re-check the validator sets at the pipeline epoch against the
+        // minimum self-bond, which may have been changed by governance, now
+        // that the slashes of this epoch have been processed
+        validator_set_update::update_validator_sets_for_min_self_bond(
+            storage,
+            &pos_params,
+            current_epoch,
+        )?;
     }
 
     // Consensus set liveness check
@@ -3118,6 +3867,7 @@ where
         // Prune liveness data from validators that are no longer in the
         // consensus set
         prune_liveness_data(storage, current_epoch)?;
+        prune_liveness_history(storage, current_epoch)?;
     }
 
     Ok(())
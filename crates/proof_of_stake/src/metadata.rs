@@ -0,0 +1,273 @@
+//! Structured validator metadata
+
+use std::collections::BTreeMap;
+
+use namada_core::address::Address;
+use namada_core::hash::Hash;
+use namada_core::storage::Epoch;
+use namada_storage::{StorageRead, StorageWrite};
+use namada_tx::data::pos::MetaDataChange;
+
+use crate::error::MetadataError;
+use crate::parameters::{
+    MAX_VALIDATOR_METADATA_EXTENSIONS, MAX_VALIDATOR_METADATA_KEY_LEN,
+    MAX_VALIDATOR_METADATA_LEN, MAX_VALIDATOR_SOCIAL_HANDLES,
+};
+use crate::storage::{
+    read_all_validator_addresses, read_metadata_schema_version,
+    read_validator_discord_handle, read_validator_metadata_extensions,
+    read_validator_social_handles, read_validator_structured_metadata,
+    write_metadata_schema_version, write_validator_discord_handle,
+    write_validator_identity_proof, write_validator_metadata_extensions,
+    write_validator_security_contact, write_validator_social_handles,
+};
+use crate::types::{
+    MetadataField, StructuredValidatorMetadata, DISCORD_PLATFORM,
+};
+
+/// The current version of the validator metadata schema. Version 1 moved the
+/// discord handle into the social handles.
+pub const METADATA_SCHEMA_VERSION: u8 = 1;
+
+/// Check that the structured metadata satisfies the validation rules. All
+/// values must be within [`MAX_VALIDATOR_METADATA_LEN`] characters. Social
+/// platforms and extension keys must be non-empty, within
+/// [`MAX_VALIDATOR_METADATA_KEY_LEN`] characters and consist only of ASCII
+/// alphanumeric characters, '-', '_' or '.'. There can be at most
+/// [`MAX_VALIDATOR_SOCIAL_HANDLES`] social handles and
+/// [`MAX_VALIDATOR_METADATA_EXTENSIONS`] extensions.
+pub fn validate_structured_metadata(
+    metadata: &StructuredValidatorMetadata,
+) -> Result<(), MetadataError> {
+    if metadata.email.is_empty() {
+        return Err(MetadataError::CannotRemoveEmail);
+    }
+    if metadata.social_handles.len() > MAX_VALIDATOR_SOCIAL_HANDLES {
+        return Err(MetadataError::TooManySocialHandles(
+            MAX_VALIDATOR_SOCIAL_HANDLES,
+        ));
+    }
+    if metadata.extensions.len() > MAX_VALIDATOR_METADATA_EXTENSIONS {
+        return Err(MetadataError::TooManyExtensions(
+            MAX_VALIDATOR_METADATA_EXTENSIONS,
+        ));
+    }
+    for key in metadata
+        .social_handles
+        .keys()
+        .chain(metadata.extensions.keys())
+    {
+        validate_key(key)?;
+    }
+
+    let fields = [
+        (MetadataField::Email, Some(&metadata.email)),
+        (MetadataField::Description, metadata.description.as_ref()),
+        (MetadataField::Website, metadata.website.as_ref()),
+        (MetadataField::Avatar, metadata.avatar.as_ref()),
+        (MetadataField::Name, metadata.name.as_ref()),
+        (
+            MetadataField::SecurityContact,
+            metadata.security_contact.as_ref(),
+        ),
+    ];
+    let social_handles =
+        metadata.social_handles.iter().map(|(platform, handle)| {
+            (MetadataField::SocialHandle(platform.clone()), Some(handle))
+        });
+    let extensions = metadata.extensions.iter().map(|(key, value)| {
+        (MetadataField::Extension(key.clone()), Some(value))
+    });
+    for (field, value) in
+        fields.into_iter().chain(social_handles).chain(extensions)
+    {
+        if value.is_some_and(|value| {
+            value.len() as u64 > MAX_VALIDATOR_METADATA_LEN
+        }) {
+            return Err(MetadataError::TooLong(
+                field.to_string(),
+                MAX_VALIDATOR_METADATA_LEN,
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn validate_key(key: &str) -> Result<(), MetadataError> {
+    if key.len() as u64 > MAX_VALIDATOR_METADATA_KEY_LEN {
+        return Err(MetadataError::TooLong(
+            key.to_string(),
+            MAX_VALIDATOR_METADATA_KEY_LEN,
+        ));
+    }
+    if key.is_empty()
+        || !key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    {
+        return Err(MetadataError::InvalidKey(key.to_string()));
+    }
+    Ok(())
+}
+
+/// Change the structured fields of a validator's metadata. Social handles
+/// and extensions are updated per entry, where an empty value removes the
+/// entry. Social platforms are case-insensitive. An empty security contact or
+/// a zero identity proof hash removes the field. The resulting metadata must
+/// pass [`validate_structured_metadata`].
+pub fn change_validator_structured_metadata<S>(
+    storage: &mut S,
+    validator: &Address,
+    security_contact: Option<String>,
+    social_handles: BTreeMap<String, String>,
+    identity_proof: Option<Hash>,
+    extensions: BTreeMap<String, String>,
+) -> namada_storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    if let Some(security_contact) = security_contact {
+        write_validator_security_contact(
+            storage,
+            validator,
+            &security_contact,
+        )?;
+    }
+    if let Some(identity_proof) = identity_proof {
+        write_validator_identity_proof(storage, validator, &identity_proof)?;
+    }
+    if !social_handles.is_empty() {
+        let mut current = read_validator_social_handles(storage, validator)?;
+        for (platform, handle) in social_handles {
+            let platform = platform.to_lowercase();
+            if handle.is_empty() {
+                current.remove(&platform);
+            } else {
+                current.insert(platform, handle);
+            }
+        }
+        write_validator_social_handles(storage, validator, &current)?;
+    }
+    if !extensions.is_empty() {
+        let mut current =
+            read_validator_metadata_extensions(storage, validator)?;
+        for (key, value) in extensions {
+            if value.is_empty() {
+                current.remove(&key);
+            } else {
+                current.insert(key, value);
+            }
+        }
+        write_validator_metadata_extensions(storage, validator, &current)?;
+    }
+
+    if let Some(metadata) =
+        read_validator_structured_metadata(storage, validator)?
+    {
+        validate_structured_metadata(&metadata)?;
+    }
+    Ok(())
+}
+
+/// Apply a metadata change to the given metadata, the same way the change is
+/// applied in storage. This is useful to check the resulting metadata with
+/// [`validate_structured_metadata`] before submitting the change.
+pub fn apply_metadata_change(
+    metadata: &mut StructuredValidatorMetadata,
+    change: &MetaDataChange,
+) {
+    fn set(field: &mut Option<String>, value: &Option<String>) {
+        if let Some(value) = value {
+            *field = (!value.is_empty()).then(|| value.clone());
+        }
+    }
+    fn set_entries(
+        entries: &mut BTreeMap<String, String>,
+        changes: impl IntoIterator<Item = (String, String)>,
+    ) {
+        for (key, value) in changes {
+            if value.is_empty() {
+                entries.remove(&key);
+            } else {
+                entries.insert(key, value);
+            }
+        }
+    }
+
+    if let Some(email) = &change.email {
+        metadata.email = email.clone();
+    }
+    set(&mut metadata.description, &change.description);
+    set(&mut metadata.website, &change.website);
+    set(&mut metadata.avatar, &change.avatar);
+    set(&mut metadata.name, &change.name);
+    set(&mut metadata.security_contact, &change.security_contact);
+    if let Some(identity_proof) = change.identity_proof {
+        metadata.identity_proof =
+            (!identity_proof.is_zero()).then_some(identity_proof);
+    }
+    set_entries(
+        &mut metadata.social_handles,
+        change
+            .discord_handle
+            .iter()
+            .map(|handle| (DISCORD_PLATFORM.to_string(), handle.clone()))
+            .chain(change.social_handles.iter().map(|(platform, handle)| {
+                (platform.to_lowercase(), handle.clone())
+            })),
+    );
+    set_entries(&mut metadata.extensions, change.extensions.clone());
+}
+
+/// Migrate the metadata of all the validators to the
+/// [`METADATA_SCHEMA_VERSION`], if it hasn't been migrated yet. The discord
+/// handle stored under its legacy key becomes a social handle.
+pub fn migrate_validator_metadata<S>(
+    storage: &mut S,
+    current_epoch: Epoch,
+) -> namada_storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    if read_metadata_schema_version(storage)? >= METADATA_SCHEMA_VERSION {
+        return Ok(());
+    }
+    tracing::info!(
+        "Migrating validator metadata to schema version \
+         {METADATA_SCHEMA_VERSION}"
+    );
+    for validator in read_all_validator_addresses(storage, current_epoch)? {
+        // Falls back on the legacy key, from which it's moved by the write
+        if let Some(discord_handle) =
+            read_validator_discord_handle(storage, &validator)?
+        {
+            write_validator_discord_handle(
+                storage,
+                &validator,
+                &discord_handle,
+            )?;
+        }
+    }
+    write_metadata_schema_version(storage, METADATA_SCHEMA_VERSION)
+}
+
+/// Find the validators whose metadata has the given field set, together with
+/// the field's value.
+pub fn find_validators_by_metadata_field<S>(
+    storage: &S,
+    field: &MetadataField,
+    epoch: Epoch,
+) -> namada_storage::Result<BTreeMap<Address, String>>
+where
+    S: StorageRead,
+{
+    let mut found = BTreeMap::new();
+    for validator in read_all_validator_addresses(storage, epoch)? {
+        let value = read_validator_structured_metadata(storage, &validator)?
+            .and_then(|metadata| metadata.get_field(field));
+        if let Some(value) = value {
+            found.insert(validator, value);
+        }
+    }
+    Ok(found)
+}
@@ -64,6 +64,16 @@ pub struct OwnedPosParams {
     /// The minimum amount of bonded tokens that a validator needs to be in
     /// either the `consensus` or `below_capacity` validator sets
     pub validator_stake_threshold: token::Amount,
+    /// The minimum amount of tokens that a validator needs to have self-bonded
+    /// to be in either the `consensus` or `below_capacity` validator sets. A
+    /// validator cannot unbond its self-bond below this amount unless it is
+    /// jailed or inactive. Zero disables the requirement.
+    pub min_self_bond: token::Amount,
+    /// The maximum fraction of the total stake that a validator can have.
+    /// Bonds and redelegations that would take a validator's stake over this
+    /// fraction are rejected. Bonds applied at genesis and compounded rewards
+    /// are not capped. One disables the cap.
+    pub max_validator_stake_share: Dec,
     /// The length, in blocks, of the sliding window for consensus validators
     /// inactivity verification
     pub liveness_window_check: u64,
@@ -98,6 +108,8 @@ impl Default for OwnedPosParams {
                 .expect("Test failed"),
             cubic_slashing_window_length: 1,
             validator_stake_threshold: token::Amount::native_whole(1_u64),
+            min_self_bond: token::Amount::zero(),
+            max_validator_stake_share: Dec::one(),
             liveness_window_check: 10_000,
             liveness_threshold: Dec::new(9, 1).expect("Test failed"),
             rewards_gain_p: Dec::from_str("0.25").expect("Test failed"),
@@ -129,6 +141,11 @@ pub enum ValidationError {
     VotesPerTokenGreaterThanOne(Dec),
     #[error("Liveness threshold cannot be greater than 1, got {0}")]
     LivenessThresholdGreaterThanOne(Dec),
+    #[error(
+        "Max validator stake share must be greater than 0 and at most 1, got \
+         {0}"
+    )]
+    MaxValidatorStakeShareOutOfRange(Dec),
     #[error("Pipeline length must be >= 2, got {0}")]
     PipelineLenTooShort(u64),
     #[error(
@@ -141,6 +158,15 @@ pub enum ValidationError {
 /// The maximum string length of any validator metadata
 pub const MAX_VALIDATOR_METADATA_LEN: u64 = 500;
 
+/// The maximum string length of a social platform or a metadata extension key
+pub const MAX_VALIDATOR_METADATA_KEY_LEN: u64 = 64;
+
+/// The maximum number of a validator's social handles
+pub const MAX_VALIDATOR_SOCIAL_HANDLES: usize = 16;
+
+/// The maximum number of a validator's metadata extensions
+pub const MAX_VALIDATOR_METADATA_EXTENSIONS: usize = 32;
+
 /// The number of fundamental units per whole token of the native staking token
 pub const TOKENS_PER_NAM: u64 = 1_000_000;
 
@@ -205,6 +231,14 @@ impl OwnedPosParams {
             ))
         }
 
+        if self.max_validator_stake_share <= Dec::zero()
+            || self.max_validator_stake_share > Dec::one()
+        {
+            errors.push(ValidationError::MaxValidatorStakeShareOutOfRange(
+                self.max_validator_stake_share,
+            ))
+        }
+
         errors
     }
 
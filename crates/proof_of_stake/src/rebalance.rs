@@ -0,0 +1,191 @@
+//! Rebalancing of a delegator's bonds across validators
+
+use std::cmp;
+use std::collections::{BTreeMap, BTreeSet};
+
+use namada_core::address::Address;
+use namada_core::arith::checked;
+use namada_core::storage::Epoch;
+use namada_core::token;
+use namada_storage::{StorageRead, StorageWrite};
+
+use crate::error::RebalanceError;
+use crate::queries::find_delegations;
+use crate::storage::read_pos_params;
+use crate::types::RebalanceStep;
+use crate::{
+    bond_tokens, is_chained_redelegation, is_validator, redelegate_tokens,
+    unbond_tokens,
+};
+
+/// Compute the operations needed to move a delegator's bonds from their
+/// `current` distribution to the `targets` distribution. Validators missing
+/// from the targets are rebalanced to zero.
+///
+/// The excess bonds at validators in `redelegation_sources` are redelegated to
+/// the validators that are short of their target first. Any remaining excess
+/// is unbonded and any remaining shortfall is bonded from the delegator's
+/// balance. The returned steps are ordered as redelegations, then unbonds and
+/// then bonds.
+pub fn compute_rebalance_plan(
+    current: &BTreeMap<Address, token::Amount>,
+    targets: &BTreeMap<Address, token::Amount>,
+    redelegation_sources: &BTreeSet<Address>,
+) -> namada_storage::Result<Vec<RebalanceStep>> {
+    let mut surpluses = vec![];
+    for (validator, &amount) in current {
+        let target = targets.get(validator).copied().unwrap_or_default();
+        if amount > target {
+            surpluses.push((validator, checked!(amount - target)?));
+        }
+    }
+    let mut deficits = vec![];
+    for (validator, &target) in targets {
+        let amount = current.get(validator).copied().unwrap_or_default();
+        if target > amount {
+            deficits.push((validator.clone(), checked!(target - amount)?));
+        }
+    }
+
+    let mut redelegations = vec![];
+    let mut unbonds = vec![];
+    let mut deficits = deficits.into_iter();
+    let mut deficit = deficits.next();
+    for (src_validator, mut surplus) in surpluses {
+        if redelegation_sources.contains(src_validator) {
+            while !surplus.is_zero() {
+                let Some((dest_validator, needed)) = deficit.as_mut() else {
+                    break;
+                };
+                let amount = cmp::min(surplus, *needed);
+                redelegations.push(RebalanceStep::Redelegate {
+                    src_validator: src_validator.clone(),
+                    dest_validator: dest_validator.clone(),
+                    amount,
+                });
+                surplus = checked!(surplus - amount)?;
+                *needed = checked!(*needed - amount)?;
+                if needed.is_zero() {
+                    deficit = deficits.next();
+                }
+            }
+        }
+        if !surplus.is_zero() {
+            unbonds.push(RebalanceStep::Unbond {
+                validator: src_validator.clone(),
+                amount: surplus,
+            });
+        }
+    }
+    let bonds = deficit
+        .into_iter()
+        .chain(deficits)
+        .map(|(validator, amount)| RebalanceStep::Bond { validator, amount });
+
+    Ok(redelegations
+        .into_iter()
+        .chain(unbonds)
+        .chain(bonds)
+        .collect())
+}
+
+/// Plan the rebalancing of the delegator's bonds to the given target amounts
+/// per validator. The targets are compared against the bonds at the pipeline
+/// epoch, without slashes applied. The bonds at validators that hold
+/// redelegated tokens which may still be slashed cannot be redelegated again
+/// and are unbonded instead.
+pub fn plan_rebalance_delegations<S>(
+    storage: &S,
+    delegator: &Address,
+    targets: &[(Address, token::Amount)],
+    current_epoch: Epoch,
+) -> namada_storage::Result<Vec<RebalanceStep>>
+where
+    S: StorageRead,
+{
+    if is_validator(storage, delegator)? {
+        return Err(RebalanceError::DelegatorIsValidator.into());
+    }
+    let mut target_map = BTreeMap::new();
+    for (validator, amount) in targets {
+        if !is_validator(storage, validator)? {
+            return Err(RebalanceError::NotAValidator(validator.clone()).into());
+        }
+        if target_map.insert(validator.clone(), *amount).is_some() {
+            return Err(
+                RebalanceError::DuplicateTarget(validator.clone()).into()
+            );
+        }
+    }
+
+    let params = read_pos_params(storage)?;
+    let pipeline_epoch = checked!(current_epoch + params.pipeline_len)?;
+    let current: BTreeMap<Address, token::Amount> =
+        find_delegations(storage, delegator, &pipeline_epoch)?
+            .into_iter()
+            .filter(|(_, amount)| !amount.is_zero())
+            .collect();
+    let mut redelegation_sources = BTreeSet::new();
+    for validator in current.keys() {
+        if !is_chained_redelegation(
+            storage,
+            &params,
+            delegator,
+            validator,
+            current_epoch,
+        )? {
+            redelegation_sources.insert(validator.clone());
+        }
+    }
+
+    compute_rebalance_plan(&current, &target_map, &redelegation_sources)
+}
+
+/// Apply a rebalancing plan of the delegator's bonds. When a source validator
+/// has been slashed, the destination of its redelegation receives the amount
+/// after slashing.
+pub fn apply_rebalance_plan<S>(
+    storage: &mut S,
+    delegator: &Address,
+    plan: &[RebalanceStep],
+    current_epoch: Epoch,
+) -> namada_storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    for step in plan {
+        match step {
+            RebalanceStep::Redelegate {
+                src_validator,
+                dest_validator,
+                amount,
+            } => redelegate_tokens(
+                storage,
+                delegator,
+                src_validator,
+                dest_validator,
+                current_epoch,
+                *amount,
+            )?,
+            RebalanceStep::Unbond { validator, amount } => {
+                unbond_tokens(
+                    storage,
+                    Some(delegator),
+                    validator,
+                    *amount,
+                    current_epoch,
+                    false,
+                )?;
+            }
+            RebalanceStep::Bond { validator, amount } => bond_tokens(
+                storage,
+                Some(delegator),
+                validator,
+                *amount,
+                current_epoch,
+                None,
+            )?,
+        }
+    }
+    Ok(())
+}
@@ -19,6 +19,7 @@ use namada_storage::collections::lazy_map::{
 use namada_storage::collections::LazyMap;
 use namada_storage::{OptionExt, ResultExt, StorageRead, StorageWrite};
 
+use crate::error::SlashSimulationError;
 use crate::event::PosEvent;
 use crate::storage::{
     enqueued_slashes_handle, read_pos_params, read_validator_last_slash_epoch,
@@ -30,14 +31,16 @@ use crate::storage::{
     write_validator_last_slash_epoch,
 };
 use crate::types::{
-    EagerRedelegatedBondsMap, ResultSlashing, Slash, SlashType, SlashedAmount,
-    Slashes, TotalRedelegatedUnbonded, ValidatorState,
+    BondSlashLoss, EagerRedelegatedBondsMap, ResultSlashing, Slash,
+    SlashSimulation, SlashType, SlashedAmount, Slashes,
+    TotalRedelegatedUnbonded, ValidatorState,
 };
 use crate::validator_set_update::update_validator_set;
 use crate::{
-    fold_and_slash_redelegated_bonds, get_total_consensus_stake,
-    jail_validator, storage, storage_key, types, EagerRedelegatedUnbonds,
-    FoldRedelegatedBondsResult, OwnedPosParams, PosParams,
+    bond_amount, fold_and_slash_redelegated_bonds, get_total_consensus_stake,
+    is_validator, jail_validator, storage, storage_key, types,
+    EagerRedelegatedUnbonds, FoldRedelegatedBondsResult, OwnedPosParams,
+    PosParams,
 };
 
 /// Apply PoS slashes from the evidence
@@ -1279,3 +1282,157 @@ where
     tracing::debug!("Cubic slash rate: {}", cubic_rate);
     Ok(cubic_rate)
 }
+
+/// Simulate slashing a validator for a hypothetical infraction of the given
+/// type committed in `infraction_epoch` and report the resulting slash rate
+/// and the loss of every bond affected by it, including the bonds that were
+/// redelegated away from the validator.
+///
+/// The hypothetical slash is enqueued and its rate computed in the same way as
+/// in [`process_slashes`], but only the slashes of the given validator are
+/// applied and the validator sets and stakes are left untouched. The given
+/// storage gets modified, so it should be a temporary write log that is
+/// dropped afterwards.
+pub fn simulate_slash<S>(
+    storage: &mut S,
+    validator: &Address,
+    slash_type: SlashType,
+    infraction_epoch: Epoch,
+    current_epoch: Epoch,
+) -> namada_storage::Result<SlashSimulation>
+where
+    S: StorageRead + StorageWrite,
+{
+    let params = read_pos_params(storage)?;
+    if !is_validator(storage, validator)? {
+        return Err(
+            SlashSimulationError::NotAValidator(validator.clone()).into()
+        );
+    }
+    if infraction_epoch > current_epoch {
+        return Err(SlashSimulationError::InfractionInFuture(
+            infraction_epoch,
+            current_epoch,
+        )
+        .into());
+    }
+    let offset = params.slash_processing_epoch_offset();
+    let processing_epoch = checked!(infraction_epoch + offset)?;
+    if processing_epoch <= current_epoch {
+        let next_epoch = current_epoch.next();
+        let earliest_epoch = checked!(next_epoch - offset)?;
+        return Err(SlashSimulationError::InfractionTooOld(
+            infraction_epoch,
+            earliest_epoch,
+        )
+        .into());
+    }
+
+    // The bonds of the validator and of the destination validators of its
+    // outgoing redelegations may be affected
+    let mut validators = validator_outgoing_redelegations_handle(validator)
+        .iter(storage)?
+        .map(|res| {
+            let (
+                NestedSubKey::Data {
+                    key: dest_validator,
+                    nested_sub_key: _,
+                },
+                _redelegation,
+            ) = res?;
+            Ok(dest_validator)
+        })
+        .collect::<namada_storage::Result<BTreeSet<_>>>()?;
+    validators.insert(validator.clone());
+
+    // We have to iterate raw bytes, cause the epoched data `last_update` field
+    // gets matched here too
+    let mut bond_ids = BTreeSet::new();
+    for res in namada_storage::iter_prefix_bytes(
+        storage,
+        &storage_key::bonds_prefix(),
+    )? {
+        let (key, _val_bytes) = res?;
+        if let Some((bond_id, _start)) = storage_key::is_bond_key(&key) {
+            if validators.contains(&bond_id.validator) {
+                bond_ids.insert(bond_id);
+            }
+        }
+    }
+    let bonds_before = bond_ids
+        .into_iter()
+        .map(|bond_id| {
+            let amount = bond_amount(storage, &bond_id, processing_epoch)?;
+            Ok((bond_id, amount))
+        })
+        .collect::<namada_storage::Result<Vec<_>>>()?;
+
+    // Enqueue the hypothetical slash at a block height that cannot collide
+    // with a real one
+    let block_height = u64::MAX;
+    let enqueued = enqueued_slashes_handle()
+        .get_data_handler()
+        .at(&processing_epoch)
+        .at(validator);
+    enqueued.insert(
+        storage,
+        block_height,
+        Slash {
+            epoch: infraction_epoch,
+            block_height,
+            r#type: slash_type,
+            rate: Dec::zero(),
+        },
+    )?;
+
+    let cubic_slash_rate =
+        compute_cubic_slash_rate(storage, &params, infraction_epoch)?;
+
+    // Compute the rates of the validator's slashes and record them
+    let enqueued_slashes = enqueued
+        .iter(storage)?
+        .map(|res| {
+            let (_height, slash) = res?;
+            Ok(slash)
+        })
+        .collect::<namada_storage::Result<Vec<_>>>()?;
+    let validator_slashes = validator_slashes_handle(validator);
+    let mut slash_rate = Dec::zero();
+    let mut validator_slash_rate = Dec::zero();
+    for slash in enqueued_slashes {
+        let rate = cmp::min(
+            Dec::one(),
+            cmp::max(slash.r#type.get_slash_rate(&params), cubic_slash_rate),
+        );
+        if slash.block_height == block_height {
+            slash_rate = rate;
+        }
+        let new_rate = checked!(validator_slash_rate + rate)?;
+        validator_slash_rate = cmp::min(Dec::one(), new_rate);
+        validator_slashes.push(storage, Slash { rate, ..slash })?;
+    }
+
+    // Only report the bonds at the destination validators that hold slashable
+    // redelegated tokens
+    let mut bond_losses = Vec::new();
+    for (bond_id, bond_before) in bonds_before {
+        let bond_after = bond_amount(storage, &bond_id, processing_epoch)?;
+        if &bond_id.validator != validator && bond_after == bond_before {
+            continue;
+        }
+        bond_losses.push(BondSlashLoss {
+            bond_id,
+            bond_before,
+            bond_after,
+        });
+    }
+
+    Ok(SlashSimulation {
+        infraction_epoch,
+        processing_epoch,
+        cubic_slash_rate,
+        slash_rate,
+        validator_slash_rate,
+        bond_losses,
+    })
+}
@@ -1,13 +1,15 @@
 //! PoS functions for reading and writing to storage and lazy collection handles
 //! associated with given `storage_key`s.
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
+use borsh::{BorshDeserialize, BorshSerialize};
 use namada_account::protocol_pk_key;
 use namada_core::address::Address;
 use namada_core::arith::checked;
 use namada_core::collections::HashSet;
 use namada_core::dec::Dec;
+use namada_core::hash::Hash;
 use namada_core::key::{common, tm_consensus_key_raw_hash};
 use namada_core::storage::Epoch;
 use namada_core::token;
@@ -18,17 +20,19 @@ use namada_storage::{Result, StorageRead, StorageWrite};
 
 use crate::storage_key::consensus_keys_key;
 use crate::types::{
-    BelowCapacityValidatorSets, BondId, Bonds, CommissionRates,
-    ConsensusValidatorSets, DelegationTargets, DelegatorRedelegatedBonded,
+    AutoCompoundBonds, BelowCapacityValidatorSets, BondId, Bonds,
+    CommissionRates, CommissionSchedules, ConsensusValidatorSets,
+    DelegationTargets, DelegatorRedelegatedBonded,
     DelegatorRedelegatedUnbonded, EpochedSlashes, IncomingRedelegations,
-    LivenessMissedVotes, LivenessSumMissedVotes, OutgoingRedelegations,
-    ReverseOrdTokenAmount, RewardsAccumulator, RewardsProducts, Slashes,
+    LivenessHistory, LivenessMissedVotes, LivenessSumMissedVotes,
+    OutgoingRedelegations, ReverseOrdTokenAmount, RewardsAccumulator,
+    RewardsProducts, Slashes, StructuredValidatorMetadata,
     TotalConsensusStakes, TotalDeltas, TotalRedelegatedBonded,
     TotalRedelegatedUnbonded, Unbonds, ValidatorAddresses,
     ValidatorConsensusKeys, ValidatorDeltas, ValidatorEthColdKeys,
     ValidatorEthHotKeys, ValidatorMetaData, ValidatorProtocolKeys,
     ValidatorSetPositions, ValidatorState, ValidatorStates,
-    ValidatorTotalUnbonded, WeightedValidator,
+    ValidatorTotalUnbonded, WeightedValidator, DISCORD_PLATFORM,
 };
 use crate::{storage_key, MetadataError, OwnedPosParams, PosParams};
 
@@ -245,6 +249,12 @@ pub fn liveness_sum_missed_votes_handle() -> LivenessSumMissedVotes {
     LivenessSumMissedVotes::open(key)
 }
 
+/// Get the storage handle to the per-epoch liveness history
+pub fn liveness_history_handle() -> LivenessHistory {
+    let key = storage_key::liveness_history_key();
+    LivenessHistory::open(key)
+}
+
 /// Get the storage handle to the total active deltas
 pub fn total_active_deltas_handle() -> TotalDeltas {
     let key = storage_key::total_active_deltas_key();
@@ -257,18 +267,143 @@ pub fn delegation_targets_handle(delegator: &Address) -> DelegationTargets {
     DelegationTargets::open(key)
 }
 
+/// Get the storage handle to the scheduled commission rate changes of
+/// validators
+pub fn commission_schedules_handle() -> CommissionSchedules {
+    let key = storage_key::commission_schedules_key();
+    CommissionSchedules::open(key)
+}
+
+/// Get the storage handle to the bonds whose rewards are automatically
+/// re-bonded
+pub fn auto_compound_bonds_handle() -> AutoCompoundBonds {
+    let key = storage_key::auto_compound_bonds_key();
+    AutoCompoundBonds::open(key)
+}
+
 // ---- Storage read + write ----
 
-/// Read owned PoS parameters
+/// The PoS parameters stored under [`storage_key::params_key`]. The
+/// parameters added since launch are stored under their own sub-keys instead,
+/// so that the parameters stored by existing chains remain readable.
+#[derive(BorshSerialize, BorshDeserialize)]
+struct StoredPosParams {
+    max_validator_slots: u64,
+    pipeline_len: u64,
+    unbonding_len: u64,
+    tm_votes_per_token: Dec,
+    block_proposer_reward: Dec,
+    block_vote_reward: Dec,
+    max_inflation_rate: Dec,
+    target_staked_ratio: Dec,
+    duplicate_vote_min_slash_rate: Dec,
+    light_client_attack_min_slash_rate: Dec,
+    cubic_slashing_window_length: u64,
+    validator_stake_threshold: token::Amount,
+    liveness_window_check: u64,
+    liveness_threshold: Dec,
+    rewards_gain_p: Dec,
+    rewards_gain_d: Dec,
+}
+
+impl From<&OwnedPosParams> for StoredPosParams {
+    fn from(params: &OwnedPosParams) -> Self {
+        let OwnedPosParams {
+            max_validator_slots,
+            pipeline_len,
+            unbonding_len,
+            tm_votes_per_token,
+            block_proposer_reward,
+            block_vote_reward,
+            max_inflation_rate,
+            target_staked_ratio,
+            duplicate_vote_min_slash_rate,
+            light_client_attack_min_slash_rate,
+            cubic_slashing_window_length,
+            validator_stake_threshold,
+            min_self_bond: _,
+            max_validator_stake_share: _,
+            liveness_window_check,
+            liveness_threshold,
+            rewards_gain_p,
+            rewards_gain_d,
+        } = *params;
+        Self {
+            max_validator_slots,
+            pipeline_len,
+            unbonding_len,
+            tm_votes_per_token,
+            block_proposer_reward,
+            block_vote_reward,
+            max_inflation_rate,
+            target_staked_ratio,
+            duplicate_vote_min_slash_rate,
+            light_client_attack_min_slash_rate,
+            cubic_slashing_window_length,
+            validator_stake_threshold,
+            liveness_window_check,
+            liveness_threshold,
+            rewards_gain_p,
+            rewards_gain_d,
+        }
+    }
+}
+
+/// Read owned PoS parameters. The parameters stored under their own sub-keys
+/// default to the values that disable them when absent.
 pub fn read_owned_pos_params<S>(
     storage: &S,
 ) -> namada_storage::Result<OwnedPosParams>
 where
     S: StorageRead,
 {
-    Ok(storage
+    let StoredPosParams {
+        max_validator_slots,
+        pipeline_len,
+        unbonding_len,
+        tm_votes_per_token,
+        block_proposer_reward,
+        block_vote_reward,
+        max_inflation_rate,
+        target_staked_ratio,
+        duplicate_vote_min_slash_rate,
+        light_client_attack_min_slash_rate,
+        cubic_slashing_window_length,
+        validator_stake_threshold,
+        liveness_window_check,
+        liveness_threshold,
+        rewards_gain_p,
+        rewards_gain_d,
+    } = storage
         .read(&storage_key::params_key())?
-        .expect("PosParams should always exist in storage after genesis"))
+        .expect("PosParams should always exist in storage after genesis");
+    let min_self_bond = storage
+        .read(&storage_key::params_min_self_bond_key())?
+        .unwrap_or_default();
+    // A share of one disables the cap
+    let max_validator_stake_share = storage
+        .read(&storage_key::params_max_validator_stake_share_key())?
+        .unwrap_or_else(Dec::one);
+    Ok(OwnedPosParams {
+        max_validator_slots,
+        pipeline_len,
+        unbonding_len,
+        tm_votes_per_token,
+        block_proposer_reward,
+        block_vote_reward,
+        max_inflation_rate,
+        target_staked_ratio,
+        duplicate_vote_min_slash_rate,
+        light_client_attack_min_slash_rate,
+        cubic_slashing_window_length,
+        validator_stake_threshold,
+        min_self_bond,
+        max_validator_stake_share,
+        liveness_window_check,
+        liveness_threshold,
+        rewards_gain_p,
+        rewards_gain_d,
+    })
 }
 
 /// Read PoS parameters
@@ -301,11 +436,31 @@ pub fn write_pos_params<S>(
     storage: &mut S,
     params: &OwnedPosParams,
 ) -> namada_storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    write_pos_params_without_sub_keys(storage, params)?;
+    storage.write(
+        &storage_key::params_min_self_bond_key(),
+        params.min_self_bond,
+    )?;
+    storage.write(
+        &storage_key::params_max_validator_stake_share_key(),
+        params.max_validator_stake_share,
+    )
+}
+
+/// Write the PoS parameters stored under [`storage_key::params_key`], leaving
+/// the parameters stored under their own sub-keys untouched
+pub fn write_pos_params_without_sub_keys<S>(
+    storage: &mut S,
+    params: &OwnedPosParams,
+) -> namada_storage::Result<()>
 where
     S: StorageRead + StorageWrite,
 {
     let key = storage_key::params_key();
-    storage.write(&key, params)
+    storage.write(&key, StoredPosParams::from(params))
 }
 
 /// Get the validator address given the raw hash of the Tendermint consensus key
@@ -504,6 +659,65 @@ where
     Ok(amount)
 }
 
+/// Read PoS validator's self-bond amount at the given epoch, after applying
+/// the slashes processed by then.
+pub fn read_validator_self_bond<S>(
+    storage: &S,
+    validator: &Address,
+    epoch: namada_core::storage::Epoch,
+) -> namada_storage::Result<token::Amount>
+where
+    S: StorageRead,
+{
+    crate::bond_amount(
+        storage,
+        &BondId {
+            source: validator.clone(),
+            validator: validator.clone(),
+        },
+        epoch,
+    )
+}
+
+/// Check if the validator's self-bond at the given epoch satisfies the
+/// `min_self_bond` PoS parameter.
+pub fn has_min_self_bond<S>(
+    storage: &S,
+    params: &PosParams,
+    validator: &Address,
+    epoch: namada_core::storage::Epoch,
+) -> namada_storage::Result<bool>
+where
+    S: StorageRead,
+{
+    if params.min_self_bond.is_zero() {
+        return Ok(true);
+    }
+    let self_bond = read_validator_self_bond(storage, validator, epoch)?;
+    Ok(self_bond >= params.min_self_bond)
+}
+
+/// Check if the validator's stake at the given epoch is over the cap set by
+/// the `max_validator_stake_share` PoS parameter. Returns the stake and the
+/// cap if it is.
+pub fn validator_stake_over_cap<S>(
+    storage: &S,
+    params: &PosParams,
+    validator: &Address,
+    epoch: namada_core::storage::Epoch,
+) -> namada_storage::Result<Option<(token::Amount, token::Amount)>>
+where
+    S: StorageRead,
+{
+    if params.max_validator_stake_share >= Dec::one() {
+        return Ok(None);
+    }
+    let total_stake = read_total_stake(storage, params, epoch)?;
+    let cap = total_stake.mul_floor(params.max_validator_stake_share)?;
+    let stake = read_validator_stake(storage, params, validator, epoch)?;
+    Ok((stake > cap).then_some((stake, cap)))
+}
+
 /// Add or remove PoS validator's stake delta value
 pub fn update_validator_deltas<S>(
     storage: &mut S,
@@ -847,7 +1061,9 @@ where
     }
 }
 
-/// Read PoS validator's discord handle.
+/// Read PoS validator's discord handle. It is stored as the social handle of
+/// the [`DISCORD_PLATFORM`], or under the legacy discord handle key if the
+/// metadata has not been migrated yet.
 pub fn read_validator_discord_handle<S>(
     storage: &S,
     validator: &Address,
@@ -855,11 +1071,17 @@ pub fn read_validator_discord_handle<S>(
 where
     S: StorageRead,
 {
-    storage.read(&storage_key::validator_discord_key(validator))
+    match read_validator_social_handles(storage, validator)?
+        .remove(DISCORD_PLATFORM)
+    {
+        Some(handle) => Ok(Some(handle)),
+        None => storage.read(&storage_key::validator_discord_key(validator)),
+    }
 }
 
-/// Write PoS validator's discord handle. If the provided arg is an empty
-/// string, remove the data.
+/// Write PoS validator's discord handle as the social handle of the
+/// [`DISCORD_PLATFORM`]. If the provided arg is an empty string, remove the
+/// data.
 pub fn write_validator_discord_handle<S>(
     storage: &mut S,
     validator: &Address,
@@ -868,12 +1090,15 @@ pub fn write_validator_discord_handle<S>(
 where
     S: StorageRead + StorageWrite,
 {
-    let key = storage_key::validator_discord_key(validator);
+    storage.delete(&storage_key::validator_discord_key(validator))?;
+    let mut social_handles = read_validator_social_handles(storage, validator)?;
     if discord_handle.is_empty() {
-        storage.delete(&key)
+        social_handles.remove(DISCORD_PLATFORM);
     } else {
-        storage.write(&key, discord_handle)
+        social_handles
+            .insert(DISCORD_PLATFORM.to_string(), discord_handle.clone());
     }
+    write_validator_social_handles(storage, validator, &social_handles)
 }
 
 /// Read PoS validator's avatar.
@@ -933,6 +1158,184 @@ where
         storage.write(&key, validator_name)
     }
 }
+
+/// Read PoS validator's security contact.
+pub fn read_validator_security_contact<S>(
+    storage: &S,
+    validator: &Address,
+) -> namada_storage::Result<Option<String>>
+where
+    S: StorageRead,
+{
+    storage.read(&storage_key::validator_security_contact_key(validator))
+}
+
+/// Write PoS validator's security contact. If the provided arg is an empty
+/// string, remove the data.
+pub fn write_validator_security_contact<S>(
+    storage: &mut S,
+    validator: &Address,
+    security_contact: &String,
+) -> namada_storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let key = storage_key::validator_security_contact_key(validator);
+    if security_contact.is_empty() {
+        storage.delete(&key)
+    } else {
+        storage.write(&key, security_contact)
+    }
+}
+
+/// Read PoS validator's social handles, keyed by their platform.
+pub fn read_validator_social_handles<S>(
+    storage: &S,
+    validator: &Address,
+) -> namada_storage::Result<BTreeMap<String, String>>
+where
+    S: StorageRead,
+{
+    Ok(storage
+        .read(&storage_key::validator_social_handles_key(validator))?
+        .unwrap_or_default())
+}
+
+/// Write PoS validator's social handles. If the provided map is empty,
+/// remove the data.
+pub fn write_validator_social_handles<S>(
+    storage: &mut S,
+    validator: &Address,
+    social_handles: &BTreeMap<String, String>,
+) -> namada_storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let key = storage_key::validator_social_handles_key(validator);
+    if social_handles.is_empty() {
+        storage.delete(&key)
+    } else {
+        storage.write(&key, social_handles)
+    }
+}
+
+/// Read PoS validator's identity proof hash.
+pub fn read_validator_identity_proof<S>(
+    storage: &S,
+    validator: &Address,
+) -> namada_storage::Result<Option<Hash>>
+where
+    S: StorageRead,
+{
+    storage.read(&storage_key::validator_identity_proof_key(validator))
+}
+
+/// Write PoS validator's identity proof hash. If the provided hash is zero,
+/// remove the data.
+pub fn write_validator_identity_proof<S>(
+    storage: &mut S,
+    validator: &Address,
+    identity_proof: &Hash,
+) -> namada_storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let key = storage_key::validator_identity_proof_key(validator);
+    if identity_proof.is_zero() {
+        storage.delete(&key)
+    } else {
+        storage.write(&key, identity_proof)
+    }
+}
+
+/// Read PoS validator's metadata extensions.
+pub fn read_validator_metadata_extensions<S>(
+    storage: &S,
+    validator: &Address,
+) -> namada_storage::Result<BTreeMap<String, String>>
+where
+    S: StorageRead,
+{
+    Ok(storage
+        .read(&storage_key::validator_metadata_extensions_key(validator))?
+        .unwrap_or_default())
+}
+
+/// Write PoS validator's metadata extensions. If the provided map is empty,
+/// remove the data.
+pub fn write_validator_metadata_extensions<S>(
+    storage: &mut S,
+    validator: &Address,
+    extensions: &BTreeMap<String, String>,
+) -> namada_storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let key = storage_key::validator_metadata_extensions_key(validator);
+    if extensions.is_empty() {
+        storage.delete(&key)
+    } else {
+        storage.write(&key, extensions)
+    }
+}
+
+/// Read the structured metadata of a PoS validator. Returns `None` if the
+/// validator has no email, which is the only required field.
+pub fn read_validator_structured_metadata<S>(
+    storage: &S,
+    validator: &Address,
+) -> namada_storage::Result<Option<StructuredValidatorMetadata>>
+where
+    S: StorageRead,
+{
+    let Some(email) = read_validator_email(storage, validator)? else {
+        return Ok(None);
+    };
+    let mut social_handles = read_validator_social_handles(storage, validator)?;
+    if let Some(discord_handle) =
+        storage.read(&storage_key::validator_discord_key(validator))?
+    {
+        social_handles
+            .entry(DISCORD_PLATFORM.to_string())
+            .or_insert(discord_handle);
+    }
+    Ok(Some(StructuredValidatorMetadata {
+        email,
+        description: read_validator_description(storage, validator)?,
+        website: read_validator_website(storage, validator)?,
+        avatar: read_validator_avatar(storage, validator)?,
+        name: read_validator_name(storage, validator)?,
+        security_contact: read_validator_security_contact(storage, validator)?,
+        social_handles,
+        identity_proof: read_validator_identity_proof(storage, validator)?,
+        extensions: read_validator_metadata_extensions(storage, validator)?,
+    }))
+}
+
+/// Read the version of the validator metadata schema in storage. Storage
+/// without the version predates the structured metadata.
+pub fn read_metadata_schema_version<S>(
+    storage: &S,
+) -> namada_storage::Result<u8>
+where
+    S: StorageRead,
+{
+    Ok(storage
+        .read(&storage_key::metadata_schema_version_key())?
+        .unwrap_or_default())
+}
+
+/// Write the version of the validator metadata schema.
+pub fn write_metadata_schema_version<S>(
+    storage: &mut S,
+    version: u8,
+) -> namada_storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    storage.write(&storage_key::metadata_schema_version_key(), version)
+}
+
 /// Write validator's metadata.
 pub fn write_validator_metadata<S>(
     storage: &mut S,
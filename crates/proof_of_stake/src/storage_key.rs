@@ -9,6 +9,8 @@ use crate::epoched;
 use crate::types::BondId;
 
 const PARAMS_STORAGE_KEY: &str = "params";
+const PARAMS_MIN_SELF_BOND_KEY: &str = "min_self_bond";
+const PARAMS_MAX_VALIDATOR_STAKE_SHARE_KEY: &str = "max_validator_stake_share";
 const VALIDATOR_ADDRESSES_KEY: &str = "validator_addresses";
 #[allow(missing_docs)]
 pub const VALIDATOR_STORAGE_PREFIX: &str = "validator";
@@ -56,13 +58,21 @@ const VALIDATOR_WEBSITE_KEY: &str = "website";
 const VALIDATOR_DISCORD_KEY: &str = "discord_handle";
 const VALIDATOR_AVATAR_KEY: &str = "avatar";
 const VALIDATOR_NAME_KEY: &str = "name";
+const VALIDATOR_SECURITY_CONTACT_KEY: &str = "security_contact";
+const VALIDATOR_SOCIAL_HANDLES_KEY: &str = "social_handles";
+const VALIDATOR_IDENTITY_PROOF_KEY: &str = "identity_proof";
+const VALIDATOR_METADATA_EXTENSIONS_KEY: &str = "metadata_extensions";
+const METADATA_SCHEMA_VERSION_KEY: &str = "metadata_schema_version";
 const LIVENESS_PREFIX: &str = "liveness";
 const LIVENESS_MISSED_VOTES: &str = "missed_votes";
 const LIVENESS_MISSED_VOTES_SUM: &str = "sum_missed_votes";
+const LIVENESS_HISTORY: &str = "history";
 const LAST_STAKED_RATIO_KEY: &str = "last_staked_ratio";
 const LAST_POS_INFLATION_AMOUNT_KEY: &str = "last_inflation_amount";
 const TOTAL_ACTIVE_DELTAS_KEY: &str = "total_active_deltas";
 const DELEGATION_TARGETS_PREFIX: &str = "delegation_targets";
+const AUTO_COMPOUND_BONDS_KEY: &str = "auto_compound_bonds";
+const COMMISSION_SCHEDULES_KEY: &str = "commission_schedules";
 
 /// Is the given key a PoS storage key?
 pub fn is_pos_key(key: &Key) -> bool {
@@ -79,9 +89,26 @@ pub fn params_key() -> Key {
         .expect("Cannot obtain a storage key")
 }
 
-/// Is storage key for PoS parameters?
+/// Storage key for the `min_self_bond` PoS parameter, which is stored apart
+/// from the parameters under [`params_key`].
+pub fn params_min_self_bond_key() -> Key {
+    params_key()
+        .push(&PARAMS_MIN_SELF_BOND_KEY.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Storage key for the `max_validator_stake_share` PoS parameter, which is
+/// stored apart from the parameters under [`params_key`].
+pub fn params_max_validator_stake_share_key() -> Key {
+    params_key()
+        .push(&PARAMS_MAX_VALIDATOR_STAKE_SHARE_KEY.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Is storage key for PoS parameters, including the parameters stored under
+/// their own sub-key?
 pub fn is_params_key(key: &Key) -> bool {
-    matches!(&key.segments[..], [DbKeySeg::AddressSeg(addr), DbKeySeg::StringSeg(key)] if addr == &ADDRESS && key == PARAMS_STORAGE_KEY)
+    matches!(&key.segments[..], [DbKeySeg::AddressSeg(addr), DbKeySeg::StringSeg(key), sub_key @ ..] if addr == &ADDRESS && key == PARAMS_STORAGE_KEY && sub_key.len() <= 1)
 }
 
 /// Storage key prefix for validator data.
@@ -279,6 +306,10 @@ pub fn is_validator_metadata_key(key: &Key) -> Option<&Address> {
                     | VALIDATOR_DISCORD_KEY
                     | VALIDATOR_AVATAR_KEY
                     | VALIDATOR_NAME_KEY
+                    | VALIDATOR_SECURITY_CONTACT_KEY
+                    | VALIDATOR_SOCIAL_HANDLES_KEY
+                    | VALIDATOR_IDENTITY_PROOF_KEY
+                    | VALIDATOR_METADATA_EXTENSIONS_KEY
             ) =>
         {
             Some(validator)
@@ -1037,6 +1068,41 @@ pub fn validator_name_key(validator: &Address) -> Key {
         .expect("Cannot obtain a storage key")
 }
 
+/// Storage key for a validator's security contact
+pub fn validator_security_contact_key(validator: &Address) -> Key {
+    validator_prefix(validator)
+        .push(&VALIDATOR_SECURITY_CONTACT_KEY.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Storage key for a validator's social handles
+pub fn validator_social_handles_key(validator: &Address) -> Key {
+    validator_prefix(validator)
+        .push(&VALIDATOR_SOCIAL_HANDLES_KEY.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Storage key for a validator's identity proof hash
+pub fn validator_identity_proof_key(validator: &Address) -> Key {
+    validator_prefix(validator)
+        .push(&VALIDATOR_IDENTITY_PROOF_KEY.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Storage key for a validator's metadata extensions
+pub fn validator_metadata_extensions_key(validator: &Address) -> Key {
+    validator_prefix(validator)
+        .push(&VALIDATOR_METADATA_EXTENSIONS_KEY.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Storage key for the version of the validator metadata schema
+pub fn metadata_schema_version_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&METADATA_SCHEMA_VERSION_KEY.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
 /// Storage prefix for the liveness data of the cosnensus validator set.
 pub fn liveness_data_prefix() -> Key {
     Key::from(ADDRESS.to_db_key())
@@ -1058,6 +1124,13 @@ pub fn liveness_sum_missed_votes_key() -> Key {
         .expect("Cannot obtain a storage key")
 }
 
+/// Storage key for the per-epoch liveness history.
+pub fn liveness_history_key() -> Key {
+    liveness_data_prefix()
+        .push(&LIVENESS_HISTORY.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
 /// Storage key for the last epoch's staked ratio.
 pub fn last_staked_ratio_key() -> Key {
     Key::from(ADDRESS.to_db_key())
@@ -1123,3 +1196,31 @@ pub fn is_delegation_targets_key(key: &Key) -> bool {
         false
     }
 }
+
+/// Storage key for the scheduled commission rate changes of validators.
+pub fn commission_schedules_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&COMMISSION_SCHEDULES_KEY.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Storage key for the bonds whose rewards are automatically re-bonded.
+pub fn auto_compound_bonds_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&AUTO_COMPOUND_BONDS_KEY.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Is storage key for the bonds whose rewards are automatically re-bonded?
+pub fn is_auto_compound_bonds_key(key: &Key) -> bool {
+    if key.segments.len() >= 2 {
+        match &key.segments[..2] {
+            [DbKeySeg::AddressSeg(addr), DbKeySeg::StringSeg(prefix)] => {
+                addr == &ADDRESS && prefix == AUTO_COMPOUND_BONDS_KEY
+            }
+            _ => false,
+        }
+    } else {
+        false
+    }
+}
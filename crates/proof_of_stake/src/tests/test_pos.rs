@@ -14,7 +14,7 @@ use namada_core::storage::{BlockHeight, Epoch};
 use namada_core::{address, key};
 use namada_state::testing::TestState;
 use namada_storage::collections::lazy_map::Collectable;
-use namada_storage::StorageRead;
+use namada_storage::{StorageRead, StorageWrite};
 use proptest::prelude::*;
 use proptest::test_runner::Config;
 // Use `RUST_LOG=info` (or another tracing level) and `--nocapture` to see
@@ -28,18 +28,19 @@ use crate::parameters::OwnedPosParams;
 use crate::queries::{
     bonds_and_unbonds, find_delegation_validators, find_delegations,
 };
+use crate::rebalance::{apply_rebalance_plan, plan_rebalance_delegations};
 use crate::rewards::{
     log_block_rewards_aux, update_rewards_products_and_mint_inflation,
     PosRewardsCalculator,
 };
-use crate::slashing::{process_slashes, slash};
+use crate::slashing::{process_slashes, simulate_slash, slash};
 use crate::storage::{
     delegation_targets_handle, get_consensus_key_set,
     liveness_sum_missed_votes_handle,
     read_below_threshold_validator_set_addresses,
-    read_consensus_validator_set_addresses_with_stake, read_total_stake,
-    read_validator_deltas_value, rewards_accumulator_handle,
-    total_deltas_handle,
+    read_consensus_validator_set_addresses_with_stake, read_owned_pos_params,
+    read_total_stake, read_validator_deltas_value, rewards_accumulator_handle,
+    total_deltas_handle, validator_rewards_products_handle, write_pos_params,
 };
 use crate::test_utils::test_init_genesis;
 use crate::tests::helpers::{
@@ -49,16 +50,23 @@ use crate::tests::helpers::{
 use crate::token::{credit_tokens, read_balance};
 use crate::types::{
     into_tm_voting_power, BondDetails, BondId, BondsAndUnbondsDetails,
-    GenesisValidator, SlashType, UnbondDetails, ValidatorState, VoteInfo,
-    WeightedValidator,
+    EpochLiveness, GenesisValidator, RebalanceStep, SlashType, UnbondDetails,
+    ValidatorState, VoteInfo, WeightedValidator,
 };
 use crate::{
-    below_capacity_validator_set_handle, bond_handle, bond_tokens,
-    change_consensus_key, consensus_validator_set_handle, is_delegator,
-    is_validator, jail_for_liveness, read_validator_stake, redelegate_tokens,
-    staking_token_address, token, unbond_handle, unbond_tokens,
-    unjail_validator, validator_consensus_key_handle,
+    apply_commission_schedules, below_capacity_validator_set_handle,
+    bond_handle, bond_tokens, change_consensus_key,
+    change_validator_commission_rate, change_validator_commission_schedule,
+    compound_rewards, consensus_validator_set_handle, is_auto_compounding,
+    is_delegator, is_validator, jail_for_liveness,
+    liveness_missed_votes_threshold, prune_liveness_history,
+    query_reward_tokens, query_rewards_history, read_commission_schedule,
+    read_validator_liveness, read_validator_stake, record_liveness_data,
+    redelegate_tokens, set_auto_compound, staking_token_address, storage_key,
+    token, unbond_handle, unbond_tokens, unjail_validator,
+    validator_commission_rate_handle, validator_consensus_key_handle,
     validator_set_positions_handle, validator_state_handle, withdraw_tokens,
+    LIVENESS_HISTORY_LEN,
 };
 
 proptest! {
@@ -2201,3 +2209,783 @@ fn test_delegation_targets() {
     assert!(de_2.prev_ranges.is_empty());
     assert_eq!(de_2.last_range.1, None);
 }
+
+#[test]
+fn test_auto_compound_rewards() {
+    let stakes = vec![token::Amount::native_whole(1000)];
+    let mut storage = TestState::default();
+    let mut current_epoch = storage.in_mem().block.epoch;
+    let params = OwnedPosParams::default();
+
+    let genesis_validators = get_genesis_validators(1, stakes);
+    let validator = genesis_validators[0].address.clone();
+
+    let delegator = address::testing::gen_implicit_address();
+    let staking_token = staking_token_address(&storage);
+    let bond_amount = token::Amount::native_whole(100);
+    credit_tokens(&mut storage, &staking_token, &delegator, bond_amount)
+        .unwrap();
+
+    let params = test_init_genesis(
+        &mut storage,
+        params,
+        genesis_validators.into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+
+    // Opting in requires a bond
+    assert!(set_auto_compound(
+        &mut storage,
+        Some(&delegator),
+        &validator,
+        true,
+        current_epoch
+    )
+    .is_err());
+
+    bond_tokens(
+        &mut storage,
+        Some(&delegator),
+        &validator,
+        bond_amount,
+        current_epoch,
+        None,
+    )
+    .unwrap();
+    set_auto_compound(
+        &mut storage,
+        Some(&delegator),
+        &validator,
+        true,
+        current_epoch,
+    )
+    .unwrap();
+    assert!(
+        is_auto_compounding(&storage, Some(&delegator), &validator).unwrap()
+    );
+
+    // Advance to the epoch in which the bond becomes active and give it some
+    // rewards
+    for _ in 0..params.pipeline_len {
+        current_epoch = advance_epoch(&mut storage, &params);
+    }
+    let rewards = token::Amount::native_whole(10);
+    validator_rewards_products_handle(&validator)
+        .insert(&mut storage, current_epoch, Dec::new(1, 1).unwrap())
+        .unwrap();
+    credit_tokens(&mut storage, &staking_token, &address::POS, rewards)
+        .unwrap();
+
+    // The rewards of the last epoch are not compounded yet, since its rewards
+    // products are only computed after the compounding
+    let mut events: Vec<namada_events::Event> = vec![];
+    current_epoch = advance_epoch(&mut storage, &params);
+    compound_rewards(&mut storage, &mut events, &params, current_epoch)
+        .unwrap();
+    assert!(events.is_empty());
+
+    current_epoch = advance_epoch(&mut storage, &params);
+    compound_rewards(&mut storage, &mut events, &params, current_epoch)
+        .unwrap();
+    assert_eq!(events.len(), 1);
+
+    let pipeline_epoch = current_epoch + params.pipeline_len;
+    let bond = bond_handle(&delegator, &validator)
+        .get_sum(&storage, pipeline_epoch, &params)
+        .unwrap()
+        .unwrap_or_default();
+    assert_eq!(bond, bond_amount + rewards);
+    assert!(read_balance(&storage, &staking_token, &delegator)
+        .unwrap()
+        .is_zero());
+    assert!(query_reward_tokens(
+        &storage,
+        Some(&delegator),
+        &validator,
+        current_epoch
+    )
+    .unwrap()
+    .is_zero());
+
+    // Opt out
+    set_auto_compound(
+        &mut storage,
+        Some(&delegator),
+        &validator,
+        false,
+        current_epoch,
+    )
+    .unwrap();
+    assert!(
+        !is_auto_compounding(&storage, Some(&delegator), &validator).unwrap()
+    );
+}
+
+#[test]
+fn test_commission_schedule() {
+    let stakes = vec![token::Amount::native_whole(1000)];
+    let mut storage = TestState::default();
+    let mut current_epoch = storage.in_mem().block.epoch;
+    let params = OwnedPosParams::default();
+
+    // Genesis validators have a rate of 0.05 and a max change of 0.01
+    let genesis_validators = get_genesis_validators(1, stakes);
+    let validator = genesis_validators[0].address.clone();
+
+    let params = test_init_genesis(
+        &mut storage,
+        params,
+        genesis_validators.into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+    let pipeline_epoch = current_epoch + params.pipeline_len;
+    let first_epoch = pipeline_epoch.next();
+    let second_epoch = first_epoch + 2_u64;
+    let first_rate = Dec::new(6, 2).unwrap();
+    let second_rate = Dec::new(7, 2).unwrap();
+
+    // Changes must be after the pipeline epoch, ordered and within the max
+    // change from the preceding rate
+    assert!(change_validator_commission_schedule(
+        &mut storage,
+        &validator,
+        vec![(pipeline_epoch, first_rate)],
+        current_epoch,
+    )
+    .is_err());
+    assert!(change_validator_commission_schedule(
+        &mut storage,
+        &validator,
+        vec![(second_epoch, second_rate), (first_epoch, first_rate)],
+        current_epoch,
+    )
+    .is_err());
+    assert!(change_validator_commission_schedule(
+        &mut storage,
+        &validator,
+        vec![(first_epoch, second_rate)],
+        current_epoch,
+    )
+    .is_err());
+
+    change_validator_commission_schedule(
+        &mut storage,
+        &validator,
+        vec![(first_epoch, first_rate), (second_epoch, second_rate)],
+        current_epoch,
+    )
+    .unwrap();
+    assert_eq!(
+        read_commission_schedule(&storage, &validator, current_epoch).unwrap(),
+        BTreeMap::from([
+            (first_epoch, first_rate),
+            (second_epoch, second_rate)
+        ])
+    );
+
+    // Direct changes are rejected while a schedule is pending
+    assert!(change_validator_commission_rate(
+        &mut storage,
+        &validator,
+        first_rate,
+        current_epoch,
+    )
+    .is_err());
+
+    // An empty schedule cancels the pending changes
+    let schedule =
+        read_commission_schedule(&storage, &validator, current_epoch)
+            .unwrap()
+            .into_iter()
+            .collect::<Vec<_>>();
+    change_validator_commission_schedule(
+        &mut storage,
+        &validator,
+        vec![],
+        current_epoch,
+    )
+    .unwrap();
+    assert!(
+        read_commission_schedule(&storage, &validator, current_epoch)
+            .unwrap()
+            .is_empty()
+    );
+    change_validator_commission_schedule(
+        &mut storage,
+        &validator,
+        schedule,
+        current_epoch,
+    )
+    .unwrap();
+
+    // The first change is written to the commission rates once it enters the
+    // pipeline
+    let mut events: Vec<namada_events::Event> = vec![];
+    current_epoch = advance_epoch(&mut storage, &params);
+    apply_commission_schedules(
+        &mut storage,
+        &mut events,
+        &params,
+        current_epoch,
+    )
+    .unwrap();
+    assert!(events.is_empty());
+    let commission_handle = validator_commission_rate_handle(&validator);
+    assert_eq!(
+        commission_handle
+            .get(&storage, first_epoch.prev().unwrap(), &params)
+            .unwrap(),
+        Some(Dec::new(5, 2).unwrap())
+    );
+    assert_eq!(
+        commission_handle
+            .get(&storage, first_epoch, &params)
+            .unwrap(),
+        Some(first_rate)
+    );
+
+    // Once in effect, the change is removed from the schedule
+    while current_epoch < first_epoch {
+        current_epoch = advance_epoch(&mut storage, &params);
+        apply_commission_schedules(
+            &mut storage,
+            &mut events,
+            &params,
+            current_epoch,
+        )
+        .unwrap();
+    }
+    assert_eq!(events.len(), 1);
+    assert_eq!(
+        read_commission_schedule(&storage, &validator, Epoch(0)).unwrap(),
+        BTreeMap::from([(second_epoch, second_rate)])
+    );
+}
+
+#[test]
+fn test_liveness_history() {
+    let stakes = vec![
+        token::Amount::native_whole(1000),
+        token::Amount::native_whole(1000),
+    ];
+    let mut storage = TestState::default();
+    let current_epoch = storage.in_mem().block.epoch;
+    let params = OwnedPosParams::default();
+
+    let genesis_validators = get_genesis_validators(2, stakes);
+    let voter = genesis_validators[0].address.clone();
+    let non_voter = genesis_validators[1].address.clone();
+
+    let params = test_init_genesis(
+        &mut storage,
+        params,
+        genesis_validators.into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+
+    let votes = vec![VoteInfo {
+        validator_address: voter.clone(),
+        validator_vp: 1,
+    }];
+    let num_blocks = 3_u64;
+    for height in 1..=num_blocks {
+        record_liveness_data(
+            &mut storage,
+            &votes,
+            current_epoch,
+            BlockHeight(height),
+            &params,
+        )
+        .unwrap();
+    }
+
+    let voter_liveness =
+        read_validator_liveness(&storage, &params, &voter, current_epoch, None)
+            .unwrap();
+    assert_eq!(voter_liveness.window_missed_votes, 0);
+    assert_eq!(
+        voter_liveness.history,
+        BTreeMap::from([(
+            current_epoch,
+            EpochLiveness {
+                expected_votes: num_blocks,
+                missed_votes: 0,
+            }
+        )])
+    );
+
+    let non_voter_liveness = read_validator_liveness(
+        &storage,
+        &params,
+        &non_voter,
+        current_epoch,
+        None,
+    )
+    .unwrap();
+    assert_eq!(non_voter_liveness.window_missed_votes, num_blocks);
+    assert_eq!(
+        non_voter_liveness.window_missed_votes_threshold,
+        liveness_missed_votes_threshold(&params).unwrap()
+    );
+    assert_eq!(non_voter_liveness.window_len, params.liveness_window_check);
+    assert_eq!(
+        non_voter_liveness.history,
+        BTreeMap::from([(
+            current_epoch,
+            EpochLiveness {
+                expected_votes: num_blocks,
+                missed_votes: num_blocks,
+            }
+        )])
+    );
+
+    // The history is kept for a bounded number of epochs
+    let last_kept_epoch = current_epoch + LIVENESS_HISTORY_LEN;
+    prune_liveness_history(&mut storage, last_kept_epoch).unwrap();
+    assert!(!read_validator_liveness(
+        &storage,
+        &params,
+        &non_voter,
+        last_kept_epoch,
+        None
+    )
+    .unwrap()
+    .history
+    .is_empty());
+    // Less history can be requested
+    assert!(read_validator_liveness(
+        &storage,
+        &params,
+        &non_voter,
+        last_kept_epoch,
+        Some(1)
+    )
+    .unwrap()
+    .history
+    .is_empty());
+
+    prune_liveness_history(&mut storage, last_kept_epoch.next()).unwrap();
+    assert!(read_validator_liveness(
+        &storage,
+        &params,
+        &non_voter,
+        current_epoch,
+        None
+    )
+    .unwrap()
+    .history
+    .is_empty());
+}
+
+#[test]
+fn test_rewards_history() {
+    let stakes = vec![token::Amount::native_whole(1000)];
+    let mut storage = TestState::default();
+    let mut current_epoch = storage.in_mem().block.epoch;
+    let params = OwnedPosParams::default();
+
+    let genesis_validators = get_genesis_validators(1, stakes);
+    let validator = genesis_validators[0].address.clone();
+
+    let delegator = address::testing::gen_implicit_address();
+    let staking_token = staking_token_address(&storage);
+    let bond_amount = token::Amount::native_whole(100);
+    credit_tokens(&mut storage, &staking_token, &delegator, bond_amount)
+        .unwrap();
+
+    let params = test_init_genesis(
+        &mut storage,
+        params,
+        genesis_validators.into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+
+    bond_tokens(
+        &mut storage,
+        Some(&delegator),
+        &validator,
+        bond_amount,
+        current_epoch,
+        None,
+    )
+    .unwrap();
+    let bond_epoch = current_epoch + params.pipeline_len;
+
+    for _ in 0..params.pipeline_len {
+        current_epoch = advance_epoch(&mut storage, &params);
+    }
+    let rewards_product = Dec::new(1, 1).unwrap();
+    validator_rewards_products_handle(&validator)
+        .insert(&mut storage, current_epoch, rewards_product)
+        .unwrap();
+
+    // The rewards of the current epoch are not computed yet
+    let history = query_rewards_history(
+        &storage,
+        Some(&delegator),
+        &validator,
+        Epoch(0),
+        current_epoch,
+        current_epoch,
+    )
+    .unwrap();
+    assert!(history.is_empty());
+
+    current_epoch = advance_epoch(&mut storage, &params);
+    let history = query_rewards_history(
+        &storage,
+        Some(&delegator),
+        &validator,
+        Epoch(0),
+        current_epoch + 10_u64,
+        current_epoch,
+    )
+    .unwrap();
+    assert_eq!(history.len(), 1);
+    let epoch_rewards = history.get(&bond_epoch).unwrap();
+    assert_eq!(epoch_rewards.bond_amount, bond_amount);
+    assert!(epoch_rewards.slashed_amount.is_zero());
+    assert_eq!(epoch_rewards.rewards_product, rewards_product);
+    assert_eq!(epoch_rewards.rewards, token::Amount::native_whole(10));
+    // The genesis validators' commission rate is 5%
+    assert_eq!(epoch_rewards.commission_rate, Some(Dec::new(5, 2).unwrap()));
+    let commission = epoch_rewards.commission.unwrap();
+    assert!(!commission.is_zero());
+    assert!(commission < epoch_rewards.rewards);
+
+    // The history agrees with the claimable rewards
+    assert_eq!(
+        epoch_rewards.rewards,
+        query_reward_tokens(
+            &storage,
+            Some(&delegator),
+            &validator,
+            current_epoch
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn test_simulate_slash() {
+    let stakes = vec![token::Amount::native_whole(1000); 10];
+    let mut storage = TestState::default();
+    let mut current_epoch = storage.in_mem().block.epoch;
+    let params = OwnedPosParams::default();
+
+    let genesis_validators = get_genesis_validators(10, stakes);
+    let validator1 = genesis_validators[0].address.clone();
+    let validator2 = genesis_validators[1].address.clone();
+
+    let delegator1 = address::testing::established_address_1();
+    let delegator2 = address::testing::established_address_2();
+    let staking_token = staking_token_address(&storage);
+    let bond_amount = token::Amount::native_whole(100);
+    for delegator in [&delegator1, &delegator2] {
+        credit_tokens(&mut storage, &staking_token, delegator, bond_amount)
+            .unwrap();
+    }
+
+    let params = test_init_genesis(
+        &mut storage,
+        params,
+        genesis_validators.into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+
+    for delegator in [&delegator1, &delegator2] {
+        bond_tokens(
+            &mut storage,
+            Some(delegator),
+            &validator1,
+            bond_amount,
+            current_epoch,
+            None,
+        )
+        .unwrap();
+    }
+    for _ in 0..params.pipeline_len {
+        current_epoch = advance_epoch(&mut storage, &params);
+    }
+    // The redelegated bond still contributes to validator1's stake until the
+    // pipeline epoch
+    redelegate_tokens(
+        &mut storage,
+        &delegator2,
+        &validator1,
+        &validator2,
+        current_epoch,
+        bond_amount,
+    )
+    .unwrap();
+    current_epoch = advance_epoch(&mut storage, &params);
+
+    // Invalid inputs
+    assert!(simulate_slash(
+        &mut storage,
+        &delegator1,
+        SlashType::DuplicateVote,
+        current_epoch,
+        current_epoch,
+    )
+    .is_err());
+    assert!(simulate_slash(
+        &mut storage,
+        &validator1,
+        SlashType::DuplicateVote,
+        current_epoch.next(),
+        current_epoch,
+    )
+    .is_err());
+    let late_epoch = current_epoch + params.slash_processing_epoch_offset();
+    assert!(simulate_slash(
+        &mut storage,
+        &validator1,
+        SlashType::DuplicateVote,
+        current_epoch,
+        late_epoch,
+    )
+    .is_err());
+
+    let simulation = simulate_slash(
+        &mut storage,
+        &validator1,
+        SlashType::DuplicateVote,
+        current_epoch,
+        current_epoch,
+    )
+    .unwrap();
+    assert_eq!(simulation.infraction_epoch, current_epoch);
+    assert_eq!(
+        simulation.processing_epoch,
+        current_epoch + params.slash_processing_epoch_offset()
+    );
+    // Validator1 holds 1200 of the total stake of 10200 in the infraction
+    // epoch, which gives a cubic slash rate above the min rate
+    let fraction = Dec::new(1200, 0).unwrap() / Dec::new(10200, 0).unwrap();
+    let cubic_slash_rate = Dec::new(9, 0).unwrap() * fraction * fraction;
+    assert_eq!(simulation.cubic_slash_rate, cubic_slash_rate);
+    assert!(cubic_slash_rate > params.duplicate_vote_min_slash_rate);
+    assert_eq!(simulation.slash_rate, cubic_slash_rate);
+    assert_eq!(simulation.validator_slash_rate, cubic_slash_rate);
+
+    // The self-bond, the bond of delegator1 and the redelegated bond of
+    // delegator2 are affected, but not the self-bond of validator2
+    let losses = simulation
+        .bond_losses
+        .iter()
+        .map(|bond_loss| (bond_loss.bond_id.clone(), bond_loss.clone()))
+        .collect::<BTreeMap<_, _>>();
+    assert_eq!(losses.len(), 3);
+    let self_bond = BondId {
+        source: validator1.clone(),
+        validator: validator1.clone(),
+    };
+    let delegation = BondId {
+        source: delegator1,
+        validator: validator1,
+    };
+    let redelegation = BondId {
+        source: delegator2,
+        validator: validator2,
+    };
+    assert_eq!(
+        losses[&self_bond].bond_before,
+        token::Amount::native_whole(1000)
+    );
+    for bond_id in [&delegation, &redelegation] {
+        let bond_loss = &losses[bond_id];
+        assert_eq!(bond_loss.bond_before, bond_amount);
+        let expected_after =
+            bond_amount - bond_amount.mul_ceil(cubic_slash_rate).unwrap();
+        assert_eq!(bond_loss.bond_after, expected_after);
+        assert!(!bond_loss.loss().is_zero());
+    }
+}
+
+#[test]
+fn test_rebalance_delegations() {
+    let stakes = vec![token::Amount::native_whole(1000); 3];
+    let mut storage = TestState::default();
+    let mut current_epoch = storage.in_mem().block.epoch;
+    let params = OwnedPosParams::default();
+
+    let genesis_validators = get_genesis_validators(3, stakes);
+    let validator1 = genesis_validators[0].address.clone();
+    let validator2 = genesis_validators[1].address.clone();
+    let validator3 = genesis_validators[2].address.clone();
+
+    let delegator = address::testing::established_address_1();
+    let staking_token = staking_token_address(&storage);
+    credit_tokens(
+        &mut storage,
+        &staking_token,
+        &delegator,
+        token::Amount::native_whole(1500),
+    )
+    .unwrap();
+
+    let params = test_init_genesis(
+        &mut storage,
+        params,
+        genesis_validators.into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+
+    for (validator, amount) in [(&validator1, 600), (&validator2, 400)] {
+        bond_tokens(
+            &mut storage,
+            Some(&delegator),
+            validator,
+            token::Amount::native_whole(amount),
+            current_epoch,
+            None,
+        )
+        .unwrap();
+    }
+    for _ in 0..params.pipeline_len {
+        current_epoch = advance_epoch(&mut storage, &params);
+    }
+    let pipeline_epoch = current_epoch + params.pipeline_len;
+
+    // Invalid targets
+    assert!(plan_rebalance_delegations(
+        &storage,
+        &delegator,
+        &[(delegator.clone(), token::Amount::native_whole(1))],
+        current_epoch,
+    )
+    .is_err());
+    assert!(plan_rebalance_delegations(
+        &storage,
+        &delegator,
+        &[
+            (validator3.clone(), token::Amount::native_whole(1)),
+            (validator3.clone(), token::Amount::native_whole(2)),
+        ],
+        current_epoch,
+    )
+    .is_err());
+    assert!(plan_rebalance_delegations(
+        &storage,
+        &validator1,
+        &[(validator3.clone(), token::Amount::native_whole(1))],
+        current_epoch,
+    )
+    .is_err());
+
+    // The excess bonds are redelegated to cover the shortfall and the rest is
+    // unbonded
+    let targets = [
+        (validator2.clone(), token::Amount::native_whole(200)),
+        (validator3.clone(), token::Amount::native_whole(500)),
+    ];
+    let plan = plan_rebalance_delegations(
+        &storage,
+        &delegator,
+        &targets,
+        current_epoch,
+    )
+    .unwrap();
+    let redelegated =
+        token::Amount::sum(plan.iter().filter_map(|step| match step {
+            RebalanceStep::Redelegate {
+                dest_validator,
+                amount,
+                ..
+            } => {
+                assert_eq!(dest_validator, &validator3);
+                Some(*amount)
+            }
+            RebalanceStep::Bond { .. } => panic!("No bond is needed"),
+            RebalanceStep::Unbond { .. } => None,
+        }))
+        .unwrap();
+    assert_eq!(redelegated, token::Amount::native_whole(500));
+    let unbonded =
+        token::Amount::sum(plan.iter().filter_map(|step| match step {
+            RebalanceStep::Unbond { amount, .. } => Some(*amount),
+            _ => None,
+        }))
+        .unwrap();
+    assert_eq!(unbonded, token::Amount::native_whole(300));
+    apply_rebalance_plan(&mut storage, &delegator, &plan, current_epoch)
+        .unwrap();
+    let delegations: BTreeMap<_, _> =
+        find_delegations(&storage, &delegator, &pipeline_epoch)
+            .unwrap()
+            .into_iter()
+            .filter(|(_, amount)| !amount.is_zero())
+            .collect();
+    assert_eq!(delegations, BTreeMap::from(targets));
+
+    // The bonds at validator3 hold a redelegation that may still be slashed,
+    // so they can only be unbonded and the shortfall is bonded from balance
+    let targets = [(validator1.clone(), token::Amount::native_whole(500))];
+    let plan = plan_rebalance_delegations(
+        &storage,
+        &delegator,
+        &targets,
+        current_epoch,
+    )
+    .unwrap();
+    assert!(plan.contains(&RebalanceStep::Unbond {
+        validator: validator3.clone(),
+        amount: token::Amount::native_whole(500),
+    }));
+    assert!(plan.contains(&RebalanceStep::Bond {
+        validator: validator1.clone(),
+        amount: token::Amount::native_whole(300),
+    }));
+    assert!(!plan.iter().any(|step| matches!(
+        step,
+        RebalanceStep::Redelegate { src_validator, .. }
+            if src_validator == &validator3
+    )));
+    apply_rebalance_plan(&mut storage, &delegator, &plan, current_epoch)
+        .unwrap();
+    let delegations: BTreeMap<_, _> =
+        find_delegations(&storage, &delegator, &pipeline_epoch)
+            .unwrap()
+            .into_iter()
+            .filter(|(_, amount)| !amount.is_zero())
+            .collect();
+    assert_eq!(delegations, BTreeMap::from(targets));
+}
+
+/// Test that the PoS parameters stored under their own sub-keys round-trip,
+/// and that they default to disabled on chains that never stored them
+#[test]
+fn test_pos_params_sub_keys() {
+    let mut storage = TestState::default();
+    let params = OwnedPosParams {
+        min_self_bond: token::Amount::native_whole(100),
+        max_validator_stake_share: Dec::new(1, 1).unwrap(),
+        ..Default::default()
+    };
+    write_pos_params(&mut storage, &params).unwrap();
+    let read_params = read_owned_pos_params(&storage).unwrap();
+    assert_eq!(read_params.min_self_bond, params.min_self_bond);
+    assert_eq!(
+        read_params.max_validator_stake_share,
+        params.max_validator_stake_share
+    );
+
+    // Chains launched before the sub-keys existed only have the params key
+    storage
+        .delete(&storage_key::params_min_self_bond_key())
+        .unwrap();
+    storage
+        .delete(&storage_key::params_max_validator_stake_share_key())
+        .unwrap();
+    let read_params = read_owned_pos_params(&storage).unwrap();
+    assert!(read_params.min_self_bond.is_zero());
+    assert_eq!(read_params.max_validator_stake_share, Dec::one());
+    assert_eq!(read_params.pipeline_len, params.pipeline_len);
+}
@@ -1,10 +1,12 @@
 #![allow(clippy::arithmetic_side_effects)]
 
 use std::cmp::min;
+use std::collections::BTreeMap;
 
 use namada_core::address::testing::arb_established_address;
 use namada_core::address::{self, Address, EstablishedAddressGen};
 use namada_core::dec::Dec;
+use namada_core::hash::Hash;
 use namada_core::key::testing::{
     arb_common_keypair, common_sk_from_simple_seed,
 };
@@ -13,6 +15,7 @@ use namada_core::storage::Epoch;
 use namada_core::token;
 use namada_state::testing::TestState;
 use namada_storage::collections::lazy_map;
+use namada_storage::{StorageRead, StorageWrite};
 use proptest::prelude::*;
 use proptest::test_runner::Config;
 // Use `RUST_LOG=info` (or another tracing level) and `--nocapture` to see
@@ -20,33 +23,53 @@ use proptest::test_runner::Config;
 use test_log::test;
 
 use crate::epoched::DEFAULT_NUM_PAST_EPOCHS;
+use crate::metadata::{
+    change_validator_structured_metadata, find_validators_by_metadata_field,
+    migrate_validator_metadata, METADATA_SCHEMA_VERSION,
+};
+use crate::parameters::{
+    MAX_VALIDATOR_METADATA_LEN, MAX_VALIDATOR_SOCIAL_HANDLES,
+};
+use crate::slashing::{process_slashes, slash};
 use crate::storage::{
     below_capacity_validator_set_handle, bond_handle,
     consensus_validator_set_handle, find_validator_by_raw_hash,
     get_num_consensus_validators,
     read_below_capacity_validator_set_addresses_with_stake,
     read_below_threshold_validator_set_addresses,
-    read_consensus_validator_set_addresses_with_stake, update_validator_deltas,
-    validator_addresses_handle, validator_consensus_key_handle,
-    validator_set_positions_handle, write_validator_address_raw_hash,
+    read_consensus_validator_set_addresses,
+    read_consensus_validator_set_addresses_with_stake,
+    read_metadata_schema_version, read_pos_params,
+    read_validator_discord_handle, read_validator_self_bond,
+    read_validator_stake, read_validator_structured_metadata,
+    update_validator_deltas, validator_addresses_handle,
+    validator_consensus_key_handle, validator_eth_cold_key_handle,
+    validator_eth_hot_key_handle, validator_protocol_key_handle,
+    validator_set_positions_handle, validator_state_handle,
+    write_metadata_schema_version, write_pos_params,
+    write_validator_address_raw_hash,
 };
+use crate::storage_key::validator_discord_key;
 use crate::test_utils::{init_genesis_helper, test_init_genesis};
 use crate::tests::helpers::{
     advance_epoch, arb_genesis_validators, arb_params_and_genesis_validators,
-    get_tendermint_set_updates,
+    get_genesis_validators, get_tendermint_set_updates,
 };
 use crate::token::credit_tokens;
 use crate::types::{
-    into_tm_voting_power, ConsensusValidator, GenesisValidator, Position,
-    ReverseOrdTokenAmount, ValidatorSetUpdate, WeightedValidator,
+    into_tm_voting_power, ConsensusValidator, GenesisValidator, MetadataField,
+    Position, ReverseOrdTokenAmount, SlashType, ValidatorSetUpdate,
+    ValidatorState, WeightedValidator,
 };
 use crate::validator_set_update::{
     insert_validator_into_validator_set, update_validator_set,
+    update_validator_sets_for_min_self_bond,
 };
 use crate::{
-    become_validator, bond_tokens, change_consensus_key, is_validator,
-    staking_token_address, unbond_tokens, withdraw_tokens, BecomeValidator,
-    OwnedPosParams,
+    become_validator, bond_tokens, change_consensus_key, change_validator_keys,
+    deactivate_validator, is_validator, redelegate_tokens,
+    staking_token_address, unbond_tokens, unjail_validator, withdraw_tokens,
+    BecomeValidator, OwnedPosParams,
 };
 
 proptest! {
@@ -1395,3 +1418,668 @@ fn test_purge_validator_information_aux(validators: Vec<GenesisValidator>) {
         assert!(!consensus_val_set.at(&ep).is_empty(&s).unwrap());
     }
 }
+
+#[test]
+fn test_min_self_bond() {
+    let mut s = TestState::default();
+    let mut current_epoch = s.in_mem().block.epoch;
+    let params = OwnedPosParams {
+        min_self_bond: token::Amount::native_whole(100),
+        ..Default::default()
+    };
+
+    let validator = address::testing::established_address_1();
+    let new_validator = address::testing::established_address_2();
+    let delegator = address::testing::established_address_3();
+    let genesis_validators = [GenesisValidator {
+        address: validator.clone(),
+        tokens: token::Amount::native_whole(1000),
+        consensus_key: key::testing::keypair_1().to_public(),
+        protocol_key: key::testing::keypair_2().to_public(),
+        eth_cold_key: key::common::PublicKey::Secp256k1(
+            key::testing::gen_keypair::<key::secp256k1::SigScheme>().ref_to(),
+        ),
+        eth_hot_key: key::common::PublicKey::Secp256k1(
+            key::testing::gen_keypair::<key::secp256k1::SigScheme>().ref_to(),
+        ),
+        commission_rate: Dec::new(1, 1).expect("Dec creation failed"),
+        max_commission_rate_change: Dec::new(1, 1)
+            .expect("Dec creation failed"),
+        metadata: Default::default(),
+    }];
+    let params = test_init_genesis(
+        &mut s,
+        params,
+        genesis_validators.into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+    let pipeline_epoch = current_epoch + params.pipeline_len;
+
+    // The self-bond cannot be unbonded below the minimum
+    let res = unbond_tokens(
+        &mut s,
+        None,
+        &validator,
+        token::Amount::native_whole(950),
+        current_epoch,
+        false,
+    );
+    assert!(res.is_err());
+    unbond_tokens(
+        &mut s,
+        None,
+        &validator,
+        token::Amount::native_whole(900),
+        current_epoch,
+        false,
+    )
+    .unwrap();
+    assert_eq!(
+        read_validator_self_bond(&s, &validator, pipeline_epoch).unwrap(),
+        params.min_self_bond
+    );
+
+    // A new validator with delegations but without the minimum self-bond stays
+    // below the threshold
+    let staking_token = staking_token_address(&s);
+    let delegation = token::Amount::native_whole(500);
+    credit_tokens(&mut s, &staking_token, &delegator, delegation).unwrap();
+    credit_tokens(&mut s, &staking_token, &new_validator, params.min_self_bond)
+        .unwrap();
+    become_validator(
+        &mut s,
+        BecomeValidator {
+            params: &params,
+            address: &new_validator,
+            consensus_key: &common_sk_from_simple_seed(1).to_public(),
+            protocol_key: &common_sk_from_simple_seed(2).to_public(),
+            eth_cold_key: &key::common::PublicKey::Secp256k1(
+                key::testing::gen_keypair::<key::secp256k1::SigScheme>()
+                    .ref_to(),
+            ),
+            eth_hot_key: &key::common::PublicKey::Secp256k1(
+                key::testing::gen_keypair::<key::secp256k1::SigScheme>()
+                    .ref_to(),
+            ),
+            current_epoch,
+            commission_rate: Dec::new(5, 2).expect("Dec creation failed"),
+            max_commission_rate_change: Dec::new(5, 2)
+                .expect("Dec creation failed"),
+            metadata: Default::default(),
+            offset_opt: None,
+        },
+    )
+    .unwrap();
+    bond_tokens(
+        &mut s,
+        Some(&delegator),
+        &new_validator,
+        delegation,
+        current_epoch,
+        None,
+    )
+    .unwrap();
+    assert_eq!(
+        validator_state_handle(&new_validator)
+            .get(&s, pipeline_epoch, &params)
+            .unwrap(),
+        Some(ValidatorState::BelowThreshold)
+    );
+    assert!(!read_consensus_validator_set_addresses(&s, pipeline_epoch)
+        .unwrap()
+        .contains(&new_validator));
+
+    // Self-bonding the minimum moves it into the consensus set with all of its
+    // stake
+    bond_tokens(
+        &mut s,
+        None,
+        &new_validator,
+        params.min_self_bond,
+        current_epoch,
+        None,
+    )
+    .unwrap();
+    assert_eq!(
+        validator_state_handle(&new_validator)
+            .get(&s, pipeline_epoch, &params)
+            .unwrap(),
+        Some(ValidatorState::Consensus)
+    );
+    let consensus_set =
+        read_consensus_validator_set_addresses_with_stake(&s, pipeline_epoch)
+            .unwrap();
+    assert!(consensus_set.contains(&WeightedValidator {
+        bonded_stake: delegation + params.min_self_bond,
+        address: new_validator.clone(),
+    }));
+
+    // Once the validator is inactive, it can unbond its whole self-bond
+    current_epoch = advance_epoch(&mut s, &params);
+    deactivate_validator(&mut s, &validator, current_epoch).unwrap();
+    unbond_tokens(
+        &mut s,
+        None,
+        &validator,
+        params.min_self_bond,
+        current_epoch,
+        false,
+    )
+    .unwrap();
+    assert!(read_validator_self_bond(
+        &s,
+        &validator,
+        current_epoch + params.pipeline_len
+    )
+    .unwrap()
+    .is_zero());
+}
+
+#[test]
+fn test_min_self_bond_param_change() {
+    let mut s = TestState::default();
+    let current_epoch = s.in_mem().block.epoch;
+    let params = OwnedPosParams {
+        max_validator_slots: 1,
+        ..Default::default()
+    };
+    let genesis_validators = get_genesis_validators(
+        2,
+        vec![
+            token::Amount::native_whole(1000),
+            token::Amount::native_whole(900),
+        ],
+    );
+    let validator = genesis_validators[0].address.clone();
+    let other_validator = genesis_validators[1].address.clone();
+    let params = test_init_genesis(
+        &mut s,
+        params,
+        genesis_validators.into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+    let pipeline_epoch = current_epoch + params.pipeline_len;
+
+    // Raising the minimum over the self-bond of the consensus validator
+    // demotes it and promotes the below-capacity validator in its place
+    write_pos_params(
+        &mut s,
+        &OwnedPosParams {
+            min_self_bond: token::Amount::native_whole(950),
+            ..params.owned.clone()
+        },
+    )
+    .unwrap();
+    let new_params = read_pos_params(&s).unwrap();
+    update_validator_sets_for_min_self_bond(&mut s, &new_params, current_epoch)
+        .unwrap();
+    assert_eq!(
+        validator_state_handle(&validator)
+            .get(&s, pipeline_epoch, &new_params)
+            .unwrap(),
+        Some(ValidatorState::BelowThreshold)
+    );
+    assert_eq!(
+        validator_state_handle(&other_validator)
+            .get(&s, pipeline_epoch, &new_params)
+            .unwrap(),
+        Some(ValidatorState::Consensus)
+    );
+    let consensus_set =
+        read_consensus_validator_set_addresses(&s, pipeline_epoch).unwrap();
+    assert!(!consensus_set.contains(&validator));
+    assert!(consensus_set.contains(&other_validator));
+
+    // Lowering it again puts the validator back into the consensus set
+    write_pos_params(&mut s, &params.owned).unwrap();
+    update_validator_sets_for_min_self_bond(&mut s, &params, current_epoch)
+        .unwrap();
+    assert_eq!(
+        validator_state_handle(&validator)
+            .get(&s, pipeline_epoch, &params)
+            .unwrap(),
+        Some(ValidatorState::Consensus)
+    );
+    assert_eq!(
+        validator_state_handle(&other_validator)
+            .get(&s, pipeline_epoch, &params)
+            .unwrap(),
+        Some(ValidatorState::BelowCapacity)
+    );
+}
+
+#[test]
+fn test_min_self_bond_after_slash() {
+    let mut s = TestState::default();
+    let mut current_epoch = s.in_mem().block.epoch;
+    let params = OwnedPosParams {
+        min_self_bond: token::Amount::native_whole(950),
+        duplicate_vote_min_slash_rate: Dec::new(1, 1)
+            .expect("Dec creation failed"),
+        cubic_slashing_window_length: 0,
+        ..Default::default()
+    };
+    let genesis_validators =
+        get_genesis_validators(10, vec![token::Amount::native_whole(1000); 10]);
+    let validator = genesis_validators[0].address.clone();
+    let params = test_init_genesis(
+        &mut s,
+        params,
+        genesis_validators.into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+    s.commit_block().unwrap();
+
+    // Slash the validator for 10% of its stake
+    current_epoch = advance_epoch(&mut s, &params);
+    let evidence_epoch = current_epoch;
+    slash(
+        &mut s,
+        &params,
+        current_epoch,
+        evidence_epoch,
+        0_u64,
+        SlashType::DuplicateVote,
+        &validator,
+        current_epoch.next(),
+    )
+    .unwrap();
+    let unfreeze_epoch =
+        evidence_epoch + params.slash_processing_epoch_offset();
+    while current_epoch < unfreeze_epoch + 4u64 {
+        current_epoch = advance_epoch(&mut s, &params);
+        process_slashes(
+            &mut s,
+            &mut namada_events::testing::VoidEventSink,
+            current_epoch,
+        )
+        .unwrap();
+    }
+    let pipeline_epoch = current_epoch + params.pipeline_len;
+    assert!(
+        read_validator_self_bond(&s, &validator, pipeline_epoch).unwrap()
+            < params.min_self_bond
+    );
+    assert!(
+        read_validator_stake(&s, &params, &validator, pipeline_epoch).unwrap()
+            >= params.validator_stake_threshold
+    );
+
+    // The slashed self-bond is below the minimum, so the unjailed validator
+    // stays below the threshold
+    unjail_validator(&mut s, &validator, current_epoch).unwrap();
+    assert_eq!(
+        validator_state_handle(&validator)
+            .get(&s, pipeline_epoch, &params)
+            .unwrap(),
+        Some(ValidatorState::BelowThreshold)
+    );
+
+    // A self-bond that leaves it below the minimum is rejected, while topping
+    // it up to the minimum brings the validator back into the consensus set
+    let staking_token = staking_token_address(&s);
+    let top_up = token::Amount::native_whole(100);
+    credit_tokens(&mut s, &staking_token, &validator, top_up).unwrap();
+    let res = bond_tokens(
+        &mut s,
+        None,
+        &validator,
+        token::Amount::native_whole(10),
+        current_epoch,
+        None,
+    );
+    assert!(res.is_err());
+    bond_tokens(&mut s, None, &validator, top_up, current_epoch, None).unwrap();
+    assert_eq!(
+        validator_state_handle(&validator)
+            .get(&s, pipeline_epoch, &params)
+            .unwrap(),
+        Some(ValidatorState::Consensus)
+    );
+}
+
+#[test]
+fn test_change_validator_keys() {
+    let mut s = TestState::default();
+    let params = OwnedPosParams::default();
+    let current_epoch = s.in_mem().block.epoch;
+
+    let genesis_validators =
+        get_genesis_validators(2, vec![token::Amount::native_whole(1000); 2]);
+    let validator = genesis_validators[0].address.clone();
+    let old_protocol_key = genesis_validators[0].protocol_key.clone();
+    let old_eth_hot_key = genesis_validators[0].eth_hot_key.clone();
+    let old_eth_cold_key = genesis_validators[0].eth_cold_key.clone();
+    let params = test_init_genesis(
+        &mut s,
+        params,
+        genesis_validators.into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+
+    let new_protocol_key = common_sk_from_simple_seed(100).to_public();
+    let new_eth_hot_key = key::common::PublicKey::Secp256k1(
+        key::testing::gen_keypair::<key::secp256k1::SigScheme>().ref_to(),
+    );
+
+    // Invalid key changes
+    assert!(change_validator_keys(
+        &mut s,
+        &address::testing::established_address_1(),
+        Some(&new_protocol_key),
+        None,
+        None,
+        current_epoch,
+    )
+    .is_err());
+    assert!(change_validator_keys(
+        &mut s,
+        &validator,
+        None,
+        None,
+        None,
+        current_epoch,
+    )
+    .is_err());
+    assert!(change_validator_keys(
+        &mut s,
+        &validator,
+        None,
+        Some(&new_protocol_key),
+        None,
+        current_epoch,
+    )
+    .is_err());
+
+    // Only the given keys are changed, starting at the pipeline epoch
+    change_validator_keys(
+        &mut s,
+        &validator,
+        Some(&new_protocol_key),
+        Some(&new_eth_hot_key),
+        None,
+        current_epoch,
+    )
+    .unwrap();
+    let pipeline_epoch = current_epoch + params.pipeline_len;
+    for epoch in Epoch::iter_bounds_inclusive(current_epoch, pipeline_epoch) {
+        let (protocol_key, eth_hot_key) = if epoch < pipeline_epoch {
+            (&old_protocol_key, &old_eth_hot_key)
+        } else {
+            (&new_protocol_key, &new_eth_hot_key)
+        };
+        assert_eq!(
+            validator_protocol_key_handle(&validator)
+                .get(&s, epoch, &params)
+                .unwrap()
+                .as_ref(),
+            Some(protocol_key)
+        );
+        assert_eq!(
+            validator_eth_hot_key_handle(&validator)
+                .get(&s, epoch, &params)
+                .unwrap()
+                .as_ref(),
+            Some(eth_hot_key)
+        );
+        assert_eq!(
+            validator_eth_cold_key_handle(&validator)
+                .get(&s, epoch, &params)
+                .unwrap(),
+            Some(old_eth_cold_key.clone())
+        );
+    }
+}
+
+#[test]
+fn test_change_validator_structured_metadata() {
+    let mut s = TestState::default();
+    let params = OwnedPosParams::default();
+    let current_epoch = s.in_mem().block.epoch;
+
+    let genesis_validators =
+        get_genesis_validators(2, vec![token::Amount::native_whole(1000); 2]);
+    let validator = genesis_validators[0].address.clone();
+    let other_validator = genesis_validators[1].address.clone();
+    test_init_genesis(
+        &mut s,
+        params,
+        genesis_validators.into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+    assert_eq!(
+        read_metadata_schema_version(&s).unwrap(),
+        METADATA_SCHEMA_VERSION
+    );
+
+    // Set some structured fields
+    let identity_proof = Hash::sha256(b"proof");
+    change_validator_structured_metadata(
+        &mut s,
+        &validator,
+        Some("security@validator.net".to_string()),
+        BTreeMap::from([
+            ("Twitter".to_string(), "@validator".to_string()),
+            ("github".to_string(), "validator".to_string()),
+        ]),
+        Some(identity_proof),
+        BTreeMap::from([("region".to_string(), "eu".to_string())]),
+    )
+    .unwrap();
+    let metadata = read_validator_structured_metadata(&s, &validator)
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        metadata.security_contact.as_deref(),
+        Some("security@validator.net")
+    );
+    assert_eq!(
+        metadata.social_handles,
+        BTreeMap::from([
+            ("github".to_string(), "validator".to_string()),
+            ("twitter".to_string(), "@validator".to_string()),
+        ])
+    );
+    assert_eq!(metadata.identity_proof, Some(identity_proof));
+    assert_eq!(
+        metadata.get_field(&MetadataField::Extension("region".to_string())),
+        Some("eu".to_string())
+    );
+
+    // Entries are updated individually and empty values remove them
+    change_validator_structured_metadata(
+        &mut s,
+        &validator,
+        Some(String::new()),
+        BTreeMap::from([("github".to_string(), String::new())]),
+        Some(Hash::zero()),
+        BTreeMap::new(),
+    )
+    .unwrap();
+    let metadata = read_validator_structured_metadata(&s, &validator)
+        .unwrap()
+        .unwrap();
+    assert_eq!(metadata.security_contact, None);
+    assert_eq!(
+        metadata.social_handles,
+        BTreeMap::from([("twitter".to_string(), "@validator".to_string())])
+    );
+    assert_eq!(metadata.identity_proof, None);
+    assert_eq!(metadata.extensions.len(), 1);
+
+    // Invalid changes
+    assert!(change_validator_structured_metadata(
+        &mut s,
+        &validator,
+        Some("x".repeat(MAX_VALIDATOR_METADATA_LEN as usize + 1)),
+        BTreeMap::new(),
+        None,
+        BTreeMap::new(),
+    )
+    .is_err());
+    assert!(change_validator_structured_metadata(
+        &mut s,
+        &validator,
+        None,
+        BTreeMap::from([("not a platform".to_string(), "x".to_string())]),
+        None,
+        BTreeMap::new(),
+    )
+    .is_err());
+    assert!(change_validator_structured_metadata(
+        &mut s,
+        &validator,
+        None,
+        (0..=MAX_VALIDATOR_SOCIAL_HANDLES)
+            .map(|i| (format!("platform{i}"), "x".to_string()))
+            .collect(),
+        None,
+        BTreeMap::new(),
+    )
+    .is_err());
+
+    // A discord handle under the legacy key is migrated into the social
+    // handles, once
+    s.write(
+        &validator_discord_key(&other_validator),
+        "validator#1234".to_string(),
+    )
+    .unwrap();
+    write_metadata_schema_version(&mut s, 0).unwrap();
+    migrate_validator_metadata(&mut s, current_epoch).unwrap();
+    assert_eq!(
+        read_metadata_schema_version(&s).unwrap(),
+        METADATA_SCHEMA_VERSION
+    );
+    assert!(s
+        .read::<String>(&validator_discord_key(&other_validator))
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        read_validator_discord_handle(&s, &other_validator).unwrap(),
+        Some("validator#1234".to_string())
+    );
+
+    // Find the validators by their metadata fields
+    let found = find_validators_by_metadata_field(
+        &s,
+        &MetadataField::SocialHandle("twitter".to_string()),
+        current_epoch,
+    )
+    .unwrap();
+    assert_eq!(
+        found,
+        BTreeMap::from([(validator.clone(), "@validator".to_string())])
+    );
+    let found = find_validators_by_metadata_field(
+        &s,
+        &MetadataField::Email,
+        current_epoch,
+    )
+    .unwrap();
+    assert_eq!(found.len(), 2);
+}
+
+#[test]
+fn test_validator_stake_cap() {
+    let mut s = TestState::default();
+    let mut current_epoch = s.in_mem().block.epoch;
+    let params = OwnedPosParams {
+        max_validator_stake_share: Dec::new(3, 1).expect("Test failed"),
+        ..Default::default()
+    };
+
+    // Genesis bonds are not capped
+    let genesis_validators =
+        get_genesis_validators(4, vec![token::Amount::native_whole(1000); 4]);
+    let validator = genesis_validators[0].address.clone();
+    let other_validator = genesis_validators[1].address.clone();
+    let params = test_init_genesis(
+        &mut s,
+        params,
+        genesis_validators.into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+
+    let delegator = address::testing::established_address_3();
+    let staking_token = staking_token_address(&s);
+    credit_tokens(
+        &mut s,
+        &staking_token,
+        &delegator,
+        token::Amount::native_whole(1000),
+    )
+    .unwrap();
+
+    // Bonds within the cap are accepted
+    bond_tokens(
+        &mut s,
+        Some(&delegator),
+        &validator,
+        token::Amount::native_whole(100),
+        current_epoch,
+        None,
+    )
+    .unwrap();
+    bond_tokens(
+        &mut s,
+        Some(&delegator),
+        &other_validator,
+        token::Amount::native_whole(300),
+        current_epoch,
+        None,
+    )
+    .unwrap();
+    for _ in 0..params.pipeline_len {
+        current_epoch = advance_epoch(&mut s, &params);
+    }
+    let pipeline_epoch = current_epoch + params.pipeline_len;
+    assert_eq!(
+        read_validator_stake(&s, &params, &validator, pipeline_epoch).unwrap(),
+        token::Amount::native_whole(1100)
+    );
+
+    // A redelegation within the cap is accepted. The cap is 30% of the total
+    // stake of 4400.
+    redelegate_tokens(
+        &mut s,
+        &delegator,
+        &other_validator,
+        &validator,
+        current_epoch,
+        token::Amount::native_whole(200),
+    )
+    .unwrap();
+    assert_eq!(
+        read_validator_stake(&s, &params, &validator, pipeline_epoch).unwrap(),
+        token::Amount::native_whole(1300)
+    );
+
+    // A redelegation over the cap is rejected
+    let res = redelegate_tokens(
+        &mut s,
+        &delegator,
+        &other_validator,
+        &validator,
+        current_epoch,
+        token::Amount::native_whole(50),
+    );
+    assert!(res.is_err());
+
+    // A bond over the cap is rejected
+    let res = bond_tokens(
+        &mut s,
+        Some(&delegator),
+        &other_validator,
+        token::Amount::native_whole(600),
+        current_epoch,
+        None,
+    );
+    assert!(res.is_err());
+}
@@ -262,6 +262,46 @@ pub type LivenessMissedVotes = NestedMap<Address, LazySet<u64>>;
 /// elements in the corresponding inner LazySet of [`LivenessMissedVotes`].
 pub type LivenessSumMissedVotes = LazyMap<Address, u64>;
 
+/// Votes of a validator recorded for liveness tracking in a single epoch.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Eq,
+    PartialEq,
+)]
+pub struct EpochLiveness {
+    /// Number of blocks for which a vote of the validator was expected, i.e.
+    /// while it was in the consensus set
+    pub expected_votes: u64,
+    /// Number of blocks for which the validator's vote was missing
+    pub missed_votes: u64,
+}
+
+/// Per-epoch liveness history of validators. Unlike [`LivenessMissedVotes`],
+/// which only covers the sliding window used for jailing, this is kept for the
+/// last [`crate::LIVENESS_HISTORY_LEN`] epochs.
+pub type LivenessHistory = NestedMap<Address, LazyMap<Epoch, EpochLiveness>>;
+
+/// Liveness of a validator: its current sliding window status and its
+/// per-epoch history.
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, BorshDeserializer)]
+pub struct ValidatorLiveness {
+    /// Missed votes within the current sliding window
+    pub window_missed_votes: u64,
+    /// Number of missed votes within the sliding window at which the
+    /// validator gets jailed
+    pub window_missed_votes_threshold: u64,
+    /// Length of the sliding window in blocks
+    pub window_len: u64,
+    /// Recorded votes per epoch, from the oldest to the latest epoch
+    pub history: BTreeMap<Epoch, EpochLiveness>,
+}
+
 /// Contains information on epoch periods (start, end) in which a delegator had
 /// a bonded with a certain validator. The `end` epoch is the first epoch at
 /// which the bond ceased to exist (exclusive).
@@ -276,6 +316,14 @@ pub struct DelegationEpochs {
 /// The set of all target validators for a given delegator.
 pub type DelegationTargets = LazyMap<Address, DelegationEpochs>;
 
+/// The scheduled commission rate changes of validators, keyed by the epoch at
+/// which they take effect.
+pub type CommissionSchedules = NestedMap<Address, LazyMap<Epoch, Dec>>;
+
+/// The validators for which each delegator opted in to have their rewards
+/// automatically re-bonded at every new epoch.
+pub type AutoCompoundBonds = NestedMap<Address, LazySet<Address>>;
+
 #[derive(
     Debug,
     Clone,
@@ -305,6 +353,139 @@ pub struct CommissionPair {
     pub epoch: Epoch,
 }
 
+/// Rewards earned by a bond in a single epoch
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Eq,
+    PartialEq,
+)]
+pub struct EpochRewards {
+    /// The bonded amount that contributed to the validator's stake, after
+    /// the slashes processed by then
+    pub bond_amount: token::Amount,
+    /// The bonded amount that was slashed by then
+    pub slashed_amount: token::Amount,
+    /// The validator's rewards product
+    pub rewards_product: Dec,
+    /// The rewards earned, net of the validator's commission
+    pub rewards: token::Amount,
+    /// The validator's commission rate, if still available in storage
+    pub commission_rate: Option<Dec>,
+    /// The commission taken from the bond's rewards, if the commission rate
+    /// is known
+    pub commission: Option<token::Amount>,
+}
+
+/// The outcome of a simulated slash of a validator for a hypothetical
+/// infraction
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, BorshDeserializer)]
+pub struct SlashSimulation {
+    /// Epoch of the hypothetical infraction
+    pub infraction_epoch: Epoch,
+    /// Epoch in which the slash would be processed
+    pub processing_epoch: Epoch,
+    /// The cubic slash rate, including the hypothetical infraction and any
+    /// other slashes already enqueued within the cubic slashing window
+    pub cubic_slash_rate: Dec,
+    /// The rate of the hypothetical slash
+    pub slash_rate: Dec,
+    /// The total rate the validator would be slashed by in the processing
+    /// epoch, including any other slashes already enqueued for it
+    pub validator_slash_rate: Dec,
+    /// The affected bonds, including bonds redelegated away from the
+    /// validator
+    pub bond_losses: Vec<BondSlashLoss>,
+}
+
+/// The effect of a simulated slash on a single bond
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, BorshDeserializer)]
+pub struct BondSlashLoss {
+    /// The bond ID. For a redelegated bond, the validator is the destination
+    /// validator of the redelegation.
+    pub bond_id: BondId,
+    /// Bond amount in the processing epoch without the simulated slash
+    pub bond_before: token::Amount,
+    /// Bond amount in the processing epoch with the simulated slash
+    pub bond_after: token::Amount,
+}
+
+impl BondSlashLoss {
+    /// The amount of tokens the bond would lose
+    pub fn loss(&self) -> token::Amount {
+        self.bond_before
+            .checked_sub(self.bond_after)
+            .unwrap_or_default()
+    }
+}
+
+/// A single operation of a plan to rebalance a delegator's bonds across
+/// validators
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+)]
+pub enum RebalanceStep {
+    /// Redelegate bonded tokens from the source to the destination validator
+    Redelegate {
+        /// Source validator
+        src_validator: Address,
+        /// Destination validator
+        dest_validator: Address,
+        /// Amount of bonded tokens to redelegate
+        amount: token::Amount,
+    },
+    /// Unbond tokens from the validator
+    Unbond {
+        /// Validator
+        validator: Address,
+        /// Amount of bonded tokens to unbond
+        amount: token::Amount,
+    },
+    /// Bond tokens from the delegator's balance to the validator
+    Bond {
+        /// Validator
+        validator: Address,
+        /// Amount of tokens to bond
+        amount: token::Amount,
+    },
+}
+
+impl Display for RebalanceStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RebalanceStep::Redelegate {
+                src_validator,
+                dest_validator,
+                amount,
+            } => write!(
+                f,
+                "Redelegate {} from {src_validator} to {dest_validator}",
+                amount.to_string_native()
+            ),
+            RebalanceStep::Unbond { validator, amount } => {
+                write!(
+                    f,
+                    "Unbond {} from {validator}",
+                    amount.to_string_native()
+                )
+            }
+            RebalanceStep::Bond { validator, amount } => {
+                write!(f, "Bond {} to {validator}", amount.to_string_native())
+            }
+        }
+    }
+}
+
 /// Epoched rewards products
 pub type RewardsProducts = LazyMap<Epoch, Dec>;
 
@@ -408,6 +589,174 @@ impl Default for ValidatorMetaData {
     }
 }
 
+/// The social platform of the handle that was stored in the discord handle
+/// field of the [`ValidatorMetaData`]
+pub const DISCORD_PLATFORM: &str = "discord";
+
+/// Structured validator metadata. In addition to the fields of
+/// [`ValidatorMetaData`], it has typed fields for a security contact, social
+/// handles, an identity proof hash and an arbitrary key/value extension map.
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    BorshSerialize,
+    BorshSchema,
+    BorshDeserialize,
+    BorshDeserializer,
+    Deserialize,
+    Serialize,
+    Eq,
+    PartialEq,
+)]
+pub struct StructuredValidatorMetadata {
+    /// Validator's email
+    pub email: String,
+    /// Validator description
+    pub description: Option<String>,
+    /// Validator website
+    pub website: Option<String>,
+    /// URL that points to a picture (e.g. PNG),
+    /// identifying the validator
+    pub avatar: Option<String>,
+    /// Validator's name
+    pub name: Option<String>,
+    /// Contact for reporting security issues
+    pub security_contact: Option<String>,
+    /// Validator's handles keyed by their social platform
+    pub social_handles: BTreeMap<String, String>,
+    /// Hash of a keybase-style proof of the validator's identity
+    pub identity_proof: Option<namada_core::hash::Hash>,
+    /// Arbitrary key/value metadata extensions
+    pub extensions: BTreeMap<String, String>,
+}
+
+impl From<ValidatorMetaData> for StructuredValidatorMetadata {
+    /// Migrate the flat metadata. The discord handle becomes the social
+    /// handle of the [`DISCORD_PLATFORM`].
+    fn from(metadata: ValidatorMetaData) -> Self {
+        let ValidatorMetaData {
+            email,
+            description,
+            website,
+            discord_handle,
+            avatar,
+            name,
+        } = metadata;
+        Self {
+            email,
+            description,
+            website,
+            avatar,
+            name,
+            social_handles: discord_handle
+                .map(|handle| (DISCORD_PLATFORM.to_string(), handle))
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        }
+    }
+}
+
+impl StructuredValidatorMetadata {
+    /// Get the value of the given metadata field, if it is set
+    pub fn get_field(&self, field: &MetadataField) -> Option<String> {
+        match field {
+            MetadataField::Email => Some(self.email.clone()),
+            MetadataField::Description => self.description.clone(),
+            MetadataField::Website => self.website.clone(),
+            MetadataField::Avatar => self.avatar.clone(),
+            MetadataField::Name => self.name.clone(),
+            MetadataField::SecurityContact => self.security_contact.clone(),
+            MetadataField::IdentityProof => {
+                self.identity_proof.as_ref().map(ToString::to_string)
+            }
+            MetadataField::SocialHandle(platform) => {
+                self.social_handles.get(platform).cloned()
+            }
+            MetadataField::Extension(key) => self.extensions.get(key).cloned(),
+        }
+    }
+}
+
+/// A field of the [`StructuredValidatorMetadata`]
+#[derive(
+    Clone,
+    Debug,
+    BorshSerialize,
+    BorshSchema,
+    BorshDeserialize,
+    BorshDeserializer,
+    Eq,
+    PartialEq,
+)]
+pub enum MetadataField {
+    /// Validator's email
+    Email,
+    /// Validator description
+    Description,
+    /// Validator website
+    Website,
+    /// Validator's avatar
+    Avatar,
+    /// Validator's name
+    Name,
+    /// Security contact
+    SecurityContact,
+    /// Identity proof hash
+    IdentityProof,
+    /// Social handle on the given platform
+    SocialHandle(String),
+    /// Metadata extension with the given key
+    Extension(String),
+}
+
+impl Display for MetadataField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Email => write!(f, "email"),
+            Self::Description => write!(f, "description"),
+            Self::Website => write!(f, "website"),
+            Self::Avatar => write!(f, "avatar"),
+            Self::Name => write!(f, "name"),
+            Self::SecurityContact => write!(f, "security-contact"),
+            Self::IdentityProof => write!(f, "identity-proof"),
+            Self::SocialHandle(platform) => write!(f, "social:{platform}"),
+            Self::Extension(key) => write!(f, "ext:{key}"),
+        }
+    }
+}
+
+impl std::str::FromStr for MetadataField {
+    type Err = String;
+
+    /// Parses the [`Display`] format. The social platform or extension key
+    /// follows a "social:" or "ext:" prefix, respectively.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(platform) = s.strip_prefix("social:") {
+            return Ok(Self::SocialHandle(platform.to_lowercase()));
+        }
+        if let Some(key) = s.strip_prefix("ext:") {
+            return Ok(Self::Extension(key.to_string()));
+        }
+        match s.to_lowercase().replace([' ', '_'], "-").as_str() {
+            "email" => Ok(Self::Email),
+            "description" => Ok(Self::Description),
+            "website" => Ok(Self::Website),
+            "avatar" => Ok(Self::Avatar),
+            "name" => Ok(Self::Name),
+            "security-contact" => Ok(Self::SecurityContact),
+            "identity-proof" => Ok(Self::IdentityProof),
+            _ => Err(format!(
+                "Metadata field {s:?} must be one of \"email\", \
+                 \"description\", \"website\", \"avatar\", \"name\", \
+                 \"security-contact\", \"identity-proof\", \
+                 \"social:<platform>\" or \"ext:<key>\""
+            )),
+        }
+    }
+}
+
 /// An update of the consensus and below-capacity validator set.
 #[derive(Debug, Clone, PartialEq)]
 pub enum ValidatorSetUpdate {
@@ -740,6 +1089,23 @@ impl Display for SlashType {
     }
 }
 
+impl std::str::FromStr for SlashType {
+    type Err = String;
+
+    /// Parses both the [`Display`] format and a kebab-case format (e.g.
+    /// "duplicate-vote"), ignoring the case
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace([' ', '_'], "-").as_str() {
+            "duplicate-vote" => Ok(Self::DuplicateVote),
+            "light-client-attack" => Ok(Self::LightClientAttack),
+            _ => Err(format!(
+                "Slash type value of {s:?} does not match \"duplicate-vote\" \
+                 nor \"light-client-attack\""
+            )),
+        }
+    }
+}
+
 /// Calculate voting power in the tendermint context (which is stored as i64)
 /// from the number of tokens
 pub fn into_tm_voting_power(votes_per_token: Dec, tokens: Amount) -> i64 {
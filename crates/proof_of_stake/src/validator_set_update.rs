@@ -12,7 +12,8 @@ use once_cell::unsync::Lazy;
 
 use crate::storage::{
     below_capacity_validator_set_handle, consensus_validator_set_handle,
-    get_num_consensus_validators, read_validator_stake,
+    get_num_consensus_validators, has_min_self_bond,
+    read_all_validator_addresses, read_validator_stake,
     validator_addresses_handle, validator_consensus_key_handle,
     validator_set_positions_handle, validator_state_handle,
 };
@@ -57,11 +58,14 @@ where
     debug_assert!(tokens_post.non_negative());
     let tokens_post = token::Amount::from_change(tokens_post);
 
+    // A validator without the minimum self-bond belongs to the below-threshold
+    // set regardless of its stake
+    let below_threshold_post = tokens_post < params.validator_stake_threshold
+        || !has_min_self_bond(storage, params, validator, epoch)?;
+
     // If token amounts both before and after the action are below the threshold
     // stake, do nothing
-    if tokens_pre < params.validator_stake_threshold
-        && tokens_post < params.validator_stake_threshold
-    {
+    if tokens_pre < params.validator_stake_threshold && below_threshold_post {
         return Ok(());
     }
 
@@ -94,7 +98,7 @@ where
                 )?
                 .unwrap_or_default();
 
-            if tokens_post < params.validator_stake_threshold {
+            if below_threshold_post {
                 tracing::debug!(
                     "Demoting this validator to the below-threshold set"
                 );
@@ -218,7 +222,9 @@ where
                     storage,
                 )?;
 
-            if tokens_post > min_consensus_validator_amount {
+            if !below_threshold_post
+                && tokens_post > min_consensus_validator_amount
+            {
                 // Place the validator into the consensus set and demote the
                 // last position min consensus validator to the
                 // below-capacity set
@@ -237,7 +243,7 @@ where
                     &consensus_val_handle,
                     &below_capacity_val_handle,
                 )?;
-            } else if tokens_post >= params.validator_stake_threshold {
+            } else if !below_threshold_post {
                 tracing::debug!("Validator remains in below-capacity set");
                 // The current validator should remain in the below-capacity set
                 insert_validator_into_set(
@@ -275,10 +281,19 @@ where
         // At non-zero offset (0 is genesis only)
         if offset > 0 {
             // If there is no position at pipeline offset, then the validator
-            // must be in the below-threshold set
-            debug_assert!(tokens_pre < params.validator_stake_threshold);
+            // must be in the below-threshold set, either because of its stake
+            // or its self-bond
+            debug_assert!(
+                tokens_pre < params.validator_stake_threshold
+                    || !params.min_self_bond.is_zero()
+            );
         }
         tracing::debug!("Target validator is below-threshold");
+        if below_threshold_post {
+            // The validator's stake is above the threshold, but its self-bond
+            // is not
+            return Ok(());
+        }
 
         // Move the validator into the appropriate set
         let num_consensus_validators =
@@ -368,7 +383,9 @@ where
     let num_consensus_validators =
         get_num_consensus_validators(storage, target_epoch)?;
 
-    if stake < params.validator_stake_threshold {
+    if stake < params.validator_stake_threshold
+        || !has_min_self_bond(storage, params, address, target_epoch)?
+    {
         validator_state_handle(address).set(
             storage,
             ValidatorState::BelowThreshold,
@@ -453,6 +470,114 @@ where
     Ok(())
 }
 
+/// Check the validators at the pipeline epoch against the `min_self_bond` PoS
+/// parameter, which may have changed or whose self-bonds may have been slashed
+/// since they were put into their validator sets. The validators that no
+/// longer have the minimum self-bond are moved to the below-threshold set,
+/// while the below-threshold validators that have it and whose stake is over
+/// the threshold are inserted back into the other sets.
+pub fn update_validator_sets_for_min_self_bond<S>(
+    storage: &mut S,
+    params: &PosParams,
+    current_epoch: Epoch,
+) -> namada_storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let pipeline_epoch = checked!(current_epoch + params.pipeline_len)?;
+    for validator in read_all_validator_addresses(storage, pipeline_epoch)? {
+        let state = validator_state_handle(&validator).get(
+            storage,
+            pipeline_epoch,
+            params,
+        )?;
+        match state {
+            Some(ValidatorState::Consensus)
+                if !has_min_self_bond(
+                    storage,
+                    params,
+                    &validator,
+                    pipeline_epoch,
+                )? =>
+            {
+                tracing::debug!(
+                    "Demoting validator {validator} without the minimum \
+                     self-bond to the below-threshold set"
+                );
+                remove_consensus_validator(
+                    storage,
+                    params,
+                    pipeline_epoch,
+                    &validator,
+                )?;
+                promote_next_below_capacity_validator_to_consensus(
+                    storage,
+                    current_epoch,
+                    params.pipeline_len,
+                )?;
+                validator_state_handle(&validator).set(
+                    storage,
+                    ValidatorState::BelowThreshold,
+                    current_epoch,
+                    params.pipeline_len,
+                )?;
+            }
+            Some(ValidatorState::BelowCapacity)
+                if !has_min_self_bond(
+                    storage,
+                    params,
+                    &validator,
+                    pipeline_epoch,
+                )? =>
+            {
+                tracing::debug!(
+                    "Demoting validator {validator} without the minimum \
+                     self-bond to the below-threshold set"
+                );
+                remove_below_capacity_validator(
+                    storage,
+                    params,
+                    pipeline_epoch,
+                    &validator,
+                )?;
+                validator_state_handle(&validator).set(
+                    storage,
+                    ValidatorState::BelowThreshold,
+                    current_epoch,
+                    params.pipeline_len,
+                )?;
+            }
+            Some(ValidatorState::BelowThreshold) => {
+                let stake = read_validator_stake(
+                    storage,
+                    params,
+                    &validator,
+                    pipeline_epoch,
+                )?;
+                if stake >= params.validator_stake_threshold
+                    && has_min_self_bond(
+                        storage,
+                        params,
+                        &validator,
+                        pipeline_epoch,
+                    )?
+                {
+                    insert_validator_into_validator_set(
+                        storage,
+                        params,
+                        &validator,
+                        stake,
+                        current_epoch,
+                        params.pipeline_len,
+                    )?;
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
 /// Remove a validator from the consensus validator set
 pub fn remove_consensus_validator<S>(
     storage: &mut S,
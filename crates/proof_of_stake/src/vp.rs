@@ -4,20 +4,23 @@ use std::collections::{BTreeMap, BTreeSet};
 use std::marker::PhantomData;
 
 use namada_core::address::Address;
+use namada_core::arith::checked;
 use namada_core::booleans::BoolResultUnitExt;
 use namada_core::storage::Key;
 use namada_state::StateRead;
 use namada_systems::governance;
 use namada_tx::action::{
-    Action, Bond, ClaimRewards, PosAction, Read, Redelegation, Unbond, Withdraw,
+    Action, AutoCompound, Bond, ClaimRewards, PosAction, Read, Redelegation,
+    Unbond, Withdraw,
 };
 use namada_tx::BatchedTxRef;
 use namada_vp::native_vp::{
     self, Ctx, CtxPreStorageRead, NativeVp, VpEvaluator,
 };
+use namada_vp::VpEnv;
 use thiserror::Error;
 
-use crate::storage::read_pos_params;
+use crate::storage::{read_pos_params, validator_stake_over_cap};
 use crate::storage_key::is_params_key;
 use crate::types::BondId;
 use crate::{storage_key, token};
@@ -31,6 +34,11 @@ pub enum Error {
         "Action {0} not authorized by {1} which is not part of verifier set"
     )]
     Unauthorized(&'static str, Address),
+    #[error(
+        "The stake of validator {0} is over the cap set by the maximum \
+         validator stake share"
+    )]
+    StakeCapExceeded(Address),
 }
 
 /// PoS functions result
@@ -114,9 +122,11 @@ where
         let mut redelegations: BTreeMap<BondId, (Address, token::Amount)> =
             Default::default();
         let mut claimed_rewards: BTreeSet<BondId> = Default::default();
+        let mut auto_compound: BTreeSet<BondId> = Default::default();
         let mut changed_commission: BTreeSet<Address> = Default::default();
         let mut changed_metadata: BTreeSet<Address> = Default::default();
         let mut changed_consensus_key: BTreeSet<Address> = Default::default();
+        let mut changed_validator_keys: BTreeSet<Address> = Default::default();
 
         // Accumulate changes from the actions
         for action in actions {
@@ -257,6 +267,26 @@ where
                         }
                         claimed_rewards.insert(bond_id);
                     }
+                    PosAction::AutoCompound(AutoCompound {
+                        validator,
+                        source,
+                        enabled: _,
+                    }) => {
+                        let bond_id = BondId {
+                            source: source.unwrap_or_else(|| validator.clone()),
+                            validator,
+                        };
+                        if !verifiers.contains(&bond_id.source) {
+                            tracing::info!(
+                                "Unauthorized PosAction::AutoCompound"
+                            );
+                            return Err(Error::Unauthorized(
+                                "AutoCompound",
+                                bond_id.source,
+                            ));
+                        }
+                        auto_compound.insert(bond_id);
+                    }
                     PosAction::CommissionChange(validator) => {
                         if !verifiers.contains(&validator) {
                             tracing::info!(
@@ -293,6 +323,18 @@ where
                         }
                         changed_consensus_key.insert(validator);
                     }
+                    PosAction::ValidatorKeysChange(validator) => {
+                        if !verifiers.contains(&validator) {
+                            tracing::info!(
+                                "Unauthorized PosAction::ValidatorKeysChange"
+                            );
+                            return Err(Error::Unauthorized(
+                                "ValidatorKeysChange",
+                                validator,
+                            ));
+                        }
+                        changed_validator_keys.insert(validator);
+                    }
                 },
                 _ => {
                     // Other actions are not relevant to PoS VP
@@ -310,7 +352,20 @@ where
             }
             // TODO: validate changes keys against the accumulated changes
         }
-        Ok(())
+
+        // Bonds and redelegations must not take a validator's stake over the
+        // cap
+        let bonded_validators = bonds
+            .iter()
+            .filter(|(_, amount)| !amount.is_zero())
+            .map(|(bond_id, _)| &bond_id.validator);
+        let redelegated_validators = redelegations
+            .values()
+            .filter(|(_, amount)| !amount.is_zero())
+            .map(|(dest_validator, _)| dest_validator);
+        self.is_within_stake_cap(
+            &bonded_validators.chain(redelegated_validators).collect(),
+        )
     }
 }
 
@@ -328,6 +383,38 @@ where
         }
     }
 
+    /// Return `Ok` if the stake of the given validators at the pipeline epoch
+    /// is within the cap set by the `max_validator_stake_share` parameter
+    fn is_within_stake_cap(
+        &self,
+        validators: &BTreeSet<&Address>,
+    ) -> Result<()> {
+        if validators.is_empty() {
+            return Ok(());
+        }
+        let params = read_pos_params(&self.ctx.post())?;
+        let current_epoch = self.ctx.get_block_epoch()?;
+        let pipeline_epoch = checked!(current_epoch + params.pipeline_len)
+            .map_err(native_vp::Error::from)?;
+        for validator in validators {
+            if validator_stake_over_cap(
+                &self.ctx.post(),
+                &params,
+                validator,
+                pipeline_epoch,
+            )?
+            .is_some()
+            {
+                tracing::info!(
+                    "Rejecting tx that takes validator {validator} over its \
+                     stake cap"
+                );
+                return Err(Error::StakeCapExceeded(validator.clone()));
+            }
+        }
+        Ok(())
+    }
+
     /// Return `Ok` if the changed parameters are valid
     fn is_valid_parameter_change(&self) -> Result<()> {
         let validation_errors: Vec<crate::parameters::ValidationError> =
@@ -1,5 +1,6 @@
 //! Structures encapsulating SDK arguments
 
+use std::collections::BTreeMap;
 use std::fmt::Display;
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -10,6 +11,7 @@ use namada_core::chain::ChainId;
 use namada_core::collections::HashMap;
 use namada_core::dec::Dec;
 use namada_core::ethereum_events::EthAddress;
+use namada_core::hash::Hash;
 use namada_core::keccak::KeccakHash;
 use namada_core::key::{common, SchemeType};
 use namada_core::masp::{MaspEpoch, PaymentAddress};
@@ -17,9 +19,12 @@ use namada_core::storage::{BlockHeight, Epoch};
 use namada_core::time::DateTimeUtc;
 use namada_core::{storage, token};
 use namada_governance::cli::onchain::{
-    DefaultProposal, PgfFundingProposal, PgfStewardProposal,
+    DefaultProposal, ParameterChangeProposal, PgfFundingProposal,
+    PgfStewardProposal, WasmAllowlistProposal,
 };
+use namada_governance::storage::proposal::RepresentationScope;
 use namada_ibc::IbcShieldingData;
+use namada_proof_of_stake::types::{MetadataField, RebalanceStep, SlashType};
 use namada_tx::data::GasLimit;
 use namada_tx::Memo;
 use serde::{Deserialize, Serialize};
@@ -361,6 +366,69 @@ impl TxShieldedTransfer {
     }
 }
 
+/// Shielded note consolidation transaction arguments
+#[derive(Clone, Debug)]
+pub struct TxShieldedConsolidate<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// The spending key whose notes to consolidate
+    pub source: C::SpendingKey,
+    /// The token whose notes to consolidate
+    pub token: C::Address,
+    /// The maximum number of notes to spend in each transaction. If not
+    /// given, it is derived from the gas limits.
+    pub max_notes: Option<usize>,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for TxShieldedConsolidate<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        TxShieldedConsolidate {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl TxShieldedConsolidate {
+    /// The maximum number of notes to spend in each transaction
+    pub fn max_notes(self, max_notes: usize) -> Self {
+        Self {
+            max_notes: Some(max_notes),
+            ..self
+        }
+    }
+
+    /// Build the consolidation transactions from this builder. They must be
+    /// submitted in the returned order.
+    pub async fn build(
+        &mut self,
+        context: &impl Namada,
+    ) -> crate::error::Result<Vec<(namada_tx::Tx, SigningTxData)>> {
+        tx::build_shielded_consolidation(context, self).await
+    }
+}
+
+/// Disposable keys management arguments
+#[derive(Clone, Debug)]
+pub struct DisposableKeys<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments, used to sweep the balances of the keys
+    pub tx: Tx<C>,
+    /// Sweep the fee token balances of the keys to this payment address
+    pub sweep_target: Option<C::PaymentAddress>,
+    /// Remove the keys that hold no funds
+    pub prune: bool,
+    /// The minimum number of blocks since their creation for keys to be
+    /// removed
+    pub prune_age: u64,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
 /// Shielding transfer-specific arguments
 #[derive(Clone, Debug)]
 pub struct TxShieldingTransferData<C: NamadaTypes = SdkTypes> {
@@ -601,6 +669,10 @@ pub struct InitProposal<C: NamadaTypes = SdkTypes> {
     pub is_pgf_stewards: bool,
     /// Flag if proposal is of type Pgf funding
     pub is_pgf_funding: bool,
+    /// Flag if proposal is of type parameter change
+    pub is_parameter_change: bool,
+    /// Flag if proposal is of type wasm allowlist
+    pub is_wasm_allowlist: bool,
     /// Path to the tx WASM file
     pub tx_code_path: PathBuf,
 }
@@ -642,6 +714,22 @@ impl<C: NamadaTypes> InitProposal<C> {
         }
     }
 
+    /// Flag if proposal is of type parameter change
+    pub fn is_parameter_change(self, is_parameter_change: bool) -> Self {
+        Self {
+            is_parameter_change,
+            ..self
+        }
+    }
+
+    /// Flag if proposal is of type wasm allowlist
+    pub fn is_wasm_allowlist(self, is_wasm_allowlist: bool) -> Self {
+        Self {
+            is_wasm_allowlist,
+            ..self
+        }
+    }
+
     /// Path to the tx WASM file
     pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
         Self {
@@ -704,6 +792,62 @@ impl InitProposal {
                 })?;
 
             tx::build_pgf_stewards_proposal(context, self, proposal).await
+        } else if self.is_parameter_change {
+            let proposal = ParameterChangeProposal::try_from(
+                self.proposal_data.as_ref(),
+            )
+            .map_err(|e| {
+                crate::error::TxSubmitError::FailedGovernaneProposalDeserialize(
+                    e.to_string(),
+                )
+            })?;
+            let nam_address = context.native_token();
+            let author_balance = rpc::get_token_balance(
+                context.client(),
+                &nam_address,
+                &proposal.proposal.author,
+            )
+            .await?;
+            let proposal = proposal
+                .validate(
+                    &governance_parameters,
+                    current_epoch,
+                    author_balance,
+                    self.tx.force,
+                )
+                .map_err(|e| {
+                    crate::error::TxSubmitError::InvalidProposal(e.to_string())
+                })?;
+
+            tx::build_parameter_change_proposal(context, self, proposal).await
+        } else if self.is_wasm_allowlist {
+            let proposal = WasmAllowlistProposal::try_from(
+                self.proposal_data.as_ref(),
+            )
+            .map_err(|e| {
+                crate::error::TxSubmitError::FailedGovernaneProposalDeserialize(
+                    e.to_string(),
+                )
+            })?;
+            let nam_address = context.native_token();
+            let author_balance = rpc::get_token_balance(
+                context.client(),
+                &nam_address,
+                &proposal.proposal.author,
+            )
+            .await?;
+            let proposal = proposal
+                .validate(
+                    &governance_parameters,
+                    current_epoch,
+                    author_balance,
+                    self.tx.force,
+                )
+                .map_err(|e| {
+                    crate::error::TxSubmitError::InvalidProposal(e.to_string())
+                })?;
+
+            tx::build_wasm_allowlist_proposal(context, self, proposal).await
         } else {
             let proposal = DefaultProposal::try_from(
                 self.proposal_data.as_ref(),
@@ -742,7 +886,8 @@ pub struct VoteProposal<C: NamadaTypes = SdkTypes> {
     pub tx: Tx<C>,
     /// Proposal id
     pub proposal_id: u64,
-    /// The vote
+    /// The vote: "yay", "nay", "abstain", "no_with_veto" or a weighted split
+    /// of the voting power such as "yay:0.7,nay:0.2,abstain:0.1"
     pub vote: String,
     /// The address of the voter
     pub voter_address: C::Address,
@@ -804,6 +949,191 @@ impl VoteProposal {
     }
 }
 
+/// Transaction to cancel a proposal before its voting period starts
+#[derive(Clone, Debug)]
+pub struct CancelProposal<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Proposal id
+    pub proposal_id: u64,
+    /// The address of the proposal author
+    pub author: C::Address,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for CancelProposal<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        CancelProposal {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> CancelProposal<C> {
+    /// Proposal id
+    pub fn proposal_id(self, proposal_id: u64) -> Self {
+        Self {
+            proposal_id,
+            ..self
+        }
+    }
+
+    /// The address of the proposal author
+    pub fn author(self, author: C::Address) -> Self {
+        Self { author, ..self }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl CancelProposal {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        let current_epoch = rpc::query_epoch(context.client()).await?;
+        tx::build_cancel_proposal(context, self, current_epoch).await
+    }
+}
+
+/// Transaction for a validator to veto a passed proposal waiting in the
+/// timelock queue
+#[derive(Clone, Debug)]
+pub struct VetoProposal<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Proposal id
+    pub proposal_id: u64,
+    /// The address of the vetoing validator
+    pub validator: C::Address,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for VetoProposal<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        VetoProposal {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> VetoProposal<C> {
+    /// Proposal id
+    pub fn proposal_id(self, proposal_id: u64) -> Self {
+        Self {
+            proposal_id,
+            ..self
+        }
+    }
+
+    /// The address of the vetoing validator
+    pub fn validator(self, validator: C::Address) -> Self {
+        Self { validator, ..self }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl VetoProposal {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        let current_epoch = rpc::query_epoch(context.client()).await?;
+        tx::build_veto_proposal(context, self, current_epoch).await
+    }
+}
+
+/// Transaction to delegate the governance voting power of an account to a
+/// representative, or to revoke such a delegation
+#[derive(Clone, Debug)]
+pub struct DelegateVote<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// The address delegating its voting power
+    pub delegator: C::Address,
+    /// The representative, or `None` to revoke the delegation
+    pub representative: Option<C::Address>,
+    /// The proposals the delegation applies to
+    pub scope: RepresentationScope,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for DelegateVote<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        DelegateVote {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> DelegateVote<C> {
+    /// The address delegating its voting power
+    pub fn delegator(self, delegator: C::Address) -> Self {
+        Self { delegator, ..self }
+    }
+
+    /// The representative, or `None` to revoke the delegation
+    pub fn representative(self, representative: Option<C::Address>) -> Self {
+        Self {
+            representative,
+            ..self
+        }
+    }
+
+    /// The proposals the delegation applies to
+    pub fn scope(self, scope: RepresentationScope) -> Self {
+        Self { scope, ..self }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl DelegateVote {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_delegate_vote(context, self).await
+    }
+}
+
 /// Transaction to initialize a new account
 #[derive(Clone, Debug)]
 pub struct TxInitAccount<C: NamadaTypes = SdkTypes> {
@@ -1306,6 +1636,71 @@ impl<C: NamadaTypes> TxBuilder<C> for Redelegate<C> {
     }
 }
 
+/// Rebalance delegations arguments
+#[derive(Clone, Debug)]
+pub struct RebalanceDelegations<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Owner of the bonds that are being rebalanced
+    pub owner: C::Address,
+    /// The target bond amount per validator. Bonds at validators that are not
+    /// listed are fully unbonded or redelegated.
+    pub targets: Vec<(C::Address, token::Amount)>,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl RebalanceDelegations {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_rebalance_delegations(context, self).await
+    }
+
+    /// Compute the redelegations, unbonds and bonds that the transaction
+    /// would apply in the current epoch, without building it
+    pub async fn preview(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<Vec<RebalanceStep>> {
+        tx::preview_rebalance_delegations(context, self).await
+    }
+}
+
+impl<C: NamadaTypes> RebalanceDelegations<C> {
+    /// Owner (or delegator or source) of the bonds
+    pub fn owner(self, owner: C::Address) -> Self {
+        Self { owner, ..self }
+    }
+
+    /// The target bond amount per validator
+    pub fn targets(self, targets: Vec<(C::Address, token::Amount)>) -> Self {
+        Self { targets, ..self }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for RebalanceDelegations<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        RebalanceDelegations {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
 /// Reveal public key
 #[derive(Clone, Debug)]
 pub struct RevealPk<C: NamadaTypes = SdkTypes> {
@@ -1545,6 +1940,59 @@ impl ClaimRewards {
     }
 }
 
+/// Rewards auto-compounding arguments
+#[derive(Clone, Debug)]
+pub struct AutoCompound<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Validator address
+    pub validator: C::Address,
+    /// Source address of the bond. For self-bonds, the validator is also
+    /// the source
+    pub source: Option<C::Address>,
+    /// Opt out of the auto-compounding instead of opting in
+    pub disable: bool,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for AutoCompound<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        AutoCompound {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> AutoCompound<C> {
+    /// Source address of the bond
+    pub fn source(self, source: C::Address) -> Self {
+        Self {
+            source: Some(source),
+            ..self
+        }
+    }
+
+    /// Opt out of the auto-compounding
+    pub fn disable(self, disable: bool) -> Self {
+        Self { disable, ..self }
+    }
+}
+
+impl AutoCompound {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_auto_compound(context, self).await
+    }
+}
+
 /// Query asset conversions
 #[derive(Clone, Debug)]
 pub struct QueryConversions<C: NamadaTypes = SdkTypes> {
@@ -1576,6 +2024,10 @@ pub struct QueryBalance<C: NamadaTypes = SdkTypes> {
     pub token: C::Address,
     /// Whether not to convert balances
     pub no_conversions: bool,
+    /// Evaluate the shielded balance at this past MASP epoch
+    pub masp_epoch: Option<MaspEpoch>,
+    /// Project the shielded rewards for this many upcoming MASP epochs
+    pub project_rewards: Option<u64>,
 }
 
 /// Query historical transfer(s)
@@ -1677,6 +2129,62 @@ impl CommissionRateChange {
     }
 }
 
+#[derive(Clone, Debug)]
+/// Commission schedule change args
+pub struct CommissionScheduleChange<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Validator address (should be self)
+    pub validator: C::Address,
+    /// Epochs and the commission rates that take effect at them. An empty
+    /// schedule cancels all pending changes.
+    pub schedule: Vec<(Epoch, Dec)>,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for CommissionScheduleChange<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        CommissionScheduleChange {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> CommissionScheduleChange<C> {
+    /// Validator address (should be self)
+    pub fn validator(self, validator: C::Address) -> Self {
+        Self { validator, ..self }
+    }
+
+    /// Epochs and the commission rates that take effect at them
+    pub fn schedule(self, schedule: Vec<(Epoch, Dec)>) -> Self {
+        Self { schedule, ..self }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl CommissionScheduleChange {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_validator_commission_schedule_change(context, self).await
+    }
+}
+
 #[derive(Clone, Debug)]
 /// Consensus key change args
 pub struct ConsensusKeyChange<C: NamadaTypes = SdkTypes> {
@@ -1737,6 +2245,84 @@ impl ConsensusKeyChange {
     }
 }
 
+#[derive(Clone, Debug)]
+/// Validator protocol and Ethereum keys change args
+pub struct ValidatorKeysChange<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Validator address (should be self)
+    pub validator: C::Address,
+    /// New protocol key
+    pub protocol_key: Option<C::PublicKey>,
+    /// New Ethereum hot key
+    pub eth_hot_key: Option<C::PublicKey>,
+    /// New Ethereum cold key
+    pub eth_cold_key: Option<C::PublicKey>,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for ValidatorKeysChange<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        ValidatorKeysChange {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> ValidatorKeysChange<C> {
+    /// Validator address (should be self)
+    pub fn validator(self, validator: C::Address) -> Self {
+        Self { validator, ..self }
+    }
+
+    /// The new protocol key
+    pub fn protocol_key(self, protocol_key: C::PublicKey) -> Self {
+        Self {
+            protocol_key: Some(protocol_key),
+            ..self
+        }
+    }
+
+    /// The new Ethereum hot key
+    pub fn eth_hot_key(self, eth_hot_key: C::PublicKey) -> Self {
+        Self {
+            eth_hot_key: Some(eth_hot_key),
+            ..self
+        }
+    }
+
+    /// The new Ethereum cold key
+    pub fn eth_cold_key(self, eth_cold_key: C::PublicKey) -> Self {
+        Self {
+            eth_cold_key: Some(eth_cold_key),
+            ..self
+        }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl ValidatorKeysChange {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_change_validator_keys(context, self).await
+    }
+}
+
 #[derive(Clone, Debug)]
 /// Commission rate change args
 pub struct MetaDataChange<C: NamadaTypes = SdkTypes> {
@@ -1758,6 +2344,14 @@ pub struct MetaDataChange<C: NamadaTypes = SdkTypes> {
     pub name: Option<String>,
     /// New validator commission rate
     pub commission_rate: Option<Dec>,
+    /// New validator security contact
+    pub security_contact: Option<String>,
+    /// Validator social handles to set, keyed by their platform
+    pub social_handles: BTreeMap<String, String>,
+    /// New validator identity proof hash
+    pub identity_proof: Option<Hash>,
+    /// Validator metadata extensions to set
+    pub extensions: BTreeMap<String, String>,
     /// Path to the TX WASM code file
     pub tx_code_path: PathBuf,
 }
@@ -1843,6 +2437,34 @@ impl<C: NamadaTypes> MetaDataChange<C> {
             ..self
         }
     }
+
+    /// New validator security contact
+    pub fn security_contact(self, security_contact: String) -> Self {
+        Self {
+            security_contact: Some(security_contact),
+            ..self
+        }
+    }
+
+    /// Validator social handle to set on the given platform
+    pub fn social_handle(mut self, platform: String, handle: String) -> Self {
+        self.social_handles.insert(platform, handle);
+        self
+    }
+
+    /// New validator identity proof hash
+    pub fn identity_proof(self, identity_proof: Hash) -> Self {
+        Self {
+            identity_proof: Some(identity_proof),
+            ..self
+        }
+    }
+
+    /// Validator metadata extension to set
+    pub fn extension(mut self, key: String, value: String) -> Self {
+        self.extensions.insert(key, value);
+        self
+    }
 }
 
 impl MetaDataChange {
@@ -2133,6 +2755,12 @@ pub struct ShieldedSync<C: NamadaTypes = SdkTypes> {
     /// If present, the shielded sync will be performed
     /// using data retrieved from the given indexer
     pub with_indexer: Option<C::MaspIndexerAddress>,
+    /// Number of threads used for trial decryption. Defaults to the number
+    /// of available cores
+    pub scan_workers: Option<usize>,
+    /// Number of scanned txs after which the shielded context is
+    /// checkpointed
+    pub checkpoint_interval: Option<usize>,
 }
 
 /// Query PoS commission rate
@@ -2155,6 +2783,54 @@ pub struct QueryMetaData<C: NamadaTypes = SdkTypes> {
     pub validator: C::Address,
 }
 
+/// Query the validators by a metadata field
+#[derive(Clone, Debug)]
+pub struct QueryValidatorsByMetadata<C: NamadaTypes = SdkTypes> {
+    /// Common query args
+    pub query: Query<C>,
+    /// The metadata field that must be set
+    pub field: MetadataField,
+    /// Only show validators whose field matches this value, ignoring case
+    pub value: Option<String>,
+    /// Epoch in which to find the validators
+    pub epoch: Option<Epoch>,
+}
+
+/// Query validator liveness
+#[derive(Clone, Debug)]
+pub struct QueryValidatorLiveness<C: NamadaTypes = SdkTypes> {
+    /// Common query args
+    pub query: Query<C>,
+    /// Address of a validator
+    pub validator: C::Address,
+    /// Number of past epochs of liveness history to query
+    pub num_epochs: Option<u64>,
+}
+
+/// Query the self-bond of a validator
+#[derive(Clone, Debug)]
+pub struct QueryValidatorSelfBond<C: NamadaTypes = SdkTypes> {
+    /// Common query args
+    pub query: Query<C>,
+    /// Address of a validator
+    pub validator: C::Address,
+    /// Epoch in which to find the self-bond
+    pub epoch: Option<Epoch>,
+}
+
+/// Query a simulated slash of a validator for a hypothetical infraction
+#[derive(Clone, Debug)]
+pub struct QuerySlashSimulation<C: NamadaTypes = SdkTypes> {
+    /// Common query args
+    pub query: Query<C>,
+    /// Address of a validator
+    pub validator: C::Address,
+    /// Type of the hypothetical infraction
+    pub slash_type: SlashType,
+    /// Epoch of the hypothetical infraction
+    pub infraction_epoch: Epoch,
+}
+
 /// Query PoS slashes
 #[derive(Clone, Debug)]
 pub struct QuerySlashes<C: NamadaTypes = SdkTypes> {
@@ -2175,6 +2851,23 @@ pub struct QueryRewards<C: NamadaTypes = SdkTypes> {
     pub validator: C::Address,
 }
 
+/// Query PoS rewards history
+#[derive(Clone, Debug)]
+pub struct QueryRewardsHistory<C: NamadaTypes = SdkTypes> {
+    /// Common query args
+    pub query: Query<C>,
+    /// Address of the source
+    pub source: Option<C::Address>,
+    /// Address of the validator
+    pub validator: C::Address,
+    /// First epoch of the history (the genesis epoch, if not given)
+    pub from_epoch: Option<Epoch>,
+    /// Last epoch of the history (the last epoch with rewards, if not given)
+    pub to_epoch: Option<Epoch>,
+    /// Path to a CSV file to write the history to, instead of printing it
+    pub output: Option<PathBuf>,
+}
+
 /// Query PoS delegations
 #[derive(Clone, Debug)]
 pub struct QueryDelegations<C: NamadaTypes = SdkTypes> {
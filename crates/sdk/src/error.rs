@@ -153,6 +153,12 @@ pub enum TxSubmitError {
          predecessor epoch in which the rate will take effect."
     )]
     TooLargeOfChange(Dec),
+    /// Commission schedule entry at an invalid epoch
+    #[error(
+        "Invalid commission schedule epoch {0}: scheduled epochs must be \
+         strictly increasing and after the pipeline epoch {1}."
+    )]
+    InvalidCommissionScheduleEpoch(Epoch, Epoch),
     /// Error retrieving from storage
     #[error("Error retrieving from storage")]
     Retrieval,
@@ -189,6 +195,18 @@ pub enum TxSubmitError {
          unbonded. Amount to unbond is {1} and the total bonds is {2}."
     )]
     LowerBondThanUnbond(Address, String, String),
+    /// The self-bond would be less than the minimum
+    #[error(
+        "The self-bond of validator {0} would be {1}, which is below the \
+         minimum of {2}."
+    )]
+    SelfBondBelowMin(Address, String, String),
+    /// The validator's stake would go over the cap
+    #[error(
+        "The stake of validator {0} would be {1}, which is over its cap of \
+         {2} set by the maximum validator stake share."
+    )]
+    StakeCapExceeded(Address, String, String),
     /// Balance is too low
     #[error(
         "The balance of the source {0} of token {1} is lower than the amount \
@@ -243,6 +261,15 @@ pub enum TxSubmitError {
     /// The proposal can't be voted
     #[error("Proposal {0} can't be voted")]
     InvalidProposalVotingPeriod(u64),
+    /// The proposal can't be cancelled
+    #[error("Proposal {0} can't be cancelled")]
+    InvalidProposalCancellation(u64),
+    /// The proposal can't be vetoed
+    #[error("Proposal {0} can't be vetoed")]
+    InvalidProposalVeto(u64),
+    /// The address is not the author of the proposal
+    #[error("Address {0} is not the author of proposal {1}")]
+    InvalidProposalAuthor(Address, u64),
     /// The proposal can't be found
     #[error("Proposal {0} can't be found")]
     ProposalDoesNotExist(u64),
@@ -306,12 +333,18 @@ pub enum TxSubmitError {
          still subject to possible slashing"
     )]
     IncomingRedelIsStillSlashable(Address, Address),
+    /// A validator is given more than once in the rebalance targets
+    #[error("The validator {0} is given more than once in the targets")]
+    DuplicateRebalanceTarget(Address),
     /// An empty string was provided as a new email
     #[error("An empty string cannot be provided as a new email")]
     InvalidEmail,
     /// The metadata string is too long
     #[error("The provided metadata string is too long")]
     MetadataTooLong,
+    /// The resulting validator metadata is invalid
+    #[error("The resulting validator metadata is invalid: {0}")]
+    InvalidMetadata(String),
     /// The consensus key is not Ed25519
     #[error("The consensus key must be an ed25519 key")]
     ConsensusKeyNotEd25519,
@@ -62,6 +62,8 @@ use namada_core::ethereum_events::EthAddress;
 use namada_core::ibc::core::host::types::identifiers::{ChannelId, PortId};
 use namada_core::key::*;
 use namada_core::masp::{ExtendedSpendingKey, PaymentAddress, TransferSource};
+use namada_core::storage::Epoch;
+use namada_governance::RepresentationScope;
 use namada_tx::data::wrapper::GasLimit;
 use namada_tx::Tx;
 use rpc::{denominate_amount, format_denominated_amount, query_native_token};
@@ -69,15 +71,17 @@ use signing::SigningTxData;
 use token::{DenominatedAmount, NATIVE_MAX_DECIMAL_PLACES};
 use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 use tx::{
-    ProcessTxResponse, TX_BECOME_VALIDATOR_WASM, TX_BOND_WASM,
-    TX_BRIDGE_POOL_WASM, TX_CHANGE_COMMISSION_WASM,
+    ProcessTxResponse, TX_AUTO_COMPOUND_WASM, TX_BECOME_VALIDATOR_WASM,
+    TX_BOND_WASM, TX_BRIDGE_POOL_WASM, TX_CANCEL_PROPOSAL,
+    TX_CHANGE_COMMISSION_SCHEDULE_WASM, TX_CHANGE_COMMISSION_WASM,
     TX_CHANGE_CONSENSUS_KEY_WASM, TX_CHANGE_METADATA_WASM,
-    TX_CLAIM_REWARDS_WASM, TX_DEACTIVATE_VALIDATOR_WASM, TX_IBC_WASM,
+    TX_CHANGE_VALIDATOR_KEYS_WASM, TX_CLAIM_REWARDS_WASM,
+    TX_DEACTIVATE_VALIDATOR_WASM, TX_DELEGATE_VOTE, TX_IBC_WASM,
     TX_INIT_ACCOUNT_WASM, TX_INIT_PROPOSAL, TX_REACTIVATE_VALIDATOR_WASM,
-    TX_REDELEGATE_WASM, TX_RESIGN_STEWARD, TX_REVEAL_PK, TX_TRANSFER_WASM,
-    TX_UNBOND_WASM, TX_UNJAIL_VALIDATOR_WASM, TX_UPDATE_ACCOUNT_WASM,
-    TX_UPDATE_STEWARD_COMMISSION, TX_VOTE_PROPOSAL, TX_WITHDRAW_WASM,
-    VP_USER_WASM,
+    TX_REBALANCE_DELEGATIONS_WASM, TX_REDELEGATE_WASM, TX_RESIGN_STEWARD,
+    TX_REVEAL_PK, TX_TRANSFER_WASM, TX_UNBOND_WASM, TX_UNJAIL_VALIDATOR_WASM,
+    TX_UPDATE_ACCOUNT_WASM, TX_UPDATE_STEWARD_COMMISSION, TX_VETO_PROPOSAL,
+    TX_VOTE_PROPOSAL, TX_WITHDRAW_WASM, VP_USER_WASM,
 };
 use wallet::{Wallet, WalletIo, WalletStorage};
 
@@ -199,6 +203,22 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
         }
     }
 
+    /// Make a TxShieldedConsolidate builder from the given minimum set of
+    /// arguments
+    fn new_shielded_consolidate(
+        &self,
+        source: ExtendedSpendingKey,
+        token: Address,
+    ) -> args::TxShieldedConsolidate {
+        args::TxShieldedConsolidate {
+            source,
+            token,
+            max_notes: None,
+            tx_code_path: PathBuf::from(TX_TRANSFER_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
     /// Make a TxShieldingTransfer builder from the given minimum set of
     /// arguments
     fn new_shielding_transfer(
@@ -307,6 +327,21 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
         }
     }
 
+    /// Make a RebalanceDelegations builder for the given minimum set of
+    /// arguments
+    fn new_rebalance_delegations(
+        &self,
+        owner: Address,
+        targets: Vec<(Address, token::Amount)>,
+    ) -> args::RebalanceDelegations {
+        args::RebalanceDelegations {
+            tx: self.tx_builder(),
+            owner,
+            targets,
+            tx_code_path: PathBuf::from(TX_REBALANCE_DELEGATIONS_WASM),
+        }
+    }
+
     /// Make a TxIbcTransfer builder from the given minimum set of arguments
     fn new_ibc_transfer(
         &self,
@@ -340,6 +375,8 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
             proposal_data,
             is_pgf_stewards: false,
             is_pgf_funding: false,
+            is_parameter_change: false,
+            is_wasm_allowlist: false,
             tx_code_path: PathBuf::from(TX_INIT_PROPOSAL),
             tx: self.tx_builder(),
         }
@@ -378,6 +415,66 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
         }
     }
 
+    /// Make a CancelProposal builder from the given minimum set of arguments
+    fn new_cancel_proposal(
+        &self,
+        proposal_id: u64,
+        author: Address,
+    ) -> args::CancelProposal {
+        args::CancelProposal {
+            author,
+            proposal_id,
+            tx_code_path: PathBuf::from(TX_CANCEL_PROPOSAL),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make a VetoProposal builder from the given minimum set of arguments
+    fn new_veto_proposal(
+        &self,
+        proposal_id: u64,
+        validator: Address,
+    ) -> args::VetoProposal {
+        args::VetoProposal {
+            validator,
+            proposal_id,
+            tx_code_path: PathBuf::from(TX_VETO_PROPOSAL),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make a DelegateVote builder from the given minimum set of arguments,
+    /// delegating the voting power for all proposals
+    fn new_delegate_vote(
+        &self,
+        delegator: Address,
+        representative: Option<Address>,
+    ) -> args::DelegateVote {
+        args::DelegateVote {
+            delegator,
+            representative,
+            scope: RepresentationScope::All,
+            tx_code_path: PathBuf::from(TX_DELEGATE_VOTE),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make ValidatorKeysChange builder from the given minimum set of
+    /// arguments
+    fn new_change_validator_keys(
+        &self,
+        validator: Address,
+    ) -> args::ValidatorKeysChange {
+        args::ValidatorKeysChange {
+            validator,
+            protocol_key: None,
+            eth_hot_key: None,
+            eth_cold_key: None,
+            tx_code_path: PathBuf::from(TX_CHANGE_VALIDATOR_KEYS_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
     /// Make a CommissionRateChange builder from the given minimum set of
     /// arguments
     fn new_change_commission_rate(
@@ -393,6 +490,21 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
         }
     }
 
+    /// Make a CommissionScheduleChange builder from the given minimum set of
+    /// arguments
+    fn new_change_commission_schedule(
+        &self,
+        schedule: Vec<(Epoch, Dec)>,
+        validator: Address,
+    ) -> args::CommissionScheduleChange {
+        args::CommissionScheduleChange {
+            schedule,
+            validator,
+            tx_code_path: PathBuf::from(TX_CHANGE_COMMISSION_SCHEDULE_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
     /// Make ConsensusKeyChange builder from the given minimum set of arguments
     fn new_change_consensus_key(
         &self,
@@ -420,6 +532,10 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
             avatar: None,
             name: None,
             commission_rate: None,
+            security_contact: None,
+            social_handles: Default::default(),
+            identity_proof: None,
+            extensions: Default::default(),
             tx_code_path: PathBuf::from(TX_CHANGE_METADATA_WASM),
             tx: self.tx_builder(),
         }
@@ -550,6 +666,18 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
         }
     }
 
+    /// Make a rewards auto-compounding builder from the given minimum set of
+    /// arguments
+    fn new_auto_compound(&self, validator: Address) -> args::AutoCompound {
+        args::AutoCompound {
+            validator,
+            source: None,
+            disable: false,
+            tx_code_path: PathBuf::from(TX_AUTO_COMPOUND_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
     /// Make a Withdraw builder from the given minimum set of arguments
     fn new_add_erc20_transfer(
         &self,
@@ -1,5 +1,6 @@
 //! MASP verification wrappers.
 
+pub mod prover;
 #[cfg(test)]
 mod test_utils;
 pub mod utils;
@@ -73,8 +74,10 @@ use thiserror::Error;
 use crate::control_flow::ShutdownSignal;
 use crate::error::{Error, QueryError};
 use crate::io::Io;
+use crate::masp::prover::{MaspTxProver, RemoteProverError};
 use crate::masp::utils::{
-    fetch_channel, FetchQueueSender, MaspClient, ProgressTracker, RetryStrategy,
+    fetch_channel, FetchQueueSender, MaspClient, ProgressTracker,
+    RetryStrategy, ScanConfig,
 };
 use crate::queries::Client;
 use crate::rpc::{query_conversion, query_denom};
@@ -176,6 +179,24 @@ pub struct MaspTokenRewardData {
     pub locked_amount_target: Uint,
 }
 
+/// Projected shielded rewards for a token over the upcoming MASP epochs
+#[derive(Debug, BorshSerialize, BorshDeserialize, BorshDeserializer)]
+pub struct MaspTokenRewardProjection {
+    /// The alias of the token in the MASP token map
+    pub name: String,
+    /// The address of the token
+    pub address: Address,
+    /// The denomination of the token
+    pub denomination: Denomination,
+    /// The amount of the token currently locked in the shielded pool
+    pub locked_amount: token::Amount,
+    /// The number of raw units of the token that the rewards refer to
+    pub precision: u128,
+    /// The reward, in raw units of the native token, obtained for every
+    /// `precision` raw units of the token held in each upcoming MASP epoch
+    pub rewards: Vec<u128>,
+}
+
 /// A return type for gen_shielded_transfer
 #[allow(clippy::large_enum_variant)]
 #[derive(Error, Debug)]
@@ -229,6 +250,12 @@ pub trait ShieldedUtils:
     /// Get a MASP transaction prover
     fn local_tx_prover(&self) -> LocalTxProver;
 
+    /// Get the prover used to generate the proofs of MASP transactions.
+    /// Defaults to proving locally.
+    fn tx_prover(&self) -> Result<MaspTxProver, RemoteProverError> {
+        Ok(self.local_tx_prover().into())
+    }
+
     /// Load up the currently saved ShieldedContext
     async fn load<U: ShieldedUtils + MaybeSend>(
         &self,
@@ -339,10 +366,20 @@ pub struct MaspChange {
 /// a masp amount
 pub type MaspAmount = ValueSum<(Option<MaspEpoch>, Address), token::Change>;
 
-// A type tracking the notes used to construct a shielded transfer. Used to
-// avoid reusing the same notes multiple times which would lead to an invalid
-// transaction
-type SpentNotesTracker = HashMap<ViewingKey, HashSet<usize>>;
+/// A type tracking the notes used to construct a shielded transfer. Used to
+/// avoid reusing the same notes multiple times which would lead to an invalid
+/// transaction
+pub type SpentNotesTracker = HashMap<ViewingKey, HashSet<usize>>;
+
+/// A batch of notes to be merged by transferring their value back to their
+/// owner
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteConsolidationBatch {
+    /// The positions of the notes of the batch
+    pub notes: BTreeSet<usize>,
+    /// The value of the notes of the batch
+    pub amount: token::Amount,
+}
 
 /// An extension of Option's cloned method for pair types
 fn cloned_pair<T: Clone, U: Clone>((a, b): (&T, &U)) -> (T, U) {
@@ -359,6 +396,70 @@ pub type TransferDelta = HashMap<Address, MaspChange>;
 /// Represents the changes that were made to a list of shielded accounts
 pub type TransactionDelta = HashMap<ViewingKey, I128Sum>;
 
+/// The notes of a shielded transaction that a viewing key managed to decrypt,
+/// each paired with the offset of its output from the transaction's first
+/// output note
+pub type DecryptedNotes = Vec<(
+    usize,
+    Note,
+    masp_primitives::sapling::PaymentAddress,
+    MemoBytes,
+)>;
+
+/// Try to decrypt all the output notes of the given shielded transaction with
+/// the given viewing key
+pub fn trial_decrypt(
+    shielded: &[Transaction],
+    vk: &ViewingKey,
+) -> DecryptedNotes {
+    type Proof = OutputDescription<
+        <
+            <Authorized as Authorization>::SaplingAuth
+            as masp_primitives::transaction::components::sapling::Authorization
+        >::Proof
+    >;
+
+    let ivk = PreparedIncomingViewingKey::new(&vk.ivk());
+    let mut decrypted = DecryptedNotes::new();
+    let mut offset = 0;
+    for tx in shielded {
+        for so in tx.sapling_bundle().map_or(&vec![], |x| &x.shielded_outputs) {
+            let decres = try_sapling_note_decryption::<_, Proof>(
+                &NETWORK,
+                1.into(),
+                &ivk,
+                so,
+            );
+            if let Some((note, pa, memo)) = decres {
+                decrypted.push((offset, note, pa, memo));
+            }
+            offset += 1;
+        }
+    }
+    decrypted
+}
+
+/// Trial-decrypt a batch of fetched txs with the given viewing keys, each
+/// paired with the last tx it has already scanned
+#[cfg(not(target_family = "wasm"))]
+fn trial_decrypt_partition(
+    batch: &[IndexedNoteEntry],
+    keys: &[(ViewingKey, Option<IndexedTx>)],
+) -> BTreeMap<(IndexedTx, ViewingKey), DecryptedNotes> {
+    let mut decrypted = BTreeMap::new();
+    for (indexed_tx, stx) in batch {
+        for (vk, _) in
+            keys.iter().filter(|(_vk, h)| h.as_ref() < Some(indexed_tx))
+        {
+            let notes = trial_decrypt(stx, vk);
+            if !notes.is_empty() {
+                decrypted.insert((indexed_tx.clone(), *vk), notes);
+            }
+        }
+    }
+    decrypted
+}
+
 /// A cache of fetched indexed transactions.
 ///
 /// An invariant that shielded-sync maintains is that
@@ -496,6 +597,9 @@ pub struct ShieldedContext<U: ShieldedUtils> {
     pub witness_map: HashMap<usize, IncrementalWitness<Node>>,
     /// The set of note positions that have been spent
     pub spents: HashSet<usize>,
+    /// Maps spent note positions to the height of the transaction that spent
+    /// them
+    pub spent_height_map: HashMap<usize, BlockHeight>,
     /// Maps asset types to their decodings
     pub asset_types: HashMap<AssetType, AssetData>,
     /// Maps note positions to their corresponding viewing keys
@@ -524,6 +628,7 @@ impl<U: ShieldedUtils + Default> Default for ShieldedContext<U> {
             div_map: HashMap::default(),
             witness_map: HashMap::default(),
             spents: HashSet::default(),
+            spent_height_map: HashMap::default(),
             asset_types: HashMap::default(),
             vk_map: HashMap::default(),
             unscanned: Default::default(),
@@ -555,6 +660,20 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
         self.utils.save(self).await
     }
 
+    /// Persist the progress of an ongoing sync. Only confirmed data is ever
+    /// checkpointed, such that an interrupted sync can safely resume from
+    /// the last checkpoint (see [`Self::load_confirmed`]).
+    async fn checkpoint(&self) -> Result<(), Error> {
+        if let ContextSyncStatus::Confirmed = self.sync_status {
+            self.save().await.map_err(|e| {
+                Error::Other(format!(
+                    "Failed to checkpoint the shielded context: {e}"
+                ))
+            })?;
+        }
+        Ok(())
+    }
+
     /// Update the merkle tree of witnesses the first time we
     /// scan new MASP transactions.
     fn update_witness_map(
@@ -592,7 +711,9 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
     }
 
     /// Fetch the current state of the multi-asset shielded pool into a
-    /// ShieldedContext
+    /// ShieldedContext. Fetched txs are trial-decrypted in parallel
+    /// according to the given [`ScanConfig`], and the confirmed context is
+    /// checkpointed periodically so that an interrupted sync can resume.
     #[allow(clippy::too_many_arguments)]
     #[cfg(not(target_family = "wasm"))]
     pub async fn fetch<IO, M>(
@@ -602,6 +723,7 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
         start_query_height: Option<BlockHeight>,
         last_query_height: Option<BlockHeight>,
         retry: RetryStrategy,
+        scan_config: ScanConfig,
         sks: &[MaspExtendedSpendingKey],
         fvks: &[ViewingKey],
     ) -> Result<(), Error>
@@ -616,6 +738,7 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
             start_query_height,
             last_query_height,
             retry,
+            scan_config,
             sks,
             fvks,
             shutdown_signal,
@@ -698,6 +821,7 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
         start_query_height: Option<BlockHeight>,
         last_query_height: Option<BlockHeight>,
         retry: RetryStrategy,
+        scan_config: ScanConfig,
         sks: &[MaspExtendedSpendingKey],
         fvks: &[ViewingKey],
         mut shutdown_signal: ShutdownSignal,
@@ -794,31 +918,63 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
                 ),
                 _ => {}
             }
-            let txs = progress.scan(fetch_recv);
-            for (ref indexed_tx, ref stx) in txs {
-                if client.capabilities().needs_witness_map_update()
-                    && Some(indexed_tx) > last_witnessed_tx.as_ref()
-                {
-                    self.update_witness_map(indexed_tx.to_owned(), stx)?;
+            let mut txs = progress.scan(fetch_recv);
+            let mut scanned_since_checkpoint = 0;
+            loop {
+                let batch: Arc<Vec<_>> = Arc::new(
+                    txs.by_ref().take(scan_config.batch_size()).collect(),
+                );
+                if batch.is_empty() {
+                    break;
                 }
-                let mut vk_heights = BTreeMap::new();
-                std::mem::swap(&mut vk_heights, &mut self.vk_heights);
-                for (vk, h) in vk_heights
-                    .iter_mut()
-                    .filter(|(_vk, h)| h.as_ref() < Some(indexed_tx))
-                {
-                    self.scan_tx(indexed_tx.to_owned(), stx, vk)?;
-                    *h = Some(indexed_tx.to_owned());
+                for (indexed_tx, stx) in batch.iter() {
+                    if client.capabilities().needs_witness_map_update()
+                        && Some(indexed_tx) > last_witnessed_tx.as_ref()
+                    {
+                        self.update_witness_map(indexed_tx.to_owned(), stx)?;
+                    }
+                }
+                // The expensive part of scanning is done by the worker pool,
+                // the decrypted notes are then applied in order
+                let mut decrypted = self
+                    .trial_decrypt_batch(batch.clone(), &scan_config)
+                    .await?;
+                for (indexed_tx, stx) in batch.iter() {
+                    let mut vk_heights = BTreeMap::new();
+                    std::mem::swap(&mut vk_heights, &mut self.vk_heights);
+                    for (vk, h) in vk_heights
+                        .iter_mut()
+                        .filter(|(_vk, h)| h.as_ref() < Some(indexed_tx))
+                    {
+                        let notes = decrypted
+                            .remove(&(indexed_tx.to_owned(), *vk))
+                            .unwrap_or_default();
+                        self.apply_scanned_tx(
+                            indexed_tx.to_owned(),
+                            stx,
+                            vk,
+                            notes,
+                        )?;
+                        *h = Some(indexed_tx.to_owned());
+                    }
+                    // possibly remove unneeded elements from the cache.
+                    self.unscanned.scanned(indexed_tx);
+                    std::mem::swap(&mut vk_heights, &mut self.vk_heights);
                 }
-                // possibly remove unneeded elements from the cache.
-                self.unscanned.scanned(indexed_tx);
-                std::mem::swap(&mut vk_heights, &mut self.vk_heights);
                 if shutdown_signal.received() {
                     let _ = self.save().await;
                     return Err(Error::Interrupt(
                         "[ShieldedSync::Scanning]".to_string(),
                     ));
                 }
+                scanned_since_checkpoint += batch.len();
+                if scan_config.checkpoint_interval != 0
+                    && scanned_since_checkpoint
+                        >= scan_config.checkpoint_interval
+                {
+                    self.checkpoint().await?;
+                    scanned_since_checkpoint = 0;
+                }
             }
 
             // If fetching failed before completing, we restart
@@ -885,72 +1041,107 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
         shielded: &[Transaction],
         vk: &ViewingKey,
     ) -> Result<(), Error> {
-        type Proof = OutputDescription<
-            <
-                <Authorized as Authorization>::SaplingAuth
-                as masp_primitives::transaction::components::sapling::Authorization
-            >::Proof
-        >;
+        // Listen for notes sent to our viewing keys, only if we are syncing
+        // (i.e. in a confirmed status)
+        let decrypted = if let ContextSyncStatus::Confirmed = self.sync_status {
+            trial_decrypt(shielded, vk)
+        } else {
+            DecryptedNotes::new()
+        };
+        self.apply_scanned_tx(indexed_tx, shielded, vk, decrypted)
+    }
 
+    /// Trial-decrypt a batch of fetched txs with all the viewing keys that
+    /// have yet to scan them. The viewing keys are partitioned among the
+    /// workers of the given [`ScanConfig`], each of which decrypts the whole
+    /// batch with its own keys on the blocking thread pool, so as not to
+    /// stall the async executor.
+    #[cfg(not(target_family = "wasm"))]
+    async fn trial_decrypt_batch(
+        &self,
+        batch: Arc<Vec<IndexedNoteEntry>>,
+        scan_config: &ScanConfig,
+    ) -> Result<BTreeMap<(IndexedTx, ViewingKey), DecryptedNotes>, Error> {
+        // Notes are only decrypted when syncing confirmed data
+        if let ContextSyncStatus::Speculative = self.sync_status {
+            return Ok(BTreeMap::new());
+        }
+        let vk_heights: Vec<_> = self
+            .vk_heights
+            .iter()
+            .map(|(vk, h)| (*vk, h.clone()))
+            .collect();
+        let workers: Vec<_> = scan_config
+            .partition_keys(&vk_heights)
+            .map(|keys| {
+                let keys = keys.to_vec();
+                let batch = batch.clone();
+                tokio::task::spawn_blocking(move || {
+                    trial_decrypt_partition(&batch, &keys)
+                })
+            })
+            .collect();
+
+        let mut decrypted = BTreeMap::new();
+        for worker in workers {
+            decrypted.extend(worker.await.map_err(|e| {
+                Error::Other(format!("Trial decryption worker failed: {e}"))
+            })?);
+        }
+        Ok(decrypted)
+    }
+
+    /// Apply the outcome of trial-decrypting the given transaction with the
+    /// given viewing key to this context. See [`Self::scan_tx`].
+    fn apply_scanned_tx(
+        &mut self,
+        indexed_tx: IndexedTx,
+        shielded: &[Transaction],
+        vk: &ViewingKey,
+        decrypted: DecryptedNotes,
+    ) -> Result<(), Error> {
         // For tracking the account changes caused by this Transaction
         let mut transaction_delta = TransactionDelta::new();
         if let ContextSyncStatus::Confirmed = self.sync_status {
-            let mut note_pos = self.tx_note_map[&indexed_tx];
-            // Listen for notes sent to our viewing keys, only if we are syncing
-            // (i.e. in a confirmed status)
-            for tx in shielded {
-                for so in
-                    tx.sapling_bundle().map_or(&vec![], |x| &x.shielded_outputs)
-                {
-                    // Let's try to see if this viewing key can decrypt latest
-                    // note
-                    let notes = self.pos_map.entry(*vk).or_default();
-                    let decres = try_sapling_note_decryption::<_, Proof>(
-                        &NETWORK,
-                        1.into(),
-                        &PreparedIncomingViewingKey::new(&vk.ivk()),
-                        so,
-                    );
-                    // So this current viewing key does decrypt this current
-                    // note...
-                    if let Some((note, pa, memo)) = decres {
-                        // Add this note to list of notes decrypted by this
-                        // viewing key
-                        notes.insert(note_pos);
-                        // Compute the nullifier now to quickly recognize when
-                        // spent
-                        let nf = note.nf(
-                            &vk.nk,
-                            note_pos.try_into().map_err(|_| {
-                                Error::Other(
-                                    "Can not get nullifier".to_string(),
-                                )
-                            })?,
-                        );
-                        self.note_map.insert(note_pos, note);
-                        self.memo_map.insert(note_pos, memo);
-                        // The payment address' diversifier is required to spend
-                        // note
-                        self.div_map.insert(note_pos, *pa.diversifier());
-                        self.nf_map.insert(nf, note_pos);
-                        // Note the account changes
-                        let balance = transaction_delta
-                            .entry(*vk)
-                            .or_insert_with(I128Sum::zero);
-                        *balance += I128Sum::from_nonnegative(
-                            note.asset_type,
-                            note.value as i128,
-                        )
-                        .map_err(|()| {
-                            Error::Other(
-                                "found note with invalid value or asset type"
-                                    .to_string(),
-                            )
-                        })?;
-                        self.vk_map.insert(note_pos, *vk);
-                    }
-                    note_pos += 1;
-                }
+            let first_note_pos = self.tx_note_map[&indexed_tx];
+            let has_outputs = shielded.iter().any(|tx| {
+                tx.sapling_bundle()
+                    .is_some_and(|x| !x.shielded_outputs.is_empty())
+            });
+            if has_outputs {
+                self.pos_map.entry(*vk).or_default();
+            }
+            for (offset, note, pa, memo) in decrypted {
+                let note_pos = checked!(first_note_pos + offset)?;
+                // Add this note to list of notes decrypted by this viewing key
+                self.pos_map.entry(*vk).or_default().insert(note_pos);
+                // Compute the nullifier now to quickly recognize when spent
+                let nf = note.nf(
+                    &vk.nk,
+                    note_pos.try_into().map_err(|_| {
+                        Error::Other("Can not get nullifier".to_string())
+                    })?,
+                );
+                self.note_map.insert(note_pos, note);
+                self.memo_map.insert(note_pos, memo);
+                // The payment address' diversifier is required to spend
+                // note
+                self.div_map.insert(note_pos, *pa.diversifier());
+                self.nf_map.insert(nf, note_pos);
+                // Note the account changes
+                let balance =
+                    transaction_delta.entry(*vk).or_insert_with(I128Sum::zero);
+                *balance += I128Sum::from_nonnegative(
+                    note.asset_type,
+                    note.value as i128,
+                )
+                .map_err(|()| {
+                    Error::Other(
+                        "found note with invalid value or asset type"
+                            .to_string(),
+                    )
+                })?;
+                self.vk_map.insert(note_pos, *vk);
             }
         }
 
@@ -963,6 +1154,7 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
                 // note is rendered unusable
                 if let Some(note_pos) = self.nf_map.get(&ss.nullifier) {
                     self.spents.insert(*note_pos);
+                    self.spent_height_map.insert(*note_pos, indexed_tx.height);
                     // Note the account changes
                     let balance = transaction_delta
                         .entry(self.vk_map[note_pos])
@@ -1154,6 +1346,194 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
         }
     }
 
+    /// Compute the total notes associated with the viewing key that were
+    /// unspent at the end of the given MASP epoch, and express that value in
+    /// terms of the asset types timestamped with that epoch. Since conversions
+    /// telescope, this reproduces the valuation that the conversion tree of
+    /// the given epoch would have yielded. If the key is not in the context,
+    /// then we do not know the balance and hence we return None.
+    pub async fn compute_exchanged_balance_at_epoch(
+        &mut self,
+        client: &(impl Client + Sync),
+        io: &impl Io,
+        vk: &ViewingKey,
+        masp_epoch: MaspEpoch,
+    ) -> Result<Option<I128Sum>, Error> {
+        let Some(avail_notes) = self.pos_map.get(vk).cloned() else {
+            return Ok(None);
+        };
+        // Get the epochs of the transactions that created or spent the notes
+        let mut epochs = BTreeMap::<BlockHeight, MaspEpoch>::new();
+        for note_idx in avail_notes {
+            let (created, spent) = self.note_heights(note_idx)?;
+            for height in std::iter::once(created).chain(spent) {
+                if epochs.contains_key(&height) {
+                    continue;
+                }
+                let epoch = rpc::query_masp_epoch_at_height(client, height)
+                    .await?
+                    .ok_or_else(|| {
+                        Error::Other(format!(
+                            "Unable to get the masp epoch of height {height}"
+                        ))
+                    })?;
+                epochs.insert(height, epoch);
+            }
+        }
+        let mut val_acc = I128Sum::zero();
+        for note_idx in self
+            .notes_at_epoch(vk, masp_epoch, &epochs)?
+            .unwrap_or_default()
+        {
+            let note = self.note_map.get(&note_idx).ok_or_else(|| {
+                Error::Other(format!("Unable to get note {note_idx}"))
+            })?;
+            val_acc +=
+                I128Sum::from_nonnegative(note.asset_type, note.value as i128)
+                    .map_err(|()| {
+                    Error::Other(
+                        "found note with invalid value or asset type"
+                            .to_string(),
+                    )
+                })?
+        }
+        let exchanged_amount = self
+            .compute_exchanged_amount(
+                client,
+                io,
+                val_acc,
+                masp_epoch,
+                BTreeMap::new(),
+            )
+            .await?
+            .0;
+        Ok(Some(exchanged_amount))
+    }
+
+    /// Get the height of the transaction that created the given note and, if
+    /// the note has been spent, the height of the transaction that spent it
+    fn note_heights(
+        &self,
+        note_idx: usize,
+    ) -> Result<(BlockHeight, Option<BlockHeight>), Error> {
+        let created = self
+            .tx_note_map
+            .iter()
+            .rev()
+            .find(|(_, first_note)| **first_note <= note_idx)
+            .map(|(indexed_tx, _)| indexed_tx.height)
+            .ok_or_else(|| {
+                Error::Other(format!(
+                    "Unable to find the transaction of note {note_idx}"
+                ))
+            })?;
+        if !self.spents.contains(&note_idx) {
+            return Ok((created, None));
+        }
+        let spent =
+            self.spent_height_map
+                .get(&note_idx)
+                .copied()
+                .ok_or_else(|| {
+                    Error::Other(format!(
+                        "Unable to find the transaction that spent note \
+                         {note_idx}"
+                    ))
+                })?;
+        Ok((created, Some(spent)))
+    }
+
+    /// Get the positions of the notes associated with the viewing key that
+    /// had been created but not yet spent by the end of the given MASP epoch,
+    /// given the MASP epochs of the heights of the transactions that created
+    /// or spent them. If the key is not in the context, then we return None.
+    fn notes_at_epoch(
+        &self,
+        vk: &ViewingKey,
+        masp_epoch: MaspEpoch,
+        epochs: &BTreeMap<BlockHeight, MaspEpoch>,
+    ) -> Result<Option<BTreeSet<usize>>, Error> {
+        let Some(avail_notes) = self.pos_map.get(vk) else {
+            return Ok(None);
+        };
+        let epoch_at = |height: BlockHeight| {
+            epochs.get(&height).copied().ok_or_else(|| {
+                Error::Other(format!(
+                    "Unable to get the masp epoch of height {height}"
+                ))
+            })
+        };
+        let mut notes = BTreeSet::new();
+        for note_idx in avail_notes {
+            let (created, spent) = self.note_heights(*note_idx)?;
+            // Notes created after the given epoch did not exist then
+            if epoch_at(created)? > masp_epoch {
+                continue;
+            }
+            // Notes spent by the end of the given epoch no longer existed then
+            if let Some(spent) = spent {
+                if epoch_at(spent)? <= masp_epoch {
+                    continue;
+                }
+            }
+            notes.insert(*note_idx);
+        }
+        Ok(Some(notes))
+    }
+
+    /// Estimate the shielded rewards, in raw units of the native token, that
+    /// the unspent notes of the given viewing key accrue in each of the given
+    /// number of upcoming MASP epochs. The estimate assumes that the holdings
+    /// of the key as well as the amounts locked in the shielded pool remain
+    /// constant. If the key is not in the context, then we return None.
+    pub async fn project_shielded_rewards(
+        &mut self,
+        client: &(impl Client + Sync),
+        io: &impl Io,
+        vk: &ViewingKey,
+        epochs: u64,
+    ) -> Result<Option<Vec<(Address, Vec<token::Amount>)>>, Error> {
+        let masp_epoch = rpc::query_masp_epoch(client).await?;
+        let Some(balance) = self
+            .compute_exchanged_balance(client, io, vk, masp_epoch)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let (decoded, _undecoded) = self
+            .decode_combine_sum_to_epoch(client, balance, masp_epoch)
+            .await;
+        let holdings: HashMap<Address, token::Change> =
+            decoded.components().map(|(k, v)| (k.clone(), *v)).collect();
+        let projections =
+            rpc::query_masp_reward_projection(client, epochs).await?;
+        let mut res = Vec::new();
+        for projection in projections {
+            let held = match holdings.get(&projection.address) {
+                Some(held) if held.is_positive() => {
+                    token::Amount::from_change(*held)
+                }
+                _ => continue,
+            };
+            let rewards = projection
+                .rewards
+                .iter()
+                .map(|reward| {
+                    held.u128_eucl_div_rem((*reward, projection.precision))
+                        .map(|(reward, _rem)| reward)
+                        .ok_or_else(|| {
+                            Error::Other(format!(
+                                "Overflow in the projected rewards of {}",
+                                projection.address
+                            ))
+                        })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            res.push((projection.address, rewards));
+        }
+        Ok(Some(res))
+    }
+
     /// Try to convert as much of the given asset type-value pair using the
     /// given allowed conversion. usage is incremented by the amount of the
     /// conversion used, the conversions are applied to the given input, and
@@ -1461,6 +1841,88 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
         Ok((val_acc, notes, conversions))
     }
 
+    /// Plan the consolidation of the unspent notes of the given token that are
+    /// held by the viewing key. The notes are grouped into batches of at most
+    /// `max_notes` notes, each returned with its value at the given epoch.
+    /// Transferring the value of a batch back to the key while only allowing
+    /// the notes of the batch to be spent merges them. Batches made of a
+    /// single note are omitted since there is nothing to merge.
+    pub async fn plan_note_consolidation(
+        &mut self,
+        client: &(impl Client + Sync),
+        io: &impl Io,
+        vk: &ViewingKey,
+        token: &Address,
+        target_epoch: MaspEpoch,
+        max_notes: usize,
+    ) -> Result<Vec<NoteConsolidationBatch>, Error> {
+        if max_notes < 2 {
+            return Err(Error::Other(
+                "At least two notes are required to consolidate".to_string(),
+            ));
+        }
+        let mut conversions = BTreeMap::new();
+        let mut batches = Vec::new();
+        let mut batch = NoteConsolidationBatch {
+            notes: BTreeSet::new(),
+            amount: token::Amount::zero(),
+        };
+        let avail_notes = self.pos_map.get(vk).cloned().unwrap_or_default();
+        for note_idx in avail_notes {
+            if self.spents.contains(&note_idx) {
+                continue;
+            }
+            let note = *self.note_map.get(&note_idx).ok_or_else(|| {
+                Error::Other(format!("Unable to get note {note_idx}"))
+            })?;
+            // Only consider the notes of the requested token
+            match self.decode_asset_type(client, note.asset_type).await {
+                Some(asset_data) if asset_data.token == *token => {}
+                _ => continue,
+            }
+            let pre_contr =
+                I128Sum::from_pair(note.asset_type, note.value as i128);
+            let (_contr, normed_contr, proposed_convs) = self
+                .compute_exchanged_amount(
+                    client,
+                    io,
+                    pre_contr,
+                    target_epoch,
+                    conversions,
+                )
+                .await?;
+            conversions = proposed_convs;
+            let contr = self
+                .decode_combine_sum_to_epoch(client, normed_contr, target_epoch)
+                .await
+                .0
+                .get(token);
+            if !contr.is_positive() {
+                continue;
+            }
+            batch.notes.insert(note_idx);
+            batch.amount = batch
+                .amount
+                .checked_add(token::Amount::from_change(contr))
+                .ok_or_else(|| {
+                    Error::Other("Consolidated amount overflow".to_string())
+                })?;
+            if batch.notes.len() == max_notes {
+                batches.push(std::mem::replace(
+                    &mut batch,
+                    NoteConsolidationBatch {
+                        notes: BTreeSet::new(),
+                        amount: token::Amount::zero(),
+                    },
+                ));
+            }
+        }
+        if batch.notes.len() > 1 {
+            batches.push(batch);
+        }
+        Ok(batches)
+    }
+
     /// Convert an amount whose units are AssetTypes to one whose units are
     /// Addresses that they decode to. All asset types not corresponding to
     /// the given epoch are ignored.
@@ -1562,6 +2024,26 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
         data: Vec<MaspTransferData>,
         fee_data: Option<MaspFeeData>,
         update_ctx: bool,
+    ) -> Result<Option<ShieldedTransfer>, TransferErr> {
+        Self::gen_shielded_transfer_with_spent_notes(
+            context,
+            data,
+            fee_data,
+            update_ctx,
+            SpentNotesTracker::new(),
+        )
+        .await
+    }
+
+    /// Make shielded components to embed within a Transfer object like
+    /// [`Self::gen_shielded_transfer`] without spending any of the given
+    /// notes.
+    pub async fn gen_shielded_transfer_with_spent_notes(
+        context: &impl Namada,
+        data: Vec<MaspTransferData>,
+        fee_data: Option<MaspFeeData>,
+        update_ctx: bool,
+        mut notes_tracker: SpentNotesTracker,
     ) -> Result<Option<ShieldedTransfer>, TransferErr> {
         // Try to get a seed from env var, if any.
         #[allow(unused_mut)]
@@ -1634,7 +2116,6 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
         // Determine epoch in which to submit potential shielded transaction
         let epoch = rpc::query_masp_epoch(context.client()).await?;
 
-        let mut notes_tracker = SpentNotesTracker::new();
         {
             // Load the current shielded context given
             // the spending key we possess
@@ -1721,7 +2202,12 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
         let builder_clone = builder.clone().map_builder(WalletMap);
         // Build and return the constructed transaction
         #[cfg(not(feature = "testing"))]
-        let prover = context.shielded().await.utils.local_tx_prover();
+        let prover = context
+            .shielded()
+            .await
+            .utils
+            .tx_prover()
+            .map_err(|err| Error::Other(err.to_string()))?;
         #[cfg(feature = "testing")]
         let prover = testing::MockTxProver(std::sync::Mutex::new(OsRng));
         let (masp_tx, metadata) = builder
@@ -2745,6 +3231,7 @@ pub mod testing {
     use super::*;
     use crate::address::testing::arb_address;
     use crate::address::MASP;
+    use crate::masp::prover::compute_value_balance;
     use crate::masp_primitives::consensus::BranchId;
     use crate::masp_primitives::constants::VALUE_COMMITMENT_RANDOMNESS_GENERATOR;
     use crate::masp_primitives::merkle_tree::FrozenCommitmentTree;
@@ -2753,37 +3240,6 @@ pub mod testing {
     use crate::masp_primitives::transaction::components::transparent::testing::arb_transparent_address;
     use crate::token::testing::arb_denomination;
 
-    /// This function computes `value` in the exponent of the value commitment
-    /// base
-    fn masp_compute_value_balance(
-        asset_type: AssetType,
-        value: i128,
-    ) -> Option<jubjub::ExtendedPoint> {
-        // Compute the absolute value (failing if -i128::MAX is
-        // the value)
-        let abs = match value.checked_abs() {
-            Some(a) => a as u128,
-            None => return None,
-        };
-
-        // Is it negative? We'll have to negate later if so.
-        let is_negative = value.is_negative();
-
-        // Compute it in the exponent
-        let mut abs_bytes = [0u8; 32];
-        abs_bytes[0..16].copy_from_slice(&abs.to_le_bytes());
-        let mut value_balance = asset_type.value_commitment_generator()
-            * jubjub::Fr::from_bytes(&abs_bytes).unwrap();
-
-        // Negate if necessary
-        if is_negative {
-            value_balance = -value_balance;
-        }
-
-        // Convert to unknown order point
-        Some(value_balance.into())
-    }
-
     /// A context object for creating the Sapling components of a Zcash
     /// transaction.
     pub struct SaplingProvingContext {
@@ -2973,7 +3429,7 @@ pub mod testing {
                     .map(|(asset_type, value_balance)| {
                         // Compute value balance for each asset
                         // Error for bad value balances (-INT128_MAX value)
-                        masp_compute_value_balance(*asset_type, *value_balance)
+                        compute_value_balance(*asset_type, *value_balance)
                     })
                     .try_fold(ctx.cv_sum, |tmp, value_balance| {
                         // Compute cv_sum minus sum of all value balances
@@ -3378,11 +3834,9 @@ pub mod fs {
     use std::io::{Read, Write};
     use std::path::PathBuf;
 
-    use namada_token::validation::{
-        get_params_dir, CONVERT_NAME, ENV_VAR_MASP_PARAMS_DIR, OUTPUT_NAME,
-        SPEND_NAME,
+    use super::prover::{
+        load_local_tx_prover, masp_params_present, ENV_VAR_MASP_REMOTE_PROVER,
     };
-
     use super::*;
 
     /// Shielded context file name
@@ -3403,14 +3857,10 @@ pub mod fs {
         /// decryptable by any viewing key in the given set
         pub fn new(context_dir: PathBuf) -> ShieldedContext<Self> {
             // Make sure that MASP parameters are downloaded to enable MASP
-            // transaction building and verification later on
-            let params_dir = get_params_dir();
-            let spend_path = params_dir.join(SPEND_NAME);
-            let convert_path = params_dir.join(CONVERT_NAME);
-            let output_path = params_dir.join(OUTPUT_NAME);
-            if !(spend_path.exists()
-                && convert_path.exists()
-                && output_path.exists())
+            // transaction building and verification later on, unless proofs
+            // are delegated to a remote prover
+            if env::var(ENV_VAR_MASP_REMOTE_PROVER).is_err()
+                && !masp_params_present()
             {
                 #[allow(clippy::print_stdout)]
                 {
@@ -3459,16 +3909,12 @@ pub mod fs {
     #[cfg_attr(not(feature = "async-send"), async_trait::async_trait(?Send))]
     impl ShieldedUtils for FsShieldedUtils {
         fn local_tx_prover(&self) -> LocalTxProver {
-            if let Ok(params_dir) = env::var(ENV_VAR_MASP_PARAMS_DIR) {
-                let params_dir = PathBuf::from(params_dir);
-                let spend_path = params_dir.join(SPEND_NAME);
-                let convert_path = params_dir.join(CONVERT_NAME);
-                let output_path = params_dir.join(OUTPUT_NAME);
-                LocalTxProver::new(&spend_path, &output_path, &convert_path)
-            } else {
-                LocalTxProver::with_default_location()
-                    .expect("unable to load MASP Parameters")
-            }
+            load_local_tx_prover()
+        }
+
+        /// Use the remote prover set in the environment, if any
+        fn tx_prover(&self) -> Result<MaspTxProver, RemoteProverError> {
+            MaspTxProver::from_env()
         }
 
         /// Try to load the last saved shielded context from the given context
@@ -3549,14 +3995,20 @@ pub mod fs {
 #[cfg(test)]
 mod test_shielded_sync {
     use core::str::FromStr;
-    use std::collections::BTreeSet;
+    use std::collections::{BTreeMap, BTreeSet};
 
     use borsh::BorshDeserialize;
+    use masp_primitives::sapling::{Note, Rseed};
     use masp_primitives::transaction::Transaction;
-    use masp_primitives::zip32::ExtendedFullViewingKey;
-    use namada_core::masp::ExtendedViewingKey;
+    use masp_primitives::zip32::{
+        ExtendedFullViewingKey, ExtendedSpendingKey as MaspExtendedSpendingKey,
+    };
+    use namada_core::address::testing::{btc, nam};
+    use namada_core::masp::{AssetData, ExtendedViewingKey, MaspEpoch};
     use namada_core::storage::{BlockHeight, TxIndex};
+    use namada_core::token::{self, Denomination, MaspDigitPos};
     use namada_tx::IndexedTx;
+    use rand_core::OsRng;
     use tempfile::tempdir;
 
     use crate::control_flow::testing_shutdown_signal;
@@ -3566,7 +4018,10 @@ mod test_shielded_sync {
     use crate::masp::test_utils::{
         test_client, TestUnscannedTracker, TestingMaspClient,
     };
-    use crate::masp::utils::{DefaultTracker, ProgressTracker, RetryStrategy};
+    use crate::masp::utils::{
+        DefaultTracker, ProgressTracker, RetryStrategy, ScanConfig,
+    };
+    use crate::masp::{find_valid_diversifier, NoteConsolidationBatch};
 
     // A viewing key derived from A_SPENDING_KEY
     pub const AA_VIEWING_KEY: &str = "zvknam1qqqqqqqqqqqqqq9v0sls5r5de7njx8ehu49pqgmqr9ygelg87l5x8y4s9r0pjlvu6x74w9gjpw856zcu826qesdre628y6tjc26uhgj6d9zqur9l5u3p99d9ggc74ald6s8y3sdtka74qmheyqvdrasqpwyv2fsmxlz57lj4grm2pthzj3sflxc0jx0edrakx3vdcngrfjmru8ywkguru8mxss2uuqxdlglaz6undx5h8w7g70t2es850g48xzdkqay5qs0yw06rtxcpjdve6";
@@ -3706,6 +4161,7 @@ mod test_shielded_sync {
                 None,
                 None,
                 RetryStrategy::Times(1),
+                ScanConfig::default(),
                 &[],
                 &[vk],
             )
@@ -3750,6 +4206,7 @@ mod test_shielded_sync {
                 None,
                 None,
                 RetryStrategy::Times(2),
+                ScanConfig::default(),
                 &[],
                 &[vk],
             )
@@ -3841,6 +4298,163 @@ mod test_shielded_sync {
         assert_eq!(height, BlockHeight(6));
     }
 
+    /// Test that the notes of a viewing key at a past epoch include the
+    /// notes spent after that epoch but not the notes created after it
+    #[test]
+    fn test_notes_at_epoch() {
+        let temp_dir = tempdir().unwrap();
+        let mut shielded_ctx =
+            FsShieldedUtils::new(temp_dir.path().to_path_buf());
+
+        let vk = ExtendedFullViewingKey::from(
+            ExtendedViewingKey::from_str(AA_VIEWING_KEY).expect("Test failed"),
+        )
+        .fvk
+        .vk;
+
+        // note 0 is created at height 1 and spent by the tx at height 3,
+        // which creates note 1
+        for (height, first_note) in [(1, 0), (3, 1)] {
+            shielded_ctx.tx_note_map.insert(
+                IndexedTx {
+                    height: height.into(),
+                    index: TxIndex(0),
+                },
+                first_note,
+            );
+        }
+        shielded_ctx.pos_map.insert(vk, BTreeSet::from([0, 1]));
+        shielded_ctx.spents.insert(0);
+        shielded_ctx.spent_height_map.insert(0, BlockHeight(3));
+        let epochs = BTreeMap::from([
+            (BlockHeight(1), MaspEpoch::new(1)),
+            (BlockHeight(3), MaspEpoch::new(3)),
+        ]);
+
+        for (epoch, expected) in [
+            (0, BTreeSet::new()),
+            (1, BTreeSet::from([0])),
+            (2, BTreeSet::from([0])),
+            (3, BTreeSet::from([1])),
+        ] {
+            let notes = shielded_ctx
+                .notes_at_epoch(&vk, MaspEpoch::new(epoch), &epochs)
+                .unwrap();
+            assert_eq!(notes, Some(expected), "epoch {epoch}");
+        }
+
+        // the epoch of the tx that spent a note must be known
+        let epochs = BTreeMap::from([(BlockHeight(1), MaspEpoch::new(1))]);
+        assert!(shielded_ctx
+            .notes_at_epoch(&vk, MaspEpoch::new(1), &epochs)
+            .is_err());
+    }
+
+    /// Test that only the unspent notes of the requested token are planned to
+    /// be consolidated, in batches of at most the given number of notes
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_plan_note_consolidation() {
+        let temp_dir = tempdir().unwrap();
+        let mut shielded_ctx =
+            FsShieldedUtils::new(temp_dir.path().to_path_buf());
+        let (client, _masp_tx_sender) = test_client(2.into());
+        let io = StdIo;
+        let vk = ExtendedFullViewingKey::from(
+            ExtendedViewingKey::from_str(AA_VIEWING_KEY).expect("Test failed"),
+        )
+        .fvk
+        .vk;
+
+        // undated asset types do not need any conversion
+        let mut asset_types = vec![];
+        for token in [nam(), btc()] {
+            let asset_data = AssetData {
+                token,
+                denom: Denomination(0),
+                position: MaspDigitPos::Zero,
+                epoch: None,
+            };
+            let asset_type = asset_data.encode().expect("Test failed");
+            shielded_ctx.asset_types.insert(asset_type, asset_data);
+            asset_types.push(asset_type);
+        }
+        let (nam_type, btc_type) = (asset_types[0], asset_types[1]);
+        let (_div, g_d) = find_valid_diversifier(&mut OsRng);
+        // note 2 holds another token and note 4 is spent
+        for (note_idx, asset_type, value) in [
+            (0, nam_type, 1),
+            (1, nam_type, 2),
+            (2, btc_type, 4),
+            (3, nam_type, 8),
+            (4, nam_type, 16),
+            (5, nam_type, 32),
+        ] {
+            shielded_ctx.note_map.insert(
+                note_idx,
+                Note {
+                    asset_type,
+                    value,
+                    g_d,
+                    pk_d: g_d,
+                    rseed: Rseed::AfterZip212([0; 32]),
+                },
+            );
+        }
+        shielded_ctx.pos_map.insert(vk, (0..6).collect());
+        shielded_ctx.spents.insert(4);
+
+        let batch = |notes: &[usize], amount: u64| NoteConsolidationBatch {
+            notes: notes.iter().copied().collect(),
+            amount: token::Amount::from_u64(amount),
+        };
+        for (max_notes, expected) in [
+            (2, vec![batch(&[0, 1], 3), batch(&[3, 5], 40)]),
+            // the last batch is made of a single note
+            (3, vec![batch(&[0, 1, 3], 11)]),
+            (4, vec![batch(&[0, 1, 3, 5], 43)]),
+        ] {
+            let batches = shielded_ctx
+                .plan_note_consolidation(
+                    &client,
+                    &io,
+                    &vk,
+                    &nam(),
+                    MaspEpoch::new(1),
+                    max_notes,
+                )
+                .await
+                .unwrap();
+            assert_eq!(batches, expected, "max notes {max_notes}");
+        }
+
+        // a single note of the token cannot be consolidated
+        let batches = shielded_ctx
+            .plan_note_consolidation(
+                &client,
+                &io,
+                &vk,
+                &btc(),
+                MaspEpoch::new(1),
+                2,
+            )
+            .await
+            .unwrap();
+        assert!(batches.is_empty());
+
+        // at least two notes are needed to consolidate them
+        assert!(shielded_ctx
+            .plan_note_consolidation(
+                &client,
+                &io,
+                &vk,
+                &nam(),
+                MaspEpoch::new(1),
+                1,
+            )
+            .await
+            .is_err());
+    }
+
     /// Test that the progress tracker correctly keeps
     /// track of how many blocks there are left to fetch
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
@@ -3867,6 +4481,7 @@ mod test_shielded_sync {
                 None,
                 None,
                 RetryStrategy::Times(1),
+                ScanConfig::default(),
                 &[],
                 &[vk],
             )
@@ -3892,6 +4507,7 @@ mod test_shielded_sync {
                 None,
                 None,
                 RetryStrategy::Times(1),
+                ScanConfig::default(),
                 &[],
                 &[vk],
             )
@@ -3908,6 +4524,7 @@ mod test_shielded_sync {
                 None,
                 None,
                 RetryStrategy::Times(1),
+                ScanConfig::default(),
                 &[],
                 &[vk],
             )
@@ -3926,6 +4543,7 @@ mod test_shielded_sync {
                 None,
                 None,
                 RetryStrategy::Times(1),
+                ScanConfig::default(),
                 &[],
                 &[vk],
             )
@@ -3962,6 +4580,7 @@ mod test_shielded_sync {
                 None,
                 None,
                 RetryStrategy::Times(1),
+                ScanConfig::default(),
                 &[],
                 &[vk],
             )
@@ -4015,6 +4634,7 @@ mod test_shielded_sync {
                 None,
                 None,
                 RetryStrategy::Times(2),
+                ScanConfig::default(),
                 &[],
                 &[vk],
             )
@@ -4037,6 +4657,68 @@ mod test_shielded_sync {
         assert_eq!(keys, expected);
     }
 
+    /// Test that trial decryption partitioned across several workers
+    /// scans all txs for every key, and that the checkpointed context
+    /// can be reloaded
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_parallel_scan_checkpoints() {
+        let (client, masp_tx_sender) = test_client(2.into());
+        let temp_dir = tempdir().unwrap();
+        let mut shielded_ctx =
+            FsShieldedUtils::new(temp_dir.path().to_path_buf());
+        let io = StdIo;
+        let progress = DefaultTracker::new(&io);
+        let vk = ExtendedFullViewingKey::from(
+            ExtendedViewingKey::from_str(AA_VIEWING_KEY).expect("Test failed"),
+        )
+        .fvk
+        .vk;
+        let sks: Vec<_> = (0u8..3)
+            .map(|seed| MaspExtendedSpendingKey::master(&[seed; 32]))
+            .collect();
+
+        let masp_tx = arbitrary_masp_tx();
+        for height in 1u64..=2 {
+            masp_tx_sender
+                .send(Some((
+                    IndexedTx {
+                        height: height.into(),
+                        index: Default::default(),
+                    },
+                    vec![masp_tx.clone()],
+                )))
+                .expect("Test failed");
+        }
+        masp_tx_sender.send(None).expect("Test failed");
+
+        shielded_ctx
+            .fetch(
+                TestingMaspClient::new(&client),
+                &progress,
+                None,
+                None,
+                RetryStrategy::Times(1),
+                ScanConfig {
+                    workers: 2,
+                    checkpoint_interval: 1,
+                },
+                &sks,
+                &[vk],
+            )
+            .await
+            .expect("Test failed");
+
+        let expected = Some(IndexedTx {
+            height: 2.into(),
+            index: Default::default(),
+        });
+        let mut reloaded = FsShieldedUtils::new(temp_dir.path().to_path_buf());
+        reloaded.load_confirmed().await.expect("Test failed");
+        assert_eq!(reloaded.vk_heights.len(), sks.len() + 1);
+        assert!(reloaded.vk_heights.values().all(|h| *h == expected));
+        assert!(reloaded.unscanned.is_empty());
+    }
+
     /// Test that if fetching gets interrupted,
     /// we persist the fetched notes in a cache
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
@@ -4074,6 +4756,7 @@ mod test_shielded_sync {
                 None,
                 None,
                 RetryStrategy::Forever,
+                ScanConfig::default(),
                 &[],
                 &[vk],
                 shutdown_signal,
@@ -0,0 +1,1078 @@
+//! Pluggable generation of MASP transaction proofs.
+//!
+//! Proofs can either be generated locally, which requires the Sapling proving
+//! parameters, or be delegated to a self-hosted prover service (see
+//! [`serve`]). When proving remotely, only the witness of each proof is sent
+//! to the service. In particular, spend proofs only require the
+//! [`ProofGenerationKey`] of the spent note, while the spending key itself
+//! never leaves the client. The value commitments, the re-randomized keys and
+//! the binding signature are always computed client-side.
+//!
+//! The witnesses are still sensitive, so the messages exchanged with the
+//! service are encrypted and authenticated with a [`ProverKey`] derived from
+//! a secret token shared by the service and its clients.
+
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+use std::ops::{AddAssign, Neg};
+use std::sync::mpsc::{sync_channel, TrySendError};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use borsh_ext::BorshSerializeExt;
+use masp_primitives::asset_type::AssetType;
+use masp_primitives::constants::{
+    SPENDING_KEY_GENERATOR, VALUE_COMMITMENT_RANDOMNESS_GENERATOR,
+};
+use masp_primitives::convert::AllowedConversion;
+use masp_primitives::group::GroupEncoding;
+use masp_primitives::merkle_tree::MerklePath;
+use masp_primitives::sapling::prover::TxProver;
+use masp_primitives::sapling::redjubjub::{PrivateKey, PublicKey, Signature};
+use masp_primitives::sapling::{
+    Diversifier, Node, PaymentAddress, ProofGenerationKey, Rseed,
+};
+use masp_primitives::transaction::components::{I128Sum, GROTH_PROOF_SIZE};
+use masp_proofs::bls12_381;
+use masp_proofs::prover::LocalTxProver;
+use namada_token::validation::{
+    get_params_dir, CONVERT_NAME, ENV_VAR_MASP_PARAMS_DIR, OUTPUT_NAME,
+    SPEND_NAME,
+};
+use orion::{aead, kdf};
+use rand_core::OsRng;
+use thiserror::Error;
+
+/// Env var pointing to the address of a remote MASP prover service. When set,
+/// the proofs of MASP transactions are generated by the given service instead
+/// of locally.
+pub const ENV_VAR_MASP_REMOTE_PROVER: &str = "NAMADA_MASP_REMOTE_PROVER";
+
+/// Env var holding the secret token shared by a remote MASP prover service
+/// and its clients
+pub const ENV_VAR_MASP_REMOTE_PROVER_TOKEN: &str =
+    "NAMADA_MASP_REMOTE_PROVER_TOKEN";
+
+/// Default timeout of the requests sent to a remote prover
+pub const DEFAULT_REMOTE_PROVER_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Maximum size in bytes of an encrypted message exchanged with a remote
+/// prover. Larger messages are rejected before being read.
+pub const MAX_PROVER_MESSAGE_SIZE: u32 = 64 * 1024;
+
+/// Salt of the derivation of a [`ProverKey`] from a secret token
+const PROVER_KEY_SALT: &[u8] = b"namada-masp-remote-prover";
+
+/// Errors from communicating with a remote prover
+#[derive(Error, Debug)]
+pub enum RemoteProverError {
+    /// Failed to reach the prover service
+    #[error("Failed to communicate with the remote prover: {0}")]
+    Io(#[from] std::io::Error),
+    /// The prover service could not generate the requested proof
+    #[error("The remote prover rejected the request: {0}")]
+    Rejected(String),
+    /// The prover service returned a malformed proof
+    #[error("The remote prover returned a malformed proof")]
+    MalformedProof,
+    /// A message could not be decrypted with the shared key
+    #[error(
+        "Failed to authenticate a message exchanged with the remote prover. \
+         Is the token correct?"
+    )]
+    Unauthenticated,
+    /// A message exceeded [`MAX_PROVER_MESSAGE_SIZE`]
+    #[error(
+        "A message of {0} bytes exchanged with the remote prover exceeds the \
+         maximum size of {MAX_PROVER_MESSAGE_SIZE} bytes"
+    )]
+    MessageTooLarge(usize),
+    /// The secret token shared with the prover service is invalid
+    #[error(
+        "{ENV_VAR_MASP_REMOTE_PROVER_TOKEN} must be set to a non-empty token \
+         to use a remote MASP prover"
+    )]
+    InvalidToken,
+}
+
+/// The key encrypting and authenticating the messages exchanged by a remote
+/// prover service and its clients, derived from a secret token that they
+/// share
+#[derive(Debug, Clone)]
+pub struct ProverKey(Arc<aead::SecretKey>);
+
+impl ProverKey {
+    /// Derive the key from the given secret token
+    pub fn from_token(token: &str) -> Result<Self, RemoteProverError> {
+        let salt = kdf::Salt::from_slice(PROVER_KEY_SALT)
+            .expect("The prover key salt must be valid");
+        kdf::Password::from_slice(token.as_bytes())
+            .and_then(|password| {
+                kdf::derive_key(&password, &salt, 3, 1 << 16, 32)
+            })
+            .map(|key| Self(Arc::new(key)))
+            .map_err(|_| RemoteProverError::InvalidToken)
+    }
+
+    /// Derive the key from the token in [`ENV_VAR_MASP_REMOTE_PROVER_TOKEN`]
+    pub fn from_env() -> Result<Self, RemoteProverError> {
+        let token = std::env::var(ENV_VAR_MASP_REMOTE_PROVER_TOKEN)
+            .map_err(|_| RemoteProverError::InvalidToken)?;
+        Self::from_token(&token)
+    }
+}
+
+/// Encrypt the given message and write it to the stream, prefixed with the
+/// length of the ciphertext
+fn write_message(
+    stream: &mut impl Write,
+    key: &ProverKey,
+    message: &impl BorshSerialize,
+) -> Result<(), RemoteProverError> {
+    let sealed = aead::seal(&key.0, &message.serialize_to_vec())
+        .map_err(|_| RemoteProverError::Unauthenticated)?;
+    let len = u32::try_from(sealed.len())
+        .ok()
+        .filter(|len| *len <= MAX_PROVER_MESSAGE_SIZE)
+        .ok_or(RemoteProverError::MessageTooLarge(sealed.len()))?;
+    stream.write_all(&len.to_be_bytes())?;
+    stream.write_all(&sealed)?;
+    stream.flush()?;
+    Ok(())
+}
+
+/// Read a length-prefixed message from the stream and decrypt it. The
+/// message is rejected without being read if it exceeds
+/// [`MAX_PROVER_MESSAGE_SIZE`].
+fn read_message<T: BorshDeserialize>(
+    stream: &mut impl Read,
+    key: &ProverKey,
+) -> Result<T, RemoteProverError> {
+    let mut len = [0; 4];
+    stream.read_exact(&mut len)?;
+    let len = u32::from_be_bytes(len);
+    if len > MAX_PROVER_MESSAGE_SIZE {
+        return Err(RemoteProverError::MessageTooLarge(
+            usize::try_from(len).unwrap_or(usize::MAX),
+        ));
+    }
+    let mut sealed = vec![0; usize::try_from(len).unwrap_or_default()];
+    stream.read_exact(&mut sealed)?;
+    let message = aead::open(&key.0, &sealed)
+        .map_err(|_| RemoteProverError::Unauthenticated)?;
+    Ok(T::try_from_slice(&message)?)
+}
+
+/// Load the local prover from the Sapling parameters found in the MASP
+/// parameters directory
+pub fn load_local_tx_prover() -> LocalTxProver {
+    if let Ok(params_dir) = std::env::var(ENV_VAR_MASP_PARAMS_DIR) {
+        let params_dir = std::path::PathBuf::from(params_dir);
+        let spend_path = params_dir.join(SPEND_NAME);
+        let convert_path = params_dir.join(CONVERT_NAME);
+        let output_path = params_dir.join(OUTPUT_NAME);
+        LocalTxProver::new(&spend_path, &output_path, &convert_path)
+    } else {
+        LocalTxProver::with_default_location()
+            .expect("unable to load MASP Parameters")
+    }
+}
+
+/// Check if the Sapling proving parameters are present in the MASP parameters
+/// directory
+pub fn masp_params_present() -> bool {
+    let params_dir = get_params_dir();
+    [SPEND_NAME, CONVERT_NAME, OUTPUT_NAME]
+        .iter()
+        .all(|name| params_dir.join(name).exists())
+}
+
+/// The witness of a proof, as sent to a remote prover service. Field
+/// elements and curve points are in their canonical byte encoding.
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub enum ProofRequest {
+    /// Witness of a spend proof
+    Spend {
+        /// The authorizing key of the proof generation key
+        ak: [u8; 32],
+        /// The nullifier deriving key of the proof generation key
+        nsk: [u8; 32],
+        /// The diversifier of the note's payment address
+        diversifier: [u8; 11],
+        /// The note's commitment randomness
+        rseed: RseedBytes,
+        /// The spend authorization randomizer
+        ar: [u8; 32],
+        /// The note's asset type
+        asset_type: AssetType,
+        /// The note's value
+        value: u64,
+        /// The commitment tree root
+        anchor: [u8; 32],
+        /// The note's merkle path
+        merkle_path: MerklePath<Node>,
+        /// The value commitment randomness
+        rcv: [u8; 32],
+    },
+    /// Witness of an output proof
+    Output {
+        /// The ephemeral secret key
+        esk: [u8; 32],
+        /// The recipient payment address
+        payment_address: Vec<u8>,
+        /// The note's commitment randomness
+        rcm: [u8; 32],
+        /// The note's asset type
+        asset_type: AssetType,
+        /// The note's value
+        value: u64,
+        /// The value commitment randomness
+        rcv: [u8; 32],
+    },
+    /// Witness of a convert proof
+    Convert {
+        /// The applied conversion
+        allowed_conversion: AllowedConversion,
+        /// The number of times the conversion is applied
+        value: u64,
+        /// The conversion tree root
+        anchor: [u8; 32],
+        /// The conversion's merkle path
+        merkle_path: MerklePath<Node>,
+        /// The value commitment randomness
+        rcv: [u8; 32],
+    },
+}
+
+/// The encoding of a [`Rseed`]
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub enum RseedBytes {
+    /// See [`Rseed::BeforeZip212`]
+    BeforeZip212([u8; 32]),
+    /// See [`Rseed::AfterZip212`]
+    AfterZip212([u8; 32]),
+}
+
+/// The response of a remote prover service: either the proof bytes or the
+/// reason why the proof could not be generated.
+pub type ProofResponse = Result<Vec<u8>, String>;
+
+impl From<&Rseed> for RseedBytes {
+    fn from(rseed: &Rseed) -> Self {
+        match rseed {
+            Rseed::BeforeZip212(rcm) => Self::BeforeZip212(rcm.to_bytes()),
+            Rseed::AfterZip212(bytes) => Self::AfterZip212(*bytes),
+        }
+    }
+}
+
+impl TryFrom<RseedBytes> for Rseed {
+    type Error = String;
+
+    fn try_from(rseed: RseedBytes) -> Result<Self, Self::Error> {
+        match rseed {
+            RseedBytes::BeforeZip212(rcm) => {
+                decode_scalar(&rcm, "rcm").map(Self::BeforeZip212)
+            }
+            RseedBytes::AfterZip212(bytes) => Ok(Self::AfterZip212(bytes)),
+        }
+    }
+}
+
+fn decode_scalar(bytes: &[u8; 32], what: &str) -> Result<jubjub::Fr, String> {
+    Option::from(jubjub::Fr::from_bytes(bytes))
+        .ok_or_else(|| format!("Invalid encoding of {what}"))
+}
+
+fn decode_base(
+    bytes: &[u8; 32],
+    what: &str,
+) -> Result<bls12_381::Scalar, String> {
+    Option::from(bls12_381::Scalar::from_bytes(bytes))
+        .ok_or_else(|| format!("Invalid encoding of {what}"))
+}
+
+impl ProofRequest {
+    /// Generate the requested proof with the given local prover
+    pub fn prove(
+        self,
+        prover: &LocalTxProver,
+    ) -> Result<[u8; GROTH_PROOF_SIZE], String> {
+        let mut ctx = prover.new_sapling_proving_context();
+        match self {
+            Self::Spend {
+                ak,
+                nsk,
+                diversifier,
+                rseed,
+                ar,
+                asset_type,
+                value,
+                anchor,
+                merkle_path,
+                rcv,
+            } => {
+                let ak = Option::from(jubjub::SubgroupPoint::from_bytes(&ak))
+                    .ok_or_else(|| "Invalid encoding of ak".to_string())?;
+                let proof_generation_key = ProofGenerationKey {
+                    ak,
+                    nsk: decode_scalar(&nsk, "nsk")?,
+                };
+                prover
+                    .spend_proof(
+                        &mut ctx,
+                        proof_generation_key,
+                        Diversifier(diversifier),
+                        rseed.try_into()?,
+                        decode_scalar(&ar, "ar")?,
+                        asset_type,
+                        value,
+                        decode_base(&anchor, "anchor")?,
+                        merkle_path,
+                        decode_scalar(&rcv, "rcv")?,
+                    )
+                    .map(|(proof, _cv, _rk)| proof)
+                    .map_err(|()| "Failed to generate spend proof".to_string())
+            }
+            Self::Output {
+                esk,
+                payment_address,
+                rcm,
+                asset_type,
+                value,
+                rcv,
+            } => {
+                let payment_address = <[u8; 43]>::try_from(payment_address)
+                    .ok()
+                    .and_then(|bytes| PaymentAddress::from_bytes(&bytes))
+                    .ok_or_else(|| {
+                        "Invalid encoding of payment address".to_string()
+                    })?;
+                let (proof, _cv) = prover.output_proof(
+                    &mut ctx,
+                    decode_scalar(&esk, "esk")?,
+                    payment_address,
+                    decode_scalar(&rcm, "rcm")?,
+                    asset_type,
+                    value,
+                    decode_scalar(&rcv, "rcv")?,
+                );
+                Ok(proof)
+            }
+            Self::Convert {
+                allowed_conversion,
+                value,
+                anchor,
+                merkle_path,
+                rcv,
+            } => prover
+                .convert_proof(
+                    &mut ctx,
+                    allowed_conversion,
+                    value,
+                    decode_base(&anchor, "anchor")?,
+                    merkle_path,
+                    decode_scalar(&rcv, "rcv")?,
+                )
+                .map(|(proof, _cv)| proof)
+                .map_err(|()| "Failed to generate convert proof".to_string()),
+        }
+    }
+}
+
+/// Configures the concurrency and the timeouts of a prover service
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ProverServiceConfig {
+    /// Number of worker threads generating proofs
+    pub workers: usize,
+    /// Number of accepted connections that may wait for a worker. Further
+    /// connections are dropped until the workers catch up.
+    pub queue_size: usize,
+    /// Read and write timeout of the connections
+    pub timeout: Duration,
+}
+
+impl Default for ProverServiceConfig {
+    fn default() -> Self {
+        let workers = std::thread::available_parallelism()
+            .map(usize::from)
+            .unwrap_or(1);
+        Self {
+            workers,
+            queue_size: workers.saturating_mul(4),
+            timeout: DEFAULT_REMOTE_PROVER_TIMEOUT,
+        }
+    }
+}
+
+/// Serve proof requests coming from the given listener with the given local
+/// prover. Every connection carries a single encrypted [`ProofRequest`],
+/// which is answered with an encrypted [`ProofResponse`]. Requests that
+/// cannot be decrypted with the given key are dropped.
+pub fn serve(
+    listener: TcpListener,
+    prover: &LocalTxProver,
+    key: &ProverKey,
+    config: &ProverServiceConfig,
+) -> std::io::Result<()> {
+    let (queue_send, queue_recv) = sync_channel::<TcpStream>(config.queue_size);
+    let queue_recv = Mutex::new(queue_recv);
+    let queue_recv = &queue_recv;
+    std::thread::scope(|scope| {
+        // The workers stop once the queue's sender is dropped
+        let queue_send = queue_send;
+        for _ in 0..config.workers.max(1) {
+            scope.spawn(move || {
+                loop {
+                    // The lock is only held while waiting for a connection
+                    let next = queue_recv
+                        .lock()
+                        .expect("The prover queue lock must not be poisoned")
+                        .recv();
+                    let Ok(stream) = next else {
+                        break;
+                    };
+                    let peer = stream.peer_addr().ok();
+                    if let Err(err) =
+                        handle_proof_request(stream, prover, key, config)
+                    {
+                        tracing::warn!(
+                            ?peer,
+                            "Failed to handle MASP proof request: {err}"
+                        );
+                    }
+                }
+            });
+        }
+        for stream in listener.incoming() {
+            match queue_send.try_send(stream?) {
+                Ok(()) => {}
+                Err(TrySendError::Full(stream)) => {
+                    tracing::warn!(
+                        peer = ?stream.peer_addr().ok(),
+                        "Dropping MASP proof request, all the workers are busy"
+                    );
+                }
+                Err(TrySendError::Disconnected(_)) => break,
+            }
+        }
+        Ok(())
+    })
+}
+
+fn handle_proof_request(
+    mut stream: TcpStream,
+    prover: &LocalTxProver,
+    key: &ProverKey,
+    config: &ProverServiceConfig,
+) -> Result<(), RemoteProverError> {
+    stream.set_read_timeout(Some(config.timeout))?;
+    stream.set_write_timeout(Some(config.timeout))?;
+    let request: ProofRequest = read_message(&mut stream, key)?;
+    let response: ProofResponse =
+        request.prove(prover).map(|proof| proof.to_vec());
+    write_message(&mut stream, key, &response)
+}
+
+/// A MASP prover delegating the generation of zero-knowledge proofs to a
+/// remote prover service.
+#[derive(Debug, Clone)]
+pub struct RemoteTxProver {
+    address: String,
+    key: ProverKey,
+    timeout: Duration,
+}
+
+impl RemoteTxProver {
+    /// Create a prover for the service listening at the given address,
+    /// sharing the given key
+    pub fn new(address: impl Into<String>, key: ProverKey) -> Self {
+        Self {
+            address: address.into(),
+            key,
+            timeout: DEFAULT_REMOTE_PROVER_TIMEOUT,
+        }
+    }
+
+    /// Set the timeout of the requests sent to the service
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Request a proof from the remote service
+    pub fn request(
+        &self,
+        request: &ProofRequest,
+    ) -> Result<[u8; GROTH_PROOF_SIZE], RemoteProverError> {
+        let addr = self.address.to_socket_addrs()?.next().ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("Could not resolve {}", self.address),
+            )
+        })?;
+        let mut stream = TcpStream::connect_timeout(&addr, self.timeout)?;
+        stream.set_read_timeout(Some(self.timeout))?;
+        stream.set_write_timeout(Some(self.timeout))?;
+        write_message(&mut stream, &self.key, request)?;
+        let response: ProofResponse = read_message(&mut stream, &self.key)?;
+        let proof = response.map_err(RemoteProverError::Rejected)?;
+        proof
+            .try_into()
+            .map_err(|_| RemoteProverError::MalformedProof)
+    }
+}
+
+/// The proving context of a [`RemoteTxProver`]. It accumulates the value
+/// commitments and their randomness to produce the binding signature.
+pub struct RemoteProvingContext {
+    bsk: jubjub::Fr,
+    // (sum of the Spend value commitments) - (sum of the Output value
+    // commitments)
+    cv_sum: jubjub::ExtendedPoint,
+    // The first error encountered while generating an infallible proof, which
+    // is then reported when producing the binding signature
+    error: Option<RemoteProverError>,
+}
+
+impl RemoteProvingContext {
+    /// Get the first error encountered while proving with this context, if
+    /// any
+    pub fn error(&self) -> Option<&RemoteProverError> {
+        self.error.as_ref()
+    }
+}
+
+/// This function computes `value` in the exponent of the value commitment
+/// base
+pub(crate) fn compute_value_balance(
+    asset_type: AssetType,
+    value: i128,
+) -> Option<jubjub::ExtendedPoint> {
+    // Compute the absolute value (failing if -i128::MAX is
+    // the value)
+    let abs = match value.checked_abs() {
+        Some(a) => a as u128,
+        None => return None,
+    };
+
+    // Is it negative? We'll have to negate later if so.
+    let is_negative = value.is_negative();
+
+    // Compute it in the exponent
+    let mut abs_bytes = [0u8; 32];
+    abs_bytes[0..16].copy_from_slice(&abs.to_le_bytes());
+    let mut value_balance = asset_type.value_commitment_generator()
+        * jubjub::Fr::from_bytes(&abs_bytes).unwrap();
+
+    // Negate if necessary
+    if is_negative {
+        value_balance = -value_balance;
+    }
+
+    // Convert to unknown order point
+    Some(value_balance.into())
+}
+
+impl TxProver for RemoteTxProver {
+    type SaplingProvingContext = RemoteProvingContext;
+
+    fn new_sapling_proving_context(&self) -> Self::SaplingProvingContext {
+        RemoteProvingContext {
+            bsk: jubjub::Fr::zero(),
+            cv_sum: jubjub::ExtendedPoint::identity(),
+            error: None,
+        }
+    }
+
+    fn spend_proof(
+        &self,
+        ctx: &mut Self::SaplingProvingContext,
+        proof_generation_key: ProofGenerationKey,
+        diversifier: Diversifier,
+        rseed: Rseed,
+        ar: jubjub::Fr,
+        asset_type: AssetType,
+        value: u64,
+        anchor: bls12_381::Scalar,
+        merkle_path: MerklePath<Node>,
+        rcv: jubjub::Fr,
+    ) -> Result<([u8; GROTH_PROOF_SIZE], jubjub::ExtendedPoint, PublicKey), ()>
+    {
+        let request = ProofRequest::Spend {
+            ak: proof_generation_key.ak.to_bytes(),
+            nsk: proof_generation_key.nsk.to_bytes(),
+            diversifier: diversifier.0,
+            rseed: (&rseed).into(),
+            ar: ar.to_bytes(),
+            asset_type,
+            value,
+            anchor: anchor.to_bytes(),
+            merkle_path,
+            rcv: rcv.to_bytes(),
+        };
+        let zkproof = self.request(&request).map_err(|err| {
+            ctx.error.get_or_insert(err);
+        })?;
+
+        // Accumulate the value commitment randomness in the context
+        ctx.bsk.add_assign(&rcv);
+
+        // Compute value commitment
+        let value_commitment: jubjub::ExtendedPoint =
+            asset_type.value_commitment(value, rcv).commitment().into();
+
+        // Accumulate the value commitment in the context
+        ctx.cv_sum += value_commitment;
+
+        // This is the result of the re-randomization, we compute it for the
+        // caller
+        let rk = PublicKey(proof_generation_key.ak.into())
+            .randomize(ar, SPENDING_KEY_GENERATOR);
+
+        Ok((zkproof, value_commitment, rk))
+    }
+
+    fn output_proof(
+        &self,
+        ctx: &mut Self::SaplingProvingContext,
+        esk: jubjub::Fr,
+        payment_address: PaymentAddress,
+        rcm: jubjub::Fr,
+        asset_type: AssetType,
+        value: u64,
+        rcv: jubjub::Fr,
+    ) -> ([u8; GROTH_PROOF_SIZE], jubjub::ExtendedPoint) {
+        let request = ProofRequest::Output {
+            esk: esk.to_bytes(),
+            payment_address: payment_address.to_bytes().to_vec(),
+            rcm: rcm.to_bytes(),
+            asset_type,
+            value,
+            rcv: rcv.to_bytes(),
+        };
+        // Output proofs cannot fail, so errors are recorded in the context
+        // and surface when producing the binding signature
+        let zkproof = self.request(&request).unwrap_or_else(|err| {
+            ctx.error.get_or_insert(err);
+            [0; GROTH_PROOF_SIZE]
+        });
+
+        // Accumulate the value commitment randomness in the context.
+        // Outputs subtract from the total.
+        ctx.bsk.add_assign(&rcv.neg());
+
+        // Compute the value commitment
+        let value_commitment: jubjub::ExtendedPoint =
+            asset_type.value_commitment(value, rcv).commitment().into();
+
+        // Accumulate the value commitment in the context. Outputs subtract
+        // from the total.
+        ctx.cv_sum -= value_commitment;
+
+        (zkproof, value_commitment)
+    }
+
+    fn convert_proof(
+        &self,
+        ctx: &mut Self::SaplingProvingContext,
+        allowed_conversion: AllowedConversion,
+        value: u64,
+        anchor: bls12_381::Scalar,
+        merkle_path: MerklePath<Node>,
+        rcv: jubjub::Fr,
+    ) -> Result<([u8; GROTH_PROOF_SIZE], jubjub::ExtendedPoint), ()> {
+        let value_commitment: jubjub::ExtendedPoint = allowed_conversion
+            .value_commitment(value, rcv)
+            .commitment()
+            .into();
+        let request = ProofRequest::Convert {
+            allowed_conversion,
+            value,
+            anchor: anchor.to_bytes(),
+            merkle_path,
+            rcv: rcv.to_bytes(),
+        };
+        let zkproof = self.request(&request).map_err(|err| {
+            ctx.error.get_or_insert(err);
+        })?;
+
+        // Accumulate the value commitment randomness in the context
+        ctx.bsk.add_assign(&rcv);
+
+        // Accumulate the value commitment in the context
+        ctx.cv_sum += value_commitment;
+
+        Ok((zkproof, value_commitment))
+    }
+
+    fn binding_sig(
+        &self,
+        ctx: &mut Self::SaplingProvingContext,
+        assets_and_values: &I128Sum,
+        sighash: &[u8; 32],
+    ) -> Result<Signature, ()> {
+        if let Some(err) = ctx.error() {
+            tracing::error!("Remote MASP proof generation failed: {err}");
+            return Err(());
+        }
+
+        // Grab the current `bsk` from the context
+        let bsk = PrivateKey(ctx.bsk);
+
+        // Grab the `bvk` using DerivePublic.
+        let bvk = PublicKey::from_private(
+            &bsk,
+            VALUE_COMMITMENT_RANDOMNESS_GENERATOR,
+        );
+
+        // In order to check internal consistency, let's use the accumulated
+        // value commitments (as the verifier would) and apply value_balance
+        // to compare against our derived bvk.
+        {
+            let final_bvk = assets_and_values
+                .components()
+                .map(|(asset_type, value_balance)| {
+                    // Compute value balance for each asset
+                    // Error for bad value balances (-INT128_MAX value)
+                    compute_value_balance(*asset_type, *value_balance)
+                })
+                .try_fold(ctx.cv_sum, |tmp, value_balance| {
+                    // Compute cv_sum minus sum of all value balances
+                    Result::<_, ()>::Ok(tmp - value_balance.ok_or(())?)
+                })?;
+
+            // The result should be the same, unless the provided
+            // valueBalance is wrong.
+            if bvk.0 != final_bvk {
+                return Err(());
+            }
+        }
+
+        // Construct signature message
+        let mut data_to_be_signed = [0u8; 64];
+        data_to_be_signed[0..32].copy_from_slice(&bvk.0.to_bytes());
+        data_to_be_signed[32..64].copy_from_slice(&sighash[..]);
+
+        // Sign
+        Ok(bsk.sign(
+            &data_to_be_signed,
+            &mut OsRng,
+            VALUE_COMMITMENT_RANDOMNESS_GENERATOR,
+        ))
+    }
+}
+
+/// The prover used to build MASP transactions
+#[derive(Clone)]
+pub enum MaspTxProver {
+    /// Generate proofs locally with the Sapling parameters
+    Local(Arc<LocalTxProver>),
+    /// Delegate the generation of proofs to a remote service
+    Remote(RemoteTxProver),
+}
+
+/// The proving context of a [`MaspTxProver`]
+pub enum MaspProvingContext {
+    /// Context of a local prover
+    Local(<LocalTxProver as TxProver>::SaplingProvingContext),
+    /// Context of a remote prover
+    Remote(RemoteProvingContext),
+}
+
+impl MaspTxProver {
+    /// Get the prover configured in the environment. Proofs are generated by
+    /// the remote service at [`ENV_VAR_MASP_REMOTE_PROVER`] if set, with the
+    /// token in [`ENV_VAR_MASP_REMOTE_PROVER_TOKEN`], or locally otherwise.
+    /// Fails if the remote prover is set without a valid token.
+    pub fn from_env() -> Result<Self, RemoteProverError> {
+        match std::env::var(ENV_VAR_MASP_REMOTE_PROVER) {
+            Ok(address) => {
+                let key = ProverKey::from_env()?;
+                Ok(Self::Remote(RemoteTxProver::new(address, key)))
+            }
+            Err(_) => Ok(Self::Local(Arc::new(load_local_tx_prover()))),
+        }
+    }
+}
+
+impl From<LocalTxProver> for MaspTxProver {
+    fn from(prover: LocalTxProver) -> Self {
+        Self::Local(Arc::new(prover))
+    }
+}
+
+impl From<RemoteTxProver> for MaspTxProver {
+    fn from(prover: RemoteTxProver) -> Self {
+        Self::Remote(prover)
+    }
+}
+
+impl TxProver for MaspTxProver {
+    type SaplingProvingContext = MaspProvingContext;
+
+    fn new_sapling_proving_context(&self) -> Self::SaplingProvingContext {
+        match self {
+            Self::Local(prover) => {
+                MaspProvingContext::Local(prover.new_sapling_proving_context())
+            }
+            Self::Remote(prover) => {
+                MaspProvingContext::Remote(prover.new_sapling_proving_context())
+            }
+        }
+    }
+
+    fn spend_proof(
+        &self,
+        ctx: &mut Self::SaplingProvingContext,
+        proof_generation_key: ProofGenerationKey,
+        diversifier: Diversifier,
+        rseed: Rseed,
+        ar: jubjub::Fr,
+        asset_type: AssetType,
+        value: u64,
+        anchor: bls12_381::Scalar,
+        merkle_path: MerklePath<Node>,
+        rcv: jubjub::Fr,
+    ) -> Result<([u8; GROTH_PROOF_SIZE], jubjub::ExtendedPoint, PublicKey), ()>
+    {
+        match (self, ctx) {
+            (Self::Local(prover), MaspProvingContext::Local(ctx)) => prover
+                .spend_proof(
+                    ctx,
+                    proof_generation_key,
+                    diversifier,
+                    rseed,
+                    ar,
+                    asset_type,
+                    value,
+                    anchor,
+                    merkle_path,
+                    rcv,
+                ),
+            (Self::Remote(prover), MaspProvingContext::Remote(ctx)) => prover
+                .spend_proof(
+                    ctx,
+                    proof_generation_key,
+                    diversifier,
+                    rseed,
+                    ar,
+                    asset_type,
+                    value,
+                    anchor,
+                    merkle_path,
+                    rcv,
+                ),
+            _ => Err(()),
+        }
+    }
+
+    fn output_proof(
+        &self,
+        ctx: &mut Self::SaplingProvingContext,
+        esk: jubjub::Fr,
+        payment_address: PaymentAddress,
+        rcm: jubjub::Fr,
+        asset_type: AssetType,
+        value: u64,
+        rcv: jubjub::Fr,
+    ) -> ([u8; GROTH_PROOF_SIZE], jubjub::ExtendedPoint) {
+        match (self, ctx) {
+            (Self::Local(prover), MaspProvingContext::Local(ctx)) => prover
+                .output_proof(
+                    ctx,
+                    esk,
+                    payment_address,
+                    rcm,
+                    asset_type,
+                    value,
+                    rcv,
+                ),
+            (Self::Remote(prover), MaspProvingContext::Remote(ctx)) => prover
+                .output_proof(
+                    ctx,
+                    esk,
+                    payment_address,
+                    rcm,
+                    asset_type,
+                    value,
+                    rcv,
+                ),
+            _ => unreachable!(
+                "The proving context must have been created by the same prover"
+            ),
+        }
+    }
+
+    fn convert_proof(
+        &self,
+        ctx: &mut Self::SaplingProvingContext,
+        allowed_conversion: AllowedConversion,
+        value: u64,
+        anchor: bls12_381::Scalar,
+        merkle_path: MerklePath<Node>,
+        rcv: jubjub::Fr,
+    ) -> Result<([u8; GROTH_PROOF_SIZE], jubjub::ExtendedPoint), ()> {
+        match (self, ctx) {
+            (Self::Local(prover), MaspProvingContext::Local(ctx)) => prover
+                .convert_proof(
+                    ctx,
+                    allowed_conversion,
+                    value,
+                    anchor,
+                    merkle_path,
+                    rcv,
+                ),
+            (Self::Remote(prover), MaspProvingContext::Remote(ctx)) => prover
+                .convert_proof(
+                    ctx,
+                    allowed_conversion,
+                    value,
+                    anchor,
+                    merkle_path,
+                    rcv,
+                ),
+            _ => Err(()),
+        }
+    }
+
+    fn binding_sig(
+        &self,
+        ctx: &mut Self::SaplingProvingContext,
+        assets_and_values: &I128Sum,
+        sighash: &[u8; 32],
+    ) -> Result<Signature, ()> {
+        match (self, ctx) {
+            (Self::Local(prover), MaspProvingContext::Local(ctx)) => {
+                prover.binding_sig(ctx, assets_and_values, sighash)
+            }
+            (Self::Remote(prover), MaspProvingContext::Remote(ctx)) => {
+                prover.binding_sig(ctx, assets_and_values, sighash)
+            }
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_remote_prover {
+    use super::*;
+
+    /// Test that proof requests survive the round-trip through the wire
+    /// encoding
+    #[test]
+    fn test_proof_request_roundtrip() {
+        let rcv = jubjub::Fr::from(42u64);
+        let rseed = Rseed::BeforeZip212(jubjub::Fr::from(7u64));
+        let path = MerklePath::from_path(vec![(Node::new([1; 32]), true)], 3);
+        let request = ProofRequest::Convert {
+            allowed_conversion: I128Sum::zero().into(),
+            value: 10,
+            anchor: bls12_381::Scalar::from(5u64).to_bytes(),
+            merkle_path: path.clone(),
+            rcv: rcv.to_bytes(),
+        };
+        let bytes = borsh::to_vec(&request).unwrap();
+        let ProofRequest::Convert {
+            value,
+            anchor,
+            merkle_path,
+            rcv: decoded_rcv,
+            ..
+        } = ProofRequest::try_from_slice(&bytes).unwrap()
+        else {
+            panic!("Unexpected request type");
+        };
+        assert_eq!(value, 10);
+        assert_eq!(
+            decode_base(&anchor, "anchor").unwrap(),
+            bls12_381::Scalar::from(5u64)
+        );
+        assert_eq!(merkle_path.auth_path, path.auth_path);
+        assert_eq!(merkle_path.position, path.position);
+        assert_eq!(decode_scalar(&decoded_rcv, "rcv").unwrap(), rcv);
+
+        let decoded: Rseed = RseedBytes::from(&rseed).try_into().unwrap();
+        assert!(matches!(
+            decoded,
+            Rseed::BeforeZip212(rcm) if rcm == jubjub::Fr::from(7u64)
+        ));
+    }
+
+    /// Test that failures of the remote prover make the binding signature
+    /// fail instead of producing an invalid transaction
+    #[test]
+    fn test_unreachable_prover_fails_binding_sig() {
+        // Bind to a free port and close it right away, so that nothing is
+        // listening there
+        let address = {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            listener.local_addr().unwrap().to_string()
+        };
+        let key = ProverKey::from_token("test").unwrap();
+        let prover = RemoteTxProver::new(address, key)
+            .with_timeout(Duration::from_secs(1));
+        let mut ctx = prover.new_sapling_proving_context();
+        let (proof, _cv) = prover.output_proof(
+            &mut ctx,
+            jubjub::Fr::one(),
+            masp_primitives::zip32::ExtendedSpendingKey::master(&[0; 32])
+                .default_address()
+                .1,
+            jubjub::Fr::one(),
+            AssetType::new(b"test").unwrap(),
+            1,
+            jubjub::Fr::one(),
+        );
+        assert_eq!(proof, [0; GROTH_PROOF_SIZE]);
+        assert!(ctx.error().is_some());
+        assert!(prover
+            .binding_sig(&mut ctx, &I128Sum::zero(), &[0; 32])
+            .is_err());
+    }
+
+    /// Test that messages can only be read with the key they were written
+    /// with, and that oversized messages are rejected before being read
+    #[test]
+    fn test_prover_messages() {
+        let key = ProverKey::from_token("secret").unwrap();
+        let response: ProofResponse = Ok(vec![1; GROTH_PROOF_SIZE]);
+        let mut wire = vec![];
+        write_message(&mut wire, &key, &response).unwrap();
+
+        let decoded: ProofResponse =
+            read_message(&mut wire.as_slice(), &key).unwrap();
+        assert_eq!(decoded, response);
+
+        let other_key = ProverKey::from_token("other").unwrap();
+        assert!(matches!(
+            read_message::<ProofResponse>(&mut wire.as_slice(), &other_key),
+            Err(RemoteProverError::Unauthenticated)
+        ));
+
+        let mut oversized =
+            (MAX_PROVER_MESSAGE_SIZE + 1).to_be_bytes().to_vec();
+        oversized.extend(&wire[4..]);
+        assert!(matches!(
+            read_message::<ProofResponse>(&mut oversized.as_slice(), &key),
+            Err(RemoteProverError::MessageTooLarge(_))
+        ));
+
+        assert!(matches!(
+            ProverKey::from_token(""),
+            Err(RemoteProverError::InvalidToken)
+        ));
+    }
+
+    /// Test that a remote prover set without its token is reported as an
+    /// error
+    #[test]
+    fn test_remote_prover_without_token() {
+        std::env::set_var(ENV_VAR_MASP_REMOTE_PROVER, "127.0.0.1:1");
+        std::env::remove_var(ENV_VAR_MASP_REMOTE_PROVER_TOKEN);
+        let result = MaspTxProver::from_env();
+        std::env::remove_var(ENV_VAR_MASP_REMOTE_PROVER);
+        assert!(matches!(result, Err(RemoteProverError::InvalidToken)));
+    }
+}
@@ -47,6 +47,58 @@ impl Iterator for RetryStrategy {
     }
 }
 
+/// Default number of scanned txs after which the shielded context
+/// is checkpointed to storage.
+pub const DEFAULT_CHECKPOINT_INTERVAL: usize = 512;
+
+/// Configures how fetched notes are trial-decrypted and how often the
+/// shielded context is persisted while scanning.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ScanConfig {
+    /// Number of blocking tasks across which the set of viewing keys
+    /// is partitioned for trial decryption.
+    pub workers: usize,
+    /// Number of scanned txs after which a checkpoint of the (confirmed)
+    /// shielded context is saved. An interrupted sync resumes from the
+    /// last checkpoint. A value of zero disables periodic checkpoints.
+    pub checkpoint_interval: usize,
+}
+
+impl Default for ScanConfig {
+    fn default() -> Self {
+        Self {
+            workers: std::thread::available_parallelism()
+                .map(usize::from)
+                .unwrap_or(1),
+            checkpoint_interval: DEFAULT_CHECKPOINT_INTERVAL,
+        }
+    }
+}
+
+impl ScanConfig {
+    /// Trial decryption is performed over batches of this many txs.
+    /// Checkpoints are only taken at batch boundaries.
+    pub(super) fn batch_size(&self) -> usize {
+        const MAX_BATCH_SIZE: usize = 64;
+        if self.checkpoint_interval == 0 {
+            MAX_BATCH_SIZE
+        } else {
+            self.checkpoint_interval.min(MAX_BATCH_SIZE)
+        }
+    }
+
+    /// Split the given viewing keys into (at most) `workers` partitions of
+    /// roughly equal size.
+    pub(super) fn partition_keys<'a, T>(
+        &self,
+        keys: &'a [T],
+    ) -> impl Iterator<Item = &'a [T]> {
+        let workers = self.workers.clamp(1, keys.len().max(1));
+        let chunk_size = keys.len().div_ceil(workers).max(1);
+        keys.chunks(chunk_size)
+    }
+}
+
 /// Enumerates the capabilities of a [`MaspClient`] implementation.
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub enum MaspClientCapabilities {
@@ -925,7 +977,7 @@ impl<'io, IO: Io> ProgressTracker<IO> for DefaultTracker<'io, IO> {
 
 #[cfg(test)]
 mod util_tests {
-    use crate::masp::utils::RetryStrategy;
+    use crate::masp::utils::{RetryStrategy, ScanConfig};
 
     #[test]
     fn test_retry_strategy() {
@@ -936,4 +988,24 @@ mod util_tests {
         }
         assert_eq!(counter, 3);
     }
+
+    #[test]
+    fn test_partition_keys() {
+        let keys: Vec<u8> = (0..10).collect();
+        let config = ScanConfig {
+            workers: 3,
+            checkpoint_interval: 0,
+        };
+        let partitions: Vec<_> = config.partition_keys(&keys).collect();
+        assert_eq!(partitions.len(), 3);
+        assert_eq!(partitions.concat(), keys);
+
+        // never spawn more workers than there are keys
+        let config = ScanConfig {
+            workers: 16,
+            checkpoint_interval: 0,
+        };
+        assert_eq!(config.partition_keys(&keys[..2]).count(), 2);
+        assert_eq!(config.partition_keys::<u8>(&[]).count(), 0);
+    }
 }
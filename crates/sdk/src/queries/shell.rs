@@ -23,8 +23,11 @@ use namada_core::uint::Uint;
 use namada_ibc::event::IbcEventType;
 use namada_state::{DBIter, LastBlock, StateRead, StorageHasher, DB};
 use namada_storage::{ResultExt, StorageRead};
+use namada_token::conversion::{
+    MaspRewardInputs, MAX_REWARD_PROJECTION_EPOCHS,
+};
 use namada_token::storage_key::masp_token_map_key;
-use namada_tx::data::DryRunResult;
+use namada_tx::data::{DryRunResult, ProposalDryRunResult};
 
 use self::eth_bridge::{EthBridge, ETH_BRIDGE};
 use crate::events::log::dumb_queries;
@@ -32,7 +35,7 @@ use crate::events::Event;
 use crate::ibc::core::host::types::identifiers::{
     ChannelId, ClientId, PortId, Sequence,
 };
-use crate::masp::MaspTokenRewardData;
+use crate::masp::{MaspTokenRewardData, MaspTokenRewardProjection};
 use crate::queries::types::{RequestCtx, RequestQuery};
 use crate::queries::{require_latest_height, EncodedResponseQuery};
 use crate::tendermint::merkle::proof::ProofOps;
@@ -72,6 +75,9 @@ router! {SHELL,
     // Epoch of the input block height
     ( "epoch_at_height" / [height: BlockHeight]) -> Option<Epoch> = epoch_at_height,
 
+    // Masp epoch of the input block height
+    ( "masp_epoch_at_height" / [height: BlockHeight]) -> Option<MaspEpoch> = masp_epoch_at_height,
+
     // Query the last committed block
     ( "last_block" ) -> Option<LastBlock> = last_block,
 
@@ -85,6 +91,9 @@ router! {SHELL,
     // Dry run a transaction
     ( "dry_run_tx" ) -> DryRunResult = (with_options dry_run_tx),
 
+    // Dry run the wasm code of a governance proposal
+    ( "dry_run_proposal" ) -> ProposalDryRunResult = (with_options dry_run_proposal),
+
     // Raw storage access - prefix iterator
     ( "prefix" / [storage_key: storage::Key] )
         -> Vec<PrefixValue> = (with_options storage_prefix),
@@ -102,6 +111,11 @@ router! {SHELL,
     // Conversion state access - read conversion
     ( "masp_reward_tokens" ) -> Vec<MaspTokenRewardData> = masp_reward_tokens,
 
+    // Project the shielded rewards of each token for the given number of
+    // upcoming MASP epochs
+    ( "masp_reward_projection" / [epochs: u64] )
+        -> Vec<MaspTokenRewardProjection> = masp_reward_projection,
+
     // Block results access - read bit-vec
     ( "results" ) -> Vec<BlockResults> = read_results,
 
@@ -140,6 +154,17 @@ where
     unimplemented!("Dry running tx requires \"wasm-runtime\" feature.")
 }
 
+fn dry_run_proposal<D, H, V, T>(
+    _ctx: RequestCtx<'_, D, H, V, T>,
+    _request: &RequestQuery,
+) -> namada_storage::Result<EncodedResponseQuery>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    unimplemented!("Dry running a proposal requires \"wasm-runtime\" feature.")
+}
+
 /// Return an estimate of the maximum time taken to decide a block
 fn max_block_time<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
@@ -349,6 +374,52 @@ where
     Ok(data)
 }
 
+/// Query to project the shielded rewards of the tokens that earn masp
+/// rewards, assuming that the locked amounts remain constant. At most
+/// [`MAX_REWARD_PROJECTION_EPOCHS`] epochs can be projected.
+fn masp_reward_projection<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    epochs: u64,
+) -> namada_storage::Result<Vec<MaspTokenRewardProjection>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    if epochs > MAX_REWARD_PROJECTION_EPOCHS {
+        return Err(namada_storage::Error::new_alloc(format!(
+            "Cannot project the MASP rewards over {epochs} epochs, the \
+             maximum is {MAX_REWARD_PROJECTION_EPOCHS}"
+        )));
+    }
+    let masp_epoch_multiplier =
+        namada_parameters::read_masp_epoch_multiplier_parameter(ctx.state)?;
+    let epochs_per_year =
+        namada_parameters::read_epochs_per_year_parameter(ctx.state)?;
+    let masp_epochs_per_year =
+        checked!(epochs_per_year / masp_epoch_multiplier)?;
+    let token_map_key = masp_token_map_key();
+    let token_map: TokenMap =
+        ctx.state.read(&token_map_key)?.unwrap_or_default();
+    let mut data = Vec::<MaspTokenRewardProjection>::new();
+    for (name, token) in token_map {
+        let inputs = MaspRewardInputs::read::<_, namada_token::Store<_>>(
+            ctx.state,
+            &token,
+            masp_epochs_per_year,
+        )?;
+        let rewards = inputs.project(&token, epochs)?;
+        data.push(MaspTokenRewardProjection {
+            name,
+            address: token,
+            denomination: inputs.denomination,
+            locked_amount: inputs.total_tokens_in_masp,
+            precision: inputs.precision,
+            rewards,
+        });
+    }
+    Ok(data)
+}
+
 fn epoch<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
 ) -> namada_storage::Result<Epoch>
@@ -396,6 +467,25 @@ where
     Ok(ctx.state.in_mem().block.pred_epochs.get_epoch(height))
 }
 
+fn masp_epoch_at_height<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    height: BlockHeight,
+) -> namada_storage::Result<Option<MaspEpoch>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let Some(epoch) = ctx.state.in_mem().block.pred_epochs.get_epoch(height)
+    else {
+        return Ok(None);
+    };
+    let masp_epoch_multiplier =
+        namada_parameters::read_masp_epoch_multiplier_parameter(ctx.state)?;
+    MaspEpoch::try_from_epoch(epoch, masp_epoch_multiplier)
+        .map(Some)
+        .map_err(namada_storage::Error::new_const)
+}
+
 fn last_block<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
 ) -> namada_storage::Result<Option<LastBlock>>
@@ -664,9 +754,11 @@ where
 #[cfg(test)]
 mod test {
     use namada_core::address;
+    use namada_token::conversion::MAX_REWARD_PROJECTION_EPOCHS;
     use namada_token::storage_key::balance_key;
 
-    use crate::queries::RPC;
+    use crate::queries::testing::TestClient;
+    use crate::queries::{RequestCtx, RequestQuery, Router, RPC};
 
     #[test]
     fn test_shell_queries_router_paths() {
@@ -682,10 +774,41 @@ mod test {
         let path = RPC.shell().dry_run_tx_path();
         assert_eq!("/shell/dry_run_tx", path);
 
+        let path = RPC.shell().dry_run_proposal_path();
+        assert_eq!("/shell/dry_run_proposal", path);
+
         let path = RPC.shell().storage_prefix_path(&key);
         assert_eq!(format!("/shell/prefix/{}", key), path);
 
         let path = RPC.shell().storage_has_key_path(&key);
         assert_eq!(format!("/shell/has_key/{}", key), path);
     }
+
+    #[test]
+    fn test_masp_reward_projection_epochs_limit() {
+        let client = TestClient::new(RPC);
+
+        // Projecting too far ahead is rejected before reading the storage
+        for epochs in [MAX_REWARD_PROJECTION_EPOCHS + 1, u64::MAX] {
+            let request = RequestQuery {
+                path: RPC.shell().masp_reward_projection_path(&epochs),
+                data: Default::default(),
+                height: 0_u32.into(),
+                prove: Default::default(),
+            };
+            let ctx = RequestCtx {
+                event_log: &client.event_log,
+                state: &client.state,
+                vp_wasm_cache: (),
+                tx_wasm_cache: (),
+                storage_read_past_height_limit: None,
+            };
+            let result = RPC.handle(ctx, &request);
+            assert!(result.is_err());
+            assert!(result
+                .unwrap_err()
+                .to_string()
+                .contains("Cannot project the MASP rewards"));
+        }
+    }
 }
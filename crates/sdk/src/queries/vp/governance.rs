@@ -1,8 +1,15 @@
 // cd namada && cargo expand ledger::queries::vp::governance
 
+use std::collections::{BTreeMap, BTreeSet};
+
+use namada_core::address::Address;
 use namada_governance::parameters::GovernanceParameters;
-use namada_governance::storage::proposal::StorageProposal;
-use namada_governance::utils::{ProposalResult, Vote};
+use namada_governance::storage::proposal::{
+    RepresentationScope, StorageProposal,
+};
+use namada_governance::utils::{
+    ProposalLifecycle, ProposalResult, QueuedProposal, RepresentedVote, Vote,
+};
 use namada_state::{DBIter, StorageHasher, DB};
 
 use crate::queries::types::RequestCtx;
@@ -11,6 +18,12 @@ use crate::queries::types::RequestCtx;
 router! {GOV,
     ( "proposal" / [id: u64 ] ) -> Option<StorageProposal> = proposal_id,
     ( "proposal" / [id: u64 ] / "votes" ) -> Vec<Vote> = proposal_id_votes,
+    ( "proposal" / [id: u64 ] / "represented_votes" ) -> Vec<RepresentedVote> = proposal_id_represented_votes,
+    ( "proposal" / [id: u64 ] / "lifecycle" ) -> Option<ProposalLifecycle> = proposal_id_lifecycle,
+    ( "proposal" / [id: u64 ] / "timelock" ) -> Option<QueuedProposal> = proposal_id_timelock,
+    ( "timelock_queue" ) -> Vec<QueuedProposal> = timelock_queue,
+    ( "representatives" / [delegator: Address] ) -> BTreeMap<RepresentationScope, Address> = representatives,
+    ( "represented" / [representative: Address] ) -> BTreeSet<Address> = represented_accounts,
     ( "parameters" ) -> GovernanceParameters = parameters,
     ( "stored_proposal_result" / [id: u64] ) -> Option<ProposalResult> = proposal_result,
 }
@@ -39,6 +52,87 @@ where
     namada_governance::storage::get_proposal_votes(ctx.state, id)
 }
 
+/// Query the full lifecycle record of the given proposal id
+fn proposal_id_lifecycle<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    id: u64,
+) -> namada_storage::Result<Option<ProposalLifecycle>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let current_epoch = ctx.state.in_mem().last_epoch;
+    namada_governance::storage::get_proposal_lifecycle(
+        ctx.state,
+        id,
+        current_epoch,
+    )
+}
+
+/// Query the given proposal id in the timelock queue
+fn proposal_id_timelock<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    id: u64,
+) -> namada_storage::Result<Option<QueuedProposal>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    namada_governance::storage::get_queued_proposal(ctx.state, id)
+}
+
+/// Query all the passed proposals waiting in the timelock queue
+fn timelock_queue<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+) -> namada_storage::Result<Vec<QueuedProposal>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    namada_governance::storage::get_queued_proposals(ctx.state)
+}
+
+/// Query the votes cast by governance representatives on behalf of the
+/// accounts that didn't vote on the given proposal id
+fn proposal_id_represented_votes<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    id: u64,
+) -> namada_storage::Result<Vec<RepresentedVote>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    namada_governance::storage::get_represented_votes(ctx.state, id)
+}
+
+/// Query the governance representatives of a delegator for each scope
+fn representatives<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    delegator: Address,
+) -> namada_storage::Result<BTreeMap<RepresentationScope, Address>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    namada_governance::storage::get_representatives(ctx.state, &delegator)
+}
+
+/// Query the accounts which directly delegated their voting power to the
+/// given representative
+fn represented_accounts<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    representative: Address,
+) -> namada_storage::Result<BTreeSet<Address>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    namada_governance::storage::get_represented_accounts(
+        ctx.state,
+        &representative,
+    )
+}
+
 /// Get the governance parameters
 fn parameters<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
@@ -6,15 +6,17 @@ use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use namada_core::address::Address;
 use namada_core::arith::{self, checked};
 use namada_core::collections::{HashMap, HashSet};
+use namada_core::dec::Dec;
 use namada_core::key::common;
 use namada_core::storage::Epoch;
 use namada_core::token;
+use namada_proof_of_stake::metadata::find_validators_by_metadata_field;
 use namada_proof_of_stake::parameters::PosParams;
 use namada_proof_of_stake::queries::{
     find_delegation_validators, find_delegations,
 };
 use namada_proof_of_stake::slashing::{
-    find_all_enqueued_slashes, find_all_slashes,
+    find_all_enqueued_slashes, find_all_slashes, simulate_slash,
 };
 use namada_proof_of_stake::storage::{
     bond_handle, read_all_validator_addresses,
@@ -24,16 +26,22 @@ use namada_proof_of_stake::storage::{
     read_validator_description, read_validator_discord_handle,
     read_validator_email, read_validator_last_slash_epoch,
     read_validator_max_commission_rate_change, read_validator_name,
-    read_validator_stake, read_validator_website, unbond_handle,
+    read_validator_self_bond, read_validator_stake,
+    read_validator_structured_metadata, read_validator_website, unbond_handle,
     validator_commission_rate_handle, validator_incoming_redelegations_handle,
     validator_slashes_handle,
 };
 pub use namada_proof_of_stake::types::ValidatorStateInfo;
 use namada_proof_of_stake::types::{
     BondId, BondsAndUnbondsDetail, BondsAndUnbondsDetails, CommissionPair,
-    Slash, ValidatorMetaData, WeightedValidator,
+    EpochRewards, MetadataField, Slash, SlashSimulation, SlashType,
+    StructuredValidatorMetadata, ValidatorLiveness, ValidatorMetaData,
+    WeightedValidator,
+};
+use namada_proof_of_stake::{
+    bond_amount, is_auto_compounding, query_reward_tokens,
+    query_rewards_history, read_commission_schedule, read_validator_liveness,
 };
-use namada_proof_of_stake::{bond_amount, query_reward_tokens};
 use namada_state::{DBIter, KeySeg, StorageHasher, DB};
 use namada_storage::collections::lazy_map;
 use namada_storage::OptionExt;
@@ -59,9 +67,15 @@ router! {POS,
         ( "commission" / [validator: Address] / [epoch: opt Epoch] )
             -> CommissionPair = validator_commission,
 
+        ( "commission_schedule" / [validator: Address] )
+            -> BTreeMap<Epoch, Dec> = validator_commission_schedule,
+
         ( "metadata" / [validator: Address] )
             -> Option<ValidatorMetaData> = validator_metadata,
 
+        ( "structured_metadata" / [validator: Address] )
+            -> Option<StructuredValidatorMetadata> = validator_structured_metadata,
+
         ( "state" / [validator: Address] / [epoch: opt Epoch] )
             -> ValidatorStateInfo = validator_state,
 
@@ -70,6 +84,15 @@ router! {POS,
 
         ( "last_infraction_epoch" / [validator: Address] )
             -> Option<Epoch> = validator_last_infraction_epoch,
+
+        ( "self_bond" / [validator: Address] / [epoch: opt Epoch] )
+            -> token::Amount = validator_self_bond,
+
+        ( "liveness" / [validator: Address] / [num_epochs: opt u64] )
+            -> ValidatorLiveness = validator_liveness,
+
+        ( "slash_simulation" / [validator: Address] / [slash_type: SlashType] / [infraction_epoch: Epoch] )
+            -> SlashSimulation = validator_slash_simulation,
     },
 
     ( "validator_set" ) = {
@@ -78,6 +101,9 @@ router! {POS,
 
         ( "below_capacity" / [epoch: opt Epoch] )
             -> BTreeSet<WeightedValidator> = below_capacity_validator_set,
+
+        ( "by_metadata" / [field: MetadataField] / [epoch: opt Epoch] )
+            -> BTreeMap<Address, String> = validators_by_metadata,
     },
 
     ( "pos_params") -> PosParams = pos_params,
@@ -103,6 +129,12 @@ router! {POS,
     ( "rewards" / [validator: Address] / [source: opt Address] )
         -> token::Amount = rewards,
 
+    ( "auto_compound" / [validator: Address] / [source: opt Address] )
+        -> bool = auto_compound,
+
+    ( "rewards_history" / [validator: Address] / [source: opt Address] / "from" / [start_epoch: Epoch] / "to" / [end_epoch: Epoch] )
+        -> BTreeMap<Epoch, EpochRewards> = rewards_history,
+
     ( "bond_with_slashing" / [source: Address] / [validator: Address] / [epoch: opt Epoch] )
         -> token::Amount = bond_with_slashing,
 
@@ -248,6 +280,20 @@ where
     read_all_validator_addresses(ctx.state, epoch)
 }
 
+/// Get the scheduled commission rate changes of a validator that have not
+/// taken effect yet
+fn validator_commission_schedule<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    validator: Address,
+) -> namada_storage::Result<BTreeMap<Epoch, Dec>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let epoch = ctx.state.in_mem().last_epoch;
+    read_commission_schedule(ctx.state, &validator, epoch)
+}
+
 /// Get the validator commission rate and max commission rate change per epoch
 fn validator_commission<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
@@ -302,6 +348,33 @@ where
     }
 }
 
+/// Get the structured validator metadata
+fn validator_structured_metadata<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    validator: Address,
+) -> namada_storage::Result<Option<StructuredValidatorMetadata>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    read_validator_structured_metadata(ctx.state, &validator)
+}
+
+/// Get the validators whose metadata has the given field set, with the field's
+/// value, at the given epoch or current when `None`.
+fn validators_by_metadata<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    field: MetadataField,
+    epoch: Option<Epoch>,
+) -> namada_storage::Result<BTreeMap<Address, String>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let epoch = epoch.unwrap_or(ctx.state.in_mem().last_epoch);
+    find_validators_by_metadata_field(ctx.state, &field, epoch)
+}
+
 /// Get the validator state
 fn validator_state<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
@@ -319,6 +392,20 @@ where
     Ok((state, epoch))
 }
 
+/// Get the self-bond of a validator at the given epoch or current when `None`
+fn validator_self_bond<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    validator: Address,
+    epoch: Option<Epoch>,
+) -> namada_storage::Result<token::Amount>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let epoch = epoch.unwrap_or(ctx.state.in_mem().last_epoch);
+    read_validator_self_bond(ctx.state, &validator, epoch)
+}
+
 /// Get the validator state
 fn validator_last_infraction_epoch<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
@@ -331,6 +418,46 @@ where
     read_validator_last_slash_epoch(ctx.state, &validator)
 }
 
+/// Get the liveness of a validator: its missed votes within the current
+/// sliding window and its per-epoch history for up to `num_epochs` past epochs
+fn validator_liveness<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    validator: Address,
+    num_epochs: Option<u64>,
+) -> namada_storage::Result<ValidatorLiveness>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let epoch = ctx.state.in_mem().last_epoch;
+    let params = read_pos_params(ctx.state)?;
+    read_validator_liveness(ctx.state, &params, &validator, epoch, num_epochs)
+}
+
+/// Simulate slashing a validator for a hypothetical infraction committed in
+/// the given epoch. The simulation runs on a temporary write log that is
+/// discarded afterwards.
+fn validator_slash_simulation<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    validator: Address,
+    slash_type: SlashType,
+    infraction_epoch: Epoch,
+) -> namada_storage::Result<SlashSimulation>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let current_epoch = ctx.state.in_mem().last_epoch;
+    let mut state = ctx.state.with_temp_write_log();
+    simulate_slash(
+        &mut state,
+        &validator,
+        slash_type,
+        infraction_epoch,
+        current_epoch,
+    )
+}
+
 /// Get the total stake of a validator at the given epoch or current when
 /// `None`. The total stake is a sum of validator's self-bonds and delegations
 /// to their address.
@@ -573,6 +700,40 @@ where
     query_reward_tokens(ctx.state, source.as_ref(), &validator, current_epoch)
 }
 
+fn rewards_history<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    validator: Address,
+    source: Option<Address>,
+    start_epoch: Epoch,
+    end_epoch: Epoch,
+) -> namada_storage::Result<BTreeMap<Epoch, EpochRewards>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let current_epoch = ctx.state.in_mem().last_epoch;
+    query_rewards_history(
+        ctx.state,
+        source.as_ref(),
+        &validator,
+        start_epoch,
+        end_epoch,
+        current_epoch,
+    )
+}
+
+fn auto_compound<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    validator: Address,
+    source: Option<Address>,
+) -> namada_storage::Result<bool>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    is_auto_compounding(ctx.state, source.as_ref(), &validator)
+}
+
 fn bonds_and_unbonds<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
     source: Option<Address>,
@@ -5,6 +5,7 @@ use std::collections::{BTreeMap, BTreeSet};
 use std::ops::ControlFlow;
 
 use borsh::BorshDeserialize;
+use borsh_ext::BorshSerializeExt;
 use masp_primitives::asset_type::AssetType;
 use masp_primitives::merkle_tree::MerklePath;
 use masp_primitives::sapling::Node;
@@ -12,6 +13,7 @@ use namada_account::Account;
 use namada_core::address::{Address, InternalAddress};
 use namada_core::arith::checked;
 use namada_core::collections::{HashMap, HashSet};
+use namada_core::dec::Dec;
 use namada_core::hash::Hash;
 use namada_core::ibc::IbcTokenHash;
 use namada_core::key::common;
@@ -29,9 +31,13 @@ use namada_gas::WholeGas;
 use namada_governance::parameters::GovernanceParameters;
 use namada_governance::pgf::parameters::PgfParameters;
 use namada_governance::pgf::storage::steward::StewardDetail;
-use namada_governance::storage::proposal::StorageProposal;
+use namada_governance::storage::content::ProposalContent;
+use namada_governance::storage::proposal::{
+    RepresentationScope, StorageProposal,
+};
 use namada_governance::utils::{
-    compute_proposal_result, ProposalResult, ProposalVotes, Vote,
+    compute_proposal_result, ProposalLifecycle, ProposalResult, ProposalVotes,
+    QueuedProposal, RepresentedVote, Vote,
 };
 use namada_ibc::storage::{
     ibc_trace_key, ibc_trace_key_prefix, is_ibc_trace_key,
@@ -39,10 +45,14 @@ use namada_ibc::storage::{
 use namada_parameters::{storage as params_storage, EpochDuration};
 use namada_proof_of_stake::parameters::PosParams;
 use namada_proof_of_stake::types::{
-    BondsAndUnbondsDetails, CommissionPair, ValidatorMetaData,
+    BondsAndUnbondsDetails, CommissionPair, EpochRewards, MetadataField,
+    SlashSimulation, SlashType, StructuredValidatorMetadata, ValidatorLiveness,
+    ValidatorMetaData,
 };
 use namada_state::LastBlock;
-use namada_tx::data::{BatchedTxResult, DryRunResult, ResultCode, TxResult};
+use namada_tx::data::{
+    BatchedTxResult, DryRunResult, ProposalDryRunResult, ResultCode, TxResult,
+};
 use namada_tx::event::{Batch as BatchAttr, Code as CodeAttr};
 use serde::Serialize;
 
@@ -52,7 +62,7 @@ use crate::error::{EncodingError, Error, QueryError, TxSubmitError};
 use crate::events::{extend, Event};
 use crate::internal_macros::echo_error;
 use crate::io::Io;
-use crate::masp::MaspTokenRewardData;
+use crate::masp::{MaspTokenRewardData, MaspTokenRewardProjection};
 use crate::queries::vp::pos::{
     EnrichedBondsAndUnbondsDetails, ValidatorStateInfo,
 };
@@ -155,6 +165,16 @@ pub async fn query_masp_epoch<C: crate::queries::Client + Sync>(
     convert_response::<C, _>(RPC.shell().masp_epoch(client).await)
 }
 
+/// Query the masp epoch of the given block height, if it exists.
+pub async fn query_masp_epoch_at_height<C: crate::queries::Client + Sync>(
+    client: &C,
+    height: BlockHeight,
+) -> Result<Option<MaspEpoch>, error::Error> {
+    convert_response::<C, _>(
+        RPC.shell().masp_epoch_at_height(client, &height).await,
+    )
+}
+
 /// Query the address of the native token
 pub async fn query_native_token<C: crate::queries::Client + Sync>(
     client: &C,
@@ -372,6 +392,17 @@ pub async fn query_masp_reward_tokens<C: crate::queries::Client + Sync>(
     convert_response::<C, _>(RPC.shell().masp_reward_tokens(client).await)
 }
 
+/// Query the projected shielded rewards of the tokens that earn masp rewards
+/// for the given number of upcoming MASP epochs.
+pub async fn query_masp_reward_projection<C: crate::queries::Client + Sync>(
+    client: &C,
+    epochs: u64,
+) -> Result<Vec<MaspTokenRewardProjection>, Error> {
+    convert_response::<C, _>(
+        RPC.shell().masp_reward_projection(client, &epochs).await,
+    )
+}
+
 /// Query a wasm code hash
 pub async fn query_wasm_code_hash(
     context: &impl Namada,
@@ -545,6 +576,22 @@ pub async fn query_tx_events<C: crate::queries::Client + Sync>(
     }
 }
 
+/// Dry run the wasm code attached to a governance proposal against the state
+/// of the last committed block, without modifying it
+pub async fn dry_run_proposal<C: crate::queries::Client + Sync>(
+    client: &C,
+    proposal_id: u64,
+) -> Result<ProposalDryRunResult, Error> {
+    let (data, height, prove) =
+        (Some(proposal_id.serialize_to_vec()), None, false);
+    convert_response::<C, _>(
+        RPC.shell()
+            .dry_run_proposal(client, data, height, prove)
+            .await,
+    )
+    .map(|response| response.data)
+}
+
 /// Dry run a transaction
 pub async fn dry_run_tx<N: Namada>(
     context: &N,
@@ -827,6 +874,23 @@ pub async fn query_proposal_by_id<C: crate::queries::Client + Sync>(
     )
 }
 
+/// Query the typed content of a proposal, validated against the proposal
+/// content schema
+pub async fn query_proposal_content<C: crate::queries::Client + Sync>(
+    client: &C,
+    proposal_id: u64,
+) -> Result<Option<ProposalContent>, Error> {
+    let Some(proposal) = query_proposal_by_id(client, proposal_id).await?
+    else {
+        return Ok(None);
+    };
+    proposal.parsed_content().map(Some).map_err(|err| {
+        Error::Other(format!(
+            "The content of proposal {proposal_id} is malformed: {err}"
+        ))
+    })
+}
+
 /// Query and return validator's commission rate and max commission rate change
 /// per epoch
 pub async fn query_commission_rate<C: crate::queries::Client + Sync>(
@@ -842,6 +906,93 @@ pub async fn query_commission_rate<C: crate::queries::Client + Sync>(
     )
 }
 
+/// Query the scheduled commission rate changes of a validator that have not
+/// taken effect yet, keyed by the epoch at which they take effect
+pub async fn query_commission_schedule<C: crate::queries::Client + Sync>(
+    client: &C,
+    validator: &Address,
+) -> Result<BTreeMap<Epoch, Dec>, Error> {
+    convert_response::<C, _>(
+        RPC.vp()
+            .pos()
+            .validator_commission_schedule(client, validator)
+            .await,
+    )
+}
+
+/// Query the self-bond of a validator at the given epoch or current when `None`
+pub async fn query_validator_self_bond<C: crate::queries::Client + Sync>(
+    client: &C,
+    validator: &Address,
+    epoch: Option<Epoch>,
+) -> Result<token::Amount, Error> {
+    convert_response::<C, _>(
+        RPC.vp()
+            .pos()
+            .validator_self_bond(client, validator, &epoch)
+            .await,
+    )
+}
+
+/// Query the liveness of a validator, with its per-epoch history for up to
+/// `num_epochs` past epochs
+pub async fn query_validator_liveness<C: crate::queries::Client + Sync>(
+    client: &C,
+    validator: &Address,
+    num_epochs: Option<u64>,
+) -> Result<ValidatorLiveness, Error> {
+    convert_response::<C, _>(
+        RPC.vp()
+            .pos()
+            .validator_liveness(client, validator, &num_epochs)
+            .await,
+    )
+}
+
+/// Simulate slashing a validator for a hypothetical infraction of the given
+/// type committed in `infraction_epoch`, without modifying the chain state
+pub async fn query_slash_simulation<C: crate::queries::Client + Sync>(
+    client: &C,
+    validator: &Address,
+    slash_type: SlashType,
+    infraction_epoch: Epoch,
+) -> Result<SlashSimulation, Error> {
+    convert_response::<C, _>(
+        RPC.vp()
+            .pos()
+            .validator_slash_simulation(
+                client,
+                validator,
+                &slash_type,
+                &infraction_epoch,
+            )
+            .await,
+    )
+}
+
+/// Query the rewards earned by a bond in each epoch of the inclusive range from
+/// `start_epoch` to `end_epoch`
+pub async fn query_rewards_history<C: crate::queries::Client + Sync>(
+    client: &C,
+    source: &Option<Address>,
+    validator: &Address,
+    start_epoch: Epoch,
+    end_epoch: Epoch,
+) -> Result<BTreeMap<Epoch, EpochRewards>, Error> {
+    convert_response::<C, _>(
+        RPC.vp()
+            .pos()
+            .rewards_history(
+                client,
+                validator,
+                source,
+                &start_epoch,
+                &end_epoch,
+            )
+            .await,
+    )
+}
+
 /// Query and return validator's metadata, including the commission rate and max
 /// commission rate change
 pub async fn query_metadata<C: crate::queries::Client + Sync>(
@@ -861,6 +1012,34 @@ pub async fn query_metadata<C: crate::queries::Client + Sync>(
     Ok((metadata, commission_info))
 }
 
+/// Query and return validator's structured metadata
+pub async fn query_structured_metadata<C: crate::queries::Client + Sync>(
+    client: &C,
+    validator: &Address,
+) -> Result<Option<StructuredValidatorMetadata>, Error> {
+    convert_response::<C, _>(
+        RPC.vp()
+            .pos()
+            .validator_structured_metadata(client, validator)
+            .await,
+    )
+}
+
+/// Query the validators whose metadata has the given field set, together with
+/// the field's value, at the given epoch or current when `None`
+pub async fn query_validators_by_metadata<C: crate::queries::Client + Sync>(
+    client: &C,
+    field: &MetadataField,
+    epoch: Option<Epoch>,
+) -> Result<BTreeMap<Address, String>, Error> {
+    convert_response::<C, _>(
+        RPC.vp()
+            .pos()
+            .validators_by_metadata(client, field, &epoch)
+            .await,
+    )
+}
+
 /// Query and return the incoming redelegation epoch for a given pair of source
 /// validator and delegator, if there is any.
 pub async fn query_incoming_redelegations<C: crate::queries::Client + Sync>(
@@ -941,10 +1120,10 @@ pub async fn query_proposal_result<C: crate::queries::Client + Sync>(
     proposal_id: u64,
 ) -> Result<Option<ProposalResult>, Error> {
     let proposal = query_proposal_by_id(client, proposal_id).await?;
-    let proposal = if let Some(proposal) = proposal {
-        proposal
-    } else {
-        return Ok(None);
+    let proposal = match proposal {
+        // A cancelled proposal is never tallied
+        Some(proposal) if !proposal.is_cancelled => proposal,
+        _ => return Ok(None),
     };
     let stored_proposal_result = convert_response::<C, Option<ProposalResult>>(
         RPC.vp().gov().proposal_result(client, &proposal_id).await,
@@ -1003,10 +1182,46 @@ pub async fn query_proposal_result<C: crate::queries::Client + Sync>(
                     }
                 }
             }
+            // The accounts that didn't vote follow their representatives
+            let represented_votes =
+                query_represented_votes(client, proposal_id)
+                    .await
+                    .unwrap_or_default();
+            for vote in represented_votes {
+                if is_validator(client, &vote.delegator).await? {
+                    continue;
+                }
+                let validators = get_delegation_validators(
+                    client,
+                    &vote.delegator,
+                    tally_epoch,
+                )
+                .await?;
+                for validator in validators {
+                    let voting_power = get_bond_amount_at(
+                        client,
+                        &vote.delegator,
+                        &validator,
+                        tally_epoch,
+                    )
+                    .await
+                    .unwrap_or_default();
+
+                    proposal_votes.add_delegator(
+                        &vote.delegator,
+                        &validator,
+                        voting_power,
+                        vote.data.clone(),
+                    );
+                }
+            }
+            let veto_threshold =
+                query_governance_parameters(client).await.veto_threshold;
             compute_proposal_result(
                 proposal_votes,
                 total_active_voting_power,
                 tally_type,
+                veto_threshold,
             )?
         }
     };
@@ -1107,6 +1322,79 @@ pub async fn query_proposal_votes<C: crate::queries::Client + Sync>(
     )
 }
 
+/// Query the full lifecycle record of a proposal
+pub async fn query_proposal_lifecycle<C: crate::queries::Client + Sync>(
+    client: &C,
+    proposal_id: u64,
+) -> Result<Option<ProposalLifecycle>, error::Error> {
+    convert_response::<C, Option<ProposalLifecycle>>(
+        RPC.vp()
+            .gov()
+            .proposal_id_lifecycle(client, &proposal_id)
+            .await,
+    )
+}
+
+/// Query a proposal waiting in the timelock queue
+pub async fn query_proposal_timelock<C: crate::queries::Client + Sync>(
+    client: &C,
+    proposal_id: u64,
+) -> Result<Option<QueuedProposal>, error::Error> {
+    convert_response::<C, Option<QueuedProposal>>(
+        RPC.vp()
+            .gov()
+            .proposal_id_timelock(client, &proposal_id)
+            .await,
+    )
+}
+
+/// Query all the passed proposals waiting in the timelock queue
+pub async fn query_timelock_queue<C: crate::queries::Client + Sync>(
+    client: &C,
+) -> Result<Vec<QueuedProposal>, error::Error> {
+    convert_response::<C, Vec<QueuedProposal>>(
+        RPC.vp().gov().timelock_queue(client).await,
+    )
+}
+
+/// Query the votes cast by governance representatives on behalf of the
+/// accounts that didn't vote on a proposal
+pub async fn query_represented_votes<C: crate::queries::Client + Sync>(
+    client: &C,
+    proposal_id: u64,
+) -> Result<Vec<RepresentedVote>, error::Error> {
+    convert_response::<C, Vec<RepresentedVote>>(
+        RPC.vp()
+            .gov()
+            .proposal_id_represented_votes(client, &proposal_id)
+            .await,
+    )
+}
+
+/// Query the governance representatives of a delegator for each scope
+pub async fn query_representatives<C: crate::queries::Client + Sync>(
+    client: &C,
+    delegator: &Address,
+) -> Result<BTreeMap<RepresentationScope, Address>, error::Error> {
+    convert_response::<C, _>(
+        RPC.vp().gov().representatives(client, delegator).await,
+    )
+}
+
+/// Query the accounts which directly delegated their governance voting power
+/// to the given representative
+pub async fn query_represented_accounts<C: crate::queries::Client + Sync>(
+    client: &C,
+    representative: &Address,
+) -> Result<BTreeSet<Address>, error::Error> {
+    convert_response::<C, _>(
+        RPC.vp()
+            .gov()
+            .represented_accounts(client, representative)
+            .await,
+    )
+}
+
 /// Query the information to estimate next epoch start
 pub async fn query_next_epoch_info<C: crate::queries::Client + Sync>(
     client: &C,
@@ -19,7 +19,8 @@ use namada_core::masp::{AssetData, ExtendedViewingKey, PaymentAddress, TxId};
 use namada_core::sign::SignatureIndex;
 use namada_core::token::{Amount, DenominatedAmount};
 use namada_governance::storage::proposal::{
-    InitProposalData, ProposalType, VoteProposalData,
+    CancelProposalData, DelegateVoteData, InitProposalData, ProposalType,
+    VetoProposalData, VoteProposalData,
 };
 use namada_governance::storage::vote::ProposalVote;
 use namada_ibc::{MsgNftTransfer, MsgTransfer};
@@ -42,18 +43,20 @@ use crate::io::*;
 use crate::rpc::validate_amount;
 use crate::token::Account;
 use crate::tx::{
-    Commitment, TX_BECOME_VALIDATOR_WASM, TX_BOND_WASM, TX_BRIDGE_POOL_WASM,
-    TX_CHANGE_COMMISSION_WASM, TX_CHANGE_CONSENSUS_KEY_WASM,
-    TX_CHANGE_METADATA_WASM, TX_CLAIM_REWARDS_WASM,
-    TX_DEACTIVATE_VALIDATOR_WASM, TX_IBC_WASM, TX_INIT_ACCOUNT_WASM,
-    TX_INIT_PROPOSAL, TX_REACTIVATE_VALIDATOR_WASM, TX_REDELEGATE_WASM,
-    TX_RESIGN_STEWARD, TX_REVEAL_PK, TX_TRANSFER_WASM, TX_UNBOND_WASM,
-    TX_UNJAIL_VALIDATOR_WASM, TX_UPDATE_ACCOUNT_WASM,
-    TX_UPDATE_STEWARD_COMMISSION, TX_VOTE_PROPOSAL, TX_WITHDRAW_WASM,
-    VP_USER_WASM,
+    Commitment, TX_AUTO_COMPOUND_WASM, TX_BECOME_VALIDATOR_WASM, TX_BOND_WASM,
+    TX_BRIDGE_POOL_WASM, TX_CANCEL_PROPOSAL,
+    TX_CHANGE_COMMISSION_SCHEDULE_WASM, TX_CHANGE_COMMISSION_WASM,
+    TX_CHANGE_CONSENSUS_KEY_WASM, TX_CHANGE_METADATA_WASM,
+    TX_CHANGE_VALIDATOR_KEYS_WASM, TX_CLAIM_REWARDS_WASM,
+    TX_DEACTIVATE_VALIDATOR_WASM, TX_DELEGATE_VOTE, TX_IBC_WASM,
+    TX_INIT_ACCOUNT_WASM, TX_INIT_PROPOSAL, TX_REACTIVATE_VALIDATOR_WASM,
+    TX_REBALANCE_DELEGATIONS_WASM, TX_REDELEGATE_WASM, TX_RESIGN_STEWARD,
+    TX_REVEAL_PK, TX_TRANSFER_WASM, TX_UNBOND_WASM, TX_UNJAIL_VALIDATOR_WASM,
+    TX_UPDATE_ACCOUNT_WASM, TX_UPDATE_STEWARD_COMMISSION, TX_VETO_PROPOSAL,
+    TX_VOTE_PROPOSAL, TX_WITHDRAW_WASM, VP_USER_WASM,
 };
 pub use crate::wallet::store::AddressVpType;
-use crate::wallet::{Wallet, WalletIo};
+use crate::wallet::{DisposableKeyPurpose, Wallet, WalletIo};
 use crate::{args, display_line, rpc, MaybeSend, Namada};
 
 /// A structure holding the signing data to craft a transaction
@@ -336,10 +339,17 @@ pub async fn aux_signing_data(
     };
 
     let fee_payer = if args.disposable_signing_key {
+        let created_at = rpc::query_block(context.client())
+            .await?
+            .map(|block| block.height);
         context
             .wallet_mut()
             .await
-            .gen_disposable_signing_key(&mut OsRng)
+            .gen_disposable_signing_key(
+                &mut OsRng,
+                created_at,
+                DisposableKeyPurpose::WrapperFeePayment,
+            )
             .to_public()
     } else {
         match &args.wrapper_fee_payer {
@@ -377,10 +387,17 @@ pub async fn init_validator_signing_data(
         Some(AccountPublicKeysMap::from_iter(validator_keys));
 
     let fee_payer = if args.disposable_signing_key {
+        let created_at = rpc::query_block(context.client())
+            .await?
+            .map(|block| block.height);
         context
             .wallet_mut()
             .await
-            .gen_disposable_signing_key(&mut OsRng)
+            .gen_disposable_signing_key(
+                &mut OsRng,
+                created_at,
+                DisposableKeyPurpose::WrapperFeePayment,
+            )
             .to_public()
     } else {
         match &args.wrapper_fee_payer {
@@ -806,6 +823,8 @@ impl<'a> Display for LedgerProposalVote<'a> {
             ProposalVote::Yay => write!(f, "yay"),
             ProposalVote::Nay => write!(f, "nay"),
             ProposalVote::Abstain => write!(f, "abstain"),
+            ProposalVote::Weighted(weighted) => write!(f, "{weighted}"),
+            ProposalVote::NoWithVeto => write!(f, "no with veto"),
         }
     }
 }
@@ -940,6 +959,25 @@ fn proposal_type_to_ledger_vector(
                 }
             }
         }
+        ProposalType::ParameterChange(changes) => {
+            output.push("Proposal type : Parameter Change".to_string());
+            for change in changes {
+                output.push(format!("Change : {}", change));
+            }
+        }
+        ProposalType::WasmAllowlist(changes) => {
+            output.push("Proposal type : Wasm Allowlist".to_string());
+            for change in changes {
+                match change {
+                    AddRemove::Add(entry) => {
+                        output.push(format!("Add : {}", entry));
+                    }
+                    AddRemove::Remove(entry) => {
+                        output.push(format!("Remove : {}", entry));
+                    }
+                }
+            }
+        }
     }
 }
 
@@ -1172,6 +1210,10 @@ pub async fn to_ledger_vector(
                 ),
                 format!("Content : {}", HEXLOWER.encode(&extra.0)),
             ]);
+            if init_proposal_data.is_expedited {
+                tv.output.push("Expedited : true".to_string());
+                tv.output_expert.push("Expedited : true".to_string());
+            }
         } else if code_sec.tag == Some(TX_VOTE_PROPOSAL.to_string()) {
             let vote_proposal = VoteProposalData::try_from_slice(
                 &tx.data(cmt)
@@ -1195,6 +1237,75 @@ pub async fn to_ledger_vector(
                 format!("Vote : {}", LedgerProposalVote(&vote_proposal.vote)),
                 format!("Voter : {}", vote_proposal.voter),
             ]);
+        } else if code_sec.tag == Some(TX_CANCEL_PROPOSAL.to_string()) {
+            let cancel_proposal = CancelProposalData::try_from_slice(
+                &tx.data(cmt)
+                    .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
+            )
+            .map_err(|err| {
+                Error::from(EncodingError::Conversion(err.to_string()))
+            })?;
+
+            tv.name = "Cancel_Proposal_0".to_string();
+
+            tv.output.extend(vec![
+                format!("Type : Cancel Proposal"),
+                format!("ID : {}", cancel_proposal.id),
+                format!("Author : {}", cancel_proposal.author),
+            ]);
+
+            tv.output_expert.extend(vec![
+                format!("ID : {}", cancel_proposal.id),
+                format!("Author : {}", cancel_proposal.author),
+            ]);
+        } else if code_sec.tag == Some(TX_VETO_PROPOSAL.to_string()) {
+            let veto_proposal = VetoProposalData::try_from_slice(
+                &tx.data(cmt)
+                    .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
+            )
+            .map_err(|err| {
+                Error::from(EncodingError::Conversion(err.to_string()))
+            })?;
+
+            tv.name = "Veto_Proposal_0".to_string();
+
+            tv.output.extend(vec![
+                format!("Type : Veto Proposal"),
+                format!("ID : {}", veto_proposal.id),
+                format!("Validator : {}", veto_proposal.validator),
+            ]);
+
+            tv.output_expert.extend(vec![
+                format!("ID : {}", veto_proposal.id),
+                format!("Validator : {}", veto_proposal.validator),
+            ]);
+        } else if code_sec.tag == Some(TX_DELEGATE_VOTE.to_string()) {
+            let delegate_vote = DelegateVoteData::try_from_slice(
+                &tx.data(cmt)
+                    .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
+            )
+            .map_err(|err| {
+                Error::from(EncodingError::Conversion(err.to_string()))
+            })?;
+
+            tv.name = "Delegate_Vote_0".to_string();
+
+            let representative = delegate_vote
+                .representative
+                .as_ref()
+                .map_or_else(|| "(revoked)".to_string(), ToString::to_string);
+            tv.output.extend(vec![
+                format!("Type : Delegate Vote"),
+                format!("Delegator : {}", delegate_vote.delegator),
+                format!("Representative : {}", representative),
+                format!("Scope : {}", delegate_vote.scope),
+            ]);
+
+            tv.output_expert.extend(vec![
+                format!("Delegator : {}", delegate_vote.delegator),
+                format!("Representative : {}", representative),
+                format!("Scope : {}", delegate_vote.scope),
+            ]);
         } else if code_sec.tag == Some(TX_REVEAL_PK.to_string()) {
             let public_key = common::PublicKey::try_from_slice(
                 &tx.data(cmt)
@@ -1693,6 +1804,33 @@ pub async fn to_ledger_vector(
             }
             tv.output_expert
                 .push(format!("Validator : {}", claim.validator));
+        } else if code_sec.tag == Some(TX_AUTO_COMPOUND_WASM.to_string()) {
+            let auto_compound = pos::AutoCompound::try_from_slice(
+                &tx.data(cmt)
+                    .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
+            )
+            .map_err(|err| {
+                Error::from(EncodingError::Conversion(err.to_string()))
+            })?;
+
+            tv.name = "Auto_Compound_0".to_string();
+
+            tv.output.push("Type : Auto Compound".to_string());
+            if let Some(source) = auto_compound.source.as_ref() {
+                tv.output.push(format!("Source : {}", source));
+            }
+            tv.output
+                .push(format!("Validator : {}", auto_compound.validator));
+            tv.output
+                .push(format!("Enabled : {}", auto_compound.enabled));
+
+            if let Some(source) = auto_compound.source.as_ref() {
+                tv.output_expert.push(format!("Source : {}", source));
+            }
+            tv.output_expert
+                .push(format!("Validator : {}", auto_compound.validator));
+            tv.output_expert
+                .push(format!("Enabled : {}", auto_compound.enabled));
         } else if code_sec.tag == Some(TX_CHANGE_COMMISSION_WASM.to_string()) {
             let commission_change = pos::CommissionChange::try_from_slice(
                 &tx.data(cmt)
@@ -1714,6 +1852,32 @@ pub async fn to_ledger_vector(
                 format!("New rate : {}", commission_change.new_rate),
                 format!("Validator : {}", commission_change.validator),
             ]);
+        } else if code_sec.tag
+            == Some(TX_CHANGE_COMMISSION_SCHEDULE_WASM.to_string())
+        {
+            let schedule_change =
+                pos::CommissionScheduleChange::try_from_slice(
+                    &tx.data(cmt).ok_or_else(|| {
+                        Error::Other("Invalid Data".to_string())
+                    })?,
+                )
+                .map_err(|err| {
+                    Error::from(EncodingError::Conversion(err.to_string()))
+                })?;
+
+            tv.name = "Change_Commission_Schedule_0".to_string();
+
+            tv.output.extend(vec![
+                format!("Type : Change commission schedule"),
+                format!("Validator : {}", schedule_change.validator),
+            ]);
+            tv.output_expert
+                .push(format!("Validator : {}", schedule_change.validator));
+            for (epoch, rate) in &schedule_change.schedule {
+                tv.output.push(format!("Epoch {} rate : {}", epoch, rate));
+                tv.output_expert
+                    .push(format!("Epoch {} rate : {}", epoch, rate));
+            }
         } else if code_sec.tag == Some(TX_CHANGE_METADATA_WASM.to_string()) {
             let metadata_change = pos::MetaDataChange::try_from_slice(
                 &tx.data(cmt)
@@ -1750,6 +1914,20 @@ pub async fn to_ledger_vector(
                 other_items
                     .push(format!("Commission rate : {}", commission_rate));
             }
+            if let Some(security_contact) = metadata_change.security_contact {
+                other_items
+                    .push(format!("Security contact : {}", security_contact));
+            }
+            for (platform, handle) in metadata_change.social_handles {
+                other_items.push(format!("Social {} : {}", platform, handle));
+            }
+            if let Some(identity_proof) = metadata_change.identity_proof {
+                other_items
+                    .push(format!("Identity proof : {}", identity_proof));
+            }
+            for (key, value) in metadata_change.extensions {
+                other_items.push(format!("Extension {} : {}", key, value));
+            }
 
             tv.output.extend(other_items.clone());
             tv.output_expert.extend(other_items);
@@ -1781,6 +1959,37 @@ pub async fn to_ledger_vector(
                 ),
                 format!("Validator : {}", consensus_key_change.validator),
             ]);
+        } else if code_sec.tag
+            == Some(TX_CHANGE_VALIDATOR_KEYS_WASM.to_string())
+        {
+            let keys_change = pos::ValidatorKeysChange::try_from_slice(
+                &tx.data(cmt)
+                    .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
+            )
+            .map_err(|err| {
+                Error::from(EncodingError::Conversion(err.to_string()))
+            })?;
+
+            tv.name = "Change_Validator_Keys_0".to_string();
+
+            let mut other_items = vec![];
+            if let Some(protocol_key) = &keys_change.protocol_key {
+                other_items
+                    .push(format!("New protocol key : {}", protocol_key));
+            }
+            if let Some(eth_hot_key) = &keys_change.eth_hot_key {
+                other_items
+                    .push(format!("New Ethereum hot key : {}", eth_hot_key));
+            }
+            if let Some(eth_cold_key) = &keys_change.eth_cold_key {
+                other_items
+                    .push(format!("New Ethereum cold key : {}", eth_cold_key));
+            }
+            other_items.push(format!("Validator : {}", keys_change.validator));
+
+            tv.output.push(format!("Type : Change validator keys"));
+            tv.output.extend(other_items.clone());
+            tv.output_expert.extend(other_items);
         } else if code_sec.tag == Some(TX_UNJAIL_VALIDATOR_WASM.to_string()) {
             let address = Address::try_from_slice(
                 &tx.data(cmt)
@@ -1871,6 +2080,34 @@ pub async fn to_ledger_vector(
                     to_ledger_decimal(&redelegation.amount.to_string_native())
                 ),
             ]);
+        } else if code_sec.tag
+            == Some(TX_REBALANCE_DELEGATIONS_WASM.to_string())
+        {
+            let rebalance = pos::RebalanceDelegations::try_from_slice(
+                &tx.data(cmt)
+                    .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
+            )
+            .map_err(|err| {
+                Error::from(EncodingError::Conversion(err.to_string()))
+            })?;
+
+            tv.name = "Rebalance_Delegations_0".to_string();
+
+            tv.output.extend(vec![
+                format!("Type : Rebalance delegations"),
+                format!("Owner : {}", rebalance.owner),
+            ]);
+            tv.output_expert
+                .push(format!("Owner : {}", rebalance.owner));
+            for (validator, amount) in &rebalance.targets {
+                let target = format!(
+                    "Target {} : {}",
+                    validator,
+                    to_ledger_decimal(&amount.to_string_native())
+                );
+                tv.output.push(target.clone());
+                tv.output_expert.push(target);
+            }
         } else if code_sec.tag == Some(TX_UPDATE_STEWARD_COMMISSION.to_string())
         {
             let update = UpdateStewardCommission::try_from_slice(
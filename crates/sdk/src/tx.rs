@@ -1,7 +1,7 @@
 //! SDK functions to construct different types of transactions
 
 use std::borrow::Cow;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs::File;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
@@ -44,25 +44,35 @@ use namada_core::storage;
 use namada_core::storage::Epoch;
 use namada_core::time::DateTimeUtc;
 use namada_governance::cli::onchain::{
-    DefaultProposal, OnChainProposal, PgfFundingProposal, PgfStewardProposal,
+    DefaultProposal, OnChainProposal, ParameterChangeProposal,
+    PgfFundingProposal, PgfStewardProposal, WasmAllowlistProposal,
 };
 use namada_governance::pgf::cli::steward::Commission;
 use namada_governance::storage::proposal::{
-    InitProposalData, ProposalType, VoteProposalData,
+    CancelProposalData, DelegateVoteData, InitProposalData, ProposalType,
+    VetoProposalData, VoteProposalData,
 };
 use namada_governance::storage::vote::ProposalVote;
 use namada_ibc::storage::channel_key;
 use namada_ibc::trace::is_nft_trace;
 use namada_ibc::{IbcShieldingData, MsgNftTransfer, MsgTransfer};
+use namada_proof_of_stake::metadata::{
+    apply_metadata_change, validate_structured_metadata,
+};
 use namada_proof_of_stake::parameters::{
     PosParams, MAX_VALIDATOR_METADATA_LEN,
 };
-use namada_proof_of_stake::types::{CommissionPair, ValidatorState};
+use namada_proof_of_stake::rebalance::compute_rebalance_plan;
+use namada_proof_of_stake::types::{
+    CommissionPair, RebalanceStep, ValidatorState,
+};
 use namada_token as token;
 use namada_token::storage_key::balance_key;
 use namada_token::DenominatedAmount;
 use namada_tx::data::pgf::UpdateStewardCommission;
-use namada_tx::data::pos::{BecomeValidator, ConsensusKeyChange};
+use namada_tx::data::pos::{
+    BecomeValidator, ConsensusKeyChange, ValidatorKeysChange,
+};
 use namada_tx::data::{
     compute_inner_tx_hash, pos, BatchedTxResult, DryRunResult, ResultCode,
 };
@@ -71,7 +81,7 @@ use num_traits::Zero;
 use rand_core::{OsRng, RngCore};
 
 use crate::args::{
-    SdkTypes, TxShieldedTransferData, TxShieldingTransferData,
+    InputAmount, SdkTypes, TxShieldedTransferData, TxShieldingTransferData,
     TxTransparentTransferData, TxUnshieldingTransferData,
 };
 use crate::control_flow::time;
@@ -80,7 +90,7 @@ use crate::io::Io;
 use crate::masp::TransferErr::Build;
 use crate::masp::{
     MaspDataLog, MaspFeeData, MaspTransferData, ShieldedContext,
-    ShieldedTransfer,
+    ShieldedTransfer, SpentNotesTracker,
 };
 use crate::queries::Client;
 use crate::rpc::{
@@ -109,6 +119,12 @@ pub const TX_REACTIVATE_VALIDATOR_WASM: &str = "tx_reactivate_validator.wasm";
 pub const TX_INIT_PROPOSAL: &str = "tx_init_proposal.wasm";
 /// Vote transaction WASM path
 pub const TX_VOTE_PROPOSAL: &str = "tx_vote_proposal.wasm";
+/// Cancel proposal transaction WASM path
+pub const TX_CANCEL_PROPOSAL: &str = "tx_cancel_proposal.wasm";
+/// Veto timelocked proposal transaction WASM path
+pub const TX_VETO_PROPOSAL: &str = "tx_veto_proposal.wasm";
+/// Delegate governance voting power transaction WASM path
+pub const TX_DELEGATE_VOTE: &str = "tx_delegate_vote.wasm";
 /// Reveal public key transaction WASM path
 pub const TX_REVEAL_PK: &str = "tx_reveal_pk.wasm";
 /// Update validity predicate WASM path
@@ -127,13 +143,20 @@ pub const TX_UNBOND_WASM: &str = "tx_unbond.wasm";
 pub const TX_WITHDRAW_WASM: &str = "tx_withdraw.wasm";
 /// Claim-rewards WASM path
 pub const TX_CLAIM_REWARDS_WASM: &str = "tx_claim_rewards.wasm";
+/// Rewards auto-compounding WASM path
+pub const TX_AUTO_COMPOUND_WASM: &str = "tx_auto_compound.wasm";
 /// Bridge pool WASM path
 pub const TX_BRIDGE_POOL_WASM: &str = "tx_bridge_pool.wasm";
 /// Change commission WASM path
 pub const TX_CHANGE_COMMISSION_WASM: &str =
     "tx_change_validator_commission.wasm";
+/// Change commission schedule WASM path
+pub const TX_CHANGE_COMMISSION_SCHEDULE_WASM: &str =
+    "tx_change_validator_commission_schedule.wasm";
 /// Change consensus key WASM path
 pub const TX_CHANGE_CONSENSUS_KEY_WASM: &str = "tx_change_consensus_key.wasm";
+/// Change validator protocol and Ethereum keys WASM path
+pub const TX_CHANGE_VALIDATOR_KEYS_WASM: &str = "tx_change_validator_keys.wasm";
 /// Change validator metadata WASM path
 pub const TX_CHANGE_METADATA_WASM: &str = "tx_change_validator_metadata.wasm";
 /// Resign steward WASM path
@@ -143,6 +166,8 @@ pub const TX_UPDATE_STEWARD_COMMISSION: &str =
     "tx_update_steward_commission.wasm";
 /// Redelegate transaction WASM path
 pub const TX_REDELEGATE_WASM: &str = "tx_redelegate.wasm";
+/// Rebalance delegations transaction WASM path
+pub const TX_REBALANCE_DELEGATIONS_WASM: &str = "tx_rebalance_delegations.wasm";
 
 /// Default timeout in seconds for requests to the `/accepted`
 /// and `/applied` ABCI query endpoints.
@@ -582,6 +607,89 @@ pub async fn build_change_consensus_key(
     .map(|tx| (tx, signing_data))
 }
 
+/// Submit validator protocol and Ethereum keys change
+pub async fn build_change_validator_keys(
+    context: &impl Namada,
+    args::ValidatorKeysChange {
+        tx: tx_args,
+        validator,
+        protocol_key,
+        eth_hot_key,
+        eth_cold_key,
+        tx_code_path,
+    }: &args::ValidatorKeysChange,
+) -> Result<(Tx, SigningTxData)> {
+    if protocol_key.is_none() && eth_hot_key.is_none() && eth_cold_key.is_none()
+    {
+        edisplay_line!(
+            context.io(),
+            "At least one of the protocol, Eth hot or Eth cold keys must be \
+             present."
+        );
+        return Err(Error::Other("No validator keys to change".to_string()));
+    }
+
+    if !rpc::is_validator(context.client(), validator).await? {
+        edisplay_line!(
+            context.io(),
+            "The given address {validator} is not a validator."
+        );
+        if !tx_args.force {
+            return Err(Error::from(TxSubmitError::InvalidValidatorAddress(
+                validator.clone(),
+            )));
+        }
+    }
+
+    let mut eth_keys = [None, None];
+    for ((name, key), eth_key) in [("hot", eth_hot_key), ("cold", eth_cold_key)]
+        .into_iter()
+        .zip(eth_keys.iter_mut())
+    {
+        if let Some(key) = key {
+            let Ok(key) = key::secp256k1::PublicKey::try_from_pk(key) else {
+                edisplay_line!(
+                    context.io(),
+                    "The Eth {name} key must be secp256k1."
+                );
+                return Err(Error::Other(format!(
+                    "Eth {name} key must be secp256k1"
+                )));
+            };
+            *eth_key = Some(key);
+        }
+    }
+    let [eth_hot_key, eth_cold_key] = eth_keys;
+
+    let data = ValidatorKeysChange {
+        validator: validator.clone(),
+        protocol_key: protocol_key.clone(),
+        eth_hot_key,
+        eth_cold_key,
+    };
+
+    // The tx must also be signed with the new keys to verify their ownership
+    let signing_data =
+        signing::init_validator_signing_data(context, tx_args, data.new_keys())
+            .await?;
+
+    let (fee_amount, _updated_balance) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
 /// Submit validator commission rate change
 pub async fn build_validator_commission_change(
     context: &impl Namada,
@@ -717,6 +825,151 @@ pub async fn build_validator_commission_change(
     .map(|tx| (tx, signing_data))
 }
 
+/// Submit validator commission schedule change
+pub async fn build_validator_commission_schedule_change(
+    context: &impl Namada,
+    args::CommissionScheduleChange {
+        tx: tx_args,
+        validator,
+        schedule,
+        tx_code_path,
+    }: &args::CommissionScheduleChange,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(validator.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(validator.clone()),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    let epoch = rpc::query_epoch(context.client()).await?;
+
+    let params: PosParams = rpc::get_pos_params(context.client()).await?;
+
+    let validator = validator.clone();
+    if rpc::is_validator(context.client(), &validator).await? {
+        let pipeline_epoch = epoch.unchecked_add(params.pipeline_len);
+
+        let CommissionPair {
+            commission_rate,
+            max_commission_change_per_epoch,
+            epoch: _,
+        } = rpc::query_commission_rate(
+            context.client(),
+            &validator,
+            Some(pipeline_epoch),
+        )
+        .await?;
+
+        match (commission_rate, max_commission_change_per_epoch) {
+            (Some(commission_rate), Some(max_commission_change_per_epoch)) => {
+                let mut prev_epoch = pipeline_epoch;
+                let mut prev_rate = commission_rate;
+                for (sched_epoch, rate) in schedule {
+                    if *sched_epoch <= prev_epoch {
+                        edisplay_line!(
+                            context.io(),
+                            "Scheduled epoch {sched_epoch} must be after the \
+                             pipeline epoch {pipeline_epoch} and after every \
+                             preceding scheduled epoch."
+                        );
+                        if !tx_args.force {
+                            return Err(Error::from(
+                                TxSubmitError::InvalidCommissionScheduleEpoch(
+                                    *sched_epoch,
+                                    pipeline_epoch,
+                                ),
+                            ));
+                        }
+                    }
+                    if rate.is_negative() || *rate > Dec::one() {
+                        edisplay_line!(
+                            context.io(),
+                            "Scheduled rate {rate} is outside of the allowed \
+                             range of values between 0.0 and 1.0."
+                        );
+                        if !tx_args.force {
+                            return Err(Error::from(
+                                TxSubmitError::InvalidCommissionRate(*rate),
+                            ));
+                        }
+                    }
+                    if rate.abs_diff(prev_rate)?
+                        > max_commission_change_per_epoch
+                    {
+                        edisplay_line!(
+                            context.io(),
+                            "Scheduled rate {rate} at epoch {sched_epoch} is \
+                             too large of a change with respect to the \
+                             preceding rate {prev_rate}."
+                        );
+                        if !tx_args.force {
+                            return Err(Error::from(
+                                TxSubmitError::TooLargeOfChange(*rate),
+                            ));
+                        }
+                    }
+                    prev_epoch = *sched_epoch;
+                    prev_rate = *rate;
+                }
+            }
+            (None, None) => {
+                edisplay_line!(
+                    context.io(),
+                    "Error retrieving commission data from validator storage. \
+                     This address may not yet be a validator."
+                );
+                if !tx_args.force {
+                    return Err(Error::from(TxSubmitError::Retrieval));
+                }
+            }
+            _ => {
+                edisplay_line!(
+                    context.io(),
+                    "Error retrieving some of the commission data from \
+                     validator storage, while other data was found. This is a \
+                     bug and should be reported."
+                );
+                if !tx_args.force {
+                    return Err(Error::from(TxSubmitError::Retrieval));
+                }
+            }
+        }
+    } else {
+        edisplay_line!(
+            context.io(),
+            "The given address {validator} is not a validator."
+        );
+        if !tx_args.force {
+            return Err(Error::from(TxSubmitError::InvalidValidatorAddress(
+                validator,
+            )));
+        }
+    }
+
+    let data = pos::CommissionScheduleChange {
+        validator: validator.clone(),
+        schedule: schedule.clone(),
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
 /// Submit validator metadata change
 pub async fn build_validator_metadata_change(
     context: &impl Namada,
@@ -730,6 +983,10 @@ pub async fn build_validator_metadata_change(
         avatar,
         name,
         commission_rate,
+        security_contact,
+        social_handles,
+        identity_proof,
+        extensions,
         tx_code_path,
     }: &args::MetaDataChange,
 ) -> Result<(Tx, SigningTxData)> {
@@ -920,8 +1177,27 @@ pub async fn build_validator_metadata_change(
         avatar: avatar.clone(),
         name: name.clone(),
         commission_rate: *commission_rate,
+        security_contact: security_contact.clone(),
+        social_handles: social_handles.clone(),
+        identity_proof: *identity_proof,
+        extensions: extensions.clone(),
     };
 
+    // Check that the resulting metadata passes the validation rules
+    if let Some(mut metadata) =
+        rpc::query_structured_metadata(context.client(), &validator).await?
+    {
+        apply_metadata_change(&mut metadata, &data);
+        if let Err(err) = validate_structured_metadata(&metadata) {
+            edisplay_line!(context.io(), "Invalid validator metadata: {err}");
+            if !tx_args.force {
+                return Err(Error::from(TxSubmitError::InvalidMetadata(
+                    err.to_string(),
+                )));
+            }
+        }
+    }
+
     build(
         context,
         tx_args,
@@ -1423,6 +1699,18 @@ pub async fn build_redelegation(
         }
     }
 
+    // The redelegation must not take the dest validator over its stake cap
+    stake_cap_or_err(
+        context,
+        &params,
+        &dest_validator,
+        *redel_amount,
+        token::Amount::zero(),
+        pipeline_epoch,
+        tx_args.force,
+    )
+    .await?;
+
     // There must be at least as many tokens in the bond as the requested
     // redelegation amount
     let bond_amount =
@@ -1488,17 +1776,120 @@ pub async fn build_redelegation(
     .map(|tx| (tx, signing_data))
 }
 
-/// Submit transaction to withdraw an unbond
-pub async fn build_withdraw(
+/// Compute the redelegations, unbonds and bonds needed to rebalance the
+/// owner's bonds to the target distribution in the current epoch
+pub async fn preview_rebalance_delegations(
     context: &impl Namada,
-    args::Withdraw {
+    args::RebalanceDelegations {
         tx: tx_args,
-        validator,
-        source,
-        tx_code_path,
-    }: &args::Withdraw,
+        owner,
+        targets,
+        tx_code_path: _,
+    }: &args::RebalanceDelegations,
+) -> Result<Vec<RebalanceStep>> {
+    // The delegator (owner) must not be a validator
+    if rpc::is_validator(context.client(), owner).await? {
+        edisplay_line!(
+            context.io(),
+            "The given address {} is a validator. A validator is prohibited \
+             from rebalancing its own bonds.",
+            owner
+        );
+        if !tx_args.force {
+            return Err(Error::from(TxSubmitError::RedelegatorIsValidator(
+                owner.clone(),
+            )));
+        }
+    }
+
+    // The targets must be distinct validators
+    let mut target_map = BTreeMap::new();
+    for (validator, amount) in targets {
+        let validator =
+            known_validator_or_err(validator.clone(), tx_args.force, context)
+                .await?;
+        if target_map.insert(validator.clone(), *amount).is_some() {
+            edisplay_line!(
+                context.io(),
+                "The validator {} is given more than once in the targets.",
+                validator
+            );
+            if !tx_args.force {
+                return Err(Error::from(
+                    TxSubmitError::DuplicateRebalanceTarget(validator),
+                ));
+            }
+        }
+    }
+
+    let params = rpc::get_pos_params(context.client()).await?;
+    let current_epoch = rpc::query_epoch(context.client()).await?;
+    let pipeline_epoch = current_epoch.unchecked_add(params.pipeline_len);
+    let current: BTreeMap<Address, token::Amount> =
+        rpc::get_delegations_of_delegator_at(
+            context.client(),
+            owner,
+            pipeline_epoch,
+        )
+        .await?
+        .into_iter()
+        .filter(|(_, amount)| !amount.is_zero())
+        .collect();
+
+    // Bonds holding incoming redelegations that may still be slashed cannot
+    // be redelegated again
+    let mut redelegation_sources = BTreeSet::new();
+    for validator in current.keys() {
+        let incoming_redel_epoch = rpc::query_incoming_redelegations(
+            context.client(),
+            validator,
+            owner,
+        )
+        .await?;
+        let is_not_chained = if let Some(redel_end_epoch) = incoming_redel_epoch
+        {
+            let last_contrib_epoch =
+                redel_end_epoch.prev().expect("End epoch must have a prev");
+            last_contrib_epoch
+                .unchecked_add(params.slash_processing_epoch_offset())
+                <= current_epoch
+        } else {
+            true
+        };
+        if is_not_chained {
+            redelegation_sources.insert(validator.clone());
+        }
+    }
+
+    compute_rebalance_plan(&current, &target_map, &redelegation_sources)
+        .map_err(|err| Error::Other(err.to_string()))
+}
+
+/// Submit transaction to rebalance delegations
+pub async fn build_rebalance_delegations(
+    context: &impl Namada,
+    args: &args::RebalanceDelegations,
 ) -> Result<(Tx, SigningTxData)> {
-    let default_address = source.clone().unwrap_or(validator.clone());
+    let tx_args = &args.tx;
+    let owner =
+        source_exists_or_err(args.owner.clone(), tx_args.force, context)
+            .await?;
+
+    let plan = preview_rebalance_delegations(context, args).await?;
+    if plan.is_empty() {
+        display_line!(
+            context.io(),
+            "The bonds of {} already match the target distribution.",
+            owner
+        );
+    } else {
+        display_line!(context.io(), "Rebalancing the bonds of {}:", owner);
+        for step in &plan {
+            display_line!(context.io(), "  {}", step);
+        }
+    }
+
+    let default_address = owner.clone();
     let default_signer = Some(default_address.clone());
     let signing_data = signing::aux_signing_data(
         context,
@@ -1507,14 +1898,82 @@ pub async fn build_withdraw(
         default_signer,
     )
     .await?;
-    let (fee_amount, _) =
+    let (fee_amount, updated_balance) =
         validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
             .await?;
 
-    let epoch = rpc::query_epoch(context.client()).await?;
+    // Check the owner's balance for the new bonds
+    let bonded =
+        token::Amount::sum(plan.iter().filter_map(|step| match step {
+            RebalanceStep::Bond { amount, .. } => Some(*amount),
+            _ => None,
+        }))
+        .ok_or_else(|| Error::Other("Bond amount overflow".to_string()))?;
+    if !bonded.is_zero() {
+        let native_token = context.native_token();
+        let check_balance = if updated_balance.source == owner
+            && updated_balance.token == native_token
+        {
+            CheckBalance::Balance(updated_balance.post_balance)
+        } else {
+            CheckBalance::Query(balance_key(&native_token, &owner))
+        };
+        check_balance_too_low_err(
+            &native_token,
+            &owner,
+            bonded,
+            check_balance,
+            tx_args.force,
+            context,
+        )
+        .await?;
+    }
 
-    // Check that the validator address is actually a validator
-    let validator =
+    let data = pos::RebalanceDelegations {
+        owner,
+        targets: args.targets.clone(),
+    };
+
+    build(
+        context,
+        tx_args,
+        args.tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Submit transaction to withdraw an unbond
+pub async fn build_withdraw(
+    context: &impl Namada,
+    args::Withdraw {
+        tx: tx_args,
+        validator,
+        source,
+        tx_code_path,
+    }: &args::Withdraw,
+) -> Result<(Tx, SigningTxData)> {
+    let default_address = source.clone().unwrap_or(validator.clone());
+    let default_signer = Some(default_address.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(default_address),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    let epoch = rpc::query_epoch(context.client()).await?;
+
+    // Check that the validator address is actually a validator
+    let validator =
         known_validator_or_err(validator.clone(), tx_args.force, context)
             .await?;
 
@@ -1625,6 +2084,84 @@ pub async fn build_claim_rewards(
     .map(|tx| (tx, signing_data))
 }
 
+/// Submit transaction to opt in or out of rewards auto-compounding
+pub async fn build_auto_compound(
+    context: &impl Namada,
+    args::AutoCompound {
+        tx: tx_args,
+        validator,
+        source,
+        disable,
+        tx_code_path,
+    }: &args::AutoCompound,
+) -> Result<(Tx, SigningTxData)> {
+    let default_address = source.clone().unwrap_or(validator.clone());
+    let default_signer = Some(default_address.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(default_address.clone()),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    // Check that the validator address is actually a validator
+    let validator =
+        known_validator_or_err(validator.clone(), tx_args.force, context)
+            .await?;
+
+    // Check that the source address exists on chain
+    let source = match source.clone() {
+        Some(source) => source_exists_or_err(source, tx_args.force, context)
+            .await
+            .map(Some),
+        None => Ok(source.clone()),
+    }?;
+
+    // Check that there is a bond whose rewards can be compounded
+    if !disable {
+        let bond = rpc::query_bond(
+            context.client(),
+            &default_address,
+            &validator,
+            None,
+        )
+        .await?;
+        if bond.is_zero() {
+            edisplay_line!(
+                context.io(),
+                "No bond from {} to validator {} was found.",
+                default_address,
+                validator
+            );
+            if !tx_args.force {
+                return Err(Error::from(TxSubmitError::NoBondFound));
+            }
+        }
+    }
+
+    let data = pos::AutoCompound {
+        validator,
+        source,
+        enabled: !disable,
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
 /// Submit a transaction to unbond
 pub async fn build_unbond(
     context: &impl Namada,
@@ -1730,6 +2267,45 @@ pub async fn build_unbond(
         }
     }
 
+    // A validator must keep the minimum self-bond, unless it's jailed or
+    // inactive
+    if bond_source == validator {
+        let params: PosParams = rpc::get_pos_params(context.client()).await?;
+        let remaining = bond_amount.checked_sub(*amount).unwrap_or_default();
+        if remaining < params.min_self_bond {
+            let current_epoch = rpc::query_epoch(context.client()).await?;
+            let pipeline_epoch =
+                current_epoch.unchecked_add(params.pipeline_len);
+            let (validator_state_at_pipeline, _) = rpc::get_validator_state(
+                context.client(),
+                &validator,
+                Some(pipeline_epoch),
+            )
+            .await?;
+            if !matches!(
+                validator_state_at_pipeline,
+                Some(ValidatorState::Jailed | ValidatorState::Inactive)
+            ) {
+                edisplay_line!(
+                    context.io(),
+                    "The validator {} cannot unbond its self-bond below the \
+                     minimum of {}. The remaining self-bond would be {}. \
+                     Deactivate the validator first to unbond more.",
+                    validator,
+                    params.min_self_bond.to_string_native(),
+                    remaining.to_string_native(),
+                );
+                if !tx_args.force {
+                    return Err(Error::from(TxSubmitError::SelfBondBelowMin(
+                        validator.clone(),
+                        remaining.to_string_native(),
+                        params.min_self_bond.to_string_native(),
+                    )));
+                }
+            }
+        }
+    }
+
     // Query the unbonds before submitting the tx
     let unbonds = rpc::query_unbond_with_slashing(
         context.client(),
@@ -1921,6 +2497,48 @@ pub async fn build_bond(
         }
     }
 
+    // The bond must not take the validator over its stake cap
+    stake_cap_or_err(
+        context,
+        &params,
+        &validator,
+        *amount,
+        *amount,
+        pipeline_epoch,
+        tx_args.force,
+    )
+    .await?;
+
+    // A self-bond must give the validator at least the minimum self-bond
+    if source.as_ref().map_or(true, |source| source == &validator) {
+        let self_bond = rpc::query_validator_self_bond(
+            context.client(),
+            &validator,
+            Some(pipeline_epoch),
+        )
+        .await?
+        .checked_add(*amount)
+        .ok_or_else(|| Error::Other("Self-bond overflow".to_string()))?;
+        if self_bond < params.min_self_bond {
+            edisplay_line!(
+                context.io(),
+                "The self-bond of validator {} would be {} at the pipeline \
+                 epoch {}, which is below the minimum of {}.",
+                validator,
+                self_bond.to_string_native(),
+                pipeline_epoch,
+                params.min_self_bond.to_string_native(),
+            );
+            if !tx_args.force {
+                return Err(Error::from(TxSubmitError::SelfBondBelowMin(
+                    validator.clone(),
+                    self_bond.to_string_native(),
+                    params.min_self_bond.to_string_native(),
+                )));
+            }
+        }
+    }
+
     let default_address = source.clone().unwrap_or(validator.clone());
     let default_signer = Some(default_address.clone());
     let signing_data = signing::aux_signing_data(
@@ -1982,6 +2600,8 @@ pub async fn build_default_proposal(
         proposal_data: _,
         is_pgf_stewards: _,
         is_pgf_funding: _,
+        is_parameter_change: _,
+        is_wasm_allowlist: _,
         tx_code_path,
     }: &args::InitProposal,
     proposal: DefaultProposal,
@@ -2050,8 +2670,11 @@ pub async fn build_vote_proposal(
     let (fee_amount, _) =
         validate_transparent_fee(context, tx, &signing_data.fee_payer).await?;
 
-    let proposal_vote = ProposalVote::try_from(vote.clone())
-        .map_err(|_| TxSubmitError::InvalidProposalVote)?;
+    let proposal_vote =
+        ProposalVote::try_from(vote.clone()).map_err(|err| {
+            edisplay_line!(context.io(), "Invalid vote {}: {}", vote, err);
+            TxSubmitError::InvalidProposalVote
+        })?;
 
     let proposal = if let Some(proposal) =
         rpc::query_proposal_by_id(context.client(), *proposal_id).await?
@@ -2083,86 +2706,311 @@ pub async fn build_vote_proposal(
             );
         }
         if !tx.force {
-            return Err(Error::from(
-                TxSubmitError::InvalidProposalVotingPeriod(*proposal_id),
+            return Err(Error::from(
+                TxSubmitError::InvalidProposalVotingPeriod(*proposal_id),
+            ));
+        }
+    }
+
+    if is_validator {
+        // Prevent a validator voter from voting if they are jailed or inactive
+        // right now
+        let state = rpc::get_validator_state(
+            context.client(),
+            voter_address,
+            Some(current_epoch),
+        )
+        .await?
+        .0
+        .expect("Expected to find the state of the validator");
+
+        if matches!(state, ValidatorState::Jailed | ValidatorState::Inactive) {
+            edisplay_line!(
+                context.io(),
+                "The voter {} is a validator who is currently jailed or \
+                 inactive. Thus, this address is prohibited from voting in \
+                 governance right now. Please try again when not jailed or \
+                 inactive.",
+                voter_address
+            );
+            if !tx.force {
+                return Err(Error::from(
+                    TxSubmitError::CannotVoteInGovernance(
+                        voter_address.clone(),
+                        current_epoch,
+                    ),
+                ));
+            }
+        }
+
+        let stake =
+            get_validator_stake(context.client(), current_epoch, voter_address)
+                .await?;
+
+        if stake.is_zero() {
+            edisplay_line!(
+                context.io(),
+                "Voter address {voter_address} is a validator but has no \
+                 stake, so it has no votes.",
+            );
+            if !tx.force {
+                return Err(Error::Other(
+                    "Voter address must have delegations".to_string(),
+                ));
+            }
+        }
+    } else {
+        // Check that there are delegations to vote with
+        let delegation_validators = rpc::get_delegation_validators(
+            context.client(),
+            voter_address,
+            current_epoch,
+        )
+        .await?;
+
+        // An account without delegations can still vote on behalf of the
+        // accounts it represents
+        let represented_accounts =
+            rpc::query_represented_accounts(context.client(), voter_address)
+                .await?;
+
+        if delegation_validators.is_empty() && represented_accounts.is_empty() {
+            edisplay_line!(
+                context.io(),
+                "Voter address {voter_address} does not have any delegations \
+                 and does not represent any account.",
+            );
+            if !tx.force {
+                return Err(Error::from(TxSubmitError::NoDelegationsFound(
+                    voter_address.clone(),
+                    current_epoch,
+                )));
+            }
+        }
+    };
+
+    let data = VoteProposalData {
+        id: *proposal_id,
+        vote: proposal_vote,
+        voter: voter_address.clone(),
+    };
+
+    build(
+        context,
+        tx,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Build a proposal cancellation
+pub async fn build_cancel_proposal(
+    context: &impl Namada,
+    args::CancelProposal {
+        tx,
+        proposal_id,
+        author,
+        tx_code_path,
+    }: &args::CancelProposal,
+    current_epoch: Epoch,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(author.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx,
+        default_signer.clone(),
+        default_signer.clone(),
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx, &signing_data.fee_payer).await?;
+
+    let proposal = if let Some(proposal) =
+        rpc::query_proposal_by_id(context.client(), *proposal_id).await?
+    {
+        proposal
+    } else {
+        return Err(Error::from(TxSubmitError::ProposalDoesNotExist(
+            *proposal_id,
+        )));
+    };
+
+    if proposal.author != *author {
+        edisplay_line!(
+            context.io(),
+            "Address {} is not the author of proposal {}.",
+            author,
+            proposal_id
+        );
+        if !tx.force {
+            return Err(Error::from(TxSubmitError::InvalidProposalAuthor(
+                author.clone(),
+                *proposal_id,
+            )));
+        }
+    }
+
+    // Check that the voting period has not started yet
+    if !proposal.can_be_cancelled(current_epoch) {
+        edisplay_line!(
+            context.io(),
+            "Proposal {} cannot be cancelled anymore.",
+            proposal_id
+        );
+        if !tx.force {
+            return Err(Error::from(
+                TxSubmitError::InvalidProposalCancellation(*proposal_id),
+            ));
+        }
+    }
+
+    let data = CancelProposalData {
+        id: *proposal_id,
+        author: author.clone(),
+    };
+
+    build(
+        context,
+        tx,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Build a validator veto on a proposal waiting in the timelock queue
+pub async fn build_veto_proposal(
+    context: &impl Namada,
+    args::VetoProposal {
+        tx,
+        proposal_id,
+        validator,
+        tx_code_path,
+    }: &args::VetoProposal,
+    current_epoch: Epoch,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(validator.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx,
+        default_signer.clone(),
+        default_signer.clone(),
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx, &signing_data.fee_payer).await?;
+
+    if !rpc::is_validator(context.client(), validator).await? {
+        edisplay_line!(
+            context.io(),
+            "The given address {validator} is not a validator."
+        );
+        if !tx.force {
+            return Err(Error::from(TxSubmitError::InvalidValidatorAddress(
+                validator.clone(),
+            )));
+        }
+    }
+
+    // Check that the proposal is still waiting in the timelock queue
+    let is_vetoable =
+        rpc::query_proposal_timelock(context.client(), *proposal_id)
+            .await?
+            .is_some_and(|queued| {
+                current_epoch < queued.timelock.execution_epoch
+            });
+    if !is_vetoable {
+        edisplay_line!(
+            context.io(),
+            "Proposal {} is not waiting in the timelock queue.",
+            proposal_id
+        );
+        if !tx.force {
+            return Err(Error::from(TxSubmitError::InvalidProposalVeto(
+                *proposal_id,
+            )));
+        }
+    }
+
+    let data = VetoProposalData {
+        id: *proposal_id,
+        validator: validator.clone(),
+    };
+
+    build(
+        context,
+        tx,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Build a governance vote delegation
+pub async fn build_delegate_vote(
+    context: &impl Namada,
+    args::DelegateVote {
+        tx,
+        delegator,
+        representative,
+        scope,
+        tx_code_path,
+    }: &args::DelegateVote,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(delegator.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx,
+        default_signer.clone(),
+        default_signer.clone(),
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx, &signing_data.fee_payer).await?;
+
+    if representative.as_ref() == Some(delegator) {
+        edisplay_line!(
+            context.io(),
+            "Address {delegator} cannot be its own representative.",
+        );
+        if !tx.force {
+            return Err(Error::Other(
+                "Representative must be different from the delegator"
+                    .to_string(),
             ));
         }
     }
 
-    if is_validator {
-        // Prevent a validator voter from voting if they are jailed or inactive
-        // right now
-        let state = rpc::get_validator_state(
-            context.client(),
-            voter_address,
-            Some(current_epoch),
-        )
-        .await?
-        .0
-        .expect("Expected to find the state of the validator");
-
-        if matches!(state, ValidatorState::Jailed | ValidatorState::Inactive) {
-            edisplay_line!(
-                context.io(),
-                "The voter {} is a validator who is currently jailed or \
-                 inactive. Thus, this address is prohibited from voting in \
-                 governance right now. Please try again when not jailed or \
-                 inactive.",
-                voter_address
-            );
-            if !tx.force {
-                return Err(Error::from(
-                    TxSubmitError::CannotVoteInGovernance(
-                        voter_address.clone(),
-                        current_epoch,
-                    ),
-                ));
-            }
-        }
-
-        let stake =
-            get_validator_stake(context.client(), current_epoch, voter_address)
-                .await?;
-
-        if stake.is_zero() {
-            edisplay_line!(
-                context.io(),
-                "Voter address {voter_address} is a validator but has no \
-                 stake, so it has no votes.",
-            );
-            if !tx.force {
-                return Err(Error::Other(
-                    "Voter address must have delegations".to_string(),
-                ));
-            }
-        }
-    } else {
-        // Check that there are delegations to vote with
-        let delegation_validators = rpc::get_delegation_validators(
-            context.client(),
-            voter_address,
-            current_epoch,
-        )
-        .await?;
-
-        if delegation_validators.is_empty() {
-            edisplay_line!(
-                context.io(),
-                "Voter address {voter_address} does not have any delegations.",
-            );
-            if !tx.force {
-                return Err(Error::from(TxSubmitError::NoDelegationsFound(
-                    voter_address.clone(),
-                    current_epoch,
-                )));
-            }
+    if rpc::is_validator(context.client(), delegator).await? {
+        edisplay_line!(
+            context.io(),
+            "Address {delegator} is a validator. Validators vote with their \
+             own stake and cannot delegate their voting power.",
+        );
+        if !tx.force {
+            return Err(Error::Other(
+                "Validators cannot delegate their voting power".to_string(),
+            ));
         }
-    };
+    }
 
-    let data = VoteProposalData {
-        id: *proposal_id,
-        vote: proposal_vote,
-        voter: voter_address.clone(),
+    let data = DelegateVoteData {
+        delegator: delegator.clone(),
+        representative: representative.clone(),
+        scope: *scope,
     };
 
     build(
@@ -2373,6 +3221,8 @@ pub async fn build_pgf_funding_proposal(
         proposal_data: _,
         is_pgf_stewards: _,
         is_pgf_funding: _,
+        is_parameter_change: _,
+        is_wasm_allowlist: _,
         tx_code_path,
     }: &args::InitProposal,
     proposal: PgfFundingProposal,
@@ -2418,6 +3268,8 @@ pub async fn build_pgf_stewards_proposal(
         proposal_data: _,
         is_pgf_stewards: _,
         is_pgf_funding: _,
+        is_parameter_change: _,
+        is_wasm_allowlist: _,
         tx_code_path,
     }: &args::InitProposal,
     proposal: PgfStewardProposal,
@@ -2456,6 +3308,109 @@ pub async fn build_pgf_stewards_proposal(
     .map(|tx| (tx, signing_data))
 }
 
+/// Build a parameter change proposal governance
+pub async fn build_parameter_change_proposal(
+    context: &impl Namada,
+    args::InitProposal {
+        tx,
+        proposal_data: _,
+        is_pgf_stewards: _,
+        is_pgf_funding: _,
+        is_parameter_change: _,
+        is_wasm_allowlist: _,
+        tx_code_path,
+    }: &args::InitProposal,
+    proposal: ParameterChangeProposal,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(proposal.proposal.author.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx,
+        Some(proposal.proposal.author.clone()),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _updated_balance) =
+        validate_transparent_fee(context, tx, &signing_data.fee_payer).await?;
+
+    let init_proposal_data = InitProposalData::try_from(proposal.clone())
+        .map_err(|e| TxSubmitError::InvalidProposal(e.to_string()))?;
+
+    let add_section = |tx: &mut Tx, data: &mut InitProposalData| {
+        let (_, extra_section_hash) =
+            tx.add_extra_section(proposal_to_vec(proposal.proposal)?, None);
+        data.content = extra_section_hash;
+        Ok(())
+    };
+
+    build(
+        context,
+        tx,
+        tx_code_path.clone(),
+        init_proposal_data,
+        add_section,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Build a wasm allowlist proposal governance, uploading the added wasm codes
+/// along with the proposal
+pub async fn build_wasm_allowlist_proposal(
+    context: &impl Namada,
+    args::InitProposal {
+        tx,
+        proposal_data: _,
+        is_pgf_stewards: _,
+        is_pgf_funding: _,
+        is_parameter_change: _,
+        is_wasm_allowlist: _,
+        tx_code_path,
+    }: &args::InitProposal,
+    proposal: WasmAllowlistProposal,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(proposal.proposal.author.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx,
+        Some(proposal.proposal.author.clone()),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _updated_balance) =
+        validate_transparent_fee(context, tx, &signing_data.fee_payer).await?;
+
+    let init_proposal_data = InitProposalData::try_from(proposal.clone())
+        .map_err(|e| TxSubmitError::InvalidProposal(e.to_string()))?;
+
+    let add_sections = |tx: &mut Tx, data: &mut InitProposalData| {
+        let (_, extra_section_hash) =
+            tx.add_extra_section(proposal_to_vec(proposal.proposal)?, None);
+        data.content = extra_section_hash;
+
+        // The codes are looked up by their hash when the proposal is
+        // initialized
+        for upload in proposal.data.add {
+            tx.add_extra_section(upload.code, None);
+        }
+        Ok(())
+    };
+
+    build(
+        context,
+        tx,
+        tx_code_path.clone(),
+        init_proposal_data,
+        add_sections,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
 /// Submit an IBC transfer
 pub async fn build_ibc_transfer(
     context: &impl Namada,
@@ -2986,6 +3941,20 @@ pub async fn build_transparent_transfer<N: Namada>(
 pub async fn build_shielded_transfer<N: Namada>(
     context: &N,
     args: &mut args::TxShieldedTransfer,
+) -> Result<(Tx, SigningTxData)> {
+    build_shielded_transfer_with_spent_notes(
+        context,
+        args,
+        SpentNotesTracker::new(),
+    )
+    .await
+}
+
+/// Build a shielded transfer that does not spend any of the given notes
+async fn build_shielded_transfer_with_spent_notes<N: Namada>(
+    context: &N,
+    args: &mut args::TxShieldedTransfer,
+    spent_notes: SpentNotesTracker,
 ) -> Result<(Tx, SigningTxData)> {
     let signing_data =
         signing::aux_signing_data(context, &args.tx, Some(MASP), Some(MASP))
@@ -3038,11 +4007,12 @@ pub async fn build_shielded_transfer<N: Namada>(
             .ok_or(Error::Other("Combined transfer overflows".to_string()))?;
     }
 
-    let shielded_parts = construct_shielded_parts(
+    let shielded_parts = construct_shielded_parts_with_spent_notes(
         context,
         transfer_data,
         masp_fee_data,
         !(args.tx.dry_run || args.tx.dry_run_wrapper),
+        spent_notes,
     )
     .await?
     .expect("Shielded transfer must have shielded parts");
@@ -3089,6 +4059,185 @@ pub async fn build_shielded_transfer<N: Namada>(
     Ok((tx, signing_data))
 }
 
+/// Build the transactions that consolidate the shielded notes of a token into
+/// a few notes by transferring them back to the owner of the spending key.
+/// Every transaction spends a distinct set of notes.
+pub async fn build_shielded_consolidation<N: Namada>(
+    context: &N,
+    args: &mut args::TxShieldedConsolidate,
+) -> Result<Vec<(Tx, SigningTxData)>> {
+    let max_notes = match args.max_notes {
+        Some(max_notes) => max_notes,
+        None => {
+            let max_block_gas: u64 = rpc::query_storage_value(
+                context.client(),
+                &namada_parameters::storage::get_max_block_gas_key(),
+            )
+            .await?;
+            let gas_scale: u64 = rpc::query_storage_value(
+                context.client(),
+                &namada_parameters::storage::get_gas_scale_key(),
+            )
+            .await?;
+            let gas_limit =
+                std::cmp::min(u64::from(args.tx.gas_limit), max_block_gas);
+            max_consolidation_notes(gas_limit.saturating_mul(gas_scale))
+        }
+    };
+    let masp_epoch = rpc::query_masp_epoch(context.client()).await?;
+    let viewing_key = ExtendedFullViewingKey::from(&args.source.into()).fvk.vk;
+    let (batches, notes) = {
+        let mut shielded = context.shielded_mut().await;
+        let _ = shielded.load().await;
+        let batches = shielded
+            .plan_note_consolidation(
+                context.client(),
+                context.io(),
+                &viewing_key,
+                &args.token,
+                masp_epoch,
+                max_notes,
+            )
+            .await?;
+        let notes = shielded
+            .pos_map
+            .get(&viewing_key)
+            .cloned()
+            .unwrap_or_default();
+        (batches, notes)
+    };
+    let denom = rpc::query_denom(context.client(), &args.token)
+        .await
+        .ok_or_else(|| {
+            Error::Other(format!(
+                "No denomination found for token: {}",
+                args.token
+            ))
+        })?;
+    let target = PaymentAddress::from(
+        masp_primitives::zip32::ExtendedSpendingKey::from(args.source)
+            .default_address()
+            .1,
+    );
+
+    let mut txs = Vec::with_capacity(batches.len());
+    for batch in batches {
+        // Only allow the transfer to spend the notes of its batch so that it
+        // merges them, regardless of the notes of the other batches being
+        // marked as spent in the shielded context or not
+        let spent_notes: SpentNotesTracker = [(
+            viewing_key,
+            notes.difference(&batch.notes).copied().collect(),
+        )]
+        .into_iter()
+        .collect();
+        let mut transfer = args::TxShieldedTransfer {
+            tx: args.tx.clone(),
+            data: vec![TxShieldedTransferData {
+                source: args.source,
+                target,
+                token: args.token.clone(),
+                amount: InputAmount::Validated(DenominatedAmount::new(
+                    batch.amount,
+                    denom,
+                )),
+            }],
+            gas_spending_keys: vec![],
+            tx_code_path: args.tx_code_path.clone(),
+        };
+        txs.push(
+            build_shielded_transfer_with_spent_notes(
+                context,
+                &mut transfer,
+                spent_notes,
+            )
+            .await?,
+        );
+    }
+    Ok(txs)
+}
+
+/// The maximum number of notes that a consolidation transaction can spend
+/// without exceeding the given gas budget, in gas sub-units. A quarter of the
+/// budget is left for the execution of the transfer itself.
+fn max_consolidation_notes(gas_budget: u64) -> usize {
+    use namada_gas::{
+        MASP_CONVERT_CHECK_GAS, MASP_FINAL_CHECK_GAS, MASP_FIXED_CONVERT_GAS,
+        MASP_FIXED_OUTPUT_GAS, MASP_FIXED_SPEND_GAS, MASP_OUTPUT_CHECK_GAS,
+        MASP_PARALLEL_GAS_DIVIDER, MASP_SPEND_CHECK_GAS,
+        MASP_VARIABLE_CONVERT_GAS, MASP_VARIABLE_OUTPUT_GAS,
+        MASP_VARIABLE_SPEND_GAS, MASP_VERIFY_SIG_GAS,
+    };
+
+    // The costs that do not depend on the number of spent notes, assuming an
+    // output note for each digit of the consolidated amount
+    const FIXED_GAS: u64 = MASP_VERIFY_SIG_GAS
+        + MASP_FIXED_SPEND_GAS
+        + MASP_FIXED_CONVERT_GAS
+        + MASP_FIXED_OUTPUT_GAS
+        + MASP_FINAL_CHECK_GAS
+        + 4 * (MASP_VARIABLE_OUTPUT_GAS / MASP_PARALLEL_GAS_DIVIDER
+            + MASP_OUTPUT_CHECK_GAS);
+    // The costs of each spent note, including the conversion it might need
+    const NOTE_GAS: u64 = MASP_VERIFY_SIG_GAS
+        + MASP_SPEND_CHECK_GAS
+        + MASP_VARIABLE_SPEND_GAS / MASP_PARALLEL_GAS_DIVIDER
+        + MASP_CONVERT_CHECK_GAS
+        + MASP_VARIABLE_CONVERT_GAS / MASP_PARALLEL_GAS_DIVIDER;
+
+    let available =
+        (gas_budget / 4).saturating_mul(3).saturating_sub(FIXED_GAS);
+    usize::try_from(available / NOTE_GAS).unwrap_or(usize::MAX)
+}
+
+/// Build a shielding transfer that sweeps the balance of the fee token held
+/// by a disposable key back into the shielded pool. The fees of the transfer
+/// are paid by the key itself out of the same balance, hence `None` is
+/// returned if the balance does not exceed them.
+pub async fn build_disposable_key_sweep<N: Namada>(
+    context: &N,
+    args: &args::Tx,
+    key: &common::PublicKey,
+    target: PaymentAddress,
+    tx_code_path: PathBuf,
+) -> Result<Option<(Tx, SigningTxData, MaspEpoch)>> {
+    let owner = Address::from(key);
+    let balance =
+        rpc::get_token_balance(context.client(), &args.fee_token, &owner)
+            .await?;
+    let fee_per_gas_unit = validate_fee(context, args).await?;
+    let total_fee =
+        checked!(fee_per_gas_unit.amount() * u64::from(args.gas_limit))?;
+    let Some(amount) = balance
+        .checked_sub(total_fee)
+        .filter(|amount| !amount.is_zero())
+    else {
+        return Ok(None);
+    };
+
+    let mut transfer = args::TxShieldingTransfer {
+        tx: args::Tx {
+            signing_keys: vec![key.clone()],
+            wrapper_fee_payer: Some(key.clone()),
+            disposable_signing_key: false,
+            ..args.clone()
+        },
+        target,
+        data: vec![TxShieldingTransferData {
+            source: owner,
+            token: args.fee_token.clone(),
+            amount: InputAmount::Validated(DenominatedAmount::new(
+                amount,
+                fee_per_gas_unit.denom(),
+            )),
+        }],
+        tx_code_path,
+    };
+    build_shielding_transfer(context, &mut transfer)
+        .await
+        .map(Some)
+}
+
 // Check if the transaction will need to pay fees via the masp and extract the
 // right masp data
 async fn get_masp_fee_payment_amount<N: Namada>(
@@ -3369,6 +4518,25 @@ async fn construct_shielded_parts<N: Namada>(
     data: Vec<MaspTransferData>,
     fee_data: Option<MaspFeeData>,
     update_ctx: bool,
+) -> Result<Option<(ShieldedTransfer, HashSet<AssetData>)>> {
+    construct_shielded_parts_with_spent_notes(
+        context,
+        data,
+        fee_data,
+        update_ctx,
+        SpentNotesTracker::new(),
+    )
+    .await
+}
+
+// Construct the shielded part of the transaction, if any, without spending any
+// of the given notes
+async fn construct_shielded_parts_with_spent_notes<N: Namada>(
+    context: &N,
+    data: Vec<MaspTransferData>,
+    fee_data: Option<MaspFeeData>,
+    update_ctx: bool,
+    spent_notes: SpentNotesTracker,
 ) -> Result<Option<(ShieldedTransfer, HashSet<AssetData>)>> {
     // Precompute asset types to increase chances of success in decoding
     let token_map = context.wallet().await.get_addresses();
@@ -3379,8 +4547,12 @@ async fn construct_shielded_parts<N: Namada>(
         .precompute_asset_types(context.client(), tokens)
         .await;
     let stx_result =
-        ShieldedContext::<N::ShieldedUtils>::gen_shielded_transfer(
-            context, data, fee_data, update_ctx,
+        ShieldedContext::<N::ShieldedUtils>::gen_shielded_transfer_with_spent_notes(
+            context,
+            data,
+            fee_data,
+            update_ctx,
+            spent_notes,
         )
         .await;
 
@@ -3793,6 +4965,57 @@ fn lift_rpc_error<T>(res: std::result::Result<T, RpcError>) -> Result<T> {
     res.map_err(|err| Error::from(TxSubmitError::TxBroadcast(err)))
 }
 
+/// Check that adding the given stake to the validator at the pipeline epoch
+/// doesn't take it over the cap set by the `max_validator_stake_share` PoS
+/// parameter. The total stake grows by `total_increase`. Force forces the
+/// stake through even if it's over the cap.
+async fn stake_cap_or_err(
+    context: &impl Namada,
+    params: &PosParams,
+    validator: &Address,
+    added_stake: token::Amount,
+    total_increase: token::Amount,
+    pipeline_epoch: Epoch,
+    force: bool,
+) -> Result<()> {
+    if params.max_validator_stake_share >= Dec::one() {
+        return Ok(());
+    }
+    let stake =
+        rpc::get_validator_stake(context.client(), pipeline_epoch, validator)
+            .await?
+            .checked_add(added_stake)
+            .ok_or_else(|| Error::Other("Stake overflow".to_string()))?;
+    let cap = rpc::get_total_staked_tokens(context.client(), pipeline_epoch)
+        .await?
+        .checked_add(total_increase)
+        .and_then(|total| {
+            total.mul_floor(params.max_validator_stake_share).ok()
+        })
+        .ok_or_else(|| Error::Other("Stake overflow".to_string()))?;
+    if stake > cap {
+        edisplay_line!(
+            context.io(),
+            "The stake of validator {} would be {} at the pipeline epoch {}, \
+             which is over its cap of {} set by the maximum validator stake \
+             share of {}.",
+            validator,
+            stake.to_string_native(),
+            pipeline_epoch,
+            cap.to_string_native(),
+            params.max_validator_stake_share,
+        );
+        if !force {
+            return Err(Error::from(TxSubmitError::StakeCapExceeded(
+                validator.clone(),
+                stake.to_string_native(),
+                cap.to_string_native(),
+            )));
+        }
+    }
+    Ok(())
+}
+
 /// Returns the given validator if the given address is a validator,
 /// otherwise returns an error, force forces the address through even
 /// if it isn't a validator
@@ -4034,3 +5257,22 @@ fn proposal_to_vec(proposal: OnChainProposal) -> Result<Vec<u8>> {
     borsh::to_vec(&proposal.content)
         .map_err(|e| Error::from(EncodingError::Conversion(e.to_string())))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test the number of notes that a consolidation transaction can spend
+    /// within a gas budget
+    #[test]
+    fn test_max_consolidation_notes() {
+        // the fixed costs of the transaction are not covered
+        assert_eq!(max_consolidation_notes(0), 0);
+        assert_eq!(max_consolidation_notes(300_000_000), 0);
+        // the smallest budget that covers ten notes
+        assert_eq!(max_consolidation_notes(493_051_848), 10);
+        assert_eq!(max_consolidation_notes(493_051_847), 9);
+        // the budget does not overflow
+        assert!(max_consolidation_notes(u64::MAX) > 10);
+    }
+}
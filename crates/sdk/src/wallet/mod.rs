@@ -21,6 +21,7 @@ use namada_core::key::*;
 use namada_core::masp::{
     ExtendedSpendingKey, ExtendedViewingKey, PaymentAddress,
 };
+use namada_core::storage::BlockHeight;
 use namada_core::time::DateTimeUtc;
 use namada_ibc::trace::is_ibc_denom;
 pub use pre_genesis::gen_key_to_store;
@@ -32,7 +33,10 @@ use zeroize::Zeroizing;
 
 pub use self::derivation_path::{DerivationPath, DerivationPathError};
 pub use self::keys::{DecryptionError, StoredKeypair};
-pub use self::store::{ConfirmationResponse, ValidatorData, ValidatorKeys};
+pub use self::store::{
+    ConfirmationResponse, DisposableKeyInfo, DisposableKeyPurpose,
+    ValidatorData, ValidatorKeys,
+};
 use crate::wallet::store::{derive_hd_secret_key, derive_hd_spending_key};
 
 const DISPOSABLE_KEY_LIFETIME_IN_SECONDS: i64 = 7 * 24 * 60 * 60; // 1 week
@@ -761,11 +765,16 @@ impl<U: WalletIo> Wallet<U> {
     }
 
     /// Generate a disposable signing key for fee payment and store it under the
-    /// precomputed alias in the wallet. This is simply a wrapper around
-    /// `gen_key` to manage the alias
+    /// precomputed alias in the wallet, along with the last committed block
+    /// height and the purpose of the key. This is simply a wrapper around
+    /// `gen_key` to manage the alias. Expired disposable keys lacking
+    /// lifecycle information are garbage collected, the others must be
+    /// pruned with [`Wallet::remove_disposable_key`] once they are empty.
     pub fn gen_disposable_signing_key(
         &mut self,
         rng: &mut (impl CryptoRng + RngCore),
+        created_at: Option<BlockHeight>,
+        purpose: DisposableKeyPurpose,
     ) -> common::SecretKey {
         #[allow(clippy::disallowed_methods)]
         let current_unix_timestamp = DateTimeUtc::now().to_unix_timestamp();
@@ -774,6 +783,10 @@ impl<U: WalletIo> Wallet<U> {
             .store
             .get_public_keys()
             .keys()
+            .filter(|key_alias| {
+                // Keys with lifecycle information might still hold funds
+                self.store.get_disposable_key_info(key_alias).is_none()
+            })
             .filter(|key_alias| {
                 check_if_disposable_key_and(
                     key_alias,
@@ -810,12 +823,51 @@ impl<U: WalletIo> Wallet<U> {
         // this key can be deleted from wallet (the transaction being
         // accepted is not enough cause we could end up doing a
         // rollback)
-        self.insert_keypair(key_alias, false, sk.clone(), None, None, None)
+        let key_alias = self
+            .insert_keypair(key_alias, false, sk.clone(), None, None, None)
             .expect("Failed to store disposable signing key");
+        self.store.insert_disposable_key_info(
+            key_alias.into(),
+            DisposableKeyInfo {
+                created_at,
+                purpose,
+            },
+        );
 
         sk
     }
 
+    /// Get all the disposable keys stored in the wallet
+    pub fn get_disposable_keys(&self) -> Vec<DisposableKey> {
+        self.store
+            .get_public_keys()
+            .iter()
+            .filter_map(|(alias, public_key)| {
+                let mut created_at_timestamp = None;
+                check_if_disposable_key_and(alias, |_pkh, timestamp| {
+                    created_at_timestamp = Some(timestamp);
+                    true
+                });
+                Some(DisposableKey {
+                    alias: alias.clone(),
+                    public_key: public_key.clone(),
+                    created_at_timestamp: created_at_timestamp?,
+                    info: self.store.get_disposable_key_info(alias).cloned(),
+                })
+            })
+            .collect()
+    }
+
+    /// Remove a disposable key from the wallet. Returns `false` if the alias
+    /// does not belong to a disposable key.
+    pub fn remove_disposable_key(&mut self, alias: &Alias) -> bool {
+        if !check_if_disposable_key_and(alias, |_pkh, _timestamp| true) {
+            return false;
+        }
+        self.store.remove_alias(alias);
+        true
+    }
+
     /// Find the stored key by an alias, a public key hash or a public key.
     /// If the key is encrypted and password not supplied, then password will be
     /// interactively prompted. Any keys that are decrypted are stored in and
@@ -1144,6 +1196,27 @@ fn disposable_key_alias(pkh: &PublicKeyHash, timestamp: i64) -> String {
     format!("disposable-key-{pkh}-created-at-{timestamp}")
 }
 
+/// A disposable key stored in the wallet
+#[derive(Debug, Clone)]
+pub struct DisposableKey {
+    /// The alias of the key
+    pub alias: Alias,
+    /// The public key
+    pub public_key: common::PublicKey,
+    /// The unix timestamp at which the key was created
+    pub created_at_timestamp: i64,
+    /// Lifecycle information of the key. Missing for keys created before it
+    /// was recorded.
+    pub info: Option<DisposableKeyInfo>,
+}
+
+impl DisposableKey {
+    /// The implicit address of the key, which holds its funds
+    pub fn address(&self) -> Address {
+        Address::from(&self.public_key)
+    }
+}
+
 #[inline]
 fn check_if_disposable_key_and<F: FnOnce(&PublicKeyHash, i64) -> bool>(
     key_alias: &Alias,
@@ -1235,7 +1308,11 @@ mod tests {
         }
 
         // add a new key - length should be 2 now
-        let new_key = wallet.gen_disposable_signing_key(&mut OsRng);
+        let new_key = wallet.gen_disposable_signing_key(
+            &mut OsRng,
+            None,
+            DisposableKeyPurpose::WrapperFeePayment,
+        );
         assert_eq!(wallet.store.get_public_keys().len(), 2);
 
         // check that indeed the first keypair was not gc'd
@@ -1258,4 +1335,68 @@ mod tests {
                 .any(|pk| *pk == new_key_pk)
         );
     }
+
+    #[test]
+    fn test_disposable_keys_lifecycle() {
+        let mut wallet = Wallet {
+            utils: TestWalletUtils,
+            store: Default::default(),
+            decrypted_key_cache: Default::default(),
+            decrypted_spendkey_cache: Default::default(),
+        };
+
+        // An expired key with lifecycle information
+        let sk = keypair_1();
+        let pkh: PublicKeyHash = (&sk.to_public()).into();
+        let alias = disposable_key_alias(&pkh, 0);
+        wallet.insert_keypair(alias.clone(), true, sk, None, None, None);
+        let info = DisposableKeyInfo {
+            created_at: Some(BlockHeight(1)),
+            purpose: DisposableKeyPurpose::WrapperFeePayment,
+        };
+        wallet
+            .store
+            .insert_disposable_key_info(alias.clone().into(), info.clone());
+
+        // The expired key is not garbage collected since it might hold funds
+        let new_key = wallet.gen_disposable_signing_key(
+            &mut OsRng,
+            Some(BlockHeight(10)),
+            DisposableKeyPurpose::WrapperFeePayment,
+        );
+        let keys = wallet.get_disposable_keys();
+        assert_eq!(keys.len(), 2);
+        let old = keys
+            .iter()
+            .find(|key| key.alias == Alias::from(alias.clone()))
+            .unwrap();
+        assert_eq!(old.created_at_timestamp, 0);
+        assert_eq!(old.info, Some(info));
+        let new = keys
+            .iter()
+            .find(|key| key.public_key == new_key.to_public())
+            .unwrap();
+        assert_eq!(
+            new.info.as_ref().unwrap().created_at,
+            Some(BlockHeight(10))
+        );
+
+        // Only disposable keys can be pruned
+        let regular = keypair_2();
+        wallet.insert_keypair(
+            "regular".to_string(),
+            true,
+            regular,
+            None,
+            None,
+            None,
+        );
+        assert!(!wallet.remove_disposable_key(&Alias::from("regular")));
+        assert!(wallet.remove_disposable_key(&Alias::from(alias.clone())));
+        assert_eq!(wallet.get_disposable_keys().len(), 1);
+        assert!(wallet
+            .store
+            .get_disposable_key_info(&Alias::from(alias))
+            .is_none());
+    }
 }
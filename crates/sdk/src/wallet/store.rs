@@ -15,6 +15,7 @@ use namada_core::key::*;
 use namada_core::masp::{
     ExtendedSpendingKey, ExtendedViewingKey, PaymentAddress,
 };
+use namada_core::storage::BlockHeight;
 use serde::{Deserialize, Serialize};
 use zeroize::Zeroizing;
 
@@ -40,6 +41,13 @@ pub struct ValidatorKeys {
     pub protocol_keypair: common::SecretKey,
     /// Special hot keypair for signing Ethereum bridge txs
     pub eth_bridge_keypair: common::SecretKey,
+    /// Rotated protocol keypair, waiting for its activation on chain
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_protocol_keypair: Option<common::SecretKey>,
+    /// Rotated Ethereum bridge hot keypair, waiting for its activation on
+    /// chain
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_eth_bridge_keypair: Option<common::SecretKey>,
 }
 
 impl ValidatorKeys {
@@ -47,6 +55,35 @@ impl ValidatorKeys {
     pub fn get_protocol_keypair(&self) -> &common::SecretKey {
         &self.protocol_keypair
     }
+
+    /// Replace the active keypairs with the rotated ones whose public keys
+    /// match the given keys that are active on chain. Returns `true` if any
+    /// keypair has been replaced.
+    pub fn activate_next_keys(
+        &mut self,
+        protocol_pk: &common::PublicKey,
+        eth_bridge_pk: &common::PublicKey,
+    ) -> bool {
+        let mut activated = false;
+        if self
+            .next_protocol_keypair
+            .as_ref()
+            .is_some_and(|sk| &sk.ref_to() == protocol_pk)
+        {
+            self.protocol_keypair = self.next_protocol_keypair.take().unwrap();
+            activated = true;
+        }
+        if self
+            .next_eth_bridge_keypair
+            .as_ref()
+            .is_some_and(|sk| &sk.ref_to() == eth_bridge_pk)
+        {
+            self.eth_bridge_keypair =
+                self.next_eth_bridge_keypair.take().unwrap();
+            activated = true;
+        }
+        activated
+    }
 }
 
 /// Special data associated with a validator
@@ -58,6 +95,31 @@ pub struct ValidatorData {
     pub keys: ValidatorKeys,
 }
 
+/// The reason why a disposable key was created
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisposableKeyPurpose {
+    /// Unlinkable payment of the fees of a wrapper transaction, typically
+    /// funded by unshielding from the MASP
+    WrapperFeePayment,
+}
+
+impl Display for DisposableKeyPurpose {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::WrapperFeePayment => write!(f, "wrapper fee payment"),
+        }
+    }
+}
+
+/// Lifecycle information of a disposable key
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct DisposableKeyInfo {
+    /// The last committed block height when the key was created, if known
+    pub created_at: Option<BlockHeight>,
+    /// The reason why the key was created
+    pub purpose: DisposableKeyPurpose,
+}
+
 /// A Storage area for keys and addresses
 #[derive(Serialize, Deserialize, Debug, Default)]
 pub struct Store {
@@ -82,6 +144,9 @@ pub struct Store {
     pub(crate) validator_data: Option<ValidatorData>,
     /// Namada address vp type
     address_vp_types: BTreeMap<AddressVpType, HashSet<Address>>,
+    /// Lifecycle information of the disposable keys
+    #[serde(default)]
+    disposable_keys: BTreeMap<Alias, DisposableKeyInfo>,
 }
 
 /// Grouping of addresses by validity predicate.
@@ -588,6 +653,24 @@ impl Store {
         self.pkhs.retain(|_key, val| val != alias);
         self.public_keys.remove(alias);
         self.derivation_paths.remove(alias);
+        self.disposable_keys.remove(alias);
+    }
+
+    /// Record the lifecycle information of a disposable key
+    pub fn insert_disposable_key_info(
+        &mut self,
+        alias: Alias,
+        info: DisposableKeyInfo,
+    ) {
+        self.disposable_keys.insert(alias, info);
+    }
+
+    /// Get the lifecycle information of a disposable key, if it was recorded
+    pub fn get_disposable_key_info(
+        &self,
+        alias: &Alias,
+    ) -> Option<&DisposableKeyInfo> {
+        self.disposable_keys.get(alias)
     }
 
     /// Extend this store from another store (typically pre-genesis).
@@ -604,6 +687,7 @@ impl Store {
             pkhs,
             validator_data: _,
             address_vp_types,
+            disposable_keys,
         } = self;
         view_keys.extend(store.view_keys);
         spend_keys.extend(store.spend_keys);
@@ -614,6 +698,7 @@ impl Store {
         addresses.extend(store.addresses);
         pkhs.extend(store.pkhs);
         address_vp_types.extend(store.address_vp_types);
+        disposable_keys.extend(store.disposable_keys);
     }
 
     /// Extend this store from pre-genesis validator wallet.
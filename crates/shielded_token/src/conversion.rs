@@ -22,6 +22,9 @@ use crate::storage_key::{
 };
 use crate::WithConversionState;
 
+/// The maximum number of MASP epochs over which the rewards can be projected
+pub const MAX_REWARD_PROJECTION_EPOCHS: u64 = 1_000;
+
 /// Compute shielded token inflation amount
 #[allow(clippy::too_many_arguments)]
 pub fn compute_inflation(
@@ -64,11 +67,11 @@ pub fn compute_inflation(
 /// Compute the precision of MASP rewards for the given token. This function
 /// must be a non-zero constant for a given token.
 pub fn calculate_masp_rewards_precision<S, TransToken>(
-    storage: &mut S,
+    storage: &S,
     addr: &Address,
 ) -> namada_storage::Result<(u128, Denomination)>
 where
-    S: StorageWrite + StorageRead,
+    S: StorageRead,
     TransToken: trans_token::Read<S>,
 {
     let denomination = TransToken::read_denom(storage, addr)?
@@ -86,6 +89,197 @@ where
     Ok((checked!(10u128 ^ precision_denom)?, denomination))
 }
 
+/// The inputs of the PD-controller that determines the shielded rewards of a
+/// token over the next MASP epoch
+#[derive(Clone, Debug)]
+pub struct MaspRewardInputs {
+    /// The precision of the rewards of the token
+    pub precision: u128,
+    /// The denomination of the token
+    pub denomination: Denomination,
+    /// The effective total supply of the native token
+    pub total_native_tokens: Amount,
+    /// The amount of the token locked in the shielded pool
+    pub total_tokens_in_masp: Amount,
+    /// The inflation of the token in the last MASP epoch
+    pub last_inflation: Amount,
+    /// The locked amount of the token in the last MASP epoch
+    pub last_locked_amount: Amount,
+    /// The maximum reward rate of the token
+    pub max_reward_rate: Dec,
+    /// The nominal proportional gain
+    pub kp_gain_nom: Dec,
+    /// The nominal derivative gain
+    pub kd_gain_nom: Dec,
+    /// The target locked amount of the token
+    pub target_locked_amount: Amount,
+    /// The number of MASP epochs per year
+    pub masp_epochs_per_year: u64,
+}
+
+impl MaspRewardInputs {
+    /// Read the PD-controller inputs of the given token from storage
+    pub fn read<S, TransToken>(
+        storage: &S,
+        token: &Address,
+        masp_epochs_per_year: u64,
+    ) -> namada_storage::Result<Self>
+    where
+        S: StorageRead,
+        TransToken: trans_token::Keys + trans_token::Read<S>,
+    {
+        use namada_storage::OptionExt;
+
+        let (precision, denomination) =
+            calculate_masp_rewards_precision::<S, TransToken>(storage, token)?;
+
+        // Query the storage for information -------------------------
+
+        //// information about the amount of native tokens on the chain
+        let total_native_tokens =
+            TransToken::get_effective_total_native_supply(storage)?;
+
+        // total locked amount in the Shielded pool
+        let total_tokens_in_masp =
+            TransToken::read_balance(storage, token, &MASP)?;
+
+        //// Values from the last epoch
+        let last_inflation: Amount = storage
+            .read(&masp_last_inflation_key::<TransToken>(token))?
+            .ok_or_err_msg("failure to read last inflation")?;
+
+        let last_locked_amount: Amount = storage
+            .read(&masp_last_locked_amount_key::<TransToken>(token))?
+            .ok_or_err_msg("failure to read last locked amount")?;
+
+        //// Parameters for each token
+        let max_reward_rate: Dec = storage
+            .read(&masp_max_reward_rate_key::<TransToken>(token))?
+            .ok_or_err_msg("max reward should properly decode")?;
+
+        let kp_gain_nom: Dec = storage
+            .read(&masp_kp_gain_key::<TransToken>(token))?
+            .ok_or_err_msg("kp_gain_nom reward should properly decode")?;
+
+        let kd_gain_nom: Dec = storage
+            .read(&masp_kd_gain_key::<TransToken>(token))?
+            .ok_or_err_msg("kd_gain_nom reward should properly decode")?;
+
+        let target_locked_amount: Amount = storage
+            .read(&masp_locked_amount_target_key::<TransToken>(token))?
+            .ok_or_err_msg("locked ratio target should properly decode")?;
+
+        Ok(Self {
+            precision,
+            denomination,
+            total_native_tokens,
+            total_tokens_in_masp,
+            last_inflation,
+            last_locked_amount,
+            max_reward_rate,
+            kp_gain_nom,
+            kd_gain_nom,
+            target_locked_amount,
+            masp_epochs_per_year,
+        })
+    }
+
+    /// Compute the inflation of the token over the next MASP epoch
+    pub fn inflation(&self) -> Uint {
+        let target_locked_dec =
+            Dec::try_from(self.target_locked_amount.raw_amount())
+                .expect("Should not fail to convert Uint to Dec");
+        let last_locked_dec =
+            Dec::try_from(self.last_locked_amount.raw_amount())
+                .expect("Should not fail to convert Uint to Dec");
+
+        compute_inflation(
+            self.total_tokens_in_masp.raw_amount(),
+            self.total_native_tokens.raw_amount(),
+            self.max_reward_rate,
+            self.last_inflation.raw_amount(),
+            self.kp_gain_nom,
+            self.kd_gain_nom,
+            self.masp_epochs_per_year,
+            target_locked_dec,
+            last_locked_dec,
+        )
+    }
+
+    /// Make the given inflation discrete, yielding the reward for every
+    /// `precision` units of the token locked in the shielded pool
+    pub fn noterize(&self, token: &Address, inflation: Uint) -> u128 {
+        // inflation-per-token = inflation / locked tokens = n/PRECISION
+        // ∴ n = (inflation * PRECISION) / locked tokens
+        // Since we must put the notes in a compatible format with the
+        // note format, we must make the inflation amount discrete.
+        if self.total_tokens_in_masp.is_zero() {
+            0u128
+        } else {
+            inflation
+                .checked_mul_div(
+                    Uint::from(self.precision),
+                    self.total_tokens_in_masp.raw_amount(),
+                )
+                .and_then(|x| x.0.try_into().ok())
+                .unwrap_or_else(|| {
+                    tracing::warn!(
+                        "MASP inflation for {} assumed to be 0 because the \
+                         computed value is too large. Please check the \
+                         inflation parameters.",
+                        *token
+                    );
+                    0u128
+                })
+        }
+    }
+
+    /// The total inflation amount corresponding to the given noterized
+    /// inflation
+    pub fn inflation_amount(
+        &self,
+        noterized_inflation: u128,
+    ) -> namada_storage::Result<Amount> {
+        Ok(Amount::from_uint(
+            checked!(
+                self.total_tokens_in_masp.raw_amount() / self.precision.into()
+                    * Uint::from(noterized_inflation)
+            )?,
+            0,
+        )
+        .unwrap())
+    }
+
+    /// Project the noterized inflation of the token over the given number of
+    /// upcoming MASP epochs by repeatedly applying the PD-controller. The
+    /// locked amount and the native token supply are assumed to stay
+    /// constant, hence the projection is only an estimate. At most
+    /// [`MAX_REWARD_PROJECTION_EPOCHS`] epochs can be projected.
+    pub fn project(
+        &self,
+        token: &Address,
+        epochs: u64,
+    ) -> namada_storage::Result<Vec<u128>> {
+        if epochs > MAX_REWARD_PROJECTION_EPOCHS {
+            return Err(namada_storage::Error::new_alloc(format!(
+                "Cannot project the MASP rewards over {epochs} epochs, the \
+                 maximum is {MAX_REWARD_PROJECTION_EPOCHS}"
+            )));
+        }
+        let mut inputs = self.clone();
+        let mut projection = Vec::new();
+        for _ in 0..epochs {
+            let noterized_inflation =
+                inputs.noterize(token, inputs.inflation());
+            inputs.last_inflation =
+                inputs.inflation_amount(noterized_inflation)?;
+            inputs.last_locked_amount = inputs.total_tokens_in_masp;
+            projection.push(noterized_inflation);
+        }
+        Ok(projection)
+    }
+}
+
 /// Compute the MASP rewards by applying the PD-controller to the genesis
 /// parameters and the last inflation and last locked rewards ratio values.
 pub fn calculate_masp_rewards<S, TransToken>(
@@ -97,97 +291,18 @@ where
     S: StorageWrite + StorageRead,
     TransToken: trans_token::Keys + trans_token::Read<S>,
 {
-    let (precision, denomination) =
-        calculate_masp_rewards_precision::<S, TransToken>(storage, token)?;
-
-    let masp_addr = MASP;
-
-    // Query the storage for information -------------------------
-
-    //// information about the amount of native tokens on the chain
-    let total_native_tokens =
-        TransToken::get_effective_total_native_supply(storage)?;
-
-    // total locked amount in the Shielded pool
-    let total_tokens_in_masp =
-        TransToken::read_balance(storage, token, &masp_addr)?;
-
-    //// Values from the last epoch
-    let last_inflation: Amount = storage
-        .read(&masp_last_inflation_key::<TransToken>(token))?
-        .expect("failure to read last inflation");
-
-    let last_locked_amount: Amount = storage
-        .read(&masp_last_locked_amount_key::<TransToken>(token))?
-        .expect("failure to read last inflation");
-
-    //// Parameters for each token
-    let max_reward_rate: Dec = storage
-        .read(&masp_max_reward_rate_key::<TransToken>(token))?
-        .expect("max reward should properly decode");
-
-    let kp_gain_nom: Dec = storage
-        .read(&masp_kp_gain_key::<TransToken>(token))?
-        .expect("kp_gain_nom reward should properly decode");
-
-    let kd_gain_nom: Dec = storage
-        .read(&masp_kd_gain_key::<TransToken>(token))?
-        .expect("kd_gain_nom reward should properly decode");
-
-    let target_locked_amount: Amount = storage
-        .read(&masp_locked_amount_target_key::<TransToken>(token))?
-        .expect("locked ratio target should properly decode");
-
-    let target_locked_dec = Dec::try_from(target_locked_amount.raw_amount())
-        .expect("Should not fail to convert Uint to Dec");
-    let last_locked_dec = Dec::try_from(last_locked_amount.raw_amount())
-        .expect("Should not fail to convert Uint to Dec");
-
-    // Initial computation of the new shielded inflation
-    let inflation = compute_inflation(
-        total_tokens_in_masp.raw_amount(),
-        total_native_tokens.raw_amount(),
-        max_reward_rate,
-        last_inflation.raw_amount(),
-        kp_gain_nom,
-        kd_gain_nom,
+    let inputs = MaspRewardInputs::read::<S, TransToken>(
+        storage,
+        token,
         masp_epochs_per_year,
-        target_locked_dec,
-        last_locked_dec,
-    );
+    )?;
 
-    // inflation-per-token = inflation / locked tokens = n/PRECISION
-    // ∴ n = (inflation * PRECISION) / locked tokens
-    // Since we must put the notes in a compatible format with the
-    // note format, we must make the inflation amount discrete.
-    let noterized_inflation = if total_tokens_in_masp.is_zero() {
-        0u128
-    } else {
-        inflation
-            .checked_mul_div(
-                Uint::from(precision),
-                total_tokens_in_masp.raw_amount(),
-            )
-            .and_then(|x| x.0.try_into().ok())
-            .unwrap_or_else(|| {
-                tracing::warn!(
-                    "MASP inflation for {} assumed to be 0 because the \
-                     computed value is too large. Please check the inflation \
-                     parameters.",
-                    *token
-                );
-                0u128
-            })
-    };
-    let inflation_amount = Amount::from_uint(
-        checked!(
-            total_tokens_in_masp.raw_amount() / precision.into()
-                * Uint::from(noterized_inflation)
-        )?,
-        0,
-    )
-    .unwrap();
-    let denom_amount = DenominatedAmount::new(inflation_amount, denomination);
+    // Initial computation of the new shielded inflation
+    let inflation = inputs.inflation();
+    let noterized_inflation = inputs.noterize(token, inflation);
+    let inflation_amount = inputs.inflation_amount(noterized_inflation)?;
+    let denom_amount =
+        DenominatedAmount::new(inflation_amount, inputs.denomination);
     tracing::info!("MASP inflation for {token} is {denom_amount}");
 
     tracing::debug!(
@@ -195,20 +310,20 @@ where
          locked_target_amount {:?}, last_locked_amount {:?}, max_reward_rate \
          {:?}, last_inflation {:?}, kp_gain_nom {:?}, kd_gain_nom {:?}, \
          epochs_per_year {:?}",
-        total_tokens_in_masp,
-        total_native_tokens,
-        target_locked_amount,
-        last_locked_amount,
-        max_reward_rate,
-        last_inflation,
-        kp_gain_nom,
-        kd_gain_nom,
+        inputs.total_tokens_in_masp,
+        inputs.total_native_tokens,
+        inputs.target_locked_amount,
+        inputs.last_locked_amount,
+        inputs.max_reward_rate,
+        inputs.last_inflation,
+        inputs.kp_gain_nom,
+        inputs.kd_gain_nom,
         masp_epochs_per_year,
     );
     tracing::debug!("Token address: {:?}", token);
     tracing::debug!("inflation from the pd controller {:?}", inflation);
-    tracing::debug!("total in the masp {:?}", total_tokens_in_masp);
-    tracing::debug!("precision {}", precision);
+    tracing::debug!("total in the masp {:?}", inputs.total_tokens_in_masp);
+    tracing::debug!("precision {}", inputs.precision);
     tracing::debug!("Noterized inflation: {}", noterized_inflation);
 
     // Is it fine to write the inflation rate, this is accurate,
@@ -222,10 +337,10 @@ where
 
     storage.write(
         &masp_last_locked_amount_key::<TransToken>(token),
-        total_tokens_in_masp,
+        inputs.total_tokens_in_masp,
     )?;
 
-    Ok(((noterized_inflation, precision), denomination))
+    Ok(((noterized_inflation, inputs.precision), inputs.denomination))
 }
 
 // This is only enabled when "wasm-runtime" is on, because we're using rayon
@@ -750,6 +865,71 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_masp_reward_projection() {
+        let mut s = TestStorage::default();
+        namada_parameters::init_test_storage(&mut s).unwrap();
+        let token_params = ShieldedParams {
+            max_reward_rate: Dec::from_str("0.1").unwrap(),
+            kp_gain_nom: Dec::from_str("0.1").unwrap(),
+            kd_gain_nom: Dec::from_str("0.1").unwrap(),
+            locked_amount_target: 10_000_u64,
+        };
+        let token = address::testing::btc();
+        let denom = Denomination(8);
+        namada_trans_token::write_params(&mut s, &token).unwrap();
+        crate::write_params::<_, namada_trans_token::Store<()>>(
+            &token_params,
+            &mut s,
+            &token,
+            &denom,
+        )
+        .unwrap();
+        write_denom(&mut s, &token, denom).unwrap();
+        let balance = Amount::native_whole(1_000_000);
+        s.write(&minted_balance_key(&token), balance).unwrap();
+        s.write(&balance_key(&token, &address::MASP), balance)
+            .unwrap();
+
+        const EPOCHS: u64 = 5;
+        let projection = MaspRewardInputs::read::<
+            _,
+            namada_trans_token::Store<_>,
+        >(&s, &token, 365)
+        .unwrap()
+        .project(&token, EPOCHS)
+        .unwrap();
+        assert_eq!(projection.len(), EPOCHS as usize);
+
+        // The projection must match the rewards actually computed while the
+        // locked amount and native supply stay the same
+        for projected in projection {
+            let ((reward, _precision), _denom) =
+                calculate_masp_rewards::<_, namada_trans_token::Store<_>>(
+                    &mut s, &token, 365,
+                )
+                .unwrap();
+            assert_eq!(reward, projected);
+        }
+
+        // Projecting too far ahead is rejected
+        let inputs = MaspRewardInputs::read::<_, namada_trans_token::Store<_>>(
+            &s, &token, 365,
+        )
+        .unwrap();
+        assert_eq!(
+            inputs
+                .project(&token, MAX_REWARD_PROJECTION_EPOCHS)
+                .unwrap()
+                .len(),
+            MAX_REWARD_PROJECTION_EPOCHS as usize
+        );
+        assert!(inputs
+            .project(&token, MAX_REWARD_PROJECTION_EPOCHS + 1)
+            .is_err());
+        assert!(inputs.project(&token, u64::MAX).is_err());
+    }
+
     pub fn tokens() -> HashMap<Address, (&'static str, Denomination)> {
         vec![
             (address::testing::nam(), ("nam", 6.into())),
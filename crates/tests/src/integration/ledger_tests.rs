@@ -737,6 +737,21 @@ fn proposal_submission() -> Result<()> {
     assert_matches!(captured.result, Ok(_));
     assert!(captured.contains("Proposal Id: 0"));
 
+    // 3.1. Dry run the proposal code
+    let proposal_dry_run_args = vec![
+        "query-proposal-dry-run",
+        "--proposal-id",
+        "0",
+        "--node",
+        &validator_one_rpc,
+    ];
+    let captured =
+        CapturedOutput::of(|| run(&node, Bin::Client, proposal_dry_run_args));
+    assert_matches!(captured.result, Ok(_));
+    assert!(captured
+        .contains("The code of proposal 0 would be successfully applied"));
+    assert!(captured.contains("Storage changes:"));
+
     // 4. Query token balance proposal author (submitted funds)
     let query_balance_args = vec![
         "balance",
@@ -13,7 +13,7 @@ use namada_core::storage::KeySeg;
 use namada_core::{address, storage};
 
 pub use crate::data::pos::{
-    Bond, ClaimRewards, Redelegation, Unbond, Withdraw,
+    AutoCompound, Bond, ClaimRewards, Redelegation, Unbond, Withdraw,
 };
 
 /// Actions applied from txs.
@@ -46,6 +46,8 @@ pub enum PosAction {
     CommissionChange(Address),
     MetadataChange(Address),
     ConsensusKeyChange(Address),
+    ValidatorKeysChange(Address),
+    AutoCompound(AutoCompound),
 }
 
 /// Gov tx actions.
@@ -54,6 +56,9 @@ pub enum PosAction {
 pub enum GovAction {
     InitProposal { author: Address },
     VoteProposal { id: u64, voter: Address },
+    CancelProposal { id: u64, author: Address },
+    DelegateVote { delegator: Address },
+    VetoProposal { id: u64, validator: Address },
 }
 
 /// PGF tx actions.
@@ -210,6 +210,43 @@ impl<T> Default for ExtendedTxResult<T> {
 /// used
 pub struct DryRunResult(pub TxResult<String>, pub WholeGas);
 
+/// The value of a storage key before and after a dry run
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+)]
+pub struct StorageDiff {
+    /// The value before the dry run, if any
+    pub before: Option<Vec<u8>>,
+    /// The value after the dry run, `None` if the key was deleted
+    pub after: Option<Vec<u8>>,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+/// The result of dry running the wasm code attached to a governance proposal
+/// in the same context as its execution after a successful vote
+pub struct ProposalDryRunResult {
+    /// The result of the proposal code or the error that made it fail
+    pub result: Result<BatchedTxResult, String>,
+    /// The storage modifications that the proposal would have committed
+    pub storage_diff: BTreeMap<storage::Key, StorageDiff>,
+    /// The gas consumed by the proposal code
+    pub gas_used: WholeGas,
+}
+
+impl ProposalDryRunResult {
+    /// Check if the proposal code ran successfully and was accepted by all
+    /// the VPs
+    pub fn is_accepted(&self) -> bool {
+        matches!(&self.result, Ok(result) if result.is_accepted())
+    }
+}
+
 /// Transaction application result. More specifically the set of inner tx
 /// results indexed by the inner tx hash
 // The generic is only used to return typed errors in protocol for error
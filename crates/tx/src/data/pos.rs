@@ -1,9 +1,12 @@
 //! Types used for PoS system transactions
+use std::collections::BTreeMap;
 
 use namada_core::address::Address;
 use namada_core::borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use namada_core::dec::Dec;
+use namada_core::hash::Hash;
 use namada_core::key::{common, secp256k1};
+use namada_core::storage::Epoch;
 use namada_core::token;
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
@@ -126,6 +129,31 @@ pub struct ClaimRewards {
     pub source: Option<Address>,
 }
 
+/// An opt-in or opt-out of the automatic re-bonding of the rewards of a bond
+/// at every new epoch.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Hash,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+pub struct AutoCompound {
+    /// Validator address
+    pub validator: Address,
+    /// Source address of the bond. For self-bonds, the validator is also the
+    /// source
+    pub source: Option<Address>,
+    /// Whether the rewards should be automatically re-bonded
+    pub enabled: bool,
+}
+
 /// A redelegation of bonded tokens from one validator to another.
 #[derive(
     Debug,
@@ -151,6 +179,29 @@ pub struct Redelegation {
     pub amount: token::Amount,
 }
 
+/// A rebalancing of a delegator's bonds to a target distribution across
+/// validators.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Hash,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+pub struct RebalanceDelegations {
+    /// Owner (delegator) of the bonds to be rebalanced
+    pub owner: Address,
+    /// The target bond amount per validator. The bonds at validators that are
+    /// not listed are fully unbonded or redelegated.
+    pub targets: Vec<(Address, token::Amount)>,
+}
+
 /// A change to the validator commission rate.
 #[derive(
     Debug,
@@ -172,6 +223,28 @@ pub struct CommissionChange {
     pub new_rate: Dec,
 }
 
+/// A schedule of future changes to the validator commission rate.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Hash,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+pub struct CommissionScheduleChange {
+    /// Validator address
+    pub validator: Address,
+    /// The new commission rates and the epochs at which they take effect,
+    /// ordered by epoch. An empty schedule cancels the pending changes.
+    pub schedule: Vec<(Epoch, Dec)>,
+}
+
 /// A change to the validator metadata.
 #[derive(
     Debug,
@@ -203,6 +276,16 @@ pub struct MetaDataChange {
     pub name: Option<String>,
     /// Validator's commission rate
     pub commission_rate: Option<Dec>,
+    /// Validator's security contact
+    pub security_contact: Option<String>,
+    /// Validator's social handles to set, keyed by their platform. An empty
+    /// handle removes the platform's handle.
+    pub social_handles: BTreeMap<String, String>,
+    /// Hash of the validator's identity proof. A zero hash removes the proof.
+    pub identity_proof: Option<Hash>,
+    /// Validator's metadata extensions to set. An empty value removes the
+    /// extension.
+    pub extensions: BTreeMap<String, String>,
 }
 
 /// A change to the validator's consensus key.
@@ -226,14 +309,56 @@ pub struct ConsensusKeyChange {
     pub consensus_key: common::PublicKey,
 }
 
+/// A rotation of the validator's protocol key and Ethereum bridge keys.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Hash,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+pub struct ValidatorKeysChange {
+    /// Validator address
+    pub validator: Address,
+    /// The new protocol key, if it is being rotated
+    pub protocol_key: Option<common::PublicKey>,
+    /// The new Ethereum hot key, if it is being rotated
+    pub eth_hot_key: Option<secp256k1::PublicKey>,
+    /// The new Ethereum cold key, if it is being rotated
+    pub eth_cold_key: Option<secp256k1::PublicKey>,
+}
+
+impl ValidatorKeysChange {
+    /// The new keys that are being rotated in
+    pub fn new_keys(&self) -> Vec<common::PublicKey> {
+        self.protocol_key
+            .iter()
+            .cloned()
+            .chain(
+                [&self.eth_hot_key, &self.eth_cold_key]
+                    .into_iter()
+                    .flatten()
+                    .map(|pk| common::PublicKey::Secp256k1(pk.clone())),
+            )
+            .collect()
+    }
+}
+
 #[cfg(any(test, feature = "testing"))]
 /// Tests and strategies for proof-of-stake
 pub mod tests {
     use namada_core::address::testing::arb_non_internal_address;
     use namada_core::dec::testing::arb_dec;
+    use namada_core::hash::testing::arb_hash;
     use namada_core::key::testing::{arb_common_pk, arb_pk};
     use namada_core::token::testing::arb_amount;
-    use proptest::{option, prop_compose};
+    use proptest::{collection, option, prop_compose};
 
     use super::*;
 
@@ -289,6 +414,18 @@ pub mod tests {
             avatar in option::of("[a-zA-Z0-9_]*"),
             name in option::of("[a-zA-Z0-9_]*"),
             commission_rate in option::of(arb_dec()),
+            security_contact in option::of("[a-zA-Z0-9_]*"),
+            social_handles in collection::btree_map(
+                "[a-z0-9_]{1,10}",
+                "[a-zA-Z0-9_]*",
+                0..4,
+            ),
+            identity_proof in option::of(arb_hash()),
+            extensions in collection::btree_map(
+                "[a-z0-9_]{1,10}",
+                "[a-zA-Z0-9_]*",
+                0..4,
+            ),
         ) -> MetaDataChange {
             MetaDataChange {
                 validator,
@@ -299,6 +436,10 @@ pub mod tests {
                 avatar,
                 name,
                 commission_rate,
+                security_contact,
+                social_handles,
+                identity_proof,
+                extensions,
             }
         }
     }
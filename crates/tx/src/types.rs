@@ -1194,6 +1194,20 @@ impl Tx {
         None
     }
 
+    /// Get the bytes of the extra data section committing to the given code
+    /// hash, as opposed to the hash of the whole (salted) section
+    pub fn get_extra_data_by_code_hash(
+        &self,
+        code_hash: &namada_core::hash::Hash,
+    ) -> Option<Vec<u8>> {
+        self.sections.iter().find_map(|section| match section {
+            Section::ExtraData(data) if data.code.hash() == *code_hash => {
+                data.code.id()
+            }
+            _ => None,
+        })
+    }
+
     /// Get the transaction section with the given hash
     pub fn get_masp_section(&self, hash: &TxId) -> Option<&Transaction> {
         for section in &self.sections {
@@ -1,20 +1,31 @@
 //! Proof of Stake system integration with functions for transactions
 
+use std::collections::BTreeMap;
+
 use namada_core::dec::Dec;
+use namada_core::hash::Hash;
 use namada_core::{key, token};
+use namada_proof_of_stake::metadata::change_validator_structured_metadata;
 pub use namada_proof_of_stake::parameters::PosParams;
 pub use namada_proof_of_stake::queries::find_delegation_validators;
+use namada_proof_of_stake::rebalance::{
+    apply_rebalance_plan, plan_rebalance_delegations,
+};
 use namada_proof_of_stake::storage::read_pos_params;
-use namada_proof_of_stake::types::{ResultSlashing, ValidatorMetaData};
+use namada_proof_of_stake::types::{
+    RebalanceStep, ResultSlashing, ValidatorMetaData,
+};
 use namada_proof_of_stake::{
     become_validator, bond_tokens, change_consensus_key,
-    change_validator_commission_rate, change_validator_metadata,
-    claim_reward_tokens, deactivate_validator, reactivate_validator,
-    redelegate_tokens, unbond_tokens, unjail_validator, withdraw_tokens,
+    change_validator_commission_rate, change_validator_commission_schedule,
+    change_validator_keys, change_validator_metadata, claim_reward_tokens,
+    deactivate_validator, reactivate_validator, redelegate_tokens,
+    set_auto_compound, unbond_tokens, unjail_validator, withdraw_tokens,
 };
 pub use namada_proof_of_stake::{parameters, storage, storage_key, types};
 use namada_tx::action::{
-    Action, ClaimRewards, PosAction, Redelegation, Unbond, Withdraw, Write,
+    Action, AutoCompound, ClaimRewards, PosAction, Redelegation, Unbond,
+    Withdraw, Write,
 };
 use namada_tx::data::pos::{BecomeValidator, Bond};
 
@@ -105,6 +116,36 @@ impl Ctx {
         change_consensus_key(self, validator, consensus_key, current_epoch)
     }
 
+    /// Rotate the validator's protocol key and/or Ethereum bridge keys.
+    pub fn change_validator_keys(
+        &mut self,
+        validator: &Address,
+        protocol_key: Option<&common::PublicKey>,
+        eth_hot_key: Option<&key::secp256k1::PublicKey>,
+        eth_cold_key: Option<&key::secp256k1::PublicKey>,
+    ) -> TxResult {
+        // The tx must be authorized by the source address
+        self.insert_verifier(validator)?;
+
+        self.push_action(Action::Pos(PosAction::ValidatorKeysChange(
+            validator.clone(),
+        )))?;
+
+        let current_epoch = self.get_block_epoch()?;
+        let eth_hot_key =
+            eth_hot_key.map(|pk| common::PublicKey::Secp256k1(pk.clone()));
+        let eth_cold_key =
+            eth_cold_key.map(|pk| common::PublicKey::Secp256k1(pk.clone()));
+        change_validator_keys(
+            self,
+            validator,
+            protocol_key,
+            eth_hot_key.as_ref(),
+            eth_cold_key.as_ref(),
+            current_epoch,
+        )
+    }
+
     /// Change validator commission rate.
     pub fn change_validator_commission_rate(
         &mut self,
@@ -122,6 +163,28 @@ impl Ctx {
         change_validator_commission_rate(self, validator, *rate, current_epoch)
     }
 
+    /// Change validator commission schedule.
+    pub fn change_validator_commission_schedule(
+        &mut self,
+        validator: &Address,
+        schedule: Vec<(Epoch, Dec)>,
+    ) -> TxResult {
+        // The tx must be authorized by the source address
+        self.insert_verifier(validator)?;
+
+        self.push_action(Action::Pos(PosAction::CommissionChange(
+            validator.clone(),
+        )))?;
+
+        let current_epoch = self.get_block_epoch()?;
+        change_validator_commission_schedule(
+            self,
+            validator,
+            schedule,
+            current_epoch,
+        )
+    }
+
     /// Unjail a jailed validator and re-enter the validator sets.
     pub fn unjail_validator(&mut self, validator: &Address) -> TxResult {
         // The tx must be authorized by the source address
@@ -162,6 +225,52 @@ impl Ctx {
         )
     }
 
+    /// Rebalance the bonds of the `owner` to the given target amounts per
+    /// validator with redelegations, unbonds and bonds, applied atomically.
+    pub fn rebalance_delegations(
+        &mut self,
+        owner: &Address,
+        targets: &[(Address, token::Amount)],
+    ) -> TxResult {
+        // The tx must be authorized by the source address
+        self.insert_verifier(owner)?;
+
+        let current_epoch = self.get_block_epoch()?;
+        let plan =
+            plan_rebalance_delegations(self, owner, targets, current_epoch)?;
+        for step in &plan {
+            let action = match step.clone() {
+                RebalanceStep::Redelegate {
+                    src_validator,
+                    dest_validator,
+                    amount,
+                } => PosAction::Redelegation(Redelegation {
+                    src_validator,
+                    dest_validator,
+                    owner: owner.clone(),
+                    amount,
+                }),
+                RebalanceStep::Unbond { validator, amount } => {
+                    PosAction::Unbond(Unbond {
+                        validator,
+                        amount,
+                        source: Some(owner.clone()),
+                    })
+                }
+                RebalanceStep::Bond { validator, amount } => {
+                    PosAction::Bond(Bond {
+                        validator,
+                        amount,
+                        source: Some(owner.clone()),
+                    })
+                }
+            };
+            self.push_action(Action::Pos(action))?;
+        }
+
+        apply_rebalance_plan(self, owner, &plan, current_epoch)
+    }
+
     /// Claim available reward tokens
     pub fn claim_reward_tokens(
         &mut self,
@@ -181,6 +290,27 @@ impl Ctx {
         claim_reward_tokens(self, source, validator, current_epoch)
     }
 
+    /// Opt in or out of the automatic re-bonding of the rewards of a bond
+    pub fn set_auto_compound(
+        &mut self,
+        source: Option<&Address>,
+        validator: &Address,
+        enabled: bool,
+    ) -> TxResult {
+        // The tx must be authorized by the source address
+        let verifier = source.as_ref().unwrap_or(&validator);
+        self.insert_verifier(verifier)?;
+
+        self.push_action(Action::Pos(PosAction::AutoCompound(AutoCompound {
+            validator: validator.clone(),
+            source: source.cloned(),
+            enabled,
+        })))?;
+
+        let current_epoch = self.get_block_epoch()?;
+        set_auto_compound(self, source, validator, enabled, current_epoch)
+    }
+
     /// Attempt to initialize a validator account. On success, returns the
     /// initialized validator account's address.
     pub fn become_validator(
@@ -300,4 +430,31 @@ impl Ctx {
             current_epoch,
         )
     }
+
+    /// Change the structured fields of validator metadata and validate the
+    /// resulting metadata.
+    pub fn change_validator_structured_metadata(
+        &mut self,
+        validator: &Address,
+        security_contact: Option<String>,
+        social_handles: BTreeMap<String, String>,
+        identity_proof: Option<Hash>,
+        extensions: BTreeMap<String, String>,
+    ) -> TxResult {
+        // The tx must be authorized by the source address
+        self.insert_verifier(validator)?;
+
+        self.push_action(Action::Pos(PosAction::MetadataChange(
+            validator.clone(),
+        )))?;
+
+        change_validator_structured_metadata(
+            self,
+            validator,
+            security_contact,
+            social_handles,
+            identity_proof,
+            extensions,
+        )
+    }
 }
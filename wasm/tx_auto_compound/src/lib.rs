@@ -0,0 +1,19 @@
+//! A tx for a user to opt in or out of the automatic re-bonding of the PoS
+//! inflationary rewards of a bond at every new epoch.
+
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let auto_compound =
+        transaction::pos::AutoCompound::try_from_slice(&data[..])
+            .wrap_err("Failed to decode AutoCompound value")?;
+
+    ctx.set_auto_compound(
+        auto_compound.source.as_ref(),
+        &auto_compound.validator,
+        auto_compound.enabled,
+    )
+    .wrap_err("Failed to set rewards auto-compounding")
+}
@@ -0,0 +1,17 @@
+//! A tx for a validator to publish a schedule of future changes to their
+//! commission rate for PoS rewards.
+
+use namada_tx_prelude::transaction::pos::CommissionScheduleChange;
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let CommissionScheduleChange {
+        validator,
+        schedule,
+    } = transaction::pos::CommissionScheduleChange::try_from_slice(&data[..])
+        .wrap_err("Failed to decode CommissionScheduleChange value")?;
+    ctx.change_validator_commission_schedule(&validator, schedule)
+        .wrap_err("Failed to change validator's commission schedule")
+}
@@ -0,0 +1,35 @@
+//! A tx for a validator to rotate their protocol and Ethereum bridge keys.
+
+use booleans::ResultBoolExt;
+use namada_tx_prelude::transaction::pos::ValidatorKeysChange;
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let keys_change = ValidatorKeysChange::try_from_slice(&data[..])
+        .wrap_err("Failed to decode ValidatorKeysChange value")?;
+
+    // Check that the tx has been signed with all the new keys
+    verify_signatures_of_pks(&tx_data.tx, keys_change.new_keys())
+        .true_or_else(|| {
+            const ERR_MSG: &str =
+                "Validator keys ownership signature verification failed";
+            debug_log!("{ERR_MSG}");
+            Error::new_const(ERR_MSG)
+        })?;
+
+    let ValidatorKeysChange {
+        validator,
+        protocol_key,
+        eth_hot_key,
+        eth_cold_key,
+    } = keys_change;
+    ctx.change_validator_keys(
+        &validator,
+        protocol_key.as_ref(),
+        eth_hot_key.as_ref(),
+        eth_cold_key.as_ref(),
+    )
+    .wrap_err("Failed to change validator keys")
+}
@@ -16,6 +16,10 @@ fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
         avatar,
         name,
         commission_rate,
+        security_contact,
+        social_handles,
+        identity_proof,
+        extensions,
     } = transaction::pos::MetaDataChange::try_from_slice(&data[..])
         .wrap_err("Failed to decode MetaDataChange value")?;
     ctx.change_validator_metadata(
@@ -28,5 +32,13 @@ fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
         name,
         commission_rate,
     )
-    .wrap_err("Failed to update validator's metadata")
+    .wrap_err("Failed to update validator's metadata")?;
+    ctx.change_validator_structured_metadata(
+        &validator,
+        security_contact,
+        social_handles,
+        identity_proof,
+        extensions,
+    )
+    .wrap_err("Failed to update validator's structured metadata")
 }
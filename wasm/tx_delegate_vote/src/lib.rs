@@ -0,0 +1,24 @@
+//! A tx to delegate the governance voting power of an account to a
+//! representative, or to revoke such a delegation
+
+use namada_tx_prelude::action::{Action, GovAction, Write};
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let tx_data = governance::DelegateVoteData::try_from_slice(&data[..])
+        .wrap_err("Failed to decode DelegateVoteData value")?;
+
+    // The tx must be authorized by the delegator
+    ctx.insert_verifier(&tx_data.delegator)?;
+
+    ctx.push_action(Action::Gov(GovAction::DelegateVote {
+        delegator: tx_data.delegator.clone(),
+    }))?;
+
+    debug_log!("apply_tx called to delegate governance voting power");
+
+    governance::delegate_vote(ctx, tx_data)
+        .wrap_err("Failed to delegate governance voting power")
+}
@@ -53,12 +53,29 @@ fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
         .transpose()
         .wrap_err("Failed to retrieve proposal code")?;
 
+    // Get the wasm codes added to the allowlists by their hash
+    let wasm_codes = tx_data
+        .get_wasm_allowlist_code_hashes()
+        .iter()
+        .map(|hash| {
+            tx.get_extra_data_by_code_hash(hash)
+                .ok_or_err_msg("Missing wasm allowlist code")
+                .map_err(|err| {
+                    ctx.set_commitment_sentinel();
+                    err
+                })
+        })
+        .collect::<EnvResult<Vec<_>>>()
+        .wrap_err("Failed to retrieve wasm allowlist codes")?;
+
     log_string("apply_tx called to create a new governance proposal");
 
     let proposal_id = governance::init_proposal::<_, token::Store<_>>(
         ctx, &tx_data, content, code,
     )
     .wrap_err("Failed to initialize new governance proposal")?;
+    governance::init_proposal_wasm_codes(ctx, proposal_id, wasm_codes)
+        .wrap_err("Failed to write wasm allowlist codes")?;
 
     ctx.emit_event(GovernanceEvent::new_proposal(proposal_id, tx_data.r#type))
 }
@@ -0,0 +1,14 @@
+//! A tx for a delegator (non-validator bond owner) to rebalance their bonds to
+//! a target distribution across validators.
+
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let transaction::pos::RebalanceDelegations { owner, targets } =
+        transaction::pos::RebalanceDelegations::try_from_slice(&data[..])
+            .wrap_err("Failed to decode a RebalanceDelegations tx data")?;
+    ctx.rebalance_delegations(&owner, &targets)
+        .wrap_err("Failed to rebalance delegations")
+}
@@ -0,0 +1,30 @@
+//! A tx for a validator to veto a passed proposal waiting in the timelock
+//! queue
+
+use namada_tx_prelude::action::{Action, GovAction, Write};
+use namada_tx_prelude::governance::event::GovernanceEvent;
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let tx_data = governance::VetoProposalData::try_from_slice(&data[..])
+        .wrap_err("Failed to decode VetoProposalData value")?;
+
+    // The tx must be authorized by the vetoing validator
+    ctx.insert_verifier(&tx_data.validator)?;
+
+    ctx.push_action(Action::Gov(GovAction::VetoProposal {
+        id: tx_data.id,
+        validator: tx_data.validator.clone(),
+    }))?;
+
+    debug_log!("apply_tx called to veto a timelocked governance proposal");
+
+    let veto_event =
+        GovernanceEvent::timelock_veto(tx_data.id, tx_data.validator.clone());
+    governance::veto_proposal(ctx, tx_data)
+        .wrap_err("Failed to veto governance proposal")?;
+
+    ctx.emit_event(veto_event)
+}
@@ -1,6 +1,7 @@
 //! A tx to vote on a proposal
 
 use namada_tx_prelude::action::{Action, GovAction, Write};
+use namada_tx_prelude::governance::event::GovernanceEvent;
 use namada_tx_prelude::proof_of_stake::find_delegation_validators;
 use namada_tx_prelude::*;
 
@@ -27,6 +28,13 @@ fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
     let delegation_targets =
         find_delegation_validators(ctx, &tx_data.voter, &current_epoch)?;
 
+    let vote_event = GovernanceEvent::vote_cast(
+        tx_data.id,
+        tx_data.voter.clone(),
+        tx_data.vote.clone(),
+    );
     governance::vote_proposal(ctx, tx_data, delegation_targets)
-        .wrap_err("Failed to vote on governance proposal")
+        .wrap_err("Failed to vote on governance proposal")?;
+
+    ctx.emit_event(vote_event)
 }